@@ -1,13 +1,14 @@
 use crate::constants::GAME_TICK_RATE_MS;
-use crate::model::arrow::Arrow;
+use crate::model::arrow::{Arrow, ArrowHit};
 use crate::model::attack_pattern::AnimationFrame;
-use crate::model::audio::{AudioManager, SoundEffect};
+use crate::model::audio::{Audio, AudioManager, NullAudio, SoundEffect};
 use crate::model::character::Character;
 use crate::model::floor::Floor;
 use crate::model::gamesave::{GameSave, PlayerStats};
 use crate::model::particle::ParticleSystem;
 use crate::model::pathfinding_cache::PathfindingCache;
 use crate::model::settings::Settings;
+use crate::model::tutorial::{Tutorial, TutorialStep};
 use crate::model::ultimate_shop::UltimateShop;
 use crate::ui::ultimate_shop::UltimateShopUI;
 use rand::RngExt;
@@ -27,6 +28,7 @@ pub enum AppState {
     UltimateShop,
     DeathScreen,
     VictoryScreen,
+    Records,
 }
 
 #[derive(PartialEq)]
@@ -41,6 +43,16 @@ pub enum PauseSubmenu {
     Settings,
 }
 
+/// Pending choice when a weapon is found while the inventory is full.
+/// Holds the ground weapon until the player swaps it in or leaves it.
+pub struct WeaponSwapPrompt {
+    pub weapon: crate::model::weapon::Weapon,
+    pub selection: usize, // Slot 0-8, or WEAPON_SWAP_LEAVE to leave it on the floor
+}
+
+/// Selection index of the "leave it" option in the weapon swap prompt
+pub const WEAPON_SWAP_LEAVE: usize = 9;
+
 /// Attack animation categories for ASCII character filtering
 #[derive(Clone, Debug, PartialEq, Copy)]
 pub enum AnimationCategory {
@@ -128,16 +140,19 @@ pub struct App {
     pub settings: Settings,
     pub temp_settings: Settings,
     pub settings_mode: SettingsMode,
+    pub settings_save_confirm: bool, // SAVE was pressed once with conflicting keybinds
+    pub settings_status: Option<String>, // Result of the last settings export/import
     pub main_menu_state: ListState,
     pub settings_state: ListState,
     pub should_quit: bool,
     pub scroll_offset: u16,
     pub last_scroll_offset: u16,
     pub start_time: Instant,
-    pub frame_count: u64,
+    pub fps_counter: crate::model::cooldown::FpsCounter,
     pub is_auto_scrolling: bool,
     pub scroll_target: f32,
     pub dev_seed_input: String,
+    pub fixed_seed: Option<u64>, // Every floor generates from this plus its level (headless runs)
     pub current_floor: Option<Floor>,
     pub walkable_tiles_cache: Option<std::collections::HashSet<(i32, i32)>>,
     pub character_position: (i32, i32),
@@ -153,10 +168,16 @@ pub struct App {
     pub inventory_focused: bool,
     pub inventory_scroll_index: usize,
     pub showing_item_description: bool,
+    pub showing_help: bool, // Controls overlay is open; swallows game input
     pub is_paused: bool,
     pub particle_system: ParticleSystem,
     pub pathfinding_cache: PathfindingCache,
     pub movement_tick_counter: u32,
+    pub key_releases_supported: bool, // Terminal reports key releases, so held keys can be tracked
+    pub held_directions: Vec<(i32, i32)>, // Movement keys currently held, most recent last
+    pub held_move_elapsed: f32,       // Game seconds since the last held-key step
+    pub attack_trigger: crate::model::cooldown::HeldTrigger, // Auto-fire while attack is held
+    pub hitstop_timer: crate::model::cooldown::HitStop, // Freeze-frame after a heavy hit
     pub char_name: String,
     pub char_name_input_mode: bool,
     pub char_creation_selection: usize,
@@ -176,23 +197,85 @@ pub struct App {
     pub pause_rebinding_mode: SettingsMode,  // Rebinding mode for pause settings
     pub music_volume: f32,                   // Music volume 0.0 - 1.0
     pub sound_volume: f32,                   // Sound effects volume 0.0 - 1.0
-    pub audio_manager: AudioManager,         // Audio playback manager
+    pub audio_manager: Box<dyn Audio>,       // Audio playback manager
     pub max_levels: u32,                     // Maximum levels before boss based on difficulty
     pub is_boss_level: bool,                 // Whether current level is a boss fight
+    pub boss_summoner: crate::model::boss::BossSummoner, // Cooldown for the boss calling in adds
+    pub adaptive_difficulty: crate::model::adaptive_difficulty::AdaptiveDifficulty, // Enemy scaling
     pub victory_win_time: f32,               // Time elapsed when victory occurred
+    pub victory_score: u32,                  // Score of the run shown on the victory screen
+    pub best_score: u32,                     // Best victory score for this save
+    pub gold_recovery: Option<crate::model::gamesave::GoldRecovery>, // Gold dropped on the last death
+    pub new_game_plus: u32, // New Game+ cycle of the current run, 0 for a first run
+    pub screen_flash_timer: f32, // Seconds left on the flash after a boss dies
     pub last_weapon_pickup: Option<(String, crate::model::item_rarity::ItemRarity)>, // Weapon name and rarity
     pub weapon_pickup_timer: f32, // Timer for weapon pickup notification display
+    pub stairs_requested: bool,   // Interact pressed on the stairs, consumed by the next update
+    pub weapon_swap_prompt: Option<WeaponSwapPrompt>, // Full-inventory swap choice, pauses the game
+    pub confirm_drop_all: bool,   // Asking before dropping every weapon but the equipped one
+    pub confirm_scrap: bool,      // Asking before scrapping the equipped weapon for gold
+    pub confirm_quit: bool,       // Asking before the pause menu's Quit ends the run
     pub empty_slot_message_timer: f32, // Timer for empty weapon slot warning message
+    pub mouse_attack_held: bool,  // Left mouse button is down (holds bow charge)
+    pub mouse_position: Option<(u16, u16)>, // Last cell the mouse moved over, for examining
+    pub examining: bool,          // Examine mode: tooltip on the nearest enemy in sight
+    pub last_move_at: Option<Instant>, // When the player last stepped (for the auto-face assist)
+    pub attack_facing: Option<(i32, i32)>, // Auto-face override for the current attack
     pub ultimate_shop: UltimateShop, // The shop system for ultimates and upgrades
     pub ultimate_shop_ui: UltimateShopUI, // UI state for the ultimate shop
     pub save_selection_state: ListState, // For save selection menu navigation
     pub available_saves: Vec<String>, // List of available save files
-    pub auto_save_timer: f32,     // Timer for auto-saving the game
+    pub save_load_error: Option<String>, // Why the last save picked on the load screen failed
+    pub run_history: crate::model::run_history::RunHistory, // Past runs, read when Records opens
+    pub run_history_file: std::path::PathBuf, // Where finished runs are recorded
+    pub records_state: ListState, // For Records screen navigation
+    pub auto_save_timer: crate::model::cooldown::IntervalTimer, // Timer for auto-saving the game
+    pub map_reveal: crate::model::cooldown::CountdownTimer, // Scrying Eye reveal window
+    pub save_toast_timer: f32,    // Seconds left on the "Saved" notification
+    pub scrap_toast: Option<(u32, f32)>, // Scrap gold and seconds left on its toast
+    pub run_mode: crate::model::settings::RunMode, // Locked in when the run starts
+    pub starting_class: crate::model::loadout::StartingClass, // Picked at character creation
+    pub tutorial: Option<Tutorial>, // Lesson progress while playing the tutorial floor
+    pub dev_instant_advance: bool, // Dev: skip the stairs and advance on the last kill
+    pub dev_god_mode: bool,       // Dev: player takes no damage
+    pub dev_freeze_enemies: bool, // Dev: enemies neither move nor attack
+    pub dev_status: Option<String>, // Dev: result of the last export, import or spawn
+    pub dev_enemy_template: usize, // Dev: index into all_enemy_templates() for the spawner
+    pub dev_pattern_preview: bool, // Dev: show the static attack pattern grid
+    pub dev_show_detection: bool, // Dev: draw enemy detection radii on dev-played floors
+    pub dev_preview_direction: usize, // Dev: index into the preview's eight facings
+    pub dev_play_active: bool,    // Current floor was entered from the dev menu
+    pub fog_origin: Option<(i32, i32)>, // Player position fog of war was last computed from
+}
+
+/// Audio for `settings`: nothing at all when muted, the sound device otherwise
+fn audio_backend(settings: &Settings) -> Box<dyn Audio> {
+    if settings.mute_audio {
+        Box::new(NullAudio)
+    } else {
+        Box::new(AudioManager::new())
+    }
+}
+
+impl Default for App {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl App {
     pub fn new() -> Self {
-        let s = Settings::load();
+        let settings = Settings::load();
+        let mut audio_manager = audio_backend(&settings);
+
+        // Start music with fade-in on app startup
+        let _ = audio_manager.start_music_with_fade_in();
+
+        Self::with_parts(settings, audio_manager)
+    }
+
+    /// Build the app around settings and audio that are already set up
+    fn with_parts(s: Settings, audio_mgr: Box<dyn Audio>) -> Self {
         let mut menu_s = ListState::default();
         menu_s.select(Some(0));
         let mut set_s = ListState::default();
@@ -203,16 +286,14 @@ impl App {
         save_s.select(Some(0));
 
         let now = Instant::now();
-        let mut audio_mgr = AudioManager::new();
-
-        // Start music with fade-in on app startup
-        let _ = audio_mgr.start_music_with_fade_in();
 
         Self {
             state: AppState::MainMenu,
             settings: s.clone(),
             temp_settings: s.clone(),
             settings_mode: SettingsMode::Navigating,
+            settings_save_confirm: false,
+            settings_status: None,
             main_menu_state: menu_s,
             settings_state: set_s,
             should_quit: false,
@@ -221,8 +302,9 @@ impl App {
             scroll_target: 60.0,
             last_scroll_offset: 0,
             start_time: now,
-            frame_count: 0,
+            fps_counter: Default::default(),
             dev_seed_input: String::new(),
+            fixed_seed: None,
             current_floor: None,
             walkable_tiles_cache: None,
             character_position: (0, 0),
@@ -238,6 +320,7 @@ impl App {
             inventory_focused: false,
             inventory_scroll_index: 0,
             showing_item_description: false,
+            showing_help: false,
             is_paused: false,
             particle_system: ParticleSystem::new(),
             pathfinding_cache: PathfindingCache::new(500),
@@ -245,6 +328,11 @@ impl App {
             char_name_input_mode: false,
             char_creation_selection: 0,
             movement_tick_counter: 0,
+            key_releases_supported: false,
+            held_directions: Vec::new(),
+            held_move_elapsed: 0.0,
+            attack_trigger: Default::default(),
+            hitstop_timer: Default::default(),
             dev_attack_pattern: crate::model::attack_pattern::AttackPattern::BasicSlash,
             active_animations: Vec::new(),
             skill_tree_selection: Some(0), // Initialize for skill tree UI
@@ -264,15 +352,52 @@ impl App {
             audio_manager: audio_mgr,
             max_levels: 5, // Default, will be updated when game starts
             is_boss_level: false,
+            boss_summoner: Default::default(),
+            adaptive_difficulty: Default::default(),
             victory_win_time: 0.0,
+            victory_score: 0,
+            best_score: 0,
+            gold_recovery: None,
+            new_game_plus: 0,
+            screen_flash_timer: 0.0,
             last_weapon_pickup: None,
             weapon_pickup_timer: 0.0,
+            stairs_requested: false,
+            weapon_swap_prompt: None,
+            confirm_drop_all: false,
+            confirm_scrap: false,
+            confirm_quit: false,
             empty_slot_message_timer: 0.0,
+            mouse_attack_held: false,
+            mouse_position: None,
+            examining: false,
+            last_move_at: None,
+            attack_facing: None,
             ultimate_shop: UltimateShop::new(),
             ultimate_shop_ui: UltimateShopUI::new(),
             save_selection_state: save_s,
             available_saves: Vec::new(),
-            auto_save_timer: 0.0,
+            save_load_error: None,
+            run_history: Default::default(),
+            run_history_file: crate::constants::RUN_HISTORY_FILE.into(),
+            records_state: ListState::default(),
+            auto_save_timer: Default::default(),
+            map_reveal: Default::default(),
+            save_toast_timer: 0.0,
+            scrap_toast: None,
+            run_mode: s.run_mode.clone(),
+            starting_class: Default::default(),
+            tutorial: None,
+            dev_instant_advance: false,
+            dev_god_mode: false,
+            dev_freeze_enemies: false,
+            dev_status: None,
+            dev_enemy_template: 0,
+            dev_pattern_preview: false,
+            dev_show_detection: false,
+            dev_preview_direction: 0,
+            dev_play_active: false,
+            fog_origin: None,
         }
     }
 
@@ -294,13 +419,7 @@ impl App {
 
     /// Get difficulty scaling multiplier for enemy health and damage
     pub fn get_enemy_difficulty_multiplier(&self) -> f32 {
-        use crate::model::item_tier::Difficulty;
-        match &self.settings.difficulty {
-            Difficulty::Easy => 0.8,
-            Difficulty::Normal => 1.0,
-            Difficulty::Hard => 1.4,
-            Difficulty::Death => 2.0,
-        }
+        self.settings.difficulty.enemy_stat_multiplier()
     }
 
     /// Classify an attack pattern into a category for ASCII filter rendering
@@ -331,6 +450,20 @@ impl App {
         }
     }
 
+    /// Move the focused inventory's selection by `delta` rows, staying on an item
+    pub fn scroll_inventory(&mut self, delta: i32) {
+        let last = self
+            .character
+            .consumable_inventory
+            .items
+            .len()
+            .saturating_sub(1);
+        self.inventory_scroll_index = self
+            .inventory_scroll_index
+            .saturating_add_signed(delta as isize)
+            .min(last);
+    }
+
     pub fn set_scroll(&mut self, offset: u16) {
         let max_scroll = 60;
         self.scroll_offset = offset.min(max_scroll);
@@ -366,24 +499,42 @@ impl App {
     }
 
     pub fn regenerate_floor(&mut self) {
+        self.fog_origin = None;
+        self.map_reveal.clear();
+
         // Use a random seed if dev_seed_input is empty, otherwise use the specified seed
-        let seed = if self.dev_seed_input.is_empty() {
+        let seed = if let Some(seed) = self.fixed_seed {
+            seed.wrapping_add(self.floor_level as u64)
+        } else if self.dev_seed_input.is_empty() {
             use rand::{Rng, RngExt};
             rand::rng().random_range(0..=u64::MAX)
         } else {
             self.dev_seed_input.parse::<u64>().unwrap_or(0)
         };
 
-        let mut floor = Floor::new(180, 60, seed);
+        // Never generate a floor smaller than the view it is drawn in
+        let viewport = (self.terminal_size.0, self.terminal_size.1.saturating_sub(2));
+        let mut floor = Floor::with_params(&self.settings.floor_gen.clamped(viewport), seed);
+        floor.set_theme(crate::model::floor_theme::FloorTheme::for_level(
+            self.floor_level,
+        ));
 
         let difficulty = self.settings.difficulty.clone();
-        floor.spawn_random_items(10, &difficulty);
+        let player_spawn = floor.find_player_spawn();
+        floor.spawn_random_items(10, &difficulty, player_spawn);
+        floor.place_spike_traps(floor.theme.spike_traps(), player_spawn);
+        let is_boss_level = self.is_current_level_boss();
+        if let Some(spawn) = player_spawn {
+            if floor.choose_treasure_room(spawn).is_some() {
+                floor.stock_treasure_room(&difficulty, !is_boss_level);
+            }
+        }
 
         // Check if this is a boss level
-        if self.is_current_level_boss() {
+        if is_boss_level {
             // Spawn a boss instead of regular enemies
             use crate::model::boss::BossType;
-            use rand::Rng;
+            use rand::{rngs::StdRng, SeedableRng};
 
             let boss_types = [
                 BossType::GoblinOverlord,
@@ -393,24 +544,158 @@ impl App {
                 BossType::CorruptedWarden,
             ];
 
-            let random_boss = boss_types[rand::rng().random_range(0..boss_types.len())];
-            floor.spawn_boss(random_boss);
+            // Drawn from the floor seed so a boss floor replays with the same boss
+            let mut rng = StdRng::seed_from_u64(seed);
+            let random_boss = boss_types[rng.random_range(0..boss_types.len())];
+            floor.spawn_boss(random_boss, &difficulty);
             self.is_boss_level = true;
+            self.boss_summoner = Default::default();
+            let _ = self.audio_manager.start_boss_music_with_fade();
+        } else if self.settings.room_enemy_placement {
+            // Regular floor with guards posted in rooms, kept clear of the player
+            floor.spawn_enemies_in_rooms(&difficulty, player_spawn);
+            self.is_boss_level = false;
         } else {
             // Regular floor with normal enemies
-            floor.spawn_enemies(&difficulty);
+            floor.spawn_enemies(&difficulty, player_spawn);
             self.is_boss_level = false;
         }
 
+        if !is_boss_level {
+            let _ = self.audio_manager.restore_normal_music();
+        }
+        floor.scale_enemies_for_new_game_plus(self.new_game_plus);
+        if self.settings.adaptive_difficulty {
+            floor.scale_enemies(self.adaptive_difficulty.multiplier());
+        }
+        self.adaptive_difficulty.start_floor();
+
         self.current_floor = Some(floor);
         self.walkable_tiles_cache = None; // Invalidate cache for new floor
         self.player_has_acted = false; // Reset action state for new level
+        self.stairs_requested = false; // An interact press from the last floor doesn't carry over
 
-        if let Some(floor) = &self.current_floor {
-            if let Some((x, y)) = floor.find_player_spawn() {
-                self.character_position = (x, y);
-                self.update_camera();
+        if let Some((x, y)) = player_spawn {
+            self.character_position = (x, y);
+            self.update_camera();
+        }
+    }
+
+    /// Write the current floor's seed, difficulty and level to the floor code file
+    pub fn export_floor_code(&mut self) {
+        let Some(floor) = &self.current_floor else {
+            self.dev_status = Some("No floor to export".to_string());
+            return;
+        };
+        let code = crate::model::floor_code::FloorCode {
+            seed: floor.seed,
+            difficulty: self.settings.difficulty.clone(),
+            floor_level: self.floor_level,
+        }
+        .encode();
+
+        let file = crate::constants::FLOOR_CODE_FILE;
+        self.dev_status = Some(match std::fs::write(file, &code) {
+            Ok(()) => format!("Exported {} to {}", code, file),
+            Err(e) => format!("Export failed: {}", e),
+        });
+    }
+
+    /// Read a floor code from the floor code file and regenerate that floor
+    pub fn import_floor_code(&mut self) {
+        use crate::model::floor_code::FloorCode;
+
+        let file = crate::constants::FLOOR_CODE_FILE;
+        let code = std::fs::read_to_string(file)
+            .map_err(|e| format!("Could not read {}: {}", file, e))
+            .and_then(|text| FloorCode::parse(&text));
+        match code {
+            Ok(code) => {
+                self.dev_seed_input = code.seed.to_string();
+                self.settings.difficulty = code.difficulty.clone();
+                self.floor_level = code.floor_level;
+                self.regenerate_floor();
+                self.dev_status = Some(format!("Imported {}", code.encode()));
             }
+            Err(e) => self.dev_status = Some(e),
+        }
+    }
+
+    /// Whether to draw enemy detection radii. Limited to floors played from the dev menu so
+    /// normal runs never show where enemies can see.
+    pub fn detection_overlay_active(&self) -> bool {
+        self.dev_show_detection && self.dev_play_active
+    }
+
+    /// Step the dev spawner's selected enemy template, wrapping at both ends
+    pub fn cycle_dev_enemy_template(&mut self, direction: i32) {
+        let count = crate::model::enemy_type::all_enemy_templates().len() as i32;
+        self.dev_enemy_template =
+            (self.dev_enemy_template as i32 + direction).rem_euclid(count) as usize;
+    }
+
+    /// Tile right in front of the player, where dev spawns appear
+    fn dev_spawn_tile(&self) -> (i32, i32) {
+        let (dx, dy) = match self.character.last_direction {
+            (0, 0) => (0, 1),
+            direction => direction,
+        };
+        (
+            self.character_position.0 + dx,
+            self.character_position.1 + dy,
+        )
+    }
+
+    /// Spawn the selected template on the tile the player faces, scaled for the
+    /// current difficulty
+    pub fn dev_spawn_selected_enemy(&mut self) {
+        let templates = crate::model::enemy_type::all_enemy_templates();
+        let template = &templates[self.dev_enemy_template % templates.len()];
+        let (x, y) = self.dev_spawn_tile();
+
+        let Some(floor) = &mut self.current_floor else {
+            self.dev_status = Some("Generate a floor first".to_string());
+            return;
+        };
+        if !floor.is_walkable(x, y) || floor.enemy_exists_at(x, y) {
+            self.dev_status = Some(format!("Tile ({}, {}) is blocked", x, y));
+            return;
+        }
+        let difficulty = &self.settings.difficulty;
+        floor
+            .enemies
+            .push(Floor::enemy_from_template(template, x, y, difficulty));
+        self.dev_status = Some(format!("Spawned {} at ({}, {})", template.name, x, y));
+    }
+
+    /// Put a training dummy in front of the player, or take away the ones already placed
+    pub fn dev_toggle_training_dummy(&mut self) {
+        let (x, y) = self.dev_spawn_tile();
+        let Some(floor) = &mut self.current_floor else {
+            self.dev_status = Some("Generate a floor first".to_string());
+            return;
+        };
+        let count = floor.enemies.len();
+        floor.enemies.retain(|enemy| enemy.dummy.is_none());
+        if floor.enemies.len() < count {
+            self.dev_status = Some("Removed the training dummy".to_string());
+            return;
+        }
+        if !floor.is_walkable(x, y) {
+            self.dev_status = Some(format!("Tile ({}, {}) is blocked", x, y));
+            return;
+        }
+        floor
+            .enemies
+            .push(crate::model::enemy::Enemy::training_dummy(x, y));
+        self.dev_status = Some(format!("Training dummy placed at ({}, {})", x, y));
+    }
+
+    pub fn dev_clear_enemies(&mut self) {
+        if let Some(floor) = &mut self.current_floor {
+            let count = floor.enemies.len();
+            floor.enemies.clear();
+            self.dev_status = Some(format!("Cleared {} enemies", count));
         }
     }
 
@@ -425,6 +710,12 @@ impl App {
         self.terminal_size = (width, height);
     }
 
+    /// The in-game layout needs a minimum size; below it the run is frozen and a notice drawn instead
+    pub fn terminal_too_small(&self) -> bool {
+        self.terminal_size.0 < crate::constants::MIN_TERMINAL_WIDTH
+            || self.terminal_size.1 < crate::constants::MIN_TERMINAL_HEIGHT
+    }
+
     pub fn is_walkable(&self, x: i32, y: i32) -> bool {
         if let Some(floor) = &self.current_floor {
             // Hard boundary check - prevent any movement out of bounds
@@ -437,6 +728,11 @@ impl App {
                 return false;
             }
 
+            // Ghost lets the player pass through enemies
+            if self.character.is_ghost() {
+                return true;
+            }
+
             // Check if an enemy is at this position
             for enemy in &floor.enemies {
                 if enemy.is_alive() && enemy.position.x == x && enemy.position.y == y {
@@ -468,6 +764,12 @@ impl App {
     /// Check if the player is dead and transition to death screen if necessary
     pub fn check_and_handle_death(&mut self) {
         if self.character.health <= 0 && self.state == AppState::Game {
+            // Nobody dies in the tutorial: patch the player up and carry on
+            if self.tutorial.is_some() {
+                self.character.health = self.character.health_max;
+                return;
+            }
+
             // Record death stats
             self.death_time_elapsed = if let Some(started_at) = self.game_started_at {
                 started_at.elapsed().as_secs_f32()
@@ -476,8 +778,22 @@ impl App {
             };
             self.levels_passed_before_death = self.floor_level.saturating_sub(1);
 
-            // Auto-save the current game state before death (for retry floor functionality)
-            self.auto_save();
+            if self.settings.adaptive_difficulty {
+                self.adaptive_difficulty.player_died();
+            }
+
+            match self.run_mode {
+                crate::model::settings::RunMode::Checkpoint => {
+                    self.drop_gold_on_death();
+                    // Auto-save the current game state before death (for retry floor functionality)
+                    self.auto_save();
+                }
+                crate::model::settings::RunMode::Permadeath => {
+                    self.record_death();
+                    let _ = GameSave::delete_save(&self.character.name);
+                    self.available_saves = GameSave::list_saves().unwrap_or_default();
+                }
+            }
 
             // Reset fade timer for death screen animation
             self.death_screen_fade_timer = 0.0;
@@ -491,11 +807,178 @@ impl App {
         }
     }
 
+    /// Record the death just shown as the end of the run. Checkpoint runs only end once
+    /// the player leaves the death screen instead of retrying the floor.
+    pub fn record_death(&self) {
+        self.record_run(
+            crate::model::run_history::RunOutcome::Death,
+            self.levels_passed_before_death,
+            self.death_time_elapsed,
+        );
+    }
+
+    /// Append the run that just ended to the history shown on the Records screen
+    fn record_run(
+        &self,
+        outcome: crate::model::run_history::RunOutcome,
+        floors_cleared: u32,
+        time_elapsed: f32,
+    ) {
+        use crate::model::run_history::{RunHistory, RunRecord};
+
+        let _ = RunHistory::append_to(
+            &self.run_history_file,
+            RunRecord::new(
+                &self.character,
+                self.settings.difficulty.name(),
+                floors_cleared,
+                time_elapsed,
+                outcome,
+            ),
+        );
+    }
+
+    /// Swap the audio backend after the mute setting changed. Unmuting opens the sound
+    /// device again and fades the music back in.
+    pub fn apply_audio_mute(&mut self) {
+        if self.settings.mute_audio == self.audio_manager.is_silent() {
+            return;
+        }
+        self.audio_manager = audio_backend(&self.settings);
+        self.audio_manager.set_sound_volume(self.sound_volume);
+        let _ = self.audio_manager.start_music_with_fade_in();
+    }
+
+    /// The terminal lost focus: close the quit prompt rather than leave it waiting on
+    /// keys the player can't see being typed
+    pub fn focus_lost(&mut self) {
+        self.confirm_quit = false;
+    }
+
+    /// Open the Records screen with the history as it is on disk
+    pub fn open_records(&mut self) {
+        self.run_history = crate::model::run_history::RunHistory::load_from(&self.run_history_file);
+        self.records_state.select(Some(0));
+        self.state = AppState::Records;
+    }
+
+    /// Leave part of the player's gold where they died. Any unrecovered pile from an
+    /// earlier death is forfeited, so gold is never duplicated.
+    pub fn drop_gold_on_death(&mut self) {
+        use crate::model::gamesave::GoldRecovery;
+
+        if !self.settings.drop_gold_on_death {
+            self.gold_recovery = None;
+            return;
+        }
+        self.gold_recovery = GoldRecovery::on_death(
+            self.character.gold,
+            self.floor_level,
+            self.character_position,
+            &self.settings.difficulty,
+        );
+        if let Some(pile) = &self.gold_recovery {
+            self.character.gold -= pile.amount;
+        }
+    }
+
+    /// Put the death pile into the regenerated floor, next to the spawn if its tile is now a wall
+    fn place_gold_recovery(&mut self) {
+        use crate::model::item::ItemDrop;
+
+        if self
+            .gold_recovery
+            .as_ref()
+            .is_some_and(|pile| pile.floor_level != self.floor_level)
+        {
+            self.gold_recovery = None;
+        }
+        let (Some(pile), Some(floor)) = (&mut self.gold_recovery, &mut self.current_floor) else {
+            return;
+        };
+        if floor.is_walkable(pile.x, pile.y) {
+            floor.add_item(ItemDrop::recovered_gold(pile.amount, pile.x, pile.y));
+        } else {
+            let (px, py) = self.character_position;
+            if floor.try_drop_item_adjacent(ItemDrop::recovered_gold(pile.amount, px, py), px, py) {
+                if let Some(item) = floor.items.last() {
+                    (pile.x, pile.y) = (item.x, item.y);
+                }
+            } else {
+                floor.add_item(ItemDrop::recovered_gold(pile.amount, px, py));
+                (pile.x, pile.y) = (px, py);
+            }
+        }
+    }
+
     /// Restart the game with a fresh character and new floor
     pub fn restart_game(&mut self) {
-        // Reset character
+        // Reset character, keeping the class picked at creation
+        self.character = Character::default();
+        self.starting_class.apply(&mut self.character);
+        self.new_game_plus = 0;
+        self.tutorial = None;
+        self.adaptive_difficulty.reset();
+        self.start_run();
+    }
+
+    /// Play the hand-authored tutorial floor with a fresh, sword-only character
+    pub fn start_tutorial(&mut self) {
         self.character = Character::default();
+        self.character.weapon_inventory = crate::model::weapon::WeaponInventory {
+            weapons: vec![crate::model::weapon::Weapon::new_sword()],
+            current_weapon_index: 0,
+        };
+        self.new_game_plus = 0;
+        self.tutorial = Some(Tutorial::default());
+        self.start_run();
+    }
+
+    /// Leave the tutorial for the main menu. Finishing it and quitting early both mark it
+    /// completed, so it is not started on launch again.
+    pub fn end_tutorial(&mut self) {
+        self.tutorial = None;
+        self.current_floor = None;
+        self.is_paused = false;
+        self.pause_menu_selection = 0;
+        self.pause_submenu = None;
+        self.settings.tutorial_completed = true;
+        let _ = self.settings.save();
+        self.state = AppState::MainMenu;
+    }
+
+    /// Tell the tutorial the player just performed `lesson`; a chime marks each new step
+    fn tutorial_progress(&mut self, lesson: TutorialStep) {
+        if let Some(tutorial) = &mut self.tutorial {
+            if tutorial.record(lesson) {
+                self.audio_manager.play_sound_effect(SoundEffect::MenuPick);
+            }
+        }
+    }
+
+    fn enter_tutorial_floor(&mut self) {
+        self.fog_origin = None;
+        self.map_reveal.clear();
+
+        let (floor, spawn) = Tutorial::floor();
+        self.current_floor = Some(floor);
+        self.walkable_tiles_cache = None;
+        self.character_position = spawn;
+        self.update_camera();
+    }
+
+    /// Start the next New Game+ cycle after a victory: shop purchases and some gold carry
+    /// over, while floors, weapons and stats start again with tougher enemies
+    pub fn start_new_game_plus(&mut self) {
+        self.character = self.character.new_game_plus(self.starting_class);
+        self.new_game_plus += 1;
+        self.start_run();
+    }
+
+    /// Reset everything but the character and begin a run at floor one
+    fn start_run(&mut self) {
         self.character_position = (0, 0);
+        self.dev_play_active = false;
 
         // Reset game state
         self.floor_level = 1;
@@ -510,6 +993,7 @@ impl App {
         // Set max levels based on selected difficulty
         self.max_levels = self.get_max_levels_for_difficulty();
         self.is_boss_level = false;
+        self.run_mode = self.settings.run_mode.clone();
 
         // Reset stats
         self.game_started_at = Some(Instant::now());
@@ -517,14 +1001,20 @@ impl App {
         self.levels_passed_before_death = 0;
         self.death_screen_fade_timer = 0.0;
         self.victory_win_time = 0.0;
+        self.victory_score = 0;
         self.empty_slot_message_timer = 0.0;
+        self.gold_recovery = None;
 
         // Reset pause menu state
         self.pause_menu_selection = 0;
         self.pause_submenu = None;
 
         // Generate new floor
-        self.regenerate_floor();
+        if self.tutorial.is_some() {
+            self.enter_tutorial_floor();
+        } else {
+            self.regenerate_floor();
+        }
 
         // Restart music with fade-in for new run
         let _ = self.audio_manager.start_music_with_fade_in();
@@ -533,6 +1023,19 @@ impl App {
         self.state = AppState::Game;
     }
 
+    /// Enter the floor of a save just loaded from the menu, with its death pile if one is waiting
+    pub fn resume_loaded_game(&mut self) {
+        self.state = AppState::Game;
+        self.arrows.clear();
+        self.active_animations.clear();
+        self.particle_system = ParticleSystem::new();
+        self.is_paused = false;
+        self.regenerate_floor();
+        self.place_gold_recovery();
+        self.audio_manager.stop_music();
+        let _ = self.audio_manager.start_music_with_fade_in();
+    }
+
     pub fn retry_current_floor(&mut self) {
         // Clone player name to avoid borrow conflicts
         let player_name = self.char_name.clone();
@@ -546,6 +1049,7 @@ impl App {
 
         // Regenerate the floor with the saved floor level
         self.regenerate_floor();
+        self.place_gold_recovery();
 
         // Restore game state flags
         self.player_has_acted = false;
@@ -563,17 +1067,29 @@ impl App {
     }
 
     pub fn auto_save(&mut self) {
+        // The tutorial floor is never saved
+        if self.tutorial.is_some() {
+            return;
+        }
         // Save current game state
         let game_save = self.create_game_save();
         let _ = game_save.save();
     }
 
+    /// Manual save from the quicksave key, confirmed with a brief toast
+    pub fn quicksave(&mut self) {
+        if self.tutorial.is_none() && self.save_game().is_ok() {
+            self.auto_save_timer.reset();
+            self.save_toast_timer = crate::constants::SAVE_TOAST_DURATION;
+        }
+    }
+
     fn create_game_save(&self) -> GameSave {
         use crate::model::gamesave::*;
         use crate::model::weapon::EnchantType as WEnchantType;
 
         let player_stats = PlayerStats {
-            attack_damage: self.character.attack_damage,
+            attack_damage: self.character.base_attack_damage(),
             attack_length: self.character.attack_length,
             attack_width: self.character.attack_width,
             dash_distance: self.character.dash_distance,
@@ -583,6 +1099,7 @@ impl App {
             enemies_killed: self.character.enemies_killed,
             speed: self.character.speed,
             ultimate_charge: self.character.ultimate_charge,
+            arrow_speed: self.character.arrow_speed,
         };
 
         let weapons = self
@@ -645,6 +1162,7 @@ impl App {
         };
 
         GameSave {
+            version: crate::model::gamesave::SAVE_VERSION,
             player_name: self.character.name.clone(),
             player_stats,
             inventory_data,
@@ -658,11 +1176,39 @@ impl App {
             time_elapsed: self
                 .game_started_at
                 .map_or(0.0, |t| t.elapsed().as_secs_f32()),
+            run_mode: self.run_mode.name().to_string(),
+            starting_class: self.starting_class.name().to_string(),
+            best_score: self.best_score,
+            gold_recovery: self.gold_recovery.clone(),
+            new_game_plus: self.new_game_plus,
+            status_effects: crate::model::gamesave::StatusEffectData::capture(
+                &self.character.status_effects,
+            ),
+            adaptive_difficulty: self.adaptive_difficulty.clone(),
         }
     }
 
     pub fn should_tick(&self) -> bool {
-        !self.is_paused && self.last_game_tick.elapsed().as_millis() >= self.game_tick_rate_ms
+        !self.is_paused
+            && !self.hitstop_timer.is_active()
+            && crate::model::cooldown::scaled_elapsed(self.last_game_tick, self.settings.game_speed)
+                * 1000.0
+                >= self.game_tick_rate_ms as f32
+    }
+
+    /// Game-time seconds simulated per frame, scaled by the game speed setting
+    pub fn tick_delta(&self) -> f32 {
+        (self.game_tick_rate_ms as f32) / 1000.0 * self.settings.game_speed
+    }
+
+    /// Dev god mode only applies while playing a floor entered from the dev menu
+    pub fn dev_god_mode_active(&self) -> bool {
+        self.dev_play_active && self.dev_god_mode
+    }
+
+    /// Dev enemy freeze only applies while playing a floor entered from the dev menu
+    pub fn dev_enemies_frozen(&self) -> bool {
+        self.dev_play_active && self.dev_freeze_enemies
     }
 
     pub fn consume_tick(&mut self) {
@@ -671,7 +1217,7 @@ impl App {
 
     pub fn move_character(&mut self, dx: i32, dy: i32) {
         // Prevent movement while attacking to avoid animation desync
-        if self.character.is_attack_animating() {
+        if self.character.is_attack_animating(self.settings.game_speed) {
             return;
         }
 
@@ -686,26 +1232,87 @@ impl App {
         }
 
         self.movement_tick_counter = 0;
+        self.step_character(dx, dy);
+    }
+
+    /// Held-key movement needs release events; without them the terminal's own key
+    /// repeat drives movement through `move_character`
+    pub fn held_movement_active(&self) -> bool {
+        self.key_releases_supported && self.settings.hold_move_rate > 0
+    }
 
-        let new_x = self.character_position.0 + dx;
-        let new_y = self.character_position.1 + dy;
+    /// A movement key went down: step once now, then keep stepping while it is held
+    pub fn press_direction(&mut self, dx: i32, dy: i32) {
+        if !self.held_movement_active() {
+            self.move_character(dx, dy);
+            return;
+        }
+        if self.held_directions.contains(&(dx, dy)) {
+            return;
+        }
+        self.held_directions.push((dx, dy));
+        self.held_move_elapsed = 0.0;
+        if !self.character.is_attack_animating(self.settings.game_speed) {
+            self.step_character(dx, dy);
+        }
+    }
+
+    pub fn release_direction(&mut self, dx: i32, dy: i32) {
+        self.held_directions.retain(|&held| held != (dx, dy));
+    }
+
+    /// Step in the most recently pressed held direction at the configured rate
+    fn update_held_movement(&mut self, delta: f32) {
+        let Some(&(dx, dy)) = self.held_directions.last() else {
+            return;
+        };
+        if !self.held_movement_active() {
+            self.held_directions.clear();
+            return;
+        }
+        if self.is_paused || self.showing_help || self.inventory_focused {
+            return;
+        }
+
+        self.held_move_elapsed += delta;
+        let interval =
+            1.0 / (self.settings.hold_move_rate as f32 * self.settings.player_speed.max(0.1));
+        if self.held_move_elapsed >= interval
+            && !self.character.is_attack_animating(self.settings.game_speed)
+        {
+            self.held_move_elapsed = 0.0;
+            self.step_character(dx, dy);
+        }
+    }
+
+    /// Turn toward (dx, dy) and move one tile if it is walkable
+    fn step_character(&mut self, dx: i32, dy: i32) {
+        let target = crate::model::collision::resolve_move(
+            self.character_position,
+            (dx, dy),
+            self.settings.corner_sliding,
+            |x, y| self.is_walkable(x, y),
+        );
 
         // Update direction regardless of movement success (allows turning in 1x1 blocks)
         if dx != 0 || dy != 0 {
             self.character.update_direction(dx, dy);
+            self.last_move_at = Some(Instant::now());
+            self.attack_facing = None;
         }
 
-        if self.is_walkable(new_x, new_y) {
-            self.character_position = (new_x, new_y);
+        if let Some(target) = target {
+            self.character_position = target;
             self.pickup_items();
             self.update_camera();
             self.player_has_acted = true; // Player has moved - enable enemy attacks
             self.consume_tick();
+            self.tutorial_progress(TutorialStep::Move);
         }
     }
 
     pub fn dash(&mut self) {
-        if !self.character.can_dash() || !self.should_tick() {
+        if !self.character.can_dash(self.settings.game_speed) || !self.should_tick() {
             return;
         }
 
@@ -715,26 +1322,43 @@ impl App {
             return;
         }
 
-        let dash_dist = self.character.dash_distance;
-        let new_x = self.character_position.0 + (dx * dash_dist);
-        let new_y = self.character_position.1 + (dy * dash_dist);
-
-        if self.is_walkable(new_x, new_y) {
-            self.character_position = (new_x, new_y);
+        // Dash passes over enemies and stops short of walls
+        let landing = self.current_floor.as_ref().and_then(|floor| {
+            floor.dash_landing(
+                self.character_position,
+                (dx, dy),
+                self.character.dash_distance,
+            )
+        });
+
+        if let Some(landing) = landing {
+            if self.settings.movement_trails {
+                let (sx, sy) = self.character_position;
+                let steps = (landing.0 - sx).abs().max((landing.1 - sy).abs());
+                for step in 0..steps {
+                    self.particle_system.emit_trail(
+                        (sx + dx * step) as f32,
+                        (sy + dy * step) as f32,
+                        Color::White,
+                    );
+                }
+            }
+            self.character_position = landing;
             self.character.start_dash_cooldown();
             self.update_camera();
             self.consume_tick();
+            self.tutorial_progress(TutorialStep::Dash);
         }
     }
 
     pub fn attack(&mut self) {
         use std::io::Write;
 
-        if !self.character.can_attack() || !self.should_tick() {
+        if !self.character.can_attack(self.settings.game_speed) || !self.should_tick() {
             return;
         }
 
-        let (dx, dy) = self.character.last_direction;
+        let (dx, dy) = self.attack_direction();
 
         // Default to forward direction if no direction set
         let (attack_dx, attack_dy) = if dx == 0 && dy == 0 {
@@ -745,7 +1369,8 @@ impl App {
 
         // --- NEW: Generate and store the animation ---
         // We use the current dev_attack_pattern, but in a real game you might switch based on weapon
-        let frames = self.dev_attack_pattern.get_animation_frames(
+        let pattern = self.get_player_attack_pattern();
+        let frames = pattern.get_animation_frames(
             self.character_position.0,
             self.character_position.1,
             attack_dx,
@@ -753,12 +1378,16 @@ impl App {
         );
 
         if !frames.is_empty() {
-            let category = Self::get_attack_pattern_category(&self.dev_attack_pattern);
+            let category = Self::get_attack_pattern_category(&pattern);
             self.active_animations
                 .push(ActiveAnimation::new_with_category(frames, category));
         }
         // ---------------------------------------------
 
+        if let Some(weapon) = self.character.weapon_inventory.get_current_weapon() {
+            self.audio_manager.play_attack_sound(&weapon.weapon_type);
+        }
+
         // Start the attack cooldown
         self.character.start_attack_cooldown();
         self.player_has_acted = true; // Player has attacked - enable enemy attacks
@@ -784,11 +1413,22 @@ impl App {
         }
     }
 
+    /// Player attack pattern with weapon enchant and skill tree area bonuses applied
+    pub fn get_player_attack_pattern(&self) -> crate::model::attack_pattern::AttackPattern {
+        self.dev_attack_pattern
+            .scaled(self.character.get_attack_area_bonus())
+    }
+
+    /// Direction the current attack faces: the auto-face target if one was picked, else movement facing
+    pub fn attack_direction(&self) -> (i32, i32) {
+        self.attack_facing.unwrap_or(self.character.last_direction)
+    }
+
     pub fn get_attack_area(&self) -> Vec<(i32, i32)> {
-        let (dx, dy) = self.character.last_direction;
+        let (dx, dy) = self.attack_direction();
         let (attack_dx, attack_dy) = if dx == 0 && dy == 0 { (0, 1) } else { (dx, dy) };
 
-        self.dev_attack_pattern.get_affected_tiles(
+        self.get_player_attack_pattern().get_affected_tiles(
             self.character_position.0,
             self.character_position.1,
             attack_dx,
@@ -800,12 +1440,13 @@ impl App {
     /// This ensures hitboxes match what's visually displayed
     pub fn get_current_attack_area(&self) -> Vec<(i32, i32)> {
         if let Some(attack_time) = self.character.last_attack_time {
-            let elapsed = attack_time.elapsed().as_secs_f32();
+            let elapsed =
+                crate::model::cooldown::scaled_elapsed(attack_time, self.settings.game_speed);
 
             // Get all frames for current attack
-            let (dx, dy) = self.character.last_direction;
+            let (dx, dy) = self.attack_direction();
             let (attack_dx, attack_dy) = if dx == 0 && dy == 0 { (0, 1) } else { (dx, dy) };
-            let frames = self.dev_attack_pattern.get_animation_frames(
+            let frames = self.get_player_attack_pattern().get_animation_frames(
                 self.character_position.0,
                 self.character_position.1,
                 attack_dx,
@@ -828,32 +1469,88 @@ impl App {
         }
     }
 
+    /// Start (or keep holding) a bow charge; the arrow is fired on release
     pub fn shoot(&mut self) {
-        if !self.character.can_shoot() || !self.should_tick() {
+        if !self.character.bow_charging
+            && (!self.character.can_shoot(self.settings.game_speed) || !self.should_tick())
+        {
             return;
         }
+        self.character.hold_bow_charge();
+        // Without release events a key tap can't be told from a hold, so it shoots at once
+        if !self.mouse_attack_held && !self.key_releases_supported {
+            self.release_shot();
+        }
+    }
 
-        let (dx, dy) = self.character.last_direction;
+    /// Fire the held bow charge as an arrow scaled by charge time
+    pub fn release_shot(&mut self) {
+        let Some(charge) = self.character.release_bow_charge() else {
+            return;
+        };
+
+        if let Some(facing) = self.auto_face_direction() {
+            self.attack_facing = Some(facing);
+        }
+        let (dx, dy) = self.attack_direction();
         let (shoot_dx, shoot_dy) = if dx == 0 && dy == 0 { (0, 1) } else { (dx, dy) };
 
-        let arrow = Arrow::new(
+        let arrow = Arrow::charged(
             self.character_position.0 as f32,
             self.character_position.1 as f32,
             shoot_dx,
             shoot_dy,
             self.character.arrow_speed,
+            charge,
         );
 
         self.arrows.push(arrow);
+        self.audio_manager
+            .play_attack_sound(&crate::model::weapon::WeaponType::Bow);
         self.character.start_bow_cooldown();
         self.consume_tick();
     }
 
+    /// Advance the bow charge and fire once the hold ends
+    pub fn update_bow_charge(&mut self, delta: f32) {
+        if !self.character.bow_charging {
+            return;
+        }
+        let holding_bow = self
+            .character
+            .weapon_inventory
+            .get_current_weapon()
+            .map(|w| w.weapon_type == crate::model::weapon::WeaponType::Bow)
+            .unwrap_or(false);
+        if !holding_bow {
+            self.character.release_bow_charge();
+            return;
+        }
+        if self.mouse_attack_held {
+            self.character.bow_charge_idle = 0.0;
+        }
+        if self.character.update_bow_charge(delta) {
+            self.release_shot();
+        }
+    }
+
     pub fn update_arrows(&mut self) {
-        let frame_time = (self.game_tick_rate_ms as f32) / 1000.0;
+        if self.hitstop_timer.is_active() {
+            return;
+        }
+        let frame_time = self.tick_delta();
+        let trails = self.settings.movement_trails;
 
         for arrow in self.arrows.iter_mut() {
+            let before = arrow.get_position();
             arrow.update(frame_time);
+            if trails && !arrow.is_dead && arrow.get_position() != before {
+                self.particle_system.emit_trail(
+                    before.0 as f32,
+                    before.1 as f32,
+                    arrow.trail_color(),
+                );
+            }
         }
 
         // Check arrow-enemy collisions FIRST before wall/walkable checks
@@ -878,35 +1575,43 @@ impl App {
             }
         }
 
-        self.arrows.retain(|arrow| arrow.is_alive());
+        let time_scale = self.settings.game_speed;
+        self.arrows.retain(|arrow| arrow.is_alive(time_scale));
     }
 
     pub fn check_arrow_collisions(&mut self) {
+        let mut player_hits = Vec::new();
         if let Some(floor) = &mut self.current_floor {
             let mut arrows_to_remove = Vec::new();
             let mut enemies_to_damage = Vec::new();
 
-            for (arrow_idx, arrow) in self.arrows.iter().enumerate() {
-                let arrow_pos = arrow.get_position();
+            let weapon_damage = self
+                .character
+                .weapon_inventory
+                .get_current_weapon()
+                .map(|w| w.damage)
+                .unwrap_or(5);
 
-                for (enemy_idx, enemy) in floor.enemies.iter().enumerate() {
-                    if enemy.is_alive() {
-                        let enemy_pos = (enemy.position.x, enemy.position.y);
-
-                        // Check if arrow collides with enemy
-                        if arrow_pos == enemy_pos {
-                            let weapon_damage = self
-                                .character
-                                .weapon_inventory
-                                .get_current_weapon()
-                                .map(|w| w.damage)
-                                .unwrap_or(5);
+            floor.rebuild_enemy_grid();
 
+            for (arrow_idx, arrow) in self.arrows.iter_mut().enumerate() {
+                let arrow_pos = arrow.get_position();
+                let enemies_here = floor.enemies_at(arrow_pos.0, arrow_pos.1);
+                match arrow.resolve_hit(self.character_position, enemies_here) {
+                    Some(ArrowHit::Player(damage)) => {
+                        player_hits.push((damage, arrow.dx as f32, arrow.dy as f32));
+                        arrows_to_remove.push(arrow_idx);
+                    }
+                    Some(ArrowHit::Enemy(enemy_idx)) => {
+                        enemies_to_damage.push((enemy_idx, arrow.get_damage(weapon_damage)));
+                        arrow.last_hit = Some(arrow_pos);
+                        if arrow.pierce_remaining > 0 {
+                            arrow.pierce_remaining -= 1;
+                        } else {
                             arrows_to_remove.push(arrow_idx);
-                            enemies_to_damage.push((enemy_idx, weapon_damage));
-                            break;
                         }
                     }
+                    None => {}
                 }
             }
 
@@ -924,7 +1629,7 @@ impl App {
                     self.audio_manager.play_sound_effect(SoundEffect::Hit);
 
                     // Charge ultimate based on damage dealt
-                    self.character.charge_ultimate(*damage);
+                    self.character.land_hit(*damage);
                 }
             }
 
@@ -935,30 +1640,81 @@ impl App {
                 }
             }
         }
+
+        for (damage, dx, dy) in player_hits {
+            self.hit_player(damage, dx, dy);
+        }
+    }
+
+    /// Apply an enemy hit to the player, knocking them along (dx, dy)
+    fn hit_player(&mut self, damage: i32, dx: f32, dy: f32) {
+        if self.dev_god_mode_active() || self.character.is_invulnerable() {
+            return;
+        }
+        self.character
+            .take_hit(damage, dx, dy, self.settings.player_knockback);
+        self.adaptive_difficulty.record_damage(damage);
+        if self.settings.combo_resets_on_damage {
+            self.character.combo.reset();
+        }
+        // Player gains ultimate charge when damaged by enemies
+        self.character.charge_ultimate(damage);
+        self.audio_manager.play_damaged_sound();
     }
 
     pub fn use_ultimate(&mut self) {
-        if !self.character.ultimate.can_use() || !self.should_tick() {
+        if self.character.ultimate_charge < 100.0
+            || !self.character.ultimate.can_use(self.settings.game_speed)
+            || !self.should_tick()
+        {
             return;
         }
 
-        self.character.ultimate.start_animation();
+        // Preview the area first; the ultimate resolves once the wind-up ends
+        self.character.ultimate_charge = 0.0;
+        self.character.ultimate.start_telegraph();
         self.character.ultimate.start_cooldown();
         self.consume_tick();
     }
 
+    /// Fire the ultimate at the end of its telegraph
+    pub fn resolve_ultimate(&mut self) {
+        // Activating starts the animation and any timed buff (Rage, Ghost)
+        self.character.activate_ultimate();
+
+        let (px, py) = self.character_position;
+        let hits = match &mut self.current_floor {
+            Some(floor) => self.character.ultimate.strike(&mut floor.enemies, px, py),
+            None => return,
+        };
+
+        for ((x, y), damage) in &hits {
+            self.particle_system.emit_crit(*x as f32, *y as f32);
+            if *damage > 0 {
+                // Counts toward the combo, but the ultimate doesn't charge itself
+                self.character.combo.register_hit();
+            }
+        }
+        if !hits.is_empty() {
+            self.audio_manager.play_sound_effect(SoundEffect::Hit);
+        }
+    }
+
     pub fn get_ultimate_area(&self) -> Vec<(i32, i32)> {
-        self.character
-            .ultimate
-            .get_affected_area(self.character_position.0, self.character_position.1)
+        self.character.ultimate.get_affected_area(
+            self.character_position.0,
+            self.character_position.1,
+            self.settings.game_speed,
+        )
     }
 
     pub fn block(&mut self) {
-        if !self.character.can_block() || !self.should_tick() {
+        if !self.character.can_block(self.settings.game_speed) || !self.should_tick() {
             return;
         }
         self.character.start_block_cooldown();
         self.consume_tick();
+        self.tutorial_progress(TutorialStep::Block);
     }
 
     pub fn switch_weapon(&mut self, slot: usize) {
@@ -972,8 +1728,60 @@ impl App {
 
         // Kill any currently playing switch sound and play new one
         self.audio_manager.stop_sound_effects();
+        let previous = self.character.weapon_inventory.current_weapon_index;
         self.character.weapon_inventory.switch_weapon(slot - 1);
         self.audio_manager.play_sound_effect(SoundEffect::ItemEquip);
+        if self.character.weapon_inventory.current_weapon_index != previous {
+            self.tutorial_progress(TutorialStep::SwitchWeapon);
+        }
+    }
+
+    /// Move the equipped weapon `delta` slots up or down the inventory, keeping it equipped
+    pub fn move_current_weapon(&mut self, delta: isize) {
+        let inventory = &mut self.character.weapon_inventory;
+        if inventory.move_weapon(inventory.current_weapon_index, delta) {
+            self.audio_manager.play_sound_effect(SoundEffect::MenuClick);
+        }
+    }
+
+    /// Close the weapon swap prompt. Swapping drops the replaced weapon;
+    /// declining (or choosing "leave it") puts the ground weapon back down.
+    pub fn resolve_weapon_swap(&mut self, slot: Option<usize>) {
+        let Some(prompt) = self.weapon_swap_prompt.take() else {
+            return;
+        };
+
+        let (to_drop, swapped) = match slot {
+            Some(slot) if slot < self.character.weapon_inventory.weapons.len() => {
+                let weapon_name = format!("{:?}", prompt.weapon.weapon_type);
+                let rarity = prompt.weapon.rarity.clone();
+                match self
+                    .character
+                    .weapon_inventory
+                    .swap_weapon(slot, prompt.weapon)
+                {
+                    Some(replaced) => {
+                        self.last_weapon_pickup = Some((weapon_name, rarity));
+                        self.weapon_pickup_timer = 3.0;
+                        (replaced, true)
+                    }
+                    None => return,
+                }
+            }
+            _ => (prompt.weapon, false),
+        };
+
+        if let Some(floor) = &mut self.current_floor {
+            let (char_x, char_y) = self.character_position;
+            let weapon_item = crate::model::item::ItemDrop::weapon(to_drop, char_x, char_y);
+            if !floor.try_drop_item_adjacent(weapon_item.clone(), char_x, char_y) {
+                floor.add_item(weapon_item);
+            }
+        }
+        if swapped {
+            self.audio_manager
+                .play_sound_effect(SoundEffect::PickedUpItem);
+        }
     }
 
     pub fn drop_weapon(&mut self, slot: usize) {
@@ -996,7 +1804,70 @@ impl App {
         }
     }
 
+    /// Drop every weapon but the equipped one onto the tiles around the player. Weapons
+    /// that find no free tile stay in the inventory.
+    pub fn drop_all_but_current_weapon(&mut self) {
+        let Some(floor) = &mut self.current_floor else {
+            return;
+        };
+        let (char_x, char_y) = self.character_position;
+        self.character
+            .weapon_inventory
+            .drop_all_but_current(|weapon| {
+                let weapon_item =
+                    crate::model::item::ItemDrop::weapon(weapon.clone(), char_x, char_y);
+                floor.try_drop_item_adjacent(weapon_item, char_x, char_y)
+            });
+    }
+
+    /// Scrap the equipped weapon for gold and show how much it was worth
+    pub fn scrap_current_weapon(&mut self) {
+        if let Some(gold) = self.character.scrap_current_weapon() {
+            self.audio_manager.play_gold_sound();
+            self.scrap_toast = Some((gold, crate::constants::SCRAP_TOAST_DURATION));
+        }
+    }
+
+    /// Facing toward the nearest visible enemy, when the auto-face assist applies
+    /// (enabled in settings and the player hasn't just moved)
+    pub fn auto_face_direction(&self) -> Option<(i32, i32)> {
+        if !self.settings.auto_face_nearest {
+            return None;
+        }
+        let recently_moved = self
+            .last_move_at
+            .is_some_and(|t| t.elapsed().as_secs_f32() < crate::constants::AUTO_FACE_MOVE_GRACE);
+        if recently_moved {
+            return None;
+        }
+
+        let (px, py) = self.character_position;
+        let radius = self
+            .settings
+            .auto_face_radius
+            .clamp(1, crate::constants::AUTO_FACE_MAX_RADIUS);
+        let (ex, ey) = self.current_floor.as_ref()?.nearest_enemy(px, py, radius)?;
+        let (dx, dy) = (ex - px, ey - py);
+
+        // Snap to one of the 8 directions, favouring straight lines when the target is mostly along an axis
+        let facing = if dx.abs() > 2 * dy.abs() {
+            (dx.signum(), 0)
+        } else if dy.abs() > 2 * dx.abs() {
+            (0, dy.signum())
+        } else {
+            (dx.signum(), dy.signum())
+        };
+        if facing == (0, 0) {
+            None
+        } else {
+            Some(facing)
+        }
+    }
+
     pub fn use_current_weapon(&mut self) {
+        // Auto-facing only applies to this action; last_direction keeps the movement facing
+        self.attack_facing = self.auto_face_direction();
+        self.tutorial_progress(TutorialStep::Attack);
         if let Some(weapon) = self.character.weapon_inventory.get_current_weapon() {
             match weapon.weapon_type {
                 crate::model::weapon::WeaponType::Sword => {
@@ -1022,44 +1893,107 @@ impl App {
         }
     }
 
+    /// Auto-fire needs to know when the attack comes back up: the mouse always reports
+    /// it, keys only with release events. Without them every key press attacks once.
+    pub fn auto_fire_active(&self) -> bool {
+        self.settings.auto_fire && (self.key_releases_supported || self.mouse_attack_held)
+    }
+
+    /// The attack key went down: attack now, then keep attacking while it is held
+    pub fn press_attack(&mut self) {
+        if self.auto_fire_active() {
+            if self.attack_trigger.is_held() {
+                return; // Terminal key repeat while held
+            }
+            self.attack_trigger.press();
+        }
+        self.use_current_weapon();
+    }
+
+    pub fn release_attack(&mut self) {
+        self.attack_trigger.release();
+        // A drawn bow looses as soon as the attack comes back up, unless the mouse still holds it
+        if !self.mouse_attack_held {
+            self.release_shot();
+        }
+    }
+
+    /// Attack again each time the held weapon's cooldown comes around. Bows loose their
+    /// arrow at once instead of charging.
+    fn update_auto_fire(&mut self, delta: f32) {
+        if !self.attack_trigger.is_held() {
+            return;
+        }
+        if !self.auto_fire_active() {
+            self.attack_trigger.release();
+            return;
+        }
+        if self.is_paused || self.showing_help || self.inventory_focused {
+            return;
+        }
+
+        let interval = self.character.current_weapon_cooldown();
+        if self.attack_trigger.tick(delta, interval) {
+            self.use_current_weapon();
+            if self.character.bow_charging {
+                self.release_shot();
+            }
+            if !self
+                .character
+                .current_weapon_ready(self.settings.game_speed)
+            {
+                self.attack_trigger.fired();
+            }
+        }
+    }
+
     pub fn cycle_dev_attack_pattern(&mut self) {
-        use crate::model::attack_pattern::AttackPattern;
+        self.step_dev_attack_pattern();
+
+        // Trigger a visual test immediately upon switching
+        self.trigger_dev_animation();
+    }
 
-        let patterns = vec![
-            AttackPattern::BasicSlash,
-            AttackPattern::GroundSlam(3),
-            AttackPattern::WhirlwindAttack,
-            AttackPattern::SwordThrust(2),
-            AttackPattern::ArrowShot(4),
-            AttackPattern::MultiShot(4, 2),
-            AttackPattern::Barrage(3),
-            AttackPattern::PiercingShot(5),
-            AttackPattern::Fireball(2),
-            AttackPattern::ChainLightning(3),
-            AttackPattern::FrostNova(2),
-            AttackPattern::MeteorShower(3, 2),
-            AttackPattern::CrescentSlash,
-            AttackPattern::Vortex(2),
-        ];
+    /// Select the next dev attack pattern without playing it
+    pub fn step_dev_attack_pattern(&mut self) {
+        use crate::model::attack_pattern::AttackPattern;
 
+        let patterns = &AttackPattern::ALL;
         let current_index = patterns
             .iter()
             .position(|p| p == &self.dev_attack_pattern)
             .unwrap_or(0);
         let next_index = (current_index + 1) % patterns.len();
         self.dev_attack_pattern = patterns[next_index].clone();
-
-        // Trigger a visual test immediately upon switching
-        self.trigger_dev_animation();
     }
 
+    /// Collect what the player walked onto: everything with auto-pickup on, otherwise only gold
     pub fn pickup_items(&mut self) {
+        self.collect_items(self.settings.auto_pickup);
+    }
+
+    /// Interact key: take everything on the player's tile, or use the stairs if standing on them
+    pub fn interact(&mut self) {
+        let (x, y) = self.character_position;
+        let Some(floor) = &self.current_floor else {
+            return;
+        };
+        if !floor.items_at(x, y).is_empty() {
+            self.collect_items(true);
+        } else if floor.is_stairs_at(x, y) {
+            self.stairs_requested = true;
+        }
+    }
+
+    fn collect_items(&mut self, everything: bool) {
         if let Some(floor) = &mut self.current_floor {
             let (char_x, char_y) = self.character_position;
             let item_count = floor.items_at(char_x, char_y).len();
 
             for _ in 0..item_count {
-                if let Some(item) = floor.pickup_item(char_x, char_y) {
+                if let Some(item) = floor.pickup_item_where(char_x, char_y, |item| {
+                    everything || item.item_type.is_gold()
+                }) {
                     use crate::model::item::ItemDropType;
                     match item.item_type {
                         ItemDropType::Consumable(consumable) => {
@@ -1068,11 +2002,14 @@ impl App {
                                 .play_sound_effect(SoundEffect::PickedUpItem);
                         }
                         ItemDropType::Gold(amount) => {
+                            if item.recovery {
+                                self.gold_recovery = None;
+                            }
                             self.character.add_gold(amount);
                             self.audio_manager.play_gold_sound();
                         }
                         ItemDropType::Weapon(weapon) => {
-                            if self.character.weapon_inventory.weapons.len() < 9 {
+                            if !self.character.weapon_inventory.is_full() {
                                 // Store weapon pickup notification
                                 let weapon_name = format!("{:?}", weapon.weapon_type);
                                 self.last_weapon_pickup =
@@ -1082,6 +2019,15 @@ impl App {
                                 self.character.weapon_inventory.add_weapon(weapon);
                                 self.audio_manager
                                     .play_sound_effect(SoundEffect::PickedUpItem);
+                                if let Some(tutorial) = &mut self.tutorial {
+                                    tutorial.record(TutorialStep::PickUp);
+                                }
+                            } else if self.weapon_swap_prompt.is_none() {
+                                // Inventory full: hold the weapon and ask which slot to swap
+                                self.weapon_swap_prompt = Some(WeaponSwapPrompt {
+                                    weapon,
+                                    selection: self.character.weapon_inventory.current_weapon_index,
+                                });
                             } else {
                                 let weapon_item =
                                     crate::model::item::ItemDrop::weapon(weapon, char_x, char_y);
@@ -1095,7 +2041,10 @@ impl App {
     }
 
     pub fn use_consumable(&mut self, index: usize) {
-        if let Some(consumable) = self.character.consumable_inventory.use_item(index) {
+        if let Some(consumable) = self
+            .character
+            .use_consumable(index, self.settings.game_speed)
+        {
             use crate::model::consumable::ConsumableType;
             use crate::model::status_effect::StatusEffect;
 
@@ -1149,17 +2098,30 @@ impl App {
                 ConsumableType::BlessedBread => {
                     self.character.heal(8);
                 }
+                ConsumableType::ScryingEye => {
+                    if let Some(duration) = consumable.get_reveal_duration() {
+                        self.map_reveal.start(duration);
+                    }
+                }
+                ConsumableType::StrengthPotion => {
+                    self.character.start_potion_buff(&consumable);
+                }
             }
         }
     }
 
     pub fn update_camera(&mut self) {
-        let vw = self.terminal_size.0 as f32;
+        let vw = (self.terminal_size.0 as f32).max(1.0);
         // Subtract 2 for the ultimate bar at the bottom (UI reserved space)
         let vh = (self.terminal_size.1 as f32 - 2.0).max(1.0);
 
-        let mut target_x = self.character_position.0 as f32 - vw / 2.0;
-        let mut target_y = self.character_position.1 as f32 - vh / 2.0;
+        // Only scroll once the player leaves the deadzone around the view's centre
+        let (mut target_x, mut target_y) = crate::model::camera::follow_target(
+            self.camera_target,
+            self.character_position,
+            (vw, vh),
+            self.settings.camera_deadzone,
+        );
 
         if let Some(floor) = &self.current_floor {
             target_x = target_x.clamp(0.0, (floor.width as f32 - vw).max(0.0));
@@ -1188,20 +2150,120 @@ impl App {
         }
     }
 
+    /// Enemy the examine tooltip describes: the one under the mouse, else the nearest one
+    /// in sight while examine mode is on. `origin` is the top-left cell of the game area.
+    pub fn examined_enemy(&self, origin: (u16, u16)) -> Option<&crate::model::enemy::Enemy> {
+        let floor = self.current_floor.as_ref()?;
+        let fog_enabled = self.fog_hides_map();
+        let enemy_at = |(x, y): (i32, i32)| {
+            floor.enemies.iter().find(|enemy| {
+                enemy.is_alive()
+                    && (enemy.position.x, enemy.position.y) == (x, y)
+                    && (!fog_enabled || floor.is_visible(x, y))
+            })
+        };
+
+        let hovered = self.mouse_position.and_then(|(mx, my)| {
+            let (sx, sy) = (mx.checked_sub(origin.0)?, my.checked_sub(origin.1)?);
+            enemy_at((
+                self.camera_offset.0.floor() as i32 + sx as i32,
+                self.camera_offset.1.floor() as i32 + sy as i32,
+            ))
+        });
+        if hovered.is_some() || !self.examining {
+            return hovered;
+        }
+        let (px, py) = self.character_position;
+        floor
+            .nearest_enemy(px, py, crate::constants::EXAMINE_RADIUS)
+            .and_then(enemy_at)
+    }
+
+    /// Whether fog of war currently hides anything; a Scrying Eye lifts it while active
+    pub fn fog_hides_map(&self) -> bool {
+        self.settings.fog_of_war && !self.map_reveal.is_active()
+    }
+
+    /// Refresh fog of war visibility, but only when the player has moved
+    pub fn update_fog_of_war(&mut self) {
+        if !self.settings.fog_of_war {
+            self.fog_origin = None;
+            return;
+        }
+        if self.fog_origin == Some(self.character_position) {
+            return;
+        }
+        if let Some(floor) = &mut self.current_floor {
+            let (px, py) = self.character_position;
+            floor.update_visibility(px, py, crate::constants::FOG_VIEW_RADIUS);
+            self.fog_origin = Some(self.character_position);
+        }
+    }
+
+    /// Keep the low-health heartbeat in sync and play cues for newly applied status effects
+    pub fn update_audio_cues(&mut self) {
+        let low_health = self.state == AppState::Game
+            && !self.is_paused
+            && self.character.is_alive()
+            && (self.character.health as f32)
+                < (self.character.health_max as f32) * crate::constants::LOW_HEALTH_THRESHOLD;
+        if low_health {
+            self.audio_manager.start_heartbeat();
+        } else if self.audio_manager.is_heartbeat_playing() {
+            self.audio_manager.stop_heartbeat();
+        }
+
+        for effect_type in self.character.status_effects.take_newly_applied() {
+            if self.state == AppState::Game {
+                self.audio_manager
+                    .play_sound_effect(SoundEffect::for_status(&effect_type));
+            }
+        }
+    }
+
     pub fn update_game_logic(&mut self) {
-        let delta = (self.game_tick_rate_ms as f32) / 1000.0;
+        // The weapon prompts freeze the run until the player decides
+        if self.weapon_swap_prompt.is_some() || self.confirm_drop_all || self.confirm_scrap {
+            return;
+        }
+
+        // Nothing moves while the player can't see the map
+        if self.state == AppState::Game && self.terminal_too_small() {
+            return;
+        }
+
+        // Hit-stop runs on real time, so a slowed game doesn't stretch the freeze
+        let frame_seconds = self.game_tick_rate_ms as f32 / 1000.0;
+        if self.hitstop_timer.tick(frame_seconds) {
+            return;
+        }
+
+        let delta = self.tick_delta();
+        // Picked up by every emission from here on, arrow hits and dash trails included
+        self.particle_system.density = self.settings.particle_density;
 
         // Ensure walkable tiles cache is populated for enemy AI calculations
         if self.state == AppState::Game {
             self.ensure_walkable_tiles_cache();
+            self.update_fog_of_war();
+            self.map_reveal.tick(delta);
         }
 
-        // Auto-save every 30 seconds during gameplay
+        // Auto-save on the configured interval during gameplay (quit and death always save)
         if self.state == AppState::Game {
-            self.auto_save_timer += delta;
-            if self.auto_save_timer >= 30.0 {
+            let interval = self.settings.autosave_interval_secs as f32;
+            if self.auto_save_timer.tick(delta, interval) {
                 self.auto_save();
-                self.auto_save_timer = 0.0;
+            }
+        }
+
+        if self.save_toast_timer > 0.0 {
+            self.save_toast_timer -= delta;
+        }
+        if let Some((_, remaining)) = &mut self.scrap_toast {
+            *remaining -= delta;
+            if *remaining <= 0.0 {
+                self.scrap_toast = None;
             }
         }
 
@@ -1213,12 +2275,43 @@ impl App {
             }
         }
 
+        if self.state == AppState::Game {
+            self.update_held_movement(delta);
+            self.update_auto_fire(delta);
+            self.update_bow_charge(delta);
+            if self
+                .character
+                .ultimate
+                .finish_telegraph(self.settings.game_speed)
+            {
+                self.resolve_ultimate();
+            }
+            self.character.update_ultimate_buff(delta);
+            self.character.update_potion_buff(delta);
+            self.character.update_dash_iframes(delta);
+            self.character.combo.update(delta);
+            self.adaptive_difficulty.tick(delta);
+
+            if self.is_boss_level {
+                if let Some(floor) = &mut self.current_floor {
+                    self.boss_summoner.update(
+                        delta,
+                        floor,
+                        &self.settings.difficulty,
+                        self.character_position,
+                    );
+                }
+            }
+        }
+
         // Update empty slot warning message timer
         if self.empty_slot_message_timer > 0.0 {
             self.empty_slot_message_timer -= delta;
         }
+        self.screen_flash_timer = (self.screen_flash_timer - delta).max(0.0);
 
         self.character.status_effects.update(delta);
+        self.update_audio_cues();
 
         // --- NEW: Update active animations ---
         // Iterate backwards to safely remove finished animations
@@ -1234,8 +2327,9 @@ impl App {
         // -------------------------------------
 
         let damage = (self.character.status_effects.get_total_damage_per_sec() * delta) as i32;
-        if damage > 0 {
+        if damage > 0 && !self.dev_god_mode_active() && !self.character.is_ghost() {
             self.character.take_damage(damage);
+            self.adaptive_difficulty.record_damage(damage);
             self.audio_manager.play_damaged_sound();
         }
 
@@ -1303,9 +2397,17 @@ impl App {
         );
         let mut attacks_on_player: Vec<(i32, f32, f32)> = Vec::new();
         let mut hit_enemy_indices: Vec<usize> = Vec::new();
+        let mut heavy_hit = false; // A hit worth a hit-stop landed this tick
+        let game_speed = self.settings.game_speed;
+        let metric = self.settings.enemy_distance;
+        let tick_seconds = self.game_tick_rate_ms as f32 / 1000.0; // Unscaled length of one attack tick
+        let enemies_frozen = self.dev_enemies_frozen();
 
         // Get current attack area before borrowing floor mutably
-        let current_attack_area = if self.character.is_attacking_animating() {
+        let current_attack_area = if self
+            .character
+            .is_attacking_animating(self.settings.game_speed)
+        {
             self.get_current_attack_area()
         } else {
             vec![]
@@ -1314,9 +2416,18 @@ impl App {
         // Build walkable tiles cache before borrowing floor mutably
         self.ensure_walkable_tiles_cache();
 
-        if let Some(floor) = &mut self.current_floor {
-            floor.update_items(delta);
+        // Item magnet: drift nearby loot toward the player and collect what arrives
+        let pickup_radius = self.character.pickup_radius();
+        let player_tile = self.character_position;
+        let magnet_arrived = self
+            .current_floor
+            .as_mut()
+            .is_some_and(|floor| floor.update_items(delta, player_tile, pickup_radius));
+        if magnet_arrived {
+            self.pickup_items();
+        }
 
+        if let Some(floor) = &mut self.current_floor {
             for item in &floor.items {
                 if matches!(item.item_type, crate::model::item::ItemDropType::Weapon(_)) {
                     self.particle_system.emit_periodic_glint(
@@ -1327,13 +2438,53 @@ impl App {
                 }
             }
 
+            if let Some((sx, sy)) = floor.stairs {
+                self.particle_system
+                    .emit_periodic_glint(sx as f32, sy as f32, Color::Cyan);
+            }
+
             let walkable_tiles = self.walkable_tiles_cache.as_ref().unwrap();
+            let pack_targets = crate::model::enemy::assign_pack_targets(
+                &floor.enemies,
+                &player_pos,
+                walkable_tiles,
+                metric,
+            );
 
-            for (enemy_idx, enemy) in floor.enemies.iter_mut().enumerate() {
+            for (index, enemy) in floor.enemies.iter_mut().enumerate() {
                 if !enemy.is_alive() {
                     continue;
                 }
 
+                // Wound-up attacks land on whoever is still inside them
+                if enemy.telegraph.is_some() {
+                    if let Some(telegraph) = enemy.tick_telegraph(delta) {
+                        if telegraph.lands_on(player_pos.x, player_pos.y) {
+                            let (dx, dy) = telegraph.direction;
+                            attacks_on_player.push((telegraph.damage, dx, dy));
+                            heavy_hit |=
+                                enemy.rarity == crate::model::enemy_type::EnemyRarity::Boss;
+                        }
+                        self.audio_manager.play_sound_effect_at(
+                            SoundEffect::Hit,
+                            (enemy.position.x, enemy.position.y),
+                            (player_pos.x, player_pos.y),
+                        );
+                        let category = Self::get_attack_pattern_category(&telegraph.pattern);
+                        self.active_animations
+                            .push(ActiveAnimation::new_with_category(
+                                telegraph.frames,
+                                category,
+                            ));
+                    }
+                    continue;
+                }
+
+                // Dev freeze: enemies stay put but can still be hit, as do training dummies
+                if enemies_frozen || enemy.dummy.is_some() {
+                    continue;
+                }
+
                 if enemy.knockback_velocity != (0.0, 0.0) {
                     let (kb_x, kb_y) = enemy.knockback_velocity;
                     let new_x = (enemy.position.x as f32 + kb_x).round() as i32;
@@ -1393,6 +2544,12 @@ impl App {
                     }
                 }
 
+                if enemy.enter_next_phase() {
+                    // Boss crossed a health threshold and switched to a harder attack set
+                    self.particle_system
+                        .emit_enrage(enemy.position.x as f32, enemy.position.y as f32);
+                }
+
                 if matches!(enemy.rarity, crate::model::enemy_type::EnemyRarity::Boss) {
                     self.particle_system.emit_periodic_glint(
                         enemy.position.x as f32,
@@ -1401,50 +2558,30 @@ impl App {
                     );
                 }
 
-                enemy.movement_ticks += enemy.speed; // Use enemy's speed for movement
-                enemy.attack_ticks += 1.0;
+                enemy.movement_ticks += enemy.speed * game_speed; // Use enemy's speed for movement
+                enemy.attack_ticks += game_speed;
+                enemy.tick_attack_cooldowns(delta);
 
-                let distance = enemy.position.distance_to(&player_pos);
-                if distance > 1 && distance <= enemy.detection_radius && enemy.movement_ticks >= 1.0
+                let distance = enemy.position.distance_with(&player_pos, metric);
+                if (distance > 1 || enemy.is_fleeing() || enemy.is_ranged())
+                    && enemy.detects(&player_pos, metric)
+                    && enemy.movement_ticks >= 1.0
                 // Use 1.0 threshold for consistency with speed
                 {
                     enemy.movement_ticks -= 1.0; // Deduct movement cost
-                    let dx = (player_pos.x - enemy.position.x).signum();
-                    let dy = (player_pos.y - enemy.position.y).signum();
-
-                    let new_x = enemy.position.x + dx;
-                    let new_y = enemy.position.y + dy;
-
-                    // Check if target position is walkable AND not occupied by player
-                    if (new_x, new_y) != (player_pos.x, player_pos.y)
-                        && walkable_tiles.contains(&(new_x, new_y))
-                    {
-                        enemy.position.x = new_x;
-                        enemy.position.y = new_y;
+                    if enemy.is_ranged() && !enemy.is_fleeing() {
+                        enemy.ranged_step(&player_pos, walkable_tiles); // Keep firing distance
+                    } else if let Some(&target) = pack_targets.get(&index) {
+                        enemy.pack_step(target, &player_pos, walkable_tiles); // Surround with the pack
                     } else {
-                        // Try moving along each axis separately, but still avoid player
-                        if dx != 0
-                            && (enemy.position.x + dx, enemy.position.y)
-                                != (player_pos.x, player_pos.y)
-                            && walkable_tiles.contains(&(enemy.position.x + dx, enemy.position.y))
-                        {
-                            enemy.position.x += dx;
-                        } else if dy != 0
-                            && (enemy.position.x, enemy.position.y + dy)
-                                != (player_pos.x, player_pos.y)
-                            && walkable_tiles.contains(&(enemy.position.x, enemy.position.y + dy))
-                        {
-                            enemy.position.y += dy;
-                        }
+                        enemy.pursuit_step(&player_pos, walkable_tiles); // Chase, or flee once badly hurt
                     }
                 } else if distance > enemy.detection_radius {
                     // Enemy is out of detection range - will wander in separate pass below
                 }
 
-                let distance = enemy.position.distance_to(&player_pos);
-                if distance <= 1 && enemy.attack_ticks >= 65.0 && self.player_has_acted {
-                    enemy.attack_ticks = 0.0;
-
+                let distance = enemy.position.distance_with(&player_pos, metric);
+                if distance <= 1 && enemy.attack_ready(tick_seconds) && self.player_has_acted {
                     let rarity_damage = match enemy.rarity {
                         crate::model::enemy_type::EnemyRarity::Fighter => 3,
                         crate::model::enemy_type::EnemyRarity::Guard => 5,
@@ -1502,38 +2639,73 @@ impl App {
                         attack_dir_y,
                     );
 
-                    if !frames.is_empty() {
-                        let category = Self::get_attack_pattern_category(&attack_pattern);
-                        self.active_animations
-                            .push(ActiveAnimation::new_with_category(frames, category));
-                    }
-
-                    attacks_on_player.push((attack_damage, dx, dy));
+                    // Telegraph the attack so the player can dodge; it lands only on the
+                    // tiles shown, with bosses winding up for longer
+                    let windup =
+                        if matches!(enemy.rarity, crate::model::enemy_type::EnemyRarity::Boss) {
+                            crate::constants::BOSS_TELEGRAPH_TIME
+                        } else {
+                            crate::constants::ENEMY_TELEGRAPH_TIME
+                        };
+                    enemy.telegraph = Some(crate::model::enemy::EnemyTelegraph {
+                        pattern: attack_pattern,
+                        frames,
+                        damage: attack_damage,
+                        direction: (dx, dy),
+                        remaining: windup,
+                    });
+
+                    // Start this attack's cooldown and cycle to the next pattern
+                    enemy.finish_attack();
+                }
+            }
 
-                    // Cycle attack pattern to the next one
-                    if !enemy.attacks.is_empty() {
-                        enemy.current_attack_index =
-                            (enemy.current_attack_index + 1) % enemy.attacks.len();
+            // Ranged enemies shoot once the player is lined up with nothing in the way
+            if !enemies_frozen && self.player_has_acted {
+                for idx in 0..floor.enemies.len() {
+                    let enemy = &floor.enemies[idx];
+                    if !enemy.is_alive() || enemy.telegraph.is_some() || !enemy.is_ranged() {
+                        continue;
+                    }
+                    let clear_shot = floor.has_line_of_sight(
+                        enemy.position.x,
+                        enemy.position.y,
+                        player_pos.x,
+                        player_pos.y,
+                    );
+                    if let Some(arrow) = floor.enemies[idx].try_ranged_shot(&player_pos, clear_shot)
+                    {
+                        self.arrows.push(arrow);
                     }
                 }
+            }
 
-                // Only register hits if player is in attack animation
-                if !current_attack_area.is_empty()
-                    && current_attack_area.contains(&(enemy.position.x, enemy.position.y))
-                {
-                    hit_enemy_indices.push(enemy_idx);
-                }
+            // Only register hits if player is in attack animation, against where enemies ended up
+            if !current_attack_area.is_empty() {
+                floor.rebuild_enemy_grid();
+                hit_enemy_indices = floor.enemies_in_area(&current_attack_area);
             }
 
-            // Second pass: handle wandering for enemies out of detection range
-            for enemy in &mut floor.enemies {
-                if !enemy.is_alive() {
+            // Second pass: enemies out of detection range head home, then idle near spawn
+            for idx in 0..floor.enemies.len() {
+                let enemy = &mut floor.enemies[idx];
+                if !enemy.is_alive() || enemies_frozen {
                     continue;
                 }
-                let distance = enemy.position.distance_to(&player_pos);
+                let distance = enemy.position.distance_with(&player_pos, metric);
                 if distance > enemy.detection_radius && enemy.movement_ticks >= 1.0 {
-                    // Inline wander behavior (single random direction instead of A*)
                     enemy.movement_ticks -= 1.0; // Deduct movement cost based on speed
+
+                    // Leash: walk back toward spawn once the player escaped. The step is
+                    // planned on a copy so the floor still shows every enemy where it stands.
+                    if !enemy.is_near_spawn() {
+                        let mut leashed = enemy.clone();
+                        leashed.return_to_spawn(floor, (player_pos.x, player_pos.y));
+                        floor.enemies[idx] = leashed;
+                        continue;
+                    }
+
+                    // Inline wander behavior (single random direction instead of A*)
                     enemy.is_wandering = true;
 
                     let directions = [
@@ -1552,8 +2724,13 @@ impl App {
                         let new_x = enemy.position.x + dx;
                         let new_y = enemy.position.y + dy;
 
-                        // Prevent wandering into player's position
-                        if (new_x, new_y) == (player_pos.x, player_pos.y) {
+                        // Prevent wandering into player's position or away from spawn
+                        if (new_x, new_y) == (player_pos.x, player_pos.y)
+                            || enemy
+                                .spawn_point
+                                .distance_to(&crate::model::enemy::Position::new(new_x, new_y))
+                                > crate::constants::ENEMY_LEASH_IDLE_RADIUS
+                        {
                             continue;
                         }
 
@@ -1579,7 +2756,7 @@ impl App {
             for idx in hit_enemy_indices {
                 if idx < floor.enemies.len() {
                     let damage = self.character.get_effective_attack_damage();
-                    let knockback_force = 1.0; // Exactly 1 block knockback per hit
+                    let knockback_force = crate::constants::ENEMY_HIT_KNOCKBACK;
 
                     // Use player's facing direction for knockback, not direction to enemy
                     let (player_dir_x, player_dir_y) = self.character.last_direction;
@@ -1591,13 +2768,21 @@ impl App {
 
                     floor.enemies[idx].apply_knockback(dx, dy, knockback_force);
                     floor.enemies[idx].take_damage(damage);
+                    heavy_hit |= floor.enemies[idx].is_heavy_hit(damage);
                     // Charge player's ultimate based on damage dealt
-                    self.character.charge_ultimate(damage);
+                    self.character.land_hit(damage);
                     // Play hit sound when enemy is damaged
                     self.audio_manager.play_sound_effect(SoundEffect::Hit);
                 }
             }
 
+            // Spike traps hurt enemies lured onto them here; the player's share goes
+            // through hit_player with the other attacks
+            let spike_damage = floor.update_hazards(delta, self.character_position);
+            if spike_damage > 0 {
+                attacks_on_player.push((spike_damage, 0.0, 0.0));
+            }
+
             let mut dead_enemies = Vec::new();
             for (idx, enemy) in floor.enemies.iter().enumerate() {
                 if !enemy.is_alive() {
@@ -1607,8 +2792,21 @@ impl App {
 
             for idx in dead_enemies.iter().rev() {
                 let enemy = floor.enemies.remove(*idx);
-                // Play death sound when enemy is killed
-                self.audio_manager.play_sound_effect(SoundEffect::Death);
+                // Play a death sound for what the enemy was made of, quieter for distant deaths
+                self.audio_manager.play_sound_effect_at(
+                    SoundEffect::for_enemy_death(&enemy.enemy_type, &enemy.rarity),
+                    (enemy.position.x, enemy.position.y),
+                    self.character_position,
+                );
+                self.particle_system.emit_death(
+                    enemy.position.x as f32,
+                    enemy.position.y as f32,
+                    &enemy.enemy_type,
+                    &enemy.rarity,
+                );
+                if enemy.rarity == crate::model::enemy_type::EnemyRarity::Boss {
+                    self.screen_flash_timer = crate::constants::SCREEN_FLASH_TIME;
+                }
                 // Increment kill counter
                 self.character.enemies_killed += 1;
 
@@ -1622,7 +2820,11 @@ impl App {
                 // Try to drop at adjacent position first, otherwise place at enemy location
                 if !floor.try_drop_item_adjacent(gold_item, enemy_x, enemy_y) {
                     gold_item = crate::model::item::ItemDrop::gold(gold_drop, enemy_x, enemy_y);
-                    floor.add_item(gold_item);
+                    if self.settings.merge_gold_piles {
+                        floor.add_item_merged(gold_item);
+                    } else {
+                        floor.add_item(gold_item);
+                    }
                 }
 
                 // Weapon drops with 33% chance
@@ -1665,11 +2867,11 @@ impl App {
         }
 
         for (attack_damage, dx, dy) in attacks_on_player {
-            self.character.apply_knockback(dx, dy, 0.5);
-            self.character.take_damage(attack_damage);
-            // Player gains ultimate charge when damaged by enemies
-            self.character.charge_ultimate(attack_damage);
-            self.audio_manager.play_damaged_sound();
+            self.hit_player(attack_damage, dx, dy);
+        }
+        if heavy_hit {
+            self.hitstop_timer
+                .trigger(self.settings.hitstop_ms as f32 / 1000.0);
         }
 
         self.particle_system.update();
@@ -1677,47 +2879,88 @@ impl App {
         // Check for player death
         self.check_and_handle_death();
 
-        // Check if all enemies on current floor are defeated - advance to next floor
-        if let Some(floor) = &self.current_floor {
-            if floor.enemies.is_empty() && self.player_has_acted {
-                // All enemies defeated - check if this was the boss level
-                if self.is_boss_level {
-                    // Victory! All levels and boss defeated
-                    self.victory_win_time = if let Some(started_at) = self.game_started_at {
-                        started_at.elapsed().as_secs_f32()
-                    } else {
-                        0.0
-                    };
-                    self.state = AppState::VictoryScreen;
-                    self.audio_manager
-                        .play_sound_effect(SoundEffect::AdvanceLevel);
+        // Check if all enemies on current floor are defeated - reveal stairs or advance
+        let (px, py) = self.character_position;
+        let mut floor_cleared = false;
+        if let Some(floor) = &mut self.current_floor {
+            // Summoned adds don't hold up a boss floor once the boss itself is dead
+            let enemies_left = if self.is_boss_level {
+                floor.has_boss()
+            } else {
+                !floor.enemies.is_empty()
+            };
+            if !enemies_left && self.player_has_acted {
+                // Boss floors end the run right away; others wait for the player to take the stairs
+                floor_cleared = if self.is_boss_level || self.dev_instant_advance {
+                    true
                 } else {
-                    // Play level advance sound and go to next floor
+                    floor.reveal_stairs(px, py);
+                    let wants_stairs =
+                        self.settings.auto_pickup || std::mem::take(&mut self.stairs_requested);
+                    floor.is_stairs_at(px, py) && wants_stairs
+                };
+            }
+        }
+
+        if floor_cleared {
+            // All enemies defeated - check if this was the boss level
+            if let Some(tutorial) = &self.tutorial {
+                // The tutorial's stairs lead back to the menu once every lesson is done
+                if tutorial.is_finished() {
                     self.audio_manager
                         .play_sound_effect(SoundEffect::AdvanceLevel);
-                    self.floor_level += 1;
-                    self.player_has_acted = false;
-                    self.regenerate_floor();
+                    self.end_tutorial();
+                }
+            } else if self.is_boss_level {
+                // Victory! All levels and boss defeated
+                self.victory_win_time = if let Some(started_at) = self.game_started_at {
+                    started_at.elapsed().as_secs_f32()
+                } else {
+                    0.0
+                };
+                self.victory_score = self.character.run_score(self.max_levels);
+                if self.victory_score > self.best_score {
+                    self.best_score = self.victory_score;
                 }
+                self.auto_save(); // Persist the best score
+                self.record_run(
+                    crate::model::run_history::RunOutcome::Victory,
+                    self.max_levels,
+                    self.victory_win_time,
+                );
+                self.state = AppState::VictoryScreen;
+                let _ = self.audio_manager.restore_normal_music();
+                self.audio_manager
+                    .play_sound_effect(SoundEffect::AdvanceLevel);
+            } else {
+                // Play level advance sound and go to next floor
+                self.audio_manager
+                    .play_sound_effect(SoundEffect::AdvanceLevel);
+                if self.settings.adaptive_difficulty {
+                    self.adaptive_difficulty
+                        .floor_cleared(self.character.health_max);
+                }
+                self.floor_level += 1;
+                self.player_has_acted = false;
+                self.regenerate_floor();
             }
         }
     }
 
     pub fn update_camera_smooth(&mut self) {
-        let dx = self.camera_target.0 - self.camera_offset.0;
-        let dy = self.camera_target.1 - self.camera_offset.1;
-
-        let ease_factor = 0.05;
-
-        if dx.abs() > 0.1 || dy.abs() > 0.1 {
-            self.camera_offset.0 += dx * ease_factor;
-            self.camera_offset.1 += dy * ease_factor;
-        } else {
-            self.camera_offset = self.camera_target;
-        }
+        self.camera_offset = crate::model::camera::ease_toward(
+            self.camera_offset,
+            self.camera_target,
+            self.settings.camera_snap,
+        );
     }
 
     pub fn save_game(&self) -> std::io::Result<()> {
+        self.game_save().save()
+    }
+
+    /// Everything `load_game` needs to resume this run
+    fn game_save(&self) -> crate::model::gamesave::GameSave {
         use crate::model::gamesave::{
             ConsumableData, EnchantData, GameSave, InventoryData, PathNodeData, PlayerStats,
             SkillTreeData, UltimateShopData, WeaponData,
@@ -1805,10 +3048,11 @@ impl App {
             .map(|(k, v)| (format!("{:?}", k), *v))
             .collect();
 
-        let save = GameSave {
+        GameSave {
+            version: crate::model::gamesave::SAVE_VERSION,
             player_name: self.char_name.clone(),
             player_stats: PlayerStats {
-                attack_damage: self.character.attack_damage,
+                attack_damage: self.character.base_attack_damage(),
                 attack_length: self.character.attack_length,
                 attack_width: self.character.attack_width,
                 dash_distance: self.character.dash_distance,
@@ -1818,6 +3062,7 @@ impl App {
                 enemies_killed: self.character.enemies_killed,
                 speed: self.character.speed,
                 ultimate_charge: self.character.ultimate_charge,
+                arrow_speed: self.character.arrow_speed,
             },
             inventory_data: InventoryData {
                 weapons,
@@ -1839,26 +3084,42 @@ impl App {
             position_y: self.character_position.1,
             difficulty: self.settings.difficulty.name().to_string(),
             time_elapsed: time,
-        };
-        save.save()
+            run_mode: self.run_mode.name().to_string(),
+            starting_class: self.starting_class.name().to_string(),
+            best_score: self.best_score,
+            gold_recovery: self.gold_recovery.clone(),
+            new_game_plus: self.new_game_plus,
+            status_effects: crate::model::gamesave::StatusEffectData::capture(
+                &self.character.status_effects,
+            ),
+            adaptive_difficulty: self.adaptive_difficulty.clone(),
+        }
     }
 
     pub fn load_game(&mut self, player_name: &str) -> std::io::Result<()> {
+        let save = crate::model::gamesave::GameSave::load(player_name)?;
+        self.restore_game_save(&save);
+        Ok(())
+    }
+
+    /// Put the character and run state back the way `save` recorded them
+    fn restore_game_save(&mut self, save: &crate::model::gamesave::GameSave) {
         use crate::model::consumable::Consumable;
-        use crate::model::gamesave::GameSave;
         use crate::model::item_tier::ItemTier;
         use crate::model::skill_tree_path::{PathType, SkillPathNode, SkillTreeManager, StatBonus};
         use crate::model::ultimate::UltimateType;
         use crate::model::ultimate_shop::StatUpgradeType;
         use crate::model::weapon::{Enchant, EnchantType, Weapon, WeaponType};
 
-        let save = GameSave::load(player_name)?;
+        self.dev_play_active = false;
 
         // Restore character name
         self.character.name = save.player_name.clone();
         self.char_name = save.player_name.clone();
 
-        // Restore character stats
+        // Restore character stats (drop any running buff so it can't revert onto loaded stats)
+        self.character.end_ultimate_buff();
+        self.character.end_potion_buff();
         self.character.attack_damage = save.player_stats.attack_damage;
         self.character.attack_length = save.player_stats.attack_length;
         self.character.attack_width = save.player_stats.attack_width;
@@ -1869,6 +3130,10 @@ impl App {
         self.character.enemies_killed = save.player_stats.enemies_killed;
         self.character.speed = save.player_stats.speed;
         self.character.ultimate_charge = save.player_stats.ultimate_charge;
+        self.character.arrow_speed = save.player_stats.arrow_speed;
+        self.character.status_effects =
+            crate::model::gamesave::StatusEffectData::restore(&save.status_effects);
+        self.adaptive_difficulty = save.adaptive_difficulty.clone();
 
         // Restore weapons
         let mut weapons = Vec::new();
@@ -1944,6 +3209,8 @@ impl App {
                 "AntitoxinVial" => crate::model::consumable::ConsumableType::AntitoxinVial,
                 "FireOilFlask" => crate::model::consumable::ConsumableType::FireOilFlask,
                 "BlessedBread" => crate::model::consumable::ConsumableType::BlessedBread,
+                "ScryingEye" => crate::model::consumable::ConsumableType::ScryingEye,
+                "StrengthPotion" => crate::model::consumable::ConsumableType::StrengthPotion,
                 _ => crate::model::consumable::ConsumableType::WeakHealingDraught,
             };
             let mut consumable = Consumable::new(consumable_type);
@@ -2017,6 +3284,7 @@ impl App {
                     "AttackSpeed" => Some(StatUpgradeType::AttackSpeed),
                     "MovementSpeed" => Some(StatUpgradeType::MovementSpeed),
                     "DashDistance" => Some(StatUpgradeType::DashDistance),
+                    "PickupRadius" => Some(StatUpgradeType::PickupRadius),
                     _ => None,
                 };
                 upgrade_type.map(|ut| (ut, *v))
@@ -2042,7 +3310,476 @@ impl App {
 
         // Restore difficulty
         self.settings.difficulty = crate::model::item_tier::Difficulty::from_name(&save.difficulty);
+        self.run_mode = crate::model::settings::RunMode::from_name(&save.run_mode);
+        self.starting_class = crate::model::loadout::StartingClass::from_name(&save.starting_class);
+        self.best_score = save.best_score;
+        self.gold_recovery = save.gold_recovery.clone();
+        self.new_game_plus = save.new_game_plus;
+    }
+}
 
-        Ok(())
+/// Headless harness: drive the game logic without a terminal, sound device or files.
+/// Public so the integration tests can use it too.
+#[doc(hidden)]
+impl App {
+    /// App with default settings (nothing is read from disk), `NullAudio`, a fixed
+    /// terminal size and floors generated from `seed`. Combat rolls stay random.
+    pub fn headless(seed: u64, width: u16, height: u16) -> Self {
+        let mut app = Self::with_parts(Settings::default(), Box::new(NullAudio));
+        app.update_terminal_size(width, height);
+        app.fixed_seed = Some(seed);
+        app.run_history_file = std::env::temp_dir().join(format!(
+            "headless_run_history_{}_{}.json",
+            std::process::id(),
+            seed
+        ));
+        app
+    }
+
+    /// Run `frames` frames of game logic the way the main loop does, without drawing
+    pub fn tick_n(&mut self, frames: u32) {
+        for _ in 0..frames {
+            self.update_game_logic();
+            self.update_arrows();
+        }
+    }
+
+    /// Defeat every enemy on the current floor, counting the kills
+    pub fn kill_all_enemies(&mut self) {
+        if let Some(floor) = &mut self.current_floor {
+            self.character.enemies_killed += floor.enemies.len() as u32;
+            floor.enemies.clear();
+        }
+        self.player_has_acted = true;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_gameplay_runs_on_null_audio() {
+        let mut app = App::headless(3, 120, 40);
+        assert!(app.audio_manager.is_silent());
+        app.restart_game();
+
+        // Low health would start the heartbeat on real audio
+        app.character.health = 1;
+        app.tick_n(10);
+        assert!(app.state == AppState::Game);
+        assert!(!app.audio_manager.is_heartbeat_playing());
+
+        // Cues along the way, like the level-up chime, are dropped without a device
+        app.character.health = app.character.health_max;
+        app.kill_all_enemies();
+        app.dev_instant_advance = true;
+        app.tick_n(1);
+        assert_eq!(app.floor_level, 2);
+        assert!(app.audio_manager.is_silent());
+    }
+
+    #[test]
+    fn test_clearing_a_floor_and_taking_the_stairs_advances() {
+        let mut app = App::headless(42, 120, 40);
+        app.restart_game();
+        assert_eq!(app.floor_level, 1);
+
+        app.kill_all_enemies();
+        app.tick_n(1);
+        let stairs = app.current_floor.as_ref().and_then(|floor| floor.stairs);
+        let stairs = stairs.expect("stairs appear once the floor is clear");
+
+        app.character_position = stairs;
+        app.stairs_requested = true;
+        app.tick_n(1);
+        assert_eq!(app.floor_level, 2);
+        assert!(!app.current_floor.as_ref().unwrap().enemies.is_empty());
+    }
+
+    #[test]
+    fn test_declined_weapon_swap_leaves_the_weapon_once() {
+        use crate::model::item::{ItemDrop, ItemDropType};
+        use crate::model::weapon::{Weapon, WeaponType};
+
+        let mut app = App::headless(21, 120, 40);
+        app.restart_game();
+        app.character.weapon_inventory.weapons = vec![Weapon::new_sword(); 9];
+        let (x, y) = app.character_position;
+        let floor = app.current_floor.as_mut().unwrap();
+        floor.items.clear();
+        floor.add_item(ItemDrop::weapon(Weapon::new_mace(), x, y));
+
+        app.interact();
+        assert!(app.weapon_swap_prompt.is_some());
+
+        app.resolve_weapon_swap(None);
+        app.resolve_weapon_swap(None);
+        let maces = app
+            .current_floor
+            .as_ref()
+            .unwrap()
+            .items
+            .iter()
+            .filter(|item| {
+                matches!(&item.item_type, ItemDropType::Weapon(w) if w.weapon_type == WeaponType::Mace)
+            })
+            .count();
+        assert_eq!(maces, 1);
+        assert!(app.weapon_swap_prompt.is_none());
+        assert!(app
+            .character
+            .weapon_inventory
+            .weapons
+            .iter()
+            .all(|w| w.weapon_type == WeaponType::Sword));
+    }
+
+    #[test]
+    fn test_share_sliders_step_down_to_exactly_zero() {
+        use crate::model::settings::SettingsRow;
+        use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+        let mut app = App::headless(3, 120, 40);
+        app.state = AppState::Settings;
+        app.temp_settings.player_knockback = 0.0;
+        app.temp_settings.particle_density = 0.0;
+        let rows = SettingsRow::settings_screen();
+        for target in [SettingsRow::PlayerKnockback, SettingsRow::ParticleDensity] {
+            let index = rows.iter().position(|row| *row == target).unwrap();
+            app.settings_state.select(Some(index));
+            // Up and back down again: summing 0.1s in f32 leaves a crumb above zero
+            for code in [KeyCode::Right; 7].into_iter().chain([KeyCode::Left; 7]) {
+                crate::input::handle_input(&mut app, KeyEvent::new(code, KeyModifiers::NONE));
+            }
+        }
+        assert_eq!(app.temp_settings.player_knockback, 0.0);
+        assert_eq!(app.temp_settings.particle_density, 0.0);
+    }
+
+    #[test]
+    fn test_held_attack_auto_fires_at_weapon_cooldown() {
+        use crate::model::loadout::StartingClass;
+        use crossterm::event::{KeyModifiers, MouseButton, MouseEvent, MouseEventKind};
+
+        let mouse = |kind| MouseEvent {
+            kind,
+            column: 0,
+            row: 0,
+            modifiers: KeyModifiers::NONE,
+        };
+
+        // Held from the keyboard with release events, then with the mouse without them
+        for via_mouse in [false, true] {
+            let mut app = App::headless(16, 120, 40);
+            app.starting_class = StartingClass::Archer;
+            app.restart_game();
+            app.settings.auto_fire = true;
+            app.key_releases_supported = !via_mouse;
+            app.last_game_tick = Instant::now() - std::time::Duration::from_secs(1);
+
+            if via_mouse {
+                crate::input::handle_mouse_event(
+                    &mut app,
+                    mouse(MouseEventKind::Down(MouseButton::Left)),
+                );
+            } else {
+                app.press_attack();
+            }
+            assert!(app.attack_trigger.is_held());
+            assert!(app.character.bow_charging);
+
+            // Cooldowns run on the clock, so let real time pass with each frame
+            let interval = app.character.current_weapon_cooldown();
+            let min_frames = (interval / app.tick_delta()).floor() as u32;
+            let mut shot_frames = Vec::new();
+            for frame in 1..=min_frames * 4 {
+                std::thread::sleep(std::time::Duration::from_millis(16));
+                app.arrows.clear();
+                app.tick_n(1);
+                if !app.arrows.is_empty() {
+                    shot_frames.push(frame);
+                }
+                if shot_frames.len() == 3 {
+                    break;
+                }
+            }
+            assert_eq!(shot_frames.len(), 3, "held attack keeps firing");
+            assert!(shot_frames[0] >= min_frames);
+            assert!(shot_frames[1] - shot_frames[0] >= min_frames);
+
+            if via_mouse {
+                crate::input::handle_mouse_event(
+                    &mut app,
+                    mouse(MouseEventKind::Up(MouseButton::Left)),
+                );
+            } else {
+                app.release_attack();
+            }
+            app.arrows.clear();
+            for _ in 0..min_frames * 2 {
+                std::thread::sleep(std::time::Duration::from_millis(16));
+                app.tick_n(1);
+            }
+            assert!(app.arrows.is_empty(), "releasing stops auto-fire");
+        }
+    }
+
+    #[test]
+    fn test_keyboard_bow_tap_fires_without_waiting() {
+        use crate::model::loadout::StartingClass;
+
+        let mut app = App::headless(12, 120, 40);
+        app.starting_class = StartingClass::Archer;
+        app.restart_game();
+
+        // No release events: the press itself shoots
+        app.last_game_tick = Instant::now() - std::time::Duration::from_secs(1);
+        app.press_attack();
+        assert_eq!(app.arrows.len(), 1);
+        assert!(!app.character.bow_charging);
+
+        // With release events the arrow leaves on release, not after the hold grace
+        app.key_releases_supported = true;
+        app.character.bow_cooldown.reset();
+        app.last_game_tick = Instant::now() - std::time::Duration::from_secs(1);
+        app.press_attack();
+        assert!(app.character.bow_charging);
+        app.release_attack();
+        assert_eq!(app.arrows.len(), 2);
+    }
+
+    #[test]
+    fn test_new_floor_forgets_a_stairs_request() {
+        let mut app = App::headless(8, 120, 40);
+        app.restart_game();
+        app.stairs_requested = true;
+        app.regenerate_floor();
+        assert!(!app.stairs_requested);
+    }
+
+    #[test]
+    fn test_game_speed_belongs_to_each_app() {
+        let mut slow = App::headless(1, 120, 40);
+        let mut fast = App::headless(1, 120, 40);
+        slow.settings.game_speed = 0.5;
+        fast.settings.game_speed = 2.0;
+
+        // Three quarters of a tick of real time: a full tick only at double speed
+        let since = std::time::Duration::from_millis(GAME_TICK_RATE_MS as u64 * 3 / 4);
+        let started = Instant::now() - since;
+        slow.last_game_tick = started;
+        fast.last_game_tick = started;
+        assert!(fast.should_tick());
+        assert!(!slow.should_tick());
+        assert_eq!(fast.tick_delta(), slow.tick_delta() * 4.0);
+    }
+
+    #[test]
+    fn test_ultimate_damage_does_not_charge_the_ultimate() {
+        let mut app = App::headless(2, 120, 40);
+        app.restart_game();
+        let mut floor = Floor::from_layout(&["#######", "#.....#", "#.....#", "#######"], 0);
+        floor
+            .enemies
+            .push(crate::model::enemy::Enemy::new(3, 1, 1.0));
+        let health_before = floor.enemies[0].health;
+        app.current_floor = Some(floor);
+        app.character_position = (2, 1);
+        app.character.ultimate_charge = 0.0;
+
+        app.resolve_ultimate();
+        let enemy = &app.current_floor.as_ref().unwrap().enemies[0];
+        assert!(enemy.health < health_before);
+        assert_eq!(app.character.ultimate_charge, 0.0);
+    }
+
+    #[test]
+    fn test_leashing_enemies_do_not_walk_through_each_other() {
+        use crate::model::enemy::{Enemy, Position};
+
+        let mut app = App::headless(4, 120, 40);
+        app.restart_game();
+        let mut floor = Floor::from_layout(&["##########", "#........#", "##########"], 0);
+        // Standing guard in the corridor, watching the player and never moving
+        let mut guard = Enemy::new(4, 1, 0.0);
+        guard.detection_radius = 100;
+        // Lost sight of the player and heading home past the guard
+        let mut leashed = Enemy::new(5, 1, 1.0);
+        leashed.spawn_point = Position::new(1, 1);
+        leashed.detection_radius = 1;
+        floor.enemies = vec![leashed, guard];
+        app.current_floor = Some(floor);
+        app.walkable_tiles_cache = None;
+        app.character_position = (8, 1);
+
+        app.tick_n(5);
+        let enemies = &app.current_floor.as_ref().unwrap().enemies;
+        assert_eq!((enemies[1].position.x, enemies[1].position.y), (4, 1));
+        assert_eq!((enemies[0].position.x, enemies[0].position.y), (5, 1));
+    }
+
+    #[test]
+    fn test_boss_floor_boss_comes_from_the_seed() {
+        let boss_on = |seed| {
+            let mut app = App::headless(seed, 120, 40);
+            app.restart_game();
+            app.floor_level = app.max_levels;
+            app.regenerate_floor();
+            let floor = app.current_floor.as_ref().unwrap();
+            let boss = (floor.enemies.iter())
+                .find(|enemy| enemy.rarity == crate::model::enemy_type::EnemyRarity::Boss);
+            boss.map(|boss| boss.name.clone())
+        };
+        for seed in 0..4 {
+            let boss = boss_on(seed);
+            assert!(boss.is_some());
+            assert_eq!(boss, boss_on(seed));
+        }
+    }
+
+    #[test]
+    fn test_gold_recovery_survives_save_and_load() {
+        use crate::model::gamesave::{GameSave, GoldRecovery};
+
+        let mut app = App::headless(11, 120, 40);
+        app.restart_game();
+        let (x, y) = app.character_position;
+        app.gold_recovery = Some(GoldRecovery {
+            floor_level: 1,
+            x,
+            y,
+            amount: 40,
+        });
+        let written = serde_json::to_string(&app.game_save()).unwrap();
+
+        let mut loaded = App::headless(11, 120, 40);
+        loaded.restore_game_save(&GameSave::from_json(&written).unwrap());
+        loaded.resume_loaded_game();
+
+        let pile = loaded.gold_recovery.clone().expect("pile is still pending");
+        assert_eq!(pile.amount, 40);
+        let floor = loaded.current_floor.as_ref().unwrap();
+        let dropped: Vec<_> = floor.items.iter().filter(|item| item.recovery).collect();
+        assert_eq!(dropped.len(), 1);
+        assert_eq!((dropped[0].x, dropped[0].y), (pile.x, pile.y));
+    }
+
+    #[test]
+    fn test_new_game_plus_starts_back_on_floor_one() {
+        use crossterm::event::KeyCode;
+
+        let mut app = App::headless(14, 120, 40);
+        app.restart_game();
+        // Standing on the victory screen after the final boss floor
+        app.floor_level = app.max_levels;
+        app.is_boss_level = true;
+        app.regenerate_floor();
+        app.state = AppState::VictoryScreen;
+
+        crate::ui::victory_screen::handle_input(&mut app, KeyCode::Char('n'));
+
+        assert!(app.state == AppState::Game);
+        assert_eq!(app.new_game_plus, 1);
+        assert_eq!(app.floor_level, 1);
+        assert!(!app.is_boss_level);
+    }
+
+    #[test]
+    fn test_failed_load_stays_on_save_selection() {
+        use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+        let mut app = App::headless(8, 120, 40);
+        app.state = AppState::SaveSelection;
+        // Listed, but with no file behind it
+        let name = format!("headless_missing_{}", std::process::id());
+        app.available_saves = vec![name.clone()];
+        app.save_selection_state.select(Some(0));
+
+        crate::input::handle_input(&mut app, KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE));
+
+        assert!(app.state == AppState::SaveSelection);
+        let error = app.save_load_error.clone().expect("load error is shown");
+        assert!(error.contains(&name));
+        assert!(app.current_floor.is_none());
+    }
+
+    #[test]
+    fn test_archer_arrow_speed_survives_save_and_load() {
+        use crate::model::gamesave::GameSave;
+        use crate::model::loadout::StartingClass;
+
+        let mut app = App::headless(5, 120, 40);
+        app.starting_class = StartingClass::Archer;
+        app.restart_game();
+        let archer_speed = app.character.arrow_speed;
+        assert!(archer_speed > crate::constants::PLAYER_ARROW_SPEED);
+        let written = serde_json::to_string(&app.game_save()).unwrap();
+
+        let mut loaded = App::headless(5, 120, 40);
+        loaded.restore_game_save(&GameSave::from_json(&written).unwrap());
+        assert_eq!(loaded.character.arrow_speed, archer_speed);
+    }
+
+    #[test]
+    fn test_held_movement_only_drops_movement_repeats() {
+        use crossterm::event::{KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
+
+        let repeat = |code| KeyEvent::new_with_kind(code, KeyModifiers::NONE, KeyEventKind::Repeat);
+        let mut app = App::headless(9, 120, 40);
+        app.key_releases_supported = true;
+        app.settings.hold_move_rate = 8;
+        assert!(app.held_movement_active());
+
+        // Menu scrolling still follows the terminal's key repeat
+        app.state = AppState::SaveSelection;
+        app.available_saves = vec!["a".to_string(), "b".to_string(), "c".to_string()];
+        app.save_selection_state.select(Some(0));
+        crate::input::handle_key_repeat(&mut app, repeat(KeyCode::Down));
+        crate::input::handle_key_repeat(&mut app, repeat(KeyCode::Down));
+        assert_eq!(app.save_selection_state.selected(), Some(2));
+
+        // In game, a repeated movement key leaves stepping to held movement
+        app.restart_game();
+        let start = app.character_position;
+        let code = KeyCode::Char(app.settings.move_right.chars().next().unwrap());
+        crate::input::handle_key_repeat(&mut app, repeat(code));
+        assert_eq!(app.character_position, start);
+        assert!(app.held_directions.is_empty());
+    }
+
+    #[test]
+    fn test_checkpoint_retry_does_not_record_a_run() {
+        use crate::model::run_history::{RunHistory, RunOutcome};
+        use crossterm::event::KeyCode;
+
+        let mut app = App::headless(21, 120, 40);
+        app.settings.run_mode = crate::model::settings::RunMode::Checkpoint;
+        app.restart_game();
+        // Dying auto-saves so the floor can be retried; keep that save apart from real ones
+        let name = format!("headless_retry_{}", std::process::id());
+        app.char_name = name.clone();
+        app.character.name = name.clone();
+        let _ = std::fs::remove_file(&app.run_history_file);
+
+        app.character.health = 0;
+        app.check_and_handle_death();
+        assert!(app.state == AppState::DeathScreen);
+        crate::ui::death_screen::handle_input(&mut app, KeyCode::Char('r'));
+        assert!(app.state == AppState::Game);
+        assert!(RunHistory::load_from(&app.run_history_file)
+            .records
+            .is_empty());
+
+        // Giving up on the death screen is what ends the run
+        app.character.health = 0;
+        app.check_and_handle_death();
+        crate::ui::death_screen::handle_input(&mut app, KeyCode::Esc);
+        let history = RunHistory::load_from(&app.run_history_file);
+        assert_eq!(history.records.len(), 1);
+        assert_eq!(history.records[0].outcome, RunOutcome::Death);
+
+        let _ = std::fs::remove_file(&app.run_history_file);
+        let _ = GameSave::delete_save(&name);
     }
 }
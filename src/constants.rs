@@ -2,33 +2,91 @@
 
 // Game loop timing
 pub const GAME_TICK_RATE_MS: u128 = 16; // 16ms = 62.5 FPS
+pub const GAME_SPEED_MIN: f32 = 0.5;
+pub const GAME_SPEED_MAX: f32 = 2.0;
+pub const GAME_SPEED_STEP: f32 = 0.25;
+pub const AUTOSAVE_INTERVAL_OPTIONS: &[u32] = &[0, 15, 30, 60, 120, 300]; // Seconds, 0 = off
+pub const SETTINGS_EXPORT_FILE: &str = "settings_export.json"; // Shareable copy written by the settings menu
+pub const DEFAULT_AUTOSAVE_INTERVAL_SECS: u32 = 30;
+pub const HOLD_MOVE_RATE_OPTIONS: &[u32] = &[0, 6, 8, 10, 12, 15, 20]; // Tiles per second while a direction is held, 0 = terminal key repeat
+pub const DEFAULT_HOLD_MOVE_RATE: u32 = 10;
+pub const SOUND_FULL_VOLUME_DISTANCE: f32 = 3.0; // Tiles from the player within which sounds play at full volume
+pub const SOUND_FALLOFF_DISTANCE: f32 = 30.0; // Tiles at which positional sounds reach their quietest
+pub const SOUND_MIN_GAIN: f32 = 0.15; // Quietest a positional sound gets, so far-off fights stay audible
+pub const MUSIC_CROSSFADE_SECS: f32 = 2.0; // Overlap when switching between the normal and boss themes
+pub const SAVE_TOAST_DURATION: f32 = 1.5; // Seconds the "Saved" notification stays up
+pub const SCRAP_TOAST_DURATION: f32 = 2.0; // Seconds the scrapped weapon's gold stays on screen
+pub const FPS_WINDOW_SECS: f32 = 1.0; // Real-time window the FPS counter averages over
+pub const FRAME_TIME_HISTORY: usize = 120; // Frame durations kept for the dev menu graph
 
 // Game world
 pub const FLOOR_WIDTH: i32 = 180;
 pub const FLOOR_HEIGHT: i32 = 60;
+pub const FLOOR_MIN_WIDTH: i32 = 40;
+pub const FLOOR_MIN_HEIGHT: i32 = 20;
+pub const FLOOR_MAX_WIDTH: i32 = 400;
+pub const FLOOR_MAX_HEIGHT: i32 = 160;
+pub const FLOOR_SIZE_STEP: i32 = 10; // Width step for the floor size settings; height moves by half
 pub const DEFAULT_SEED: u64 = 12345;
 
 // Dungeon generation
-pub const FILL_PROBABILITY: u32 = 45; // Percentage chance of wall tile
+pub const FILL_PROBABILITY: u32 = 45; // Percentage chance of a tile starting as open floor
+pub const FILL_PROBABILITY_MIN: u32 = 30; // Lower leaves only scattered pockets
+pub const FILL_PROBABILITY_MAX: u32 = 60; // Higher opens the floor into one field
 pub const CELLULAR_AUTOMATA_ITERATIONS: u32 = 5;
+pub const MAX_CELLULAR_AUTOMATA_ITERATIONS: u32 = 10;
+pub const TUNNEL_WIDTH: u32 = 2; // Tiles across carved tunnels, so corridors aren't single-file chokepoints
+pub const MAX_TUNNEL_WIDTH: u32 = 4;
 pub const CUTOFF_BIG_AREA: i32 = 3;
+pub const ROOM_CORE_WALL_DISTANCE: u32 = 3; // Tiles at least this far from a wall form a room's core
+pub const ROOM_MIN_CORE_TILES: usize = 6; // Smaller open patches are merged into neighbouring rooms
 pub const NEIGHBOR_THRESHOLD_BIG: u32 = 3;
 pub const NEIGHBOR_THRESHOLD_SMALL: u32 = 3;
 
 // Spawning
 pub const ITEMS_PER_FLOOR: usize = 10;
 pub const ENEMY_SPAWN_RADIUS: i32 = 20;
+pub const ENEMY_SPAWN_TILES_PER_ENEMY: usize = 700; // Open tiles in the largest region per enemy on Normal
+pub const ENEMY_SPAWN_JITTER: f32 = 0.2; // Enemy count varies by up to +/-20% per floor
+pub const ENEMY_SPAWN_SAFE_RADIUS: i32 = 5; // Scattered enemies never spawn within this many tiles of the player
+pub const MIN_OPEN_TILES_PER_ENEMY: usize = 40; // Hard cap so small caves never get overcrowded
+pub const ROOM_MAX_GUARDS: usize = 4; // Guards posted in a single room
+pub const ROOM_CHAMPION_MIN_TILES: usize = 400; // Rooms this large are also led by a champion
+pub const ROOM_PLAYER_ROOM_WEIGHT: f32 = 0.25; // Share of enemies the player's spawn room gets relative to its size
+pub const ROOM_PLAYER_CLEARANCE: i32 = 6; // No enemy spawns within this many tiles of the player
+pub const TREASURE_GOLD_PILES: usize = 3; // Gold piles placed beside the treasure room weapon
+pub const TREASURE_GOLD_PER_PILE: u32 = 25;
+pub const TREASURE_GUARD_HEALTH_MULTIPLIER: f32 = 1.5; // Extra health for the treasure room guard
+pub const TREASURE_FLOOR_COLOR: u8 = 137; // Faint gold tint on treasure room floor tiles
+pub const MAX_PICKUP_RADIUS: i32 = 4; // Item magnet upgrade cap (tiles)
+pub const MAX_WEAPON_ENCHANTS: usize = 3; // Enchants the shop will put on a single weapon
+pub const ITEM_MAGNET_STEP_TIME: f32 = 0.08; // Seconds per tile an attracted item drifts
+pub const STAIRS_GLYPH: &str = ">"; // Exit tile revealed once a floor is cleared
+pub const STAIRS_MIN_DISTANCE: usize = 3; // Minimum path distance from the player when placing stairs
 
 // Combat
 pub const PLAYER_BASE_DAMAGE: i32 = 10;
 pub const PLAYER_ATTACK_COOLDOWN: f32 = 0.5;
 pub const PLAYER_BOW_COOLDOWN: f32 = 0.3;
 pub const PLAYER_DASH_COOLDOWN: f32 = 5.0;
+pub const PLAYER_DASH_IFRAMES: f32 = 0.3; // Seconds of invulnerability after dashing
 pub const PLAYER_BLOCK_COOLDOWN: f32 = 6.0;
+pub const PLAYER_HIT_KNOCKBACK: f32 = 0.5; // Knockback force of an enemy hit on the player, before the player's setting
+pub const ENEMY_HIT_KNOCKBACK: f32 = 1.0; // Knockback force of a player hit, scaled by enemy resistance
+pub const KNOCKBACK_SCALE_STEP: f32 = 0.1; // Settings slider step for knockback taken
+pub const CONSUMABLE_GLOBAL_COOLDOWN: f32 = 1.0; // Shared pause after using any non-offensive consumable
+pub const STRENGTH_POTION_DAMAGE_MULTIPLIER: f32 = 1.5; // Attack damage multiplier while a Strength Potion lasts
+pub const STRENGTH_POTION_DURATION: f32 = 15.0;
 pub const PLAYER_MOVEMENT_TICKS_REQUIRED: u32 = 2; // Require 2 game ticks between moves (32ms per move = ~1.95 blocks/sec, ~31 moves/sec)
 pub const ENEMY_MOVEMENT_TICKS_REQUIRED: u32 = 12; // Enemies move every 12 ticks (192ms per move = ~0.33 blocks/sec, ~5.2 moves/sec) - reduced from 5 for balance
 pub const ENEMY_SPEED_MULTIPLIER: f32 = 0.5; // Global multiplier for enemy speed (0.5 = 50% speed, adjust for difficulty/balance)
 pub const BOSS_BASE_SPEED: f32 = 2.5; // Base speed for boss enemies (higher than normal enemies)
+pub const BOSS_SUMMON_INTERVAL: f32 = 12.0; // Seconds between a boss's calls for reinforcements
+pub const BOSS_SUMMON_COUNT: usize = 2; // Adds summoned per call
+pub const BOSS_MAX_ADDS: usize = 4; // Summoned adds alive at once
+pub const BOSS_SUMMON_RADIUS: i32 = 3; // Adds appear within this many steps of the boss
+pub const ENEMY_LEASH_IDLE_RADIUS: i32 = 3; // Enemies that lost the player idle within this distance of spawn
+pub const ENEMY_UNARMED_ATTACK_COOLDOWN: f32 = 1.0; // Seconds between hits for enemies without attack data
 
 // Enemy combat and gold drops
 pub const ENEMY_BASE_HEALTH: i32 = 20;
@@ -37,20 +95,112 @@ pub const GUARD_BASE_GOLD: u32 = 15;
 pub const CHAMPION_BASE_GOLD: u32 = 25;
 pub const ELITE_BASE_GOLD: u32 = 50;
 pub const BOSS_BASE_GOLD: u32 = 150;
+pub const WEAPON_SCRAP_GOLD_PER_DAMAGE: u32 = 2; // Scrap gold per point of weapon damage, times the rarity multiplier
+pub const DEATH_GOLD_DROP_FRACTION: f32 = 0.5; // Share of gold left behind on death at Normal difficulty
+
+// Run score
+pub const RUN_SCORE_PER_KILL: u32 = 10;
+pub const RUN_SCORE_PER_LEVEL: u32 = 100;
+pub const RUN_SCORE_PER_RARITY: u32 = 50; // Per rarity tier above Common of the best weapon held
+pub const RUN_SCORE_PER_SKILL_LEVEL: u32 = 25;
+
+// New Game+
+pub const NEW_GAME_PLUS_GOLD_KEPT: f32 = 0.25; // Share of the winning run's gold carried over
+pub const NEW_GAME_PLUS_ENEMY_SCALING: f32 = 0.25; // Extra enemy health and damage per cycle
+
+// Adaptive difficulty
+pub const ADAPTIVE_STEP: f32 = 0.05; // Enemy stat change after a floor that was notably easy or hard
+pub const ADAPTIVE_DEATH_STEP: f32 = 0.15; // Enemy stat drop after a death
+pub const ADAPTIVE_MAX_OFFSET: f32 = 0.3; // Adaptive scaling never moves enemy stats more than 30% either way
+pub const ADAPTIVE_FAST_CLEAR_SECS: f32 = 90.0; // Clearing a floor this fast counts as dominating it
+pub const ADAPTIVE_SLOW_CLEAR_SECS: f32 = 300.0; // Taking this long counts as struggling
+pub const ADAPTIVE_LOW_DAMAGE_FRACTION: f32 = 0.1; // Share of max health taken that still counts as a clean clear
+pub const ADAPTIVE_HIGH_DAMAGE_FRACTION: f32 = 0.75; // Share of max health taken that counts as struggling
+
+// Hazards
+pub const SPIKE_CYCLE: f32 = 3.0; // Seconds between one raising of a spike trap and the next
+pub const SPIKE_WARNING_TIME: f32 = 0.6; // Seconds the spikes rattle before coming up
+pub const SPIKE_RAISED_TIME: f32 = 0.8; // Seconds the spikes stay up
+pub const SPIKE_STRIKE_INTERVAL: f32 = 0.4; // Seconds between hits while standing on raised spikes
+pub const SPIKE_DAMAGE: i32 = 8;
 
 // Projectiles
 pub const ARROW_SPEED: f32 = 8.0;
 pub const ARROW_MAX_DISTANCE: f32 = 50.0;
 pub const THROW_SPEED: f32 = 10.0;
 pub const FIRE_OIL_IMPACT_RADIUS: i32 = 4;
+pub const BOW_CHARGE_TIME: f32 = 1.0; // Seconds of holding attack for a full charge
+pub const BOW_CHARGE_RELEASE_GRACE: f32 = 0.5; // Keyboard hold ends once key repeats stop for this long
+pub const BOW_CHARGE_MAX_DAMAGE_MULTIPLIER: f32 = 2.0; // Damage multiplier at full charge
+pub const BOW_CHARGE_MAX_SPEED_MULTIPLIER: f32 = 1.75; // Arrow speed multiplier at full charge
+pub const BOW_CHARGE_PIERCE_COUNT: u32 = 1; // Enemies a fully charged arrow passes through
+pub const ENEMY_ARROW_SPEED: f32 = 6.0; // Slower than the player's arrows so they can be dodged
+pub const RANGED_ENEMY_MIN_REACH: i32 = 3; // Enemies with an attack this long fight from range
+pub const RANGED_ENEMY_PREFERRED_DISTANCE: i32 = 3; // Ranged enemies back off when the player is closer
+pub const RANGED_ENEMY_FIRE_RANGE: i32 = 7; // Furthest a ranged enemy shoots from
+pub const RANGED_ENEMY_FIRE_TICKS: f32 = 80.0; // Attack ticks between shots
+pub const PACK_RADIUS: i32 = 4; // Chasing enemies of a type this close together surround the player
+
+// Telegraphs
+pub const ULTIMATE_TELEGRAPH_TIME: f32 = 0.6; // Wind-up before the player's ultimate resolves
+pub const BOSS_TELEGRAPH_TIME: f32 = 0.8; // Wind-up before a boss attack lands
+pub const ENEMY_TELEGRAPH_TIME: f32 = 0.35; // Wind-up before a regular enemy attack lands
+pub const ULTIMATE_SHOCKWAVE_KNOCKBACK: f32 = 2.0; // Knockback force of the Shockwave ultimate
+
+// Combo
+pub const COMBO_WINDOW: f32 = 2.0; // Seconds a combo survives without a new hit
+pub const COMBO_CHARGE_STEP: f32 = 0.1; // Extra ultimate charge multiplier per chained hit
+pub const COMBO_MAX_MULTIPLIER: f32 = 2.0;
+
+// Ultimate buffs
+pub const RAGE_DAMAGE_MULTIPLIER: i32 = 2; // Attack damage multiplier while Rage is active
+pub const RAGE_ATTACK_SPEED_MULTIPLIER: f32 = 2.0; // Attack speed multiplier while Rage is active
+
+// Trails
+pub const TRAIL_LIFETIME: f32 = 0.25; // Seconds a trail particle stays on screen
+pub const MAX_TRAIL_PARTICLES: usize = 64; // Cap so many arrows can't flood the particle system
+
+// Particles
+pub const MAX_PARTICLES: usize = 256; // Live particle cap at full density; emissions past it are skipped
+pub const PARTICLE_DENSITY_STEP: f32 = 0.1; // Settings slider step
+
+// Death effects
+pub const DEATH_PARTICLE_LIFETIME: f32 = 0.6;
+pub const BOSS_DEATH_RING_COUNT: usize = 3; // Rings of debris in a boss's death burst
+pub const SCREEN_FLASH_TIME: f32 = 0.25; // Seconds the screen flashes when a boss dies
+
+// Hit-stop
+pub const HITSTOP_DEFAULT_MS: u32 = 60; // Freeze on a heavy hit, adjustable in the settings
+pub const HITSTOP_MAX_MS: u32 = 150; // No setting or pile-up of hits freezes longer than this
+pub const HITSTOP_STEP_MS: u32 = 20;
+pub const HITSTOP_HEAVY_HIT_FRACTION: f32 = 0.25; // Share of an enemy's max health one hit must deal to count as heavy
 
 // Camera
 pub const CAMERA_SMOOTH_FACTOR: f32 = 0.1;
+pub const CAMERA_EASE_FACTOR: f32 = 0.05; // Fraction of the remaining distance the camera covers per frame
+pub const CAMERA_DEADZONE_DEFAULT: u32 = 30; // Percent of the view the player moves in before it scrolls
+pub const CAMERA_DEADZONE_MAX: u32 = 80;
+pub const CAMERA_DEADZONE_STEP: u32 = 10;
+
+// Fog of war
+pub const FOG_VIEW_RADIUS: i32 = 10; // Tiles the player can see around them
+pub const FOG_EXPLORED_COLOR: u8 = 236; // Dim color index for remembered tiles
+pub const SCRYING_EYE_REVEAL_SECS: f32 = 6.0; // How long a Scrying Eye reveals the floor
+pub const SCRYING_MARK_COLOR: u8 = 54; // Background behind enemies and treasure during a reveal
 
 // UI
 pub const LOGO_ANIMATION_SPEED: f32 = 0.05; // Lower = slower animation (0.05 = ~2s, 0.01 = ~10s)
 pub const HEALTH_BAR_WIDTH: u16 = 20;
+pub const MIN_TERMINAL_WIDTH: u16 = 60; // Smallest playable terminal: right panel plus a usable map
+pub const MIN_TERMINAL_HEIGHT: u16 = 20; // Room for the bottom bars plus a usable map
 pub const INVENTORY_MAX_ITEMS: usize = 5;
+pub const STATUS_BAR_WIDTH: u16 = 3; // Duration bar beside each status icon
+pub const STATUS_BAR_FULL_DURATION: f32 = 8.0; // Seconds shown as a full status bar
+pub const FLOOR_CODE_FILE: &str = "floor_code.txt"; // Dev menu floor code export/import
+pub const RUN_HISTORY_FILE: &str = "run_history.json"; // Finished runs for the Records screen
+pub const RUN_HISTORY_MAX_RECORDS: usize = 50; // Oldest runs are dropped past this many
+pub const TRAINING_DUMMY_HEALTH: i32 = 9999; // Shown on the dev training dummy, which never loses it
+pub const EXAMINE_RADIUS: i32 = 10; // Reach of the examine key when picking the nearest enemy
 
 // Player character defaults
 pub const PLAYER_BASE_HEALTH: i32 = 100;
@@ -61,6 +211,10 @@ pub const PLAYER_ATTACK_WIDTH: i32 = 1; // Width of attack area
 pub const PLAYER_ARROW_SPEED: f32 = 8.0; // Tiles per second
 pub const PLAYER_ATTACK_ANIMATION_TIME: f32 = 0.2; // 200ms animation
 pub const PLAYER_DAMAGE_ANIMATION_TIME: f32 = 1.0; // 1 second damage flash
+pub const AUTO_FACE_DEFAULT_RADIUS: i32 = 8; // Tiles searched for a target by the auto-face assist
+pub const AUTO_FACE_MAX_RADIUS: i32 = 20;
+pub const AUTO_FACE_MOVE_GRACE: f32 = 0.4; // Seconds after moving during which movement direction wins
+pub const LOW_HEALTH_THRESHOLD: f32 = 0.25; // Fraction of max health that triggers the heartbeat cue
 pub const COOLDOWN_BAR_HEIGHT: u16 = 3;
 
 // Colors (for consistency)
@@ -1,5 +1,5 @@
 use crate::app::{App, AppState};
-use crossterm::event::{self, KeyEvent, MouseButton, MouseEvent, MouseEventKind};
+use crossterm::event::{KeyEvent, MouseButton, MouseEvent, MouseEventKind};
 
 pub mod handlers;
 pub mod menu;
@@ -16,9 +16,18 @@ pub fn handle_input(app: &mut App, key: KeyEvent) {
         AppState::UltimateShop => handlers::handle_ultimate_shop_input(app, key),
         AppState::DeathScreen => crate::ui::death_screen::handle_input(app, key.code),
         AppState::VictoryScreen => crate::ui::victory_screen::handle_input(app, key.code),
+        AppState::Records => handlers::handle_records_input(app, key),
     }
 }
 
+pub fn handle_key_repeat(app: &mut App, key: KeyEvent) {
+    handlers::handle_key_repeat(app, key);
+}
+
+pub fn handle_key_release(app: &mut App, key: KeyEvent) {
+    handlers::handle_key_release(app, key);
+}
+
 pub fn handle_mouse_event(app: &mut App, mouse: MouseEvent) {
     match app.state {
         AppState::Settings => match mouse.kind {
@@ -30,43 +39,95 @@ pub fn handle_mouse_event(app: &mut App, mouse: MouseEvent) {
                         _ => return,
                     };
 
-                    match app.settings_state.selected().unwrap_or(0) {
-                        0 => app.temp_settings.move_up = k,
-                        1 => app.temp_settings.move_left = k,
-                        2 => app.temp_settings.move_down = k,
-                        3 => app.temp_settings.move_right = k,
-                        4 => app.temp_settings.attack = k,
-                        5 => app.temp_settings.dash = k,
-                        6 => app.temp_settings.block = k,
-                        7 => app.temp_settings.toggle_inv = k,
-                        8 => app.temp_settings.special_item = k,
-                        _ => {}
+                    let rows = crate::model::settings::SettingsRow::settings_screen();
+                    if let Some(crate::model::settings::SettingsRow::Rebind(index)) =
+                        menu::selected_row(&app.settings_state, &rows)
+                    {
+                        if let Some(binding) = app.temp_settings.binding_mut(index) {
+                            *binding = k;
+                        }
                     }
                     app.settings_mode = crate::app::SettingsMode::Navigating;
                 }
             }
-            event::MouseEventKind::ScrollUp => app.set_scroll(app.scroll_offset.saturating_sub(2)),
-            event::MouseEventKind::ScrollDown => {
-                app.set_scroll(app.scroll_offset.saturating_add(2))
-            }
+            MouseEventKind::ScrollUp => handle_scroll(app, -1),
+            MouseEventKind::ScrollDown => handle_scroll(app, 1),
             _ => {}
         },
         AppState::Game => match mouse.kind {
             MouseEventKind::Down(MouseButton::Left) => {
-                app.use_current_weapon();
+                app.mouse_attack_held = true;
+                app.press_attack();
             }
-            event::MouseEventKind::ScrollUp => app.set_scroll(app.scroll_offset.saturating_sub(2)),
-            event::MouseEventKind::ScrollDown => {
-                app.set_scroll(app.scroll_offset.saturating_add(2))
+            MouseEventKind::Up(MouseButton::Left) => {
+                app.mouse_attack_held = false;
+                app.release_attack();
             }
+            MouseEventKind::Down(MouseButton::Right) if app.settings.block == "RightClick" => {
+                app.block();
+            }
+            MouseEventKind::Moved => app.mouse_position = Some((mouse.column, mouse.row)),
+            MouseEventKind::ScrollUp => handle_scroll(app, -1),
+            MouseEventKind::ScrollDown => handle_scroll(app, 1),
             _ => {}
         },
         _ => match mouse.kind {
-            event::MouseEventKind::ScrollUp => app.set_scroll(app.scroll_offset.saturating_sub(2)),
-            event::MouseEventKind::ScrollDown => {
-                app.set_scroll(app.scroll_offset.saturating_add(2))
-            }
+            MouseEventKind::ScrollUp => handle_scroll(app, -1),
+            MouseEventKind::ScrollDown => handle_scroll(app, 1),
             _ => {}
         },
     }
 }
+
+/// Mouse wheel: step through the list on screen one row per notch (-1 up, 1 down).
+/// During play only a focused inventory scrolls, so the wheel never moves a hidden menu.
+fn handle_scroll(app: &mut App, direction: i32) {
+    use crate::model::settings::SettingsRow;
+
+    match app.state {
+        AppState::MainMenu => {
+            app.skip_auto_scroll();
+            let total = crate::ui::main_menu::MENU_ITEMS.len();
+            if direction < 0 {
+                menu::move_selection_up(&mut app.main_menu_state, total);
+            } else {
+                menu::move_selection_down(&mut app.main_menu_state, total);
+            }
+        }
+        AppState::SaveSelection => {
+            let total = app.available_saves.len();
+            if direction < 0 {
+                menu::move_selection_up(&mut app.save_selection_state, total);
+            } else {
+                menu::move_selection_down(&mut app.save_selection_state, total);
+            }
+        }
+        AppState::Records => {
+            let total = app.run_history.records.len();
+            if direction < 0 {
+                menu::move_selection_up(&mut app.records_state, total);
+            } else {
+                menu::move_selection_down(&mut app.records_state, total);
+            }
+        }
+        AppState::Settings if app.settings_mode == crate::app::SettingsMode::Navigating => {
+            let rows = SettingsRow::settings_screen();
+            if direction < 0 {
+                menu::move_row_up(&mut app.settings_state, &rows);
+            } else {
+                menu::move_row_down(&mut app.settings_state, &rows);
+            }
+        }
+        AppState::UltimateShop => {
+            if direction < 0 {
+                app.ultimate_shop_ui.previous();
+            } else {
+                app.ultimate_shop_ui.next();
+            }
+        }
+        AppState::Game if app.inventory_focused && !app.is_paused => {
+            app.scroll_inventory(direction);
+        }
+        _ => {}
+    }
+}
@@ -1,6 +1,6 @@
 use crate::app::{App, AppState};
 use crate::model::audio::SoundEffect;
-use crate::model::settings::Settings;
+use crate::model::settings::{Settings, SettingsRow};
 use crossterm::event::KeyCode;
 
 pub fn handle_main_menu_input(app: &mut App, key: crossterm::event::KeyEvent) {
@@ -9,11 +9,17 @@ pub fn handle_main_menu_input(app: &mut App, key: crossterm::event::KeyEvent) {
 
     match key.code {
         KeyCode::Up | KeyCode::Char('w') | KeyCode::Char('W') => {
-            super::menu::move_selection_up(&mut app.main_menu_state, 5);
+            super::menu::move_selection_up(
+                &mut app.main_menu_state,
+                crate::ui::main_menu::MENU_ITEMS.len(),
+            );
             app.audio_manager.play_sound_effect(SoundEffect::MenuSwitch);
         }
         KeyCode::Down | KeyCode::Char('s') | KeyCode::Char('S') => {
-            super::menu::move_selection_down(&mut app.main_menu_state, 5);
+            super::menu::move_selection_down(
+                &mut app.main_menu_state,
+                crate::ui::main_menu::MENU_ITEMS.len(),
+            );
             app.audio_manager.play_sound_effect(SoundEffect::MenuSwitch);
         }
         KeyCode::Enter | KeyCode::Char(' ') => {
@@ -38,9 +44,11 @@ pub fn handle_main_menu_input(app: &mut App, key: crossterm::event::KeyEvent) {
                     app.save_selection_state.select(Some(0));
                     app.state = AppState::SaveSelection;
                 }
-                Some(2) => app.state = AppState::Settings,
-                Some(3) => app.state = AppState::DevMenu,
-                Some(4) => app.should_quit = true,
+                Some(2) => app.start_tutorial(),
+                Some(3) => app.open_records(),
+                Some(4) => app.state = AppState::Settings,
+                Some(5) => app.state = AppState::DevMenu,
+                Some(6) => app.should_quit = true,
                 _ => {}
             }
         }
@@ -49,6 +57,9 @@ pub fn handle_main_menu_input(app: &mut App, key: crossterm::event::KeyEvent) {
 }
 
 pub fn handle_save_selection_input(app: &mut App, key: crossterm::event::KeyEvent) {
+    // Any key press dismisses the last load error
+    app.save_load_error = None;
+
     match key.code {
         KeyCode::Up | KeyCode::Char('w') | KeyCode::Char('W') => {
             super::menu::move_selection_up(
@@ -69,18 +80,13 @@ pub fn handle_save_selection_input(app: &mut App, key: crossterm::event::KeyEven
             if let Some(index) = app.save_selection_state.selected() {
                 if index < app.available_saves.len() {
                     let save_name = app.available_saves[index].clone();
-                    // Try to load the game - proceed regardless due to errors
-                    let _ = app.load_game(&save_name);
+                    // Stay on the list if the save can't be read, e.g. it is from a newer build
+                    if let Err(e) = app.load_game(&save_name) {
+                        app.save_load_error = Some(format!("Can't load {}: {}", save_name, e));
+                        return;
+                    }
 
-                    // Always transition to Game state
-                    app.state = AppState::Game;
-                    app.arrows.clear();
-                    app.active_animations.clear();
-                    app.particle_system = crate::model::particle::ParticleSystem::new();
-                    app.is_paused = false;
-                    app.regenerate_floor();
-                    app.audio_manager.stop_music();
-                    let _ = app.audio_manager.start_music_with_fade_in();
+                    app.resume_loaded_game();
                 }
             }
         }
@@ -92,52 +98,63 @@ pub fn handle_save_selection_input(app: &mut App, key: crossterm::event::KeyEven
     }
 }
 
+pub fn handle_records_input(app: &mut App, key: crossterm::event::KeyEvent) {
+    let total = app.run_history.records.len();
+    match key.code {
+        KeyCode::Up | KeyCode::Char('w') | KeyCode::Char('W') => {
+            super::menu::move_selection_up(&mut app.records_state, total);
+            app.audio_manager.play_sound_effect(SoundEffect::MenuSwitch);
+        }
+        KeyCode::Down | KeyCode::Char('s') | KeyCode::Char('S') => {
+            super::menu::move_selection_down(&mut app.records_state, total);
+            app.audio_manager.play_sound_effect(SoundEffect::MenuSwitch);
+        }
+        KeyCode::Esc => {
+            app.audio_manager.play_sound_effect(SoundEffect::MenuSwitch);
+            app.state = AppState::MainMenu;
+        }
+        _ => {}
+    }
+}
+
 pub fn handle_settings_input(app: &mut App, key: crossterm::event::KeyEvent) {
+    let rows = SettingsRow::settings_screen();
+    let row = super::menu::selected_row(&app.settings_state, &rows);
+
+    // Any input other than confirming SAVE cancels a pending conflict confirmation
+    let confirming_save = app.settings_mode == crate::app::SettingsMode::Navigating
+        && matches!(key.code, KeyCode::Enter | KeyCode::Char(' '))
+        && row == Some(SettingsRow::Save);
+    if !confirming_save {
+        app.settings_save_confirm = false;
+    }
+    app.settings_status = None;
+
     match app.settings_mode {
         crate::app::SettingsMode::Navigating => match key.code {
             KeyCode::Up | KeyCode::Char('w') | KeyCode::Char('W') => {
-                super::menu::move_selection_up(&mut app.settings_state, 23);
+                super::menu::move_row_up(&mut app.settings_state, &rows);
                 app.audio_manager.play_sound_effect(SoundEffect::MenuSwitch);
             }
             KeyCode::Down | KeyCode::Char('s') | KeyCode::Char('S') => {
-                super::menu::move_selection_down(&mut app.settings_state, 23);
+                super::menu::move_row_down(&mut app.settings_state, &rows);
                 app.audio_manager.play_sound_effect(SoundEffect::MenuSwitch);
             }
-            KeyCode::Left | KeyCode::Char('a') | KeyCode::Char('A') => {
-                let sel = app.settings_state.selected().unwrap_or(0);
-                // Music volume
-                if sel == 16 {
-                    app.temp_settings.music_volume =
-                        (app.temp_settings.music_volume - 0.05).max(0.0);
-                    app.audio_manager.play_sound_effect(SoundEffect::MenuSwitch);
-                }
-                // Sound volume
-                else if sel == 17 {
-                    app.temp_settings.sound_volume =
-                        (app.temp_settings.sound_volume - 0.05).max(0.0);
-                    app.audio_manager.play_sound_effect(SoundEffect::MenuSwitch);
-                }
+            KeyCode::Left | KeyCode::Char('a') | KeyCode::Char('A')
+                if adjust_settings_row(app, row, -1) =>
+            {
+                app.audio_manager.play_sound_effect(SoundEffect::MenuSwitch);
             }
-            KeyCode::Right | KeyCode::Char('d') | KeyCode::Char('D') => {
-                let sel = app.settings_state.selected().unwrap_or(0);
-                // Music volume
-                if sel == 16 {
-                    app.temp_settings.music_volume =
-                        (app.temp_settings.music_volume + 0.05).min(1.0);
-                    app.audio_manager.play_sound_effect(SoundEffect::MenuSwitch);
-                }
-                // Sound volume
-                else if sel == 17 {
-                    app.temp_settings.sound_volume =
-                        (app.temp_settings.sound_volume + 0.05).min(1.0);
-                    app.audio_manager.play_sound_effect(SoundEffect::MenuSwitch);
-                }
+            KeyCode::Right | KeyCode::Char('d') | KeyCode::Char('D')
+                if adjust_settings_row(app, row, 1) =>
+            {
+                app.audio_manager.play_sound_effect(SoundEffect::MenuSwitch);
             }
             KeyCode::PageUp => app.set_scroll(app.scroll_offset.saturating_sub(5)),
             KeyCode::PageDown => app.set_scroll(app.scroll_offset.saturating_add(5)),
             KeyCode::Enter | KeyCode::Char(' ') => {
                 app.audio_manager.play_sound_effect(SoundEffect::MenuPick);
-                handle_settings_selection(app);
+                handle_settings_selection(app, row);
             }
             KeyCode::Esc => {
                 app.audio_manager.play_sound_effect(SoundEffect::MenuPick);
@@ -159,23 +176,10 @@ pub fn handle_settings_input(app: &mut App, key: crossterm::event::KeyEvent) {
                 return;
             }
 
-            let k = format!("{:?}", key.code);
-            match app.settings_state.selected().unwrap_or(0) {
-                0 => app.temp_settings.move_up = k,
-                1 => app.temp_settings.move_left = k,
-                2 => app.temp_settings.move_down = k,
-                3 => app.temp_settings.move_right = k,
-                4 => app.temp_settings.attack = k,
-                5 => app.temp_settings.dash = k,
-                6 => app.temp_settings.block = k,
-                7 => app.temp_settings.toggle_inv = k,
-                8 => app.temp_settings.use_consumable = k,
-                9 => app.temp_settings.inventory_up = k,
-                10 => app.temp_settings.inventory_down = k,
-                11 => app.temp_settings.item_describe = k,
-                12 => app.temp_settings.pause = k,
-                13 => app.temp_settings.special_item = k,
-                _ => {}
+            if let Some(SettingsRow::Rebind(index)) = row {
+                if let Some(binding) = app.temp_settings.binding_mut(index) {
+                    *binding = format!("{:?}", key.code);
+                }
             }
             app.audio_manager.play_sound_effect(SoundEffect::MenuPick);
             app.settings_mode = crate::app::SettingsMode::Navigating;
@@ -183,6 +187,90 @@ pub fn handle_settings_input(app: &mut App, key: crossterm::event::KeyEvent) {
     }
 }
 
+/// Move a 0.0 - 1.0 share by one `step`, snapped to whole steps so stepping down
+/// lands on exactly 0.0 instead of a float crumb just above it
+fn step_share(value: f32, step: f32, direction: i32) -> f32 {
+    (((value / step).round() + direction as f32) * step).clamp(0.0, 1.0)
+}
+
+/// Left/Right on a slider row; returns true if the row is adjustable
+fn adjust_settings_row(app: &mut App, row: Option<SettingsRow>, direction: i32) -> bool {
+    let step = direction as f32;
+    let s = &mut app.temp_settings;
+    match row {
+        Some(SettingsRow::MusicVolume) => {
+            s.music_volume = (s.music_volume + 0.05 * step).clamp(0.0, 1.0);
+        }
+        Some(SettingsRow::SoundVolume) => {
+            s.sound_volume = (s.sound_volume + 0.05 * step).clamp(0.0, 1.0);
+        }
+        Some(SettingsRow::AutoFaceRadius) => {
+            s.auto_face_radius =
+                (s.auto_face_radius + direction).clamp(1, crate::constants::AUTO_FACE_MAX_RADIUS);
+        }
+        Some(SettingsRow::GameSpeed) => {
+            s.game_speed = (s.game_speed + crate::constants::GAME_SPEED_STEP * step).clamp(
+                crate::constants::GAME_SPEED_MIN,
+                crate::constants::GAME_SPEED_MAX,
+            );
+        }
+        Some(SettingsRow::ParticleDensity) => {
+            s.particle_density = step_share(
+                s.particle_density,
+                crate::constants::PARTICLE_DENSITY_STEP,
+                direction,
+            );
+        }
+        Some(SettingsRow::PlayerKnockback) => {
+            s.player_knockback = step_share(
+                s.player_knockback,
+                crate::constants::KNOCKBACK_SCALE_STEP,
+                direction,
+            );
+        }
+        Some(SettingsRow::AutosaveInterval) => s.cycle_autosave_interval(direction),
+        Some(SettingsRow::HoldMoveRate) => s.cycle_hold_move_rate(direction),
+        Some(SettingsRow::KeyPreset) => s.apply_key_preset(s.key_preset().cycled(direction)),
+        Some(SettingsRow::HitStop) => {
+            let step = crate::constants::HITSTOP_STEP_MS as i32 * direction;
+            s.hitstop_ms = (s.hitstop_ms as i32 + step)
+                .clamp(0, crate::constants::HITSTOP_MAX_MS as i32)
+                as u32;
+        }
+        Some(SettingsRow::CameraDeadzone) => {
+            let step = crate::constants::CAMERA_DEADZONE_STEP as i32 * direction;
+            s.camera_deadzone = (s.camera_deadzone as i32 + step)
+                .clamp(0, crate::constants::CAMERA_DEADZONE_MAX as i32)
+                as u32;
+        }
+        Some(SettingsRow::FloorWidth) => {
+            s.floor_gen.width += crate::constants::FLOOR_SIZE_STEP * direction;
+            s.floor_gen = s.floor_gen.clamped((0, 0));
+        }
+        Some(SettingsRow::FloorHeight) => {
+            s.floor_gen.height += crate::constants::FLOOR_SIZE_STEP / 2 * direction;
+            s.floor_gen = s.floor_gen.clamped((0, 0));
+        }
+        Some(SettingsRow::FillProbability) => {
+            s.floor_gen.fill_probability = s
+                .floor_gen
+                .fill_probability
+                .saturating_add_signed(direction);
+            s.floor_gen = s.floor_gen.clamped((0, 0));
+        }
+        Some(SettingsRow::CaveSmoothing) => {
+            s.floor_gen.iterations = s.floor_gen.iterations.saturating_add_signed(direction);
+            s.floor_gen = s.floor_gen.clamped((0, 0));
+        }
+        Some(SettingsRow::TunnelWidth) => {
+            s.floor_gen.tunnel_width = s.floor_gen.tunnel_width.saturating_add_signed(direction);
+            s.floor_gen = s.floor_gen.clamped((0, 0));
+        }
+        _ => return false,
+    }
+    true
+}
+
 fn key_matches(key_code: KeyCode, key_string: &str) -> bool {
     match key_string {
         "W" => matches!(key_code, KeyCode::Char('w') | KeyCode::Char('W')),
@@ -199,11 +287,17 @@ fn key_matches(key_code: KeyCode, key_string: &str) -> bool {
         "Enter" | "Return" => matches!(key_code, KeyCode::Enter),
         "Up" => matches!(key_code, KeyCode::Up),
         "Down" => matches!(key_code, KeyCode::Down),
+        "Left" => matches!(key_code, KeyCode::Left),
+        "Right" => matches!(key_code, KeyCode::Right),
         "LeftClick" => false,  // Not handled via KeyCode
         "RightClick" => false, // Not handled via KeyCode
         _ => {
-            // Try to parse as single char
-            if key_string.len() == 1 {
+            // Try to parse as single char, bare ("x") or as stored by rebinding ("Char('x')")
+            let key_string = key_string
+                .strip_prefix("Char('")
+                .and_then(|k| k.strip_suffix("')"))
+                .unwrap_or(key_string);
+            if key_string.chars().count() == 1 {
                 if let Some(c) = key_string.chars().next() {
                     matches!(key_code, KeyCode::Char(ch) if ch.to_lowercase().eq(c.to_lowercase()))
                 } else {
@@ -216,6 +310,43 @@ fn key_matches(key_code: KeyCode, key_string: &str) -> bool {
     }
 }
 
+/// Direction a movement key steps the player in, if it is bound to one
+fn movement_direction(settings: &Settings, key_code: KeyCode) -> Option<(i32, i32)> {
+    if key_matches(key_code, &settings.move_up) {
+        Some((0, -1))
+    } else if key_matches(key_code, &settings.move_down) {
+        Some((0, 1))
+    } else if key_matches(key_code, &settings.move_left) {
+        Some((-1, 0))
+    } else if key_matches(key_code, &settings.move_right) {
+        Some((1, 0))
+    } else {
+        None
+    }
+}
+
+/// Repeats act like presses, except for movement keys in game while held movement is
+/// stepping the player on its own
+pub fn handle_key_repeat(app: &mut App, key: crossterm::event::KeyEvent) {
+    if app.state == AppState::Game
+        && app.held_movement_active()
+        && movement_direction(&app.settings, key.code).is_some()
+    {
+        return;
+    }
+    super::handle_input(app, key);
+}
+
+/// Key releases only arrive on terminals that report them; they end held movement
+pub fn handle_key_release(app: &mut App, key: crossterm::event::KeyEvent) {
+    if let Some((dx, dy)) = movement_direction(&app.settings, key.code) {
+        app.release_direction(dx, dy);
+    }
+    if key_matches(key.code, &app.settings.attack) {
+        app.release_attack();
+    }
+}
+
 pub fn handle_game_input(app: &mut App, key: crossterm::event::KeyEvent) {
     // If showing item description, only allow Esc to close it
     if app.showing_item_description {
@@ -225,6 +356,30 @@ pub fn handle_game_input(app: &mut App, key: crossterm::event::KeyEvent) {
         return;
     }
 
+    // Help overlay pauses game input and closes on any key
+    if app.showing_help {
+        app.showing_help = false;
+        return;
+    }
+
+    // Full-inventory swap prompt takes all input until resolved
+    if app.weapon_swap_prompt.is_some() {
+        crate::ui::pause_menu::handle_weapon_swap_input(app, key.code);
+        return;
+    }
+    if app.confirm_drop_all {
+        crate::ui::pause_menu::handle_drop_all_input(app, key.code);
+        return;
+    }
+    if app.confirm_scrap {
+        crate::ui::pause_menu::handle_scrap_input(app, key.code);
+        return;
+    }
+    if app.confirm_quit {
+        crate::ui::pause_menu::handle_quit_input(app, key.code);
+        return;
+    }
+
     let settings = &app.settings;
 
     // Check for pause key - can be pressed anytime during gameplay
@@ -302,13 +457,24 @@ pub fn handle_game_input(app: &mut App, key: crossterm::event::KeyEvent) {
                 || key_matches(key.code, &settings.move_right);
 
             // Handle inventory navigation - always allowed
-            if app.inventory_focused && key_matches(key.code, &settings.inventory_up) {
-                if app.inventory_scroll_index > 0 {
-                    app.inventory_scroll_index -= 1;
-                }
+            let shift = key
+                .modifiers
+                .contains(crossterm::event::KeyModifiers::SHIFT);
+            if app.inventory_focused && shift && key_matches(key.code, &settings.inventory_up) {
+                // Shift moves the equipped weapon a slot up instead of scrolling
+                app.move_current_weapon(-1);
+                return;
+            } else if app.inventory_focused
+                && shift
+                && key_matches(key.code, &settings.inventory_down)
+            {
+                app.move_current_weapon(1);
+                return;
+            } else if app.inventory_focused && key_matches(key.code, &settings.inventory_up) {
+                app.scroll_inventory(-1);
                 return;
             } else if app.inventory_focused && key_matches(key.code, &settings.inventory_down) {
-                app.inventory_scroll_index += 1;
+                app.scroll_inventory(1);
                 return;
             } else if app.inventory_focused && key_matches(key.code, &settings.toggle_inv) {
                 app.inventory_focused = false;
@@ -317,6 +483,18 @@ pub fn handle_game_input(app: &mut App, key: crossterm::event::KeyEvent) {
             } else if app.inventory_focused && key_matches(key.code, &settings.item_describe) {
                 app.showing_item_description = true;
                 return;
+            } else if app.inventory_focused && matches!(key.code, KeyCode::Char('x' | 'X')) {
+                // Ask before clearing out every weapon but the equipped one
+                app.confirm_drop_all = app.character.weapon_inventory.weapons.len() > 1;
+                return;
+            } else if app.inventory_focused && matches!(key.code, KeyCode::Char('z' | 'Z')) {
+                // The equipped weapon is the one scrapped, so always ask first
+                app.confirm_scrap = app
+                    .character
+                    .weapon_inventory
+                    .get_current_weapon()
+                    .is_some();
+                return;
             }
 
             // Block movement only while inventory focused
@@ -326,19 +504,13 @@ pub fn handle_game_input(app: &mut App, key: crossterm::event::KeyEvent) {
 
             // Process all game actions (works with or without inventory focus)
             if is_movement {
-                if key_matches(key.code, &settings.move_up) {
-                    app.move_character(0, -1);
-                } else if key_matches(key.code, &settings.move_down) {
-                    app.move_character(0, 1);
-                } else if key_matches(key.code, &settings.move_left) {
-                    app.move_character(-1, 0);
-                } else if key_matches(key.code, &settings.move_right) {
-                    app.move_character(1, 0);
+                if let Some((dx, dy)) = movement_direction(settings, key.code) {
+                    app.press_direction(dx, dy);
                 }
             } else if key_matches(key.code, &settings.dash) {
                 app.dash();
             } else if key_matches(key.code, &settings.attack) {
-                app.use_current_weapon();
+                app.press_attack();
             } else if key_matches(key.code, &settings.use_consumable) {
                 // Use the selected consumable (or first one if not focused)
                 let idx = if app.inventory_focused {
@@ -351,29 +523,41 @@ pub fn handle_game_input(app: &mut App, key: crossterm::event::KeyEvent) {
                 app.use_ultimate();
             } else if key_matches(key.code, &settings.toggle_inv) {
                 app.inventory_focused = true;
-            } else if key.code == KeyCode::Char('f') || key.code == KeyCode::Char('F') {
+            } else if key_matches(key.code, &settings.block) {
                 app.block();
-            } else if key.code == KeyCode::Char('t') || key.code == KeyCode::Char('T') {
+            } else if key_matches(key.code, &settings.skill_tree) {
                 // Open skill tree during gameplay
                 app.previous_state = Some(AppState::Game);
                 app.state = AppState::SkillTree;
                 app.is_paused = true; // Automatically pause the game
                 app.skill_tree_selection = Some(0);
-            } else if key.code == KeyCode::Char('y') || key.code == KeyCode::Char('Y') {
+            } else if key_matches(key.code, &settings.ultimate_shop) {
                 // Open ultimate shop during gameplay
                 app.previous_state = Some(AppState::Game);
                 app.state = AppState::UltimateShop;
                 app.is_paused = true; // Automatically pause the game
+            } else if key_matches(key.code, &settings.quicksave) {
+                app.quicksave();
+            } else if key_matches(key.code, &settings.interact) {
+                app.interact();
+            } else if key.code == KeyCode::Char('?')
+                || (matches!(key.code, KeyCode::Char('h' | 'H')) && !settings.binds_key("H"))
+            {
+                app.showing_help = true;
+            } else if matches!(key.code, KeyCode::Char('v' | 'V')) {
+                app.examining = !app.examining;
             }
         }
     }
 }
 
-fn handle_settings_selection(app: &mut App) {
-    let sel = app.settings_state.selected().unwrap_or(0);
-    match sel {
-        0..=13 => app.settings_mode = crate::app::SettingsMode::Rebinding,
-        14 => {
+fn handle_settings_selection(app: &mut App, row: Option<SettingsRow>) {
+    let Some(row) = row else {
+        return;
+    };
+    match row {
+        SettingsRow::Rebind(_) => app.settings_mode = crate::app::SettingsMode::Rebinding,
+        SettingsRow::Difficulty => {
             // Difficulty toggle (current difficulty)
             app.temp_settings.difficulty = match &app.temp_settings.difficulty {
                 crate::model::item_tier::Difficulty::Easy => {
@@ -390,7 +574,7 @@ fn handle_settings_selection(app: &mut App) {
                 }
             };
         }
-        15 => {
+        SettingsRow::DefaultDifficulty => {
             // Default difficulty toggle
             app.temp_settings.default_difficulty = match &app.temp_settings.default_difficulty {
                 crate::model::item_tier::Difficulty::Easy => {
@@ -407,41 +591,180 @@ fn handle_settings_selection(app: &mut App) {
                 }
             };
         }
-        16 | 17 => {
-            // Volume sliders - just navigable with Left/Right arrows
+        SettingsRow::MusicVolume
+        | SettingsRow::SoundVolume
+        | SettingsRow::AutoFaceRadius
+        | SettingsRow::GameSpeed
+        | SettingsRow::ParticleDensity
+        | SettingsRow::PlayerKnockback
+        | SettingsRow::AutosaveInterval
+        | SettingsRow::HoldMoveRate
+        | SettingsRow::HitStop
+        | SettingsRow::CameraDeadzone
+        | SettingsRow::FloorWidth
+        | SettingsRow::FloorHeight
+        | SettingsRow::FillProbability
+        | SettingsRow::CaveSmoothing
+        | SettingsRow::TunnelWidth => {
+            // Sliders - adjusted with Left/Right arrows
             // No action needed on Enter
         }
-        18 => {
+        SettingsRow::KeyPreset => {
+            // Step on to the next preset, same as Right
+            let next = app.temp_settings.key_preset().cycled(1);
+            app.temp_settings.apply_key_preset(next);
+        }
+        SettingsRow::SkipLogoAnimation => {
             // Skip logo animation toggle
             app.temp_settings.skip_logo_animation = !app.temp_settings.skip_logo_animation;
         }
-        20 => {
+        SettingsRow::RunMode => {
+            // Run mode toggle (only applies to new runs)
+            app.temp_settings.run_mode = app.temp_settings.run_mode.toggled();
+        }
+        SettingsRow::FogOfWar => {
+            // Fog of war toggle
+            app.temp_settings.fog_of_war = !app.temp_settings.fog_of_war;
+        }
+        SettingsRow::AutoFaceNearest => {
+            // Auto-face nearest enemy toggle
+            app.temp_settings.auto_face_nearest = !app.temp_settings.auto_face_nearest;
+        }
+        SettingsRow::MergeGoldPiles => {
+            // Gold pile merging toggle
+            app.temp_settings.merge_gold_piles = !app.temp_settings.merge_gold_piles;
+        }
+        SettingsRow::MovementTrails => {
+            // Arrow and dash trail toggle
+            app.temp_settings.movement_trails = !app.temp_settings.movement_trails;
+        }
+        SettingsRow::DropGoldOnDeath => {
+            // Soulslike gold recovery toggle
+            app.temp_settings.drop_gold_on_death = !app.temp_settings.drop_gold_on_death;
+        }
+        SettingsRow::ShowFps => {
+            // FPS counter toggle
+            app.temp_settings.show_fps = !app.temp_settings.show_fps;
+        }
+        SettingsRow::AutoFire => {
+            // Keep attacking while the attack key is held
+            app.temp_settings.auto_fire = !app.temp_settings.auto_fire;
+        }
+        SettingsRow::MuteAudio => {
+            // Swap in silent audio; applied on SAVE
+            app.temp_settings.mute_audio = !app.temp_settings.mute_audio;
+        }
+        SettingsRow::ConfirmQuit => {
+            // Yes/no prompt before quitting a run from the pause menu
+            app.temp_settings.confirm_quit = !app.temp_settings.confirm_quit;
+        }
+        SettingsRow::CameraSnap => {
+            // Instant camera instead of easing after the player
+            app.temp_settings.camera_snap = !app.temp_settings.camera_snap;
+        }
+        SettingsRow::CornerSliding => {
+            // Slide along walls instead of stopping on a blocked diagonal
+            app.temp_settings.corner_sliding = !app.temp_settings.corner_sliding;
+        }
+        SettingsRow::AdaptiveDifficulty => {
+            // Ease or toughen enemies on later floors depending on how the player fares
+            app.temp_settings.adaptive_difficulty = !app.temp_settings.adaptive_difficulty;
+        }
+        SettingsRow::ComboResetOnDamage => {
+            // Whether taking damage breaks the combo
+            app.temp_settings.combo_resets_on_damage = !app.temp_settings.combo_resets_on_damage;
+        }
+        SettingsRow::LowDetail => {
+            // Cheaper rendering for slow terminals and screen readers
+            app.temp_settings.low_detail = !app.temp_settings.low_detail;
+        }
+        SettingsRow::RoomEnemyPlacement => {
+            // Room-clustered vs scattered enemy spawns (applies to new floors)
+            app.temp_settings.room_enemy_placement = !app.temp_settings.room_enemy_placement;
+        }
+        SettingsRow::AutoPickup => {
+            // Walk-over pickup vs the interact key for items and stairs
+            app.temp_settings.auto_pickup = !app.temp_settings.auto_pickup;
+        }
+        SettingsRow::EnemyDistance => {
+            // Chebyshev lets diagonal neighbours count as melee range
+            app.temp_settings.enemy_distance = app.temp_settings.enemy_distance.toggled();
+        }
+        SettingsRow::TunnelStyle => {
+            // Cave tunnel shape (applies to new floors)
+            app.temp_settings.floor_gen.tunnel_style =
+                app.temp_settings.floor_gen.tunnel_style.toggled();
+        }
+        SettingsRow::Separator => {}
+        SettingsRow::Save => {
+            // Conflicting keybinds need a second SAVE to confirm
+            if !app.temp_settings.find_conflicts().is_empty() && !app.settings_save_confirm {
+                app.settings_save_confirm = true;
+                return;
+            }
             // Save changes
             app.settings = app.temp_settings.clone();
             // Sync volume to app and audio manager
             app.music_volume = app.settings.music_volume;
             app.sound_volume = app.settings.sound_volume;
             app.audio_manager.set_music_volume(app.music_volume);
+            app.apply_audio_mute();
             let _ = app.settings.save();
             app.state = AppState::MainMenu;
         }
-        21 => {
+        SettingsRow::Discard => {
             // Discard and back
             app.temp_settings = app.settings.clone();
             app.state = AppState::MainMenu;
         }
-        22 => {
+        SettingsRow::Reset => {
             // Reset to default settings
-            app.settings = Settings::default();
+            app.settings = Settings {
+                tutorial_completed: app.settings.tutorial_completed,
+                ..Settings::default()
+            };
             app.temp_settings = app.settings.clone();
+            app.apply_audio_mute();
             let _ = app.settings.save();
             app.state = AppState::MainMenu;
         }
-        _ => {}
+        SettingsRow::Export => {
+            // Write the settings being edited to a shareable file
+            let path = crate::constants::SETTINGS_EXPORT_FILE;
+            app.settings_status = Some(match app.temp_settings.export_to(path) {
+                Ok(()) => format!("Exported settings to {}", path),
+                Err(e) => format!("Export failed: {}", e),
+            });
+        }
+        SettingsRow::Import => {
+            // Load a shared file into the edited settings; SAVE still applies them
+            let path = crate::constants::SETTINGS_EXPORT_FILE;
+            app.settings_status = Some(match Settings::import_from(path) {
+                Ok((settings, rejected)) => {
+                    app.temp_settings = settings;
+                    if rejected.is_empty() {
+                        format!("Imported {} - select SAVE to apply", path)
+                    } else {
+                        format!(
+                            "Imported {} - reset to default: {}",
+                            path,
+                            rejected.join(", ")
+                        )
+                    }
+                }
+                Err(e) => format!("Import failed: {}", e),
+            });
+        }
     }
 }
 
 pub fn handle_dev_menu_input(app: &mut App, key: crossterm::event::KeyEvent) {
+    // The pattern preview takes every key until it is closed
+    if app.dev_pattern_preview {
+        crate::ui::dev_menu::handle_preview_input(app, key.code);
+        return;
+    }
+
     // Call the dev menu handler
     crate::ui::dev_menu::handle_input(app, key.code.clone());
 
@@ -470,6 +793,7 @@ pub fn handle_dev_menu_input(app: &mut App, key: crossterm::event::KeyEvent) {
                     app.character_position = (x, y);
                     app.update_camera(); // Initialize camera position
                     app.game_started_at = Some(std::time::Instant::now());
+                    app.dev_play_active = true;
                     app.state = AppState::Game;
                 }
             }
@@ -519,7 +843,7 @@ pub fn handle_character_creation_input(app: &mut App, key: crossterm::event::Key
         match key.code {
             KeyCode::Tab => {
                 // Tab cycles forward through fields
-                app.char_creation_selection = (app.char_creation_selection + 1) % 3;
+                app.char_creation_selection = (app.char_creation_selection + 1) % 4;
                 // If cycling back to name, enter input mode
                 if app.char_creation_selection == 0 {
                     app.char_name_input_mode = true;
@@ -528,7 +852,7 @@ pub fn handle_character_creation_input(app: &mut App, key: crossterm::event::Key
             KeyCode::BackTab => {
                 // Shift+Tab cycles backward through fields
                 if app.char_creation_selection == 0 {
-                    app.char_creation_selection = 2;
+                    app.char_creation_selection = 3;
                 } else {
                     app.char_creation_selection -= 1;
                 }
@@ -547,7 +871,7 @@ pub fn handle_character_creation_input(app: &mut App, key: crossterm::event::Key
                 }
             }
             KeyCode::Down | KeyCode::Char('s') | KeyCode::Char('S') => {
-                if app.char_creation_selection < 2 {
+                if app.char_creation_selection < 3 {
                     app.char_creation_selection += 1;
                 }
             }
@@ -568,6 +892,8 @@ pub fn handle_character_creation_input(app: &mut App, key: crossterm::event::Key
                             crate::model::item_tier::Difficulty::Hard
                         }
                     };
+                } else if app.char_creation_selection == 2 {
+                    app.starting_class = app.starting_class.previous();
                 }
             }
             KeyCode::Right | KeyCode::Char('d') | KeyCode::Char('D') => {
@@ -587,13 +913,16 @@ pub fn handle_character_creation_input(app: &mut App, key: crossterm::event::Key
                             crate::model::item_tier::Difficulty::Easy
                         }
                     };
+                } else if app.char_creation_selection == 2 {
+                    app.starting_class = app.starting_class.next();
                 }
             }
             KeyCode::Enter | KeyCode::Char(' ') => {
-                if app.char_creation_selection == 2 {
+                if app.char_creation_selection == 3 {
                     // Start the game
                     // Reset character to fresh state for new game
                     app.character = crate::model::character::Character::default();
+                    app.starting_class.apply(&mut app.character);
                     app.character.name = app.char_name.clone();
                     app.character_position = (0, 0);
                     app.floor_level = 1;
@@ -609,6 +938,11 @@ pub fn handle_character_creation_input(app: &mut App, key: crossterm::event::Key
                     // Set max levels based on selected difficulty
                     app.max_levels = app.get_max_levels_for_difficulty();
                     app.is_boss_level = false;
+                    app.run_mode = app.settings.run_mode.clone();
+                    // Keep the best run from a previous save under the same name
+                    app.best_score = crate::model::gamesave::GameSave::load(&app.char_name)
+                        .map(|save| save.best_score)
+                        .unwrap_or(0);
 
                     // Initialize game time
                     app.game_started_at = Some(std::time::Instant::now());
@@ -743,8 +1077,7 @@ pub fn handle_ultimate_shop_input(app: &mut App, key: crossterm::event::KeyEvent
                                     app.ultimate_shop_ui.show_message(msg);
                                     app.audio_manager.play_sound_effect(SoundEffect::MenuPick);
                                     // Apply stat upgrade immediately
-                                    apply_stat_upgrade(
-                                        app,
+                                    app.character.apply_stat_upgrade(
                                         shop_upg.upgrade_type,
                                         shop_upg.stack_amount,
                                     );
@@ -758,6 +1091,28 @@ pub fn handle_ultimate_shop_input(app: &mut App, key: crossterm::event::KeyEvent
                         }
                     }
                 }
+                crate::ui::ultimate_shop::ShopTab::Enchants => {
+                    if let Some(idx) = app.ultimate_shop_ui.enchant_list_state.selected() {
+                        if let Some(shop_enchant) = app.ultimate_shop.enchants.get(idx) {
+                            let result = app.ultimate_shop.purchase_enchant(
+                                &mut app.character.gold,
+                                app.character.weapon_inventory.get_current_weapon_mut(),
+                                &shop_enchant.enchant_type,
+                            );
+                            match result {
+                                Ok(msg) => {
+                                    app.ultimate_shop_ui.show_message(msg);
+                                    app.audio_manager.play_sound_effect(SoundEffect::MenuPick);
+                                }
+                                Err(err) => {
+                                    app.ultimate_shop_ui
+                                        .show_message(format!("Cannot purchase: {}", err));
+                                    app.audio_manager.play_sound_effect(SoundEffect::MenuSwitch);
+                                }
+                            }
+                        }
+                    }
+                }
             }
         }
         KeyCode::Char('q') | KeyCode::Char('Q') | KeyCode::Esc => {
@@ -769,36 +1124,3 @@ pub fn handle_ultimate_shop_input(app: &mut App, key: crossterm::event::KeyEvent
         _ => {}
     }
 }
-
-/// Apply a stat upgrade to the character
-fn apply_stat_upgrade(
-    app: &mut App,
-    upgrade: crate::model::ultimate_shop::StatUpgradeType,
-    amount: f32,
-) {
-    use crate::model::ultimate_shop::StatUpgradeType;
-
-    match upgrade {
-        StatUpgradeType::MaxHealth => {
-            app.character.health_max += amount as i32;
-            app.character.health = app.character.health_max;
-        }
-        StatUpgradeType::AttackDamage => {
-            app.character.attack_damage += amount as i32;
-        }
-        StatUpgradeType::AttackSpeed => {
-            // Reduce cooldown by this percentage (amount is already in percentage format)
-            let reduction = 1.0 - (amount as f32 / 100.0); // Convert to decimal
-            let current_duration = app.character.attack_cooldown.duration();
-            app.character
-                .attack_cooldown
-                .set_duration(current_duration * reduction);
-        }
-        StatUpgradeType::MovementSpeed => {
-            app.character.speed += amount;
-        }
-        StatUpgradeType::DashDistance => {
-            app.character.dash_distance += amount as i32;
-        }
-    }
-}
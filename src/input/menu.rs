@@ -1,13 +1,36 @@
+use crate::model::menu::{next_selectable, previous_selectable};
+use crate::model::settings::SettingsRow;
 use ratatui::widgets::ListState;
 
 /// Move selection up with wrapping
 pub fn move_selection_up(state: &mut ListState, total_items: usize) {
     let s = state.selected().unwrap_or(0);
-    state.select(Some(if s == 0 { total_items - 1 } else { s - 1 }));
+    state.select(Some(previous_selectable(s, total_items, |_| true)));
 }
 
 /// Move selection down with wrapping
 pub fn move_selection_down(state: &mut ListState, total_items: usize) {
     let s = state.selected().unwrap_or(0);
-    state.select(Some(if s >= total_items - 1 { 0 } else { s + 1 }));
+    state.select(Some(next_selectable(s, total_items, |_| true)));
+}
+
+/// Move up through settings rows with wrapping, skipping separators
+pub fn move_row_up(state: &mut ListState, rows: &[SettingsRow]) {
+    let s = state.selected().unwrap_or(0);
+    state.select(Some(previous_selectable(s, rows.len(), |i| {
+        rows[i].is_selectable()
+    })));
+}
+
+/// Move down through settings rows with wrapping, skipping separators
+pub fn move_row_down(state: &mut ListState, rows: &[SettingsRow]) {
+    let s = state.selected().unwrap_or(0);
+    state.select(Some(next_selectable(s, rows.len(), |i| {
+        rows[i].is_selectable()
+    })));
+}
+
+/// The settings row under the selection, if any
+pub fn selected_row(state: &ListState, rows: &[SettingsRow]) -> Option<SettingsRow> {
+    state.selected().and_then(|i| rows.get(i).copied())
 }
@@ -1,3 +1,9 @@
+pub mod app;
 pub mod colors;
 pub mod constants;
+pub mod emoji;
+pub mod input;
 pub mod model;
+pub mod ui;
+
+pub const LOGO: &str = include_str!("logo.txt");
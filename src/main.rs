@@ -8,30 +8,46 @@ use std::{
     time::{Duration, Instant},
 };
 
-mod app;
-mod colors;
-mod constants;
-mod emoji;
-mod input;
-mod model;
-mod ui;
-
-pub const LOGO: &str = include_str!("logo.txt");
+use roguelite_dungeon::{app, input, ui};
 
 fn main() -> io::Result<()> {
     enable_raw_mode()?;
     let mut stdout = io::stdout();
-    crossterm::execute!(stdout, EnterAlternateScreen, event::EnableMouseCapture)?;
+    crossterm::execute!(
+        stdout,
+        EnterAlternateScreen,
+        event::EnableMouseCapture,
+        event::EnableFocusChange
+    )?;
+
+    // Ask for key release events where the terminal supports them, for held-key movement
+    let key_releases = supports_keyboard_enhancement().unwrap_or(false)
+        && crossterm::execute!(
+            stdout,
+            event::PushKeyboardEnhancementFlags(
+                event::KeyboardEnhancementFlags::REPORT_EVENT_TYPES
+            )
+        )
+        .is_ok();
     let mut terminal = Terminal::new(CrosstermBackend::new(stdout))?;
 
     let mut app = app::App::new();
+    app.key_releases_supported = key_releases;
+    // First launch: teach the controls before anything else
+    if !app.settings.tutorial_completed {
+        app.start_tutorial();
+    }
     let _ = run_app(&mut terminal, &mut app);
 
+    if key_releases {
+        let _ = crossterm::execute!(terminal.backend_mut(), event::PopKeyboardEnhancementFlags);
+    }
     disable_raw_mode()?;
     crossterm::execute!(
         terminal.backend_mut(),
         LeaveAlternateScreen,
-        event::DisableMouseCapture
+        event::DisableMouseCapture,
+        event::DisableFocusChange
     )?;
     Ok(())
 }
@@ -63,6 +79,7 @@ where
         app.audio_manager.update(elapsed);
 
         terminal.draw(|f| ui::draw(f, app))?;
+        app.fps_counter.record_frame(Instant::now());
         app.last_scroll_offset = app.scroll_offset;
 
         let elapsed = last_tick.elapsed();
@@ -74,20 +91,20 @@ where
 
         if event::poll(timeout)? {
             match event::read()? {
-                Event::Key(key) => {
-                    if key.kind == event::KeyEventKind::Press {
-                        input::handle_input(app, key);
-                    }
-                }
+                Event::Key(key) => match key.kind {
+                    event::KeyEventKind::Press => input::handle_input(app, key),
+                    event::KeyEventKind::Repeat => input::handle_key_repeat(app, key),
+                    event::KeyEventKind::Release => input::handle_key_release(app, key),
+                },
                 Event::Mouse(mouse) => {
                     input::handle_mouse_event(app, mouse);
                 }
+                Event::FocusLost => app.focus_lost(),
                 _ => {}
             }
         }
 
         if last_tick.elapsed() >= tick_rate {
-            app.frame_count += 1;
             last_tick = Instant::now();
         }
 
@@ -0,0 +1,102 @@
+use crate::constants::{
+    ADAPTIVE_DEATH_STEP, ADAPTIVE_FAST_CLEAR_SECS, ADAPTIVE_HIGH_DAMAGE_FRACTION,
+    ADAPTIVE_LOW_DAMAGE_FRACTION, ADAPTIVE_MAX_OFFSET, ADAPTIVE_SLOW_CLEAR_SECS, ADAPTIVE_STEP,
+};
+use serde::{Deserialize, Serialize};
+
+/// Nudges enemy stats toward how the player is doing. The offset is kept apart from the
+/// chosen difficulty so it can be shown and reset on its own.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct AdaptiveDifficulty {
+    pub offset: f32, // Added to the enemy stat multiplier, within +/-ADAPTIVE_MAX_OFFSET
+    #[serde(skip)]
+    floor_damage: i32, // Damage taken on the current floor
+    #[serde(skip)]
+    floor_time: f32, // Seconds spent on the current floor
+}
+
+impl AdaptiveDifficulty {
+    /// Multiplier applied to enemy stats on top of the difficulty's own
+    pub fn multiplier(&self) -> f32 {
+        1.0 + self.offset
+    }
+
+    pub fn record_damage(&mut self, amount: i32) {
+        self.floor_damage += amount.max(0);
+    }
+
+    pub fn tick(&mut self, delta: f32) {
+        self.floor_time += delta;
+    }
+
+    /// Start tracking a fresh floor
+    pub fn start_floor(&mut self) {
+        self.floor_damage = 0;
+        self.floor_time = 0.0;
+    }
+
+    /// Judge the floor just cleared: a quick clear with little damage taken makes the
+    /// next floors harder, a slow or bruising one makes them easier
+    pub fn floor_cleared(&mut self, max_health: i32) {
+        let max_health = max_health.max(1) as f32;
+        let damage = self.floor_damage as f32;
+        let step = if self.floor_time <= ADAPTIVE_FAST_CLEAR_SECS
+            && damage <= max_health * ADAPTIVE_LOW_DAMAGE_FRACTION
+        {
+            ADAPTIVE_STEP
+        } else if self.floor_time >= ADAPTIVE_SLOW_CLEAR_SECS
+            || damage >= max_health * ADAPTIVE_HIGH_DAMAGE_FRACTION
+        {
+            -ADAPTIVE_STEP
+        } else {
+            0.0
+        };
+        self.nudge(step);
+        self.start_floor();
+    }
+
+    pub fn player_died(&mut self) {
+        self.nudge(-ADAPTIVE_DEATH_STEP);
+        self.start_floor();
+    }
+
+    pub fn reset(&mut self) {
+        *self = Self::default();
+    }
+
+    fn nudge(&mut self, step: f32) {
+        self.offset = (self.offset + step).clamp(-ADAPTIVE_MAX_OFFSET, ADAPTIVE_MAX_OFFSET);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fast_clean_clears_raise_and_death_lowers() {
+        let mut adaptive = AdaptiveDifficulty::default();
+        for _ in 0..3 {
+            adaptive.tick(ADAPTIVE_FAST_CLEAR_SECS / 2.0);
+            adaptive.floor_cleared(100);
+        }
+        let raised = adaptive.multiplier();
+        assert!(raised > 1.0);
+
+        adaptive.record_damage(40);
+        adaptive.player_died();
+        assert!(adaptive.multiplier() < raised);
+    }
+
+    #[test]
+    fn test_offset_stays_within_bounds() {
+        let mut adaptive = AdaptiveDifficulty::default();
+        for _ in 0..50 {
+            adaptive.player_died();
+        }
+        assert_eq!(adaptive.offset, -ADAPTIVE_MAX_OFFSET);
+
+        adaptive.reset();
+        assert_eq!(adaptive.multiplier(), 1.0);
+    }
+}
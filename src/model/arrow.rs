@@ -1,9 +1,27 @@
+use crate::constants::{
+    BOW_CHARGE_MAX_DAMAGE_MULTIPLIER, BOW_CHARGE_MAX_SPEED_MULTIPLIER, BOW_CHARGE_PIERCE_COUNT,
+};
 use std::time::Instant;
 
 #[derive(Clone, Debug, PartialEq)]
 pub enum ProjectileType {
     Arrow,   // Standard bow arrow
     FireOil, // Thrown fire flask (area damage)
+    Charged, // Fully charged bow shot (pierces enemies)
+}
+
+/// Who fired a projectile, which decides what it can hit
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ArrowOwner {
+    Player,                // Hits enemies, scaled by the weapon's damage
+    Enemy { damage: i32 }, // Hits only the player, for a fixed amount
+}
+
+/// What an arrow struck on its current tile
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ArrowHit {
+    Enemy(usize), // Index into the floor's enemies
+    Player(i32),  // Damage dealt to the player
 }
 
 #[derive(Clone, Debug)]
@@ -17,6 +35,10 @@ pub struct Arrow {
     pub max_distance: f32, // Maximum distance arrow can travel
     pub is_dead: bool,     // Stopped by collision
     pub projectile_type: ProjectileType,
+    pub damage_multiplier: f32,       // Applied to weapon damage on hit
+    pub pierce_remaining: u32,        // Enemies the arrow can still pass through
+    pub last_hit: Option<(i32, i32)>, // Tile of the last enemy hit, so piercing doesn't re-hit
+    pub owner: ArrowOwner,
 }
 
 impl Arrow {
@@ -31,7 +53,31 @@ impl Arrow {
             max_distance: 50.0, // Max distance in tiles
             is_dead: false,
             projectile_type: ProjectileType::Arrow,
+            damage_multiplier: 1.0,
+            pierce_remaining: 0,
+            last_hit: None,
+            owner: ArrowOwner::Player,
+        }
+    }
+
+    /// Create a bow arrow scaled by how long the shot was charged (0.0 = tap, 1.0 = full)
+    pub fn charged(x: f32, y: f32, dx: i32, dy: i32, speed: f32, charge: f32) -> Self {
+        let charge = charge.clamp(0.0, 1.0);
+        let speed = speed * (1.0 + (BOW_CHARGE_MAX_SPEED_MULTIPLIER - 1.0) * charge);
+        let mut arrow = Self::new(x, y, dx, dy, speed);
+        arrow.damage_multiplier = 1.0 + (BOW_CHARGE_MAX_DAMAGE_MULTIPLIER - 1.0) * charge;
+        if charge >= 1.0 {
+            arrow.projectile_type = ProjectileType::Charged;
+            arrow.pierce_remaining = BOW_CHARGE_PIERCE_COUNT;
         }
+        arrow
+    }
+
+    /// Arrow shot by an enemy; it flies past other enemies and only hurts the player
+    pub fn enemy_shot(x: f32, y: f32, dx: i32, dy: i32, damage: i32) -> Self {
+        let mut arrow = Self::new(x, y, dx, dy, crate::constants::ENEMY_ARROW_SPEED);
+        arrow.owner = ArrowOwner::Enemy { damage };
+        arrow
     }
 
     pub fn new_with_type(
@@ -52,10 +98,28 @@ impl Arrow {
             max_distance: 50.0,
             is_dead: false,
             projectile_type,
+            damage_multiplier: 1.0,
+            pierce_remaining: 0,
+            last_hit: None,
+            owner: ArrowOwner::Player,
         }
     }
 
+    /// Damage dealt on hit for the given weapon damage
+    pub fn get_damage(&self, base_damage: i32) -> i32 {
+        ((base_damage as f32) * self.damage_multiplier).round() as i32
+    }
+
     pub fn get_glyph(&self) -> &'static str {
+        if self.projectile_type == ProjectileType::Charged {
+            return match (self.dx, self.dy) {
+                (1, 0) => "⇒",
+                (-1, 0) => "⇐",
+                (0, 1) => "⇓",
+                (0, -1) => "⇑",
+                _ => "✦",
+            };
+        }
         match (self.dx, self.dy) {
             (1, 0) => "→",  // Right arrow
             (-1, 0) => "←", // Left arrow
@@ -65,11 +129,44 @@ impl Arrow {
         }
     }
 
-    pub fn is_alive(&self) -> bool {
+    /// Color of the projectile itself; enemy shots stand out from the player's
+    pub fn color(&self) -> ratatui::prelude::Color {
+        match self.owner {
+            ArrowOwner::Player => ratatui::prelude::Color::White,
+            ArrowOwner::Enemy { .. } => ratatui::prelude::Color::LightMagenta,
+        }
+    }
+
+    /// Color of the trail left behind this projectile
+    pub fn trail_color(&self) -> ratatui::prelude::Color {
+        match self.projectile_type {
+            ProjectileType::Arrow => ratatui::prelude::Color::Gray,
+            ProjectileType::FireOil => ratatui::prelude::Color::Rgb(255, 140, 0),
+            ProjectileType::Charged => ratatui::prelude::Color::LightCyan,
+        }
+    }
+
+    /// What this arrow strikes, given the player's tile and the enemies on the arrow's tile.
+    /// Player arrows ignore the player and enemy arrows fly past enemies, so nobody
+    /// hurts their own side.
+    pub fn resolve_hit(&self, player: (i32, i32), enemies_here: &[usize]) -> Option<ArrowHit> {
+        if self.is_dead {
+            return None;
+        }
+        let pos = self.get_position();
+        match self.owner {
+            ArrowOwner::Enemy { damage } => (pos == player).then_some(ArrowHit::Player(damage)),
+            ArrowOwner::Player if self.last_hit == Some(pos) => None, // Still inside the last enemy
+            ArrowOwner::Player => enemies_here.first().map(|&idx| ArrowHit::Enemy(idx)),
+        }
+    }
+
+    /// Whether the arrow is still in flight at game speed `time_scale`
+    pub fn is_alive(&self, time_scale: f32) -> bool {
         if self.is_dead {
             return false;
         }
-        let elapsed = self.created_at.elapsed().as_secs_f32();
+        let elapsed = crate::model::cooldown::scaled_elapsed(self.created_at, time_scale);
         let distance_traveled = elapsed * self.speed;
         distance_traveled < self.max_distance
     }
@@ -97,6 +194,7 @@ impl Arrow {
         match self.projectile_type {
             ProjectileType::Arrow => 1,   // Single-target hit
             ProjectileType::FireOil => 4, // 4-tile radius explosion
+            ProjectileType::Charged => 1, // Single-target hit, but pierces
         }
     }
 
@@ -1,6 +1,13 @@
 use ratatui::style::Color;
 use serde::{Deserialize, Serialize};
 
+/// Upper bound for line/projectile reach after scaling
+pub const MAX_PATTERN_REACH: i32 = 14;
+/// Upper bound for circular/area radius after scaling
+pub const MAX_PATTERN_RADIUS: i32 = 8;
+/// Upper bound for Meteor Shower impact width after scaling
+pub const MAX_METEOR_WIDTH: i32 = 5;
+
 #[derive(Clone, Debug, PartialEq)]
 pub struct AnimationFrame {
     pub tiles: Vec<(i32, i32)>,
@@ -57,6 +64,24 @@ pub enum AttackPattern {
 }
 
 impl AttackPattern {
+    /// One of each pattern at its dev menu test size, in cycling order
+    pub const ALL: [AttackPattern; 14] = [
+        AttackPattern::BasicSlash,
+        AttackPattern::GroundSlam(3),
+        AttackPattern::WhirlwindAttack,
+        AttackPattern::SwordThrust(2),
+        AttackPattern::ArrowShot(4),
+        AttackPattern::MultiShot(4, 2),
+        AttackPattern::Barrage(3),
+        AttackPattern::PiercingShot(5),
+        AttackPattern::Fireball(2),
+        AttackPattern::ChainLightning(3),
+        AttackPattern::FrostNova(2),
+        AttackPattern::MeteorShower(3, 2),
+        AttackPattern::CrescentSlash,
+        AttackPattern::Vortex(2),
+    ];
+
     /// Get all affected tiles with animation frames based on direction
     /// Returns animation frames for visual rendering
     pub fn get_animation_frames(
@@ -923,6 +948,34 @@ impl AttackPattern {
         frames
     }
 
+    /// Return a copy of this pattern with its reach/radius params grown by `bonus` tiles.
+    /// Fixed-shape patterns are returned unchanged; results are clamped to `MAX_PATTERN_REACH`
+    /// and `MAX_PATTERN_RADIUS` so area attacks can't cover the whole floor.
+    pub fn scaled(&self, bonus: i32) -> AttackPattern {
+        let reach = |r: i32| (r + bonus).clamp(1, MAX_PATTERN_REACH.max(r));
+        let radius = |r: i32| (r + bonus).clamp(1, MAX_PATTERN_RADIUS.max(r));
+        match self {
+            AttackPattern::BasicSlash => AttackPattern::BasicSlash,
+            AttackPattern::GroundSlam(r) => AttackPattern::GroundSlam(radius(*r)),
+            AttackPattern::WhirlwindAttack => AttackPattern::WhirlwindAttack,
+            AttackPattern::SwordThrust(r) => AttackPattern::SwordThrust(reach(*r)),
+            AttackPattern::ArrowShot(r) => AttackPattern::ArrowShot(reach(*r)),
+            AttackPattern::MultiShot(r, spread) => AttackPattern::MultiShot(reach(*r), *spread),
+            AttackPattern::Barrage(r) => AttackPattern::Barrage(reach(*r)),
+            AttackPattern::PiercingShot(r) => AttackPattern::PiercingShot(reach(*r)),
+            AttackPattern::Fireball(r) => AttackPattern::Fireball(radius(*r)),
+            AttackPattern::ChainLightning(r) => AttackPattern::ChainLightning(reach(*r)),
+            AttackPattern::FrostNova(r) => AttackPattern::FrostNova(radius(*r)),
+            AttackPattern::MeteorShower(r, width) => {
+                // Both the distance and the impact width grow, but width stays tight
+                let width = (*width + bonus).clamp(1, MAX_METEOR_WIDTH.max(*width));
+                AttackPattern::MeteorShower(reach(*r), width)
+            }
+            AttackPattern::CrescentSlash => AttackPattern::CrescentSlash,
+            AttackPattern::Vortex(r) => AttackPattern::Vortex(radius(*r)),
+        }
+    }
+
     /// Get a human-readable name for the pattern
     pub fn name(&self) -> &str {
         match self {
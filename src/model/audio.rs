@@ -1,16 +1,20 @@
+use crate::model::status_effect::StatusEffectType;
 use rodio::{Decoder, OutputStream, Sink, Source};
 use std::collections::HashMap;
 use std::fs::{self, File};
 use std::io::BufReader;
 use std::path::PathBuf;
 use std::sync::{Arc, Mutex};
+use std::time::Duration;
 
-/// Fade state for music transitions
+/// Fade state for music transitions. While crossfading, the new track fades in on
+/// `sink` and the previous one fades out on `outgoing_sink`
 #[derive(Clone, Copy, Debug)]
 pub enum FadeState {
     None,
     FadingIn { current_time: f32, duration: f32 },
     FadingOut { current_time: f32, duration: f32 },
+    Crossfading { current_time: f32, duration: f32 },
 }
 
 #[derive(Clone, Copy, Debug, Hash, Eq, PartialEq)]
@@ -26,6 +30,19 @@ pub enum SoundEffect {
     MenuPick,
     ItemEquip,
     Gold,
+    LowHealthHeartbeat,
+    StatusBleed,
+    StatusPoison,
+    StatusBurn,
+    StatusDebuff,
+    StatusBuff,
+    GhostDeath,
+    BoneShatter,
+    BossDeath,
+    SwordSwish,
+    BowTwang,
+    StaffWhoosh,
+    MaceThud,
 }
 
 impl SoundEffect {
@@ -43,6 +60,78 @@ impl SoundEffect {
             SoundEffect::MenuPick => "MenuPick.mp3",
             SoundEffect::ItemEquip => "ItemEquip.mp3",
             SoundEffect::Gold => "Gold.ogg",
+            SoundEffect::LowHealthHeartbeat => "Heartbeat.mp3",
+            SoundEffect::StatusBleed => "StatusBleed.mp3",
+            SoundEffect::StatusPoison => "StatusPoison.mp3",
+            SoundEffect::StatusBurn => "StatusBurn.mp3",
+            SoundEffect::StatusDebuff => "StatusDebuff.mp3",
+            SoundEffect::StatusBuff => "StatusBuff.mp3",
+            SoundEffect::GhostDeath => "GhostDeath.mp3",
+            SoundEffect::BoneShatter => "BoneShatter.mp3",
+            SoundEffect::BossDeath => "BossDeath.mp3",
+            SoundEffect::SwordSwish => "SwordSwish.mp3",
+            SoundEffect::BowTwang => "BowTwang.mp3",
+            SoundEffect::StaffWhoosh => "StaffWhoosh.mp3",
+            SoundEffect::MaceThud => "MaceThud.mp3",
+        }
+    }
+
+    /// Cue played when an enemy dies, by what it was made of
+    pub fn for_enemy_death(
+        enemy_type: &crate::model::enemy_type::EnemyType,
+        rarity: &crate::model::enemy_type::EnemyRarity,
+    ) -> Self {
+        use crate::model::enemy_type::{EnemyRarity, EnemyType};
+
+        match (rarity, enemy_type) {
+            (EnemyRarity::Boss, _) => SoundEffect::BossDeath,
+            (_, EnemyType::Ghost) => SoundEffect::GhostDeath,
+            (_, EnemyType::Undead) => SoundEffect::BoneShatter,
+        }
+    }
+
+    /// Swing or shot cue for an attack with `weapon_type`
+    pub fn for_weapon(weapon_type: &crate::model::weapon::WeaponType) -> Self {
+        use crate::model::weapon::WeaponType;
+
+        match weapon_type {
+            WeaponType::Sword | WeaponType::Spear => SoundEffect::SwordSwish,
+            WeaponType::Bow => SoundEffect::BowTwang,
+            WeaponType::Staff => SoundEffect::StaffWhoosh,
+            WeaponType::Mace | WeaponType::Axe => SoundEffect::MaceThud,
+        }
+    }
+
+    /// Cue played when a status effect is newly applied to the player
+    pub fn for_status(effect_type: &StatusEffectType) -> Self {
+        match effect_type {
+            StatusEffectType::Bleed => SoundEffect::StatusBleed,
+            StatusEffectType::Poison => SoundEffect::StatusPoison,
+            StatusEffectType::Burn => SoundEffect::StatusBurn,
+            StatusEffectType::Stun | StatusEffectType::Cripple | StatusEffectType::Fear => {
+                SoundEffect::StatusDebuff
+            }
+            StatusEffectType::PoisonImmunity | StatusEffectType::Healing => SoundEffect::StatusBuff,
+        }
+    }
+
+    /// Synthesized tone (frequency Hz, length seconds) used when the sound file is missing
+    fn fallback_tone(&self) -> Option<(f32, f32)> {
+        match self {
+            SoundEffect::LowHealthHeartbeat => Some((55.0, 0.12)),
+            SoundEffect::StatusBleed => Some((330.0, 0.15)),
+            SoundEffect::StatusPoison => Some((180.0, 0.25)),
+            SoundEffect::StatusBurn => Some((440.0, 0.12)),
+            SoundEffect::StatusDebuff => Some((140.0, 0.2)),
+            SoundEffect::StatusBuff => Some((660.0, 0.15)),
+            SoundEffect::GhostDeath => Some((880.0, 0.3)),
+            SoundEffect::BoneShatter => Some((260.0, 0.08)),
+            SoundEffect::BossDeath => Some((65.0, 0.6)),
+            SoundEffect::SwordSwish => Some((1200.0, 0.05)),
+            SoundEffect::BowTwang => Some((520.0, 0.1)),
+            SoundEffect::StaffWhoosh => Some((300.0, 0.18)),
+            SoundEffect::MaceThud => Some((90.0, 0.1)),
+            _ => None,
         }
     }
 
@@ -70,6 +159,56 @@ struct CachedSoundEffect {
     data: Arc<Vec<u8>>,
 }
 
+/// Gain for a sound `distance` tiles from the player: full volume up close, fading
+/// linearly to a floor that keeps distant sounds audible
+pub fn distance_attenuation(distance: f32) -> f32 {
+    use crate::constants::{SOUND_FALLOFF_DISTANCE, SOUND_FULL_VOLUME_DISTANCE, SOUND_MIN_GAIN};
+
+    let span = SOUND_FALLOFF_DISTANCE - SOUND_FULL_VOLUME_DISTANCE;
+    let t = ((distance - SOUND_FULL_VOLUME_DISTANCE) / span).clamp(0.0, 1.0);
+    1.0 - t * (1.0 - SOUND_MIN_GAIN)
+}
+
+/// Everything the game asks of its audio. `AudioManager` plays through the sound
+/// device; `NullAudio` stands in when there is nothing to hear.
+pub trait Audio {
+    /// Play a sound effect at full volume
+    fn play_sound_effect(&mut self, effect: SoundEffect);
+    /// Play a sound effect that happened at `world_pos`, quieter the further it is
+    /// from `player_pos`
+    fn play_sound_effect_at(
+        &mut self,
+        effect: SoundEffect,
+        world_pos: (i32, i32),
+        player_pos: (i32, i32),
+    );
+    /// Swing or shot cue for `weapon_type`, cutting off the previous one
+    fn play_attack_sound(&mut self, weapon_type: &crate::model::weapon::WeaponType);
+    fn play_damaged_sound(&mut self);
+    fn play_gold_sound(&mut self);
+    /// Looping low-health heartbeat
+    fn start_heartbeat(&mut self);
+    fn stop_heartbeat(&mut self);
+    fn is_heartbeat_playing(&self) -> bool;
+    fn start_music_with_fade_in(&mut self) -> Result<(), Box<dyn std::error::Error>>;
+    fn start_boss_music_with_fade(&mut self) -> Result<(), Box<dyn std::error::Error>>;
+    fn restore_normal_music(&mut self) -> Result<(), Box<dyn std::error::Error>>;
+    fn start_death_fade_out(&mut self, duration: f32);
+    fn start_fade_in(&mut self, duration: f32, target: f32);
+    /// Advance fades and crossfades by `delta_time` seconds
+    fn update(&mut self, delta_time: f32);
+    fn set_music_volume(&mut self, volume: f32);
+    fn set_sound_volume(&mut self, volume: f32);
+    fn stop_music(&mut self);
+    fn pause_music(&mut self);
+    fn resume_music(&mut self);
+    fn stop_sound_effects(&mut self);
+    /// Whether calls are dropped instead of played
+    fn is_silent(&self) -> bool {
+        false
+    }
+}
+
 /// Audio manager for handling music and sound effects using rodio
 pub struct AudioManager {
     sink: Option<Arc<Mutex<Sink>>>,
@@ -77,6 +216,10 @@ pub struct AudioManager {
     // Separate sink and stream for sound effects (so SFX and music volumes are independent)
     effects_sink: Option<Arc<Mutex<Sink>>>,
     _effects_stream: Option<Box<OutputStream>>,
+    /// Dedicated looping sink for the low-health heartbeat so it never queues behind other SFX
+    heartbeat_sink: Option<Sink>,
+    /// Sink for attack cues; each attack cuts off the last so rapid swings don't pile up
+    attack_sink: Option<Sink>,
 
     music_volume: f32,
     sound_volume: f32,
@@ -84,6 +227,13 @@ pub struct AudioManager {
     fade_state: FadeState,
     music_files: Vec<PathBuf>,
     current_file_index: usize,
+    /// Tracks in audio/music/boss, played on boss floors
+    boss_music_files: Vec<PathBuf>,
+    /// Whether the boss theme is (or should be) the current track
+    boss_music: bool,
+    /// Previous track while a crossfade is running, and the volume it started from
+    outgoing_sink: Option<Arc<Mutex<Sink>>>,
+    crossfade_from: f32,
     /// Cache of loaded sound effects to avoid file I/O on every play
     sfx_cache: HashMap<SoundEffect, CachedSoundEffect>,
 }
@@ -91,17 +241,24 @@ impl AudioManager {
     pub fn new() -> Self {
         // Load all MP3 files from audio/music folder
         let music_files = Self::load_music_files();
+        let boss_music_files = Self::music_files_in("audio/music/boss");
         let mut manager = Self {
             sink: None,
             _stream: None,
             effects_sink: None,
             _effects_stream: None,
+            heartbeat_sink: None,
+            attack_sink: None,
             music_volume: 0.0, // Start at 0 for fade-in
             sound_volume: 0.5,
             target_volume: 0.5,
             fade_state: FadeState::None,
             music_files,
             current_file_index: 0,
+            boss_music_files,
+            boss_music: false,
+            outgoing_sink: None,
+            crossfade_from: 0.0,
             sfx_cache: HashMap::new(),
         };
 
@@ -113,7 +270,19 @@ impl AudioManager {
 
     /// Load all MP3 files from audio/music directory
     fn load_music_files() -> Vec<PathBuf> {
-        let music_dir = PathBuf::from("audio/music");
+        let mut files = Self::music_files_in("audio/music");
+
+        if files.is_empty() {
+            // Fallback to darkhelm.mp3 if no music folder
+            files.push(PathBuf::from("audio/darkhelm.mp3"));
+        }
+
+        files
+    }
+
+    /// Audio files directly inside `dir`, empty if it doesn't exist
+    fn music_files_in(dir: &str) -> Vec<PathBuf> {
+        let music_dir = PathBuf::from(dir);
         let mut files = Vec::new();
 
         if let Ok(entries) = fs::read_dir(&music_dir) {
@@ -129,12 +298,7 @@ impl AudioManager {
                 }
             }
         }
-
-        if files.is_empty() {
-            // Fallback to darkhelm.mp3 if no music folder
-            files.push(PathBuf::from("audio/darkhelm.mp3"));
-        }
-
+        files.sort();
         files
     }
 
@@ -152,6 +316,19 @@ impl AudioManager {
             SoundEffect::MenuPick,
             SoundEffect::ItemEquip,
             SoundEffect::Gold,
+            SoundEffect::LowHealthHeartbeat,
+            SoundEffect::StatusBleed,
+            SoundEffect::StatusPoison,
+            SoundEffect::StatusBurn,
+            SoundEffect::StatusDebuff,
+            SoundEffect::StatusBuff,
+            SoundEffect::GhostDeath,
+            SoundEffect::BoneShatter,
+            SoundEffect::BossDeath,
+            SoundEffect::SwordSwish,
+            SoundEffect::BowTwang,
+            SoundEffect::StaffWhoosh,
+            SoundEffect::MaceThud,
         ];
 
         for effect in &effects {
@@ -175,11 +352,11 @@ impl AudioManager {
         }
     }
 
-    /// Play a sound effect using cached data (safe, non-blocking, low overhead)
-    pub fn play_sound_effect(&mut self, effect: SoundEffect) {
+    /// Play a sound effect scaled by `gain` (1.0 = as recorded)
+    fn play_sound_effect_with_gain(&mut self, effect: SoundEffect, gain: f32) {
         // Special handling for Hit - use random variant
         if effect == SoundEffect::Hit {
-            self.play_random_hit();
+            self.play_random_hit_with_gain(gain);
             return;
         }
 
@@ -203,15 +380,43 @@ impl AudioManager {
                     if let Ok(sink_guard) = sink.lock() {
                         // Apply sound volume
                         sink_guard.set_volume(self.sound_volume);
-                        sink_guard.append(source);
+                        sink_guard.append(source.amplify(gain));
                     }
                 }
             }
+        } else if let Some((freq, secs)) = effect.fallback_tone() {
+            self.ensure_effects_sink();
+            if let Some(sink) = &self.effects_sink {
+                if let Ok(sink_guard) = sink.lock() {
+                    sink_guard.set_volume(self.sound_volume);
+                    sink_guard.append(Self::tone(freq, secs).amplify(gain));
+                }
+            }
         }
     }
 
-    /// Play a random hit sound effect (Hit1, Hit2, or Hit3)
-    pub fn play_random_hit(&mut self) {
+    /// Open the effects output stream and sink if they don't exist yet
+    fn ensure_effects_sink(&mut self) {
+        if self.effects_sink.is_none() {
+            if let Ok(mut stream) = rodio::OutputStreamBuilder::open_default_stream() {
+                stream.log_on_drop(false);
+                let sink = Sink::connect_new(stream.mixer());
+                self.effects_sink = Some(Arc::new(Mutex::new(sink)));
+                self._effects_stream = Some(Box::new(stream));
+            }
+        }
+    }
+
+    /// Short sine blip with a fade so it doesn't click
+    fn tone(freq: f32, secs: f32) -> impl Source + Send + 'static {
+        rodio::source::SineWave::new(freq)
+            .take_duration(Duration::from_secs_f32(secs))
+            .fade_out(Duration::from_secs_f32(secs))
+            .amplify(0.4)
+    }
+
+    /// Play a random hit sound effect (Hit1, Hit2, or Hit3) scaled by `gain`
+    fn play_random_hit_with_gain(&mut self, gain: f32) {
         let path = SoundEffect::get_random_hit();
         if let Ok(data) = fs::read(&path) {
             // Ensure we have an effects stream and sink
@@ -230,7 +435,7 @@ impl AudioManager {
                 if let Ok(source) = Decoder::new(cursor) {
                     if let Ok(sink_guard) = sink.lock() {
                         sink_guard.set_volume(self.sound_volume);
-                        sink_guard.append(source);
+                        sink_guard.append(source.amplify(gain));
                     }
                 }
             }
@@ -268,8 +473,125 @@ impl AudioManager {
         }
     }
 
+    /// Track for the current mode: the boss theme if one is wanted and available
+    fn current_track(&self) -> Option<PathBuf> {
+        let boss_track = self.boss_music_files.first().filter(|_| self.boss_music);
+        boss_track
+            .or_else(|| self.music_files.get(self.current_file_index))
+            .cloned()
+    }
+
+    /// Start the current track on a new sink of the running stream and hand the old
+    /// sink over to fade out. Without a running stream only the mode changes, and the
+    /// next `start_music_with_fade_in` picks the right track.
+    fn crossfade_to_current_track(
+        &mut self,
+        duration: f32,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let (Some(stream), Some(path)) = (&self._stream, self.current_track()) else {
+            return Ok(());
+        };
+
+        let source = Decoder::new(BufReader::new(File::open(path)?))?;
+        let sink = Sink::connect_new(stream.mixer());
+        sink.append(source.repeat_infinite());
+        sink.set_volume(0.0);
+        sink.play();
+
+        // A crossfade already running is cut short so only two tracks ever overlap
+        self.finish_crossfade();
+        self.outgoing_sink = self.sink.replace(Arc::new(Mutex::new(sink)));
+        self.crossfade_from = self.music_volume;
+        self.fade_state = FadeState::Crossfading {
+            current_time: 0.0,
+            duration,
+        };
+        Ok(())
+    }
+
+    /// Stop the outgoing track of a crossfade, if any. The incoming track keeps its
+    /// current volume for the next fade to start from.
+    fn finish_crossfade(&mut self) {
+        if let Some(sink) = self.outgoing_sink.take() {
+            if let Ok(sink_guard) = sink.lock() {
+                sink_guard.stop();
+            }
+        }
+        if matches!(self.fade_state, FadeState::Crossfading { .. }) {
+            self.fade_state = FadeState::None;
+        }
+    }
+
+    /// Get current music volume
+    pub fn get_music_volume(&self) -> f32 {
+        self.music_volume
+    }
+
+    /// Get current sound effects volume
+    pub fn get_sound_volume(&self) -> f32 {
+        self.sound_volume
+    }
+
+    /// Check if music is playing
+    pub fn is_music_playing(&self) -> bool {
+        if let Some(sink) = &self.sink {
+            if let Ok(sink_guard) = sink.lock() {
+                return !sink_guard.is_paused();
+            }
+        }
+        false
+    }
+}
+
+impl Audio for AudioManager {
+    /// Play a sound effect using cached data (safe, non-blocking, low overhead)
+    fn play_sound_effect(&mut self, effect: SoundEffect) {
+        self.play_sound_effect_with_gain(effect, 1.0);
+    }
+
+    /// Play a sound effect that happened at `world_pos`, quieter the further it is from
+    /// the player. The effects volume setting still applies on top.
+    fn play_sound_effect_at(
+        &mut self,
+        effect: SoundEffect,
+        world_pos: (i32, i32),
+        player_pos: (i32, i32),
+    ) {
+        let (dx, dy) = (world_pos.0 - player_pos.0, world_pos.1 - player_pos.1);
+        let distance = ((dx * dx + dy * dy) as f32).sqrt();
+        self.play_sound_effect_with_gain(effect, distance_attenuation(distance));
+    }
+
+    /// Play the swing or shot cue for `weapon_type`, replacing any attack cue still playing
+    fn play_attack_sound(&mut self, weapon_type: &crate::model::weapon::WeaponType) {
+        let effect = SoundEffect::for_weapon(weapon_type);
+        if self.attack_sink.is_none() {
+            self.ensure_effects_sink();
+            let Some(stream) = &self._effects_stream else {
+                return;
+            };
+            self.attack_sink = Some(Sink::connect_new(stream.mixer()));
+        }
+        let Some(sink) = &self.attack_sink else {
+            return;
+        };
+
+        sink.clear();
+        sink.set_volume(self.sound_volume);
+        let cached = self
+            .sfx_cache
+            .get(&effect)
+            .and_then(|c| Decoder::new(std::io::Cursor::new(c.data.as_ref().clone())).ok());
+        if let Some(source) = cached {
+            sink.append(source);
+        } else if let Some((freq, secs)) = effect.fallback_tone() {
+            sink.append(Self::tone(freq, secs));
+        }
+        sink.play();
+    }
+
     /// Play Damaged sound with up to +3 pitch variations
-    pub fn play_damaged_sound(&mut self) {
+    fn play_damaged_sound(&mut self) {
         use rand::{Rng, RngExt};
         let mut rng = rand::rng();
         let pitch_variation = rng.random_range(-3..=3) as f32;
@@ -277,21 +599,60 @@ impl AudioManager {
     }
 
     /// Play Gold pickup sound with random pitch variation (-5 to +5)
-    pub fn play_gold_sound(&mut self) {
+    fn play_gold_sound(&mut self) {
         use rand::{Rng, RngExt};
         let mut rng = rand::rng();
         let pitch_variation = rng.random_range(-5..=5) as f32;
         self.play_sound_with_pitch(SoundEffect::Gold, pitch_variation);
     }
 
-    /// Start playing music with fade-in
-    pub fn start_music_with_fade_in(&mut self) -> Result<(), Box<dyn std::error::Error>> {
-        if self.music_files.is_empty() {
-            return Err("No music files found".into());
+    /// Start the looping low-health heartbeat (no-op if it is already playing)
+    fn start_heartbeat(&mut self) {
+        if self.heartbeat_sink.is_some() {
+            return;
         }
+        self.ensure_effects_sink();
+        let Some(stream) = &self._effects_stream else {
+            return;
+        };
+        let sink = Sink::connect_new(stream.mixer());
+        sink.set_volume(self.sound_volume);
+
+        let cached = self
+            .sfx_cache
+            .get(&SoundEffect::LowHealthHeartbeat)
+            .and_then(|c| Decoder::new(std::io::Cursor::new(c.data.as_ref().clone())).ok());
+        if let Some(source) = cached {
+            sink.append(source.repeat_infinite());
+        } else {
+            // "lub-dub" followed by a pause, about one beat per second
+            let beat = rodio::source::Zero::new(1, 48000)
+                .take_duration(Duration::from_secs_f32(0.9))
+                .mix(Self::tone(55.0, 0.12))
+                .mix(Self::tone(50.0, 0.12).delay(Duration::from_secs_f32(0.22)));
+            sink.append(beat.repeat_infinite());
+        }
+        self.heartbeat_sink = Some(sink);
+    }
+
+    /// Stop the low-health heartbeat
+    fn stop_heartbeat(&mut self) {
+        if let Some(sink) = self.heartbeat_sink.take() {
+            sink.stop();
+        }
+    }
 
-        // Get current music file
-        let path = &self.music_files[self.current_file_index];
+    fn is_heartbeat_playing(&self) -> bool {
+        self.heartbeat_sink.is_some()
+    }
+
+    /// Start playing music with fade-in
+    fn start_music_with_fade_in(&mut self) -> Result<(), Box<dyn std::error::Error>> {
+        // Get current music file: the boss theme on boss floors
+        let Some(path) = self.current_track() else {
+            return Err("No music files found".into());
+        };
+        self.finish_crossfade();
 
         // Create output stream using default device
         let mut stream = rodio::OutputStreamBuilder::open_default_stream()?;
@@ -301,7 +662,7 @@ impl AudioManager {
         let sink = Sink::connect_new(stream.mixer());
 
         // Open file
-        let file = File::open(path)?;
+        let file = File::open(&path)?;
         let reader = BufReader::new(file);
 
         // Decode audio file
@@ -331,8 +692,30 @@ impl AudioManager {
         Ok(())
     }
 
+    /// Switch to the boss theme, crossfading from the normal track
+    fn start_boss_music_with_fade(&mut self) -> Result<(), Box<dyn std::error::Error>> {
+        if self.boss_music {
+            return Ok(());
+        }
+        if self.boss_music_files.is_empty() {
+            return Err("No boss music found".into());
+        }
+        self.boss_music = true;
+        self.crossfade_to_current_track(crate::constants::MUSIC_CROSSFADE_SECS)
+    }
+
+    /// Switch back from the boss theme to the normal track, crossfading between them
+    fn restore_normal_music(&mut self) -> Result<(), Box<dyn std::error::Error>> {
+        if !self.boss_music {
+            return Ok(());
+        }
+        self.boss_music = false;
+        self.crossfade_to_current_track(crate::constants::MUSIC_CROSSFADE_SECS)
+    }
+
     /// Start fade-out transition with muffling effect for death
-    pub fn start_death_fade_out(&mut self, duration: f32) {
+    fn start_death_fade_out(&mut self, duration: f32) {
+        self.finish_crossfade();
         self.fade_state = FadeState::FadingOut {
             current_time: 0.0,
             duration,
@@ -341,7 +724,8 @@ impl AudioManager {
     }
 
     /// Start fade-in transition
-    pub fn start_fade_in(&mut self, duration: f32, target: f32) {
+    fn start_fade_in(&mut self, duration: f32, target: f32) {
+        self.finish_crossfade();
         self.fade_state = FadeState::FadingIn {
             current_time: 0.0,
             duration,
@@ -350,7 +734,7 @@ impl AudioManager {
     }
 
     /// Update fade transitions (call every frame)
-    pub fn update(&mut self, delta_time: f32) {
+    fn update(&mut self, delta_time: f32) {
         match self.fade_state {
             FadeState::FadingIn {
                 mut current_time,
@@ -409,13 +793,45 @@ impl AudioManager {
                     duration,
                 };
             }
+            FadeState::Crossfading {
+                mut current_time,
+                duration,
+            } => {
+                // Both volumes move linearly from where they were, so neither track jumps.
+                // A target of 0 (music muted) keeps the new track silent throughout.
+                current_time += delta_time;
+                let progress = (current_time / duration).min(1.0);
+                self.music_volume = progress * self.target_volume;
+                let outgoing_volume = (1.0 - progress) * self.crossfade_from;
+
+                if let Some(sink) = &self.sink {
+                    if let Ok(sink_guard) = sink.lock() {
+                        sink_guard.set_volume(self.music_volume);
+                    }
+                }
+                if let Some(sink) = &self.outgoing_sink {
+                    if let Ok(sink_guard) = sink.lock() {
+                        sink_guard.set_volume(outgoing_volume);
+                    }
+                }
+
+                if progress >= 1.0 {
+                    self.finish_crossfade();
+                } else {
+                    self.fade_state = FadeState::Crossfading {
+                        current_time,
+                        duration,
+                    };
+                }
+            }
             FadeState::None => {}
         }
     }
 
     /// Set music volume (0.0 to 1.0) - changes volume of currently playing track
-    pub fn set_music_volume(&mut self, volume: f32) {
+    fn set_music_volume(&mut self, volume: f32) {
         let volume = volume.clamp(0.0, 1.0);
+        self.finish_crossfade();
         self.music_volume = volume;
         self.target_volume = volume;
         self.fade_state = FadeState::None;
@@ -428,13 +844,8 @@ impl AudioManager {
         }
     }
 
-    /// Get current music volume
-    pub fn get_music_volume(&self) -> f32 {
-        self.music_volume
-    }
-
     /// Set sound effects volume (0.0 to 1.0)
-    pub fn set_sound_volume(&mut self, volume: f32) {
+    fn set_sound_volume(&mut self, volume: f32) {
         self.sound_volume = volume.clamp(0.0, 1.0);
 
         // Apply volume to effects sink if it exists
@@ -443,15 +854,14 @@ impl AudioManager {
                 sink_guard.set_volume(self.sound_volume);
             }
         }
-    }
-
-    /// Get current sound effects volume
-    pub fn get_sound_volume(&self) -> f32 {
-        self.sound_volume
+        for sink in self.heartbeat_sink.iter().chain(&self.attack_sink) {
+            sink.set_volume(self.sound_volume);
+        }
     }
 
     /// Stop background music
-    pub fn stop_music(&mut self) {
+    fn stop_music(&mut self) {
+        self.finish_crossfade();
         if let Some(sink) = &self.sink {
             if let Ok(sink_guard) = sink.lock() {
                 sink_guard.stop();
@@ -462,19 +872,9 @@ impl AudioManager {
         self.fade_state = FadeState::None;
     }
 
-    /// Check if music is playing
-    pub fn is_music_playing(&self) -> bool {
-        if let Some(sink) = &self.sink {
-            if let Ok(sink_guard) = sink.lock() {
-                return !sink_guard.is_paused();
-            }
-        }
-        false
-    }
-
     /// Pause music
-    pub fn pause_music(&mut self) {
-        if let Some(sink) = &self.sink {
+    fn pause_music(&mut self) {
+        for sink in self.sink.iter().chain(&self.outgoing_sink) {
             if let Ok(sink_guard) = sink.lock() {
                 sink_guard.pause();
             }
@@ -482,8 +882,8 @@ impl AudioManager {
     }
 
     /// Resume music
-    pub fn resume_music(&mut self) {
-        if let Some(sink) = &self.sink {
+    fn resume_music(&mut self) {
+        for sink in self.sink.iter().chain(&self.outgoing_sink) {
             if let Ok(sink_guard) = sink.lock() {
                 sink_guard.play();
             }
@@ -491,12 +891,15 @@ impl AudioManager {
     }
 
     /// Stop all sound effects (used for interrupting/killing existing sounds)
-    pub fn stop_sound_effects(&mut self) {
+    fn stop_sound_effects(&mut self) {
         if let Some(sink) = &self.effects_sink {
             if let Ok(sink_guard) = sink.lock() {
                 sink_guard.stop();
             }
         }
+        if let Some(sink) = &self.attack_sink {
+            sink.clear();
+        }
     }
 }
 
@@ -505,3 +908,103 @@ impl Default for AudioManager {
         Self::new()
     }
 }
+
+/// Audio that drops every call, for tests and when audio is muted in the settings
+#[derive(Clone, Copy, Debug, Default)]
+pub struct NullAudio;
+
+impl Audio for NullAudio {
+    fn play_sound_effect(&mut self, _effect: SoundEffect) {}
+    fn play_sound_effect_at(
+        &mut self,
+        _effect: SoundEffect,
+        _world_pos: (i32, i32),
+        _player_pos: (i32, i32),
+    ) {
+    }
+    fn play_attack_sound(&mut self, _weapon_type: &crate::model::weapon::WeaponType) {}
+    fn play_damaged_sound(&mut self) {}
+    fn play_gold_sound(&mut self) {}
+    fn start_heartbeat(&mut self) {}
+    fn stop_heartbeat(&mut self) {}
+    fn is_heartbeat_playing(&self) -> bool {
+        false
+    }
+    fn start_music_with_fade_in(&mut self) -> Result<(), Box<dyn std::error::Error>> {
+        Ok(())
+    }
+    fn start_boss_music_with_fade(&mut self) -> Result<(), Box<dyn std::error::Error>> {
+        Ok(())
+    }
+    fn restore_normal_music(&mut self) -> Result<(), Box<dyn std::error::Error>> {
+        Ok(())
+    }
+    fn start_death_fade_out(&mut self, _duration: f32) {}
+    fn start_fade_in(&mut self, _duration: f32, _target: f32) {}
+    fn update(&mut self, _delta_time: f32) {}
+    fn set_music_volume(&mut self, _volume: f32) {}
+    fn set_sound_volume(&mut self, _volume: f32) {}
+    fn stop_music(&mut self) {}
+    fn pause_music(&mut self) {}
+    fn resume_music(&mut self) {}
+    fn stop_sound_effects(&mut self) {}
+    fn is_silent(&self) -> bool {
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::constants::{SOUND_FALLOFF_DISTANCE, SOUND_MIN_GAIN};
+
+    #[test]
+    fn test_each_weapon_type_has_its_attack_cue() {
+        use crate::model::weapon::WeaponType;
+
+        assert_eq!(
+            SoundEffect::for_weapon(&WeaponType::Sword),
+            SoundEffect::SwordSwish
+        );
+        assert_eq!(
+            SoundEffect::for_weapon(&WeaponType::Bow),
+            SoundEffect::BowTwang
+        );
+        assert_eq!(
+            SoundEffect::for_weapon(&WeaponType::Staff),
+            SoundEffect::StaffWhoosh
+        );
+        assert_eq!(
+            SoundEffect::for_weapon(&WeaponType::Mace),
+            SoundEffect::MaceThud
+        );
+
+        // Every cue can still be heard before its sound file ships
+        for weapon_type in [
+            WeaponType::Sword,
+            WeaponType::Bow,
+            WeaponType::Mace,
+            WeaponType::Spear,
+            WeaponType::Axe,
+            WeaponType::Staff,
+        ] {
+            assert!(SoundEffect::for_weapon(&weapon_type)
+                .fallback_tone()
+                .is_some());
+        }
+    }
+
+    #[test]
+    fn test_distant_sounds_are_quieter_but_audible() {
+        assert_eq!(distance_attenuation(0.0), 1.0);
+        assert_eq!(distance_attenuation(1.0), 1.0);
+
+        let near = distance_attenuation(8.0);
+        let far = distance_attenuation(20.0);
+        assert!(near < 1.0 && far < near);
+
+        let quietest = distance_attenuation(SOUND_FALLOFF_DISTANCE);
+        assert!((quietest - SOUND_MIN_GAIN).abs() < 1e-6);
+        assert_eq!(distance_attenuation(500.0), quietest);
+    }
+}
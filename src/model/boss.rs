@@ -1,6 +1,9 @@
 use crate::constants::*;
 use crate::model::attack_pattern::AttackPattern;
+use crate::model::cooldown::IntervalTimer;
 use crate::model::enemy::Enemy;
+use crate::model::floor::Floor;
+use crate::model::item_tier::Difficulty;
 use std::time::Instant;
 
 /// Boss enemy types with unique attack patterns and mechanics
@@ -18,6 +21,18 @@ pub enum BossType {
     CorruptedWarden,
 }
 
+impl BossType {
+    pub fn name(&self) -> &'static str {
+        match self {
+            BossType::GoblinOverlord => "Goblin Overlord",
+            BossType::SkeletalKnight => "Skeletal Knight",
+            BossType::FlameSorcerer => "Flame Sorcerer",
+            BossType::ShadowAssassin => "Shadow Assassin",
+            BossType::CorruptedWarden => "Corrupted Warden",
+        }
+    }
+}
+
 /// Boss phase state for multi-phase mechanics
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub enum BossPhase {
@@ -29,6 +44,33 @@ pub enum BossPhase {
     Third,
 }
 
+impl BossPhase {
+    /// Fraction of max health below which the boss enters this phase
+    pub fn health_threshold(&self) -> f32 {
+        match self {
+            BossPhase::First => 1.0,
+            BossPhase::Second => 0.66,
+            BossPhase::Third => 0.33,
+        }
+    }
+
+    pub fn enrage_multiplier(&self) -> f32 {
+        match self {
+            BossPhase::First => 1.0,
+            BossPhase::Second => 1.2,
+            BossPhase::Third => 1.5,
+        }
+    }
+
+    fn damage_multiplier(&self) -> f32 {
+        match self {
+            BossPhase::First => 1.0,
+            BossPhase::Second => 1.1,
+            BossPhase::Third => 1.3,
+        }
+    }
+}
+
 /// Enhanced enemy with boss-specific mechanics
 #[derive(Clone, Debug)]
 pub struct BossEnemy {
@@ -118,6 +160,9 @@ impl BossEnemy {
         let mut base_enemy = Enemy::new(x, y, BOSS_BASE_SPEED * ENEMY_SPEED_MULTIPLIER);
         base_enemy.health = base_health;
         base_enemy.max_health = base_max_health;
+        base_enemy.name = boss_type.name().to_string();
+        base_enemy.rarity = crate::model::enemy_type::EnemyRarity::Boss;
+        base_enemy.base_gold = base_enemy.rarity.base_gold();
 
         Self {
             base_enemy,
@@ -149,21 +194,18 @@ impl BossEnemy {
             self.phase_transition_cooldown = Some(Instant::now());
 
             // Enrage multiplier increases in later phases
-            self.enrage_multiplier = match self.current_phase {
-                BossPhase::First => 1.0,
-                BossPhase::Second => 1.2,
-                BossPhase::Third => 1.5,
-            };
+            self.enrage_multiplier = self.current_phase.enrage_multiplier();
         }
     }
 
-    /// Check if special ability is ready
-    pub fn can_use_special_ability(&self, current_time: Instant) -> bool {
+    /// Check if special ability is ready at `current_time`, counting game time at speed
+    /// `time_scale`
+    pub fn can_use_special_ability(&self, current_time: Instant, time_scale: f32) -> bool {
         match self.special_ability_cooldown {
             None => true,
             Some(last_time) => {
-                let elapsed = current_time.elapsed().as_secs_f32();
-                let last_elapsed = current_time.duration_since(last_time).as_secs_f32();
+                let last_elapsed =
+                    current_time.duration_since(last_time).as_secs_f32() * time_scale;
                 last_elapsed >= 8.0 // 8 second cooldown for special abilities
             }
         }
@@ -171,12 +213,10 @@ impl BossEnemy {
 
     /// Get modified attack damage based on phase and enrage
     pub fn get_effective_damage(&self) -> i32 {
-        let phase_multiplier = match self.current_phase {
-            BossPhase::First => 1.0,
-            BossPhase::Second => 1.1,
-            BossPhase::Third => 1.3,
-        };
+        self.damage_with(self.current_phase.damage_multiplier() * self.enrage_multiplier)
+    }
 
+    fn damage_with(&self, multiplier: f32) -> i32 {
         // Get base damage from boss type
         let base_damage = match self.boss_type {
             BossType::GoblinOverlord => 25,
@@ -186,7 +226,86 @@ impl BossEnemy {
             BossType::CorruptedWarden => 28,
         };
 
-        ((base_damage as f32 * phase_multiplier * self.enrage_multiplier) as i32).max(1)
+        ((base_damage as f32 * multiplier) as i32).max(1)
+    }
+
+    /// Attack sets the boss swaps to in its second and third phases, each harder than the last
+    pub fn phase_attacks(&self) -> Vec<crate::model::enemy::AttackPhase> {
+        let (second, third) = match self.boss_type {
+            BossType::GoblinOverlord => (
+                vec![
+                    AttackPattern::WhirlwindAttack,
+                    AttackPattern::GroundSlam(2),
+                    AttackPattern::GroundSlam(3),
+                ],
+                vec![
+                    AttackPattern::WhirlwindAttack,
+                    AttackPattern::GroundSlam(3),
+                    AttackPattern::CrescentSlash,
+                ],
+            ),
+            BossType::SkeletalKnight => (
+                vec![
+                    AttackPattern::SwordThrust(3),
+                    AttackPattern::GroundSlam(2),
+                    AttackPattern::BasicSlash,
+                ],
+                vec![
+                    AttackPattern::SwordThrust(3),
+                    AttackPattern::GroundSlam(2),
+                    AttackPattern::WhirlwindAttack,
+                ],
+            ),
+            BossType::FlameSorcerer => (
+                vec![
+                    AttackPattern::Fireball(3),
+                    AttackPattern::MeteorShower(5, 3),
+                    AttackPattern::Vortex(2),
+                ],
+                vec![
+                    AttackPattern::MeteorShower(5, 3),
+                    AttackPattern::Fireball(4),
+                    AttackPattern::Vortex(3),
+                ],
+            ),
+            BossType::ShadowAssassin => (
+                vec![
+                    AttackPattern::SwordThrust(3),
+                    AttackPattern::CrescentSlash,
+                    AttackPattern::WhirlwindAttack,
+                ],
+                vec![
+                    AttackPattern::CrescentSlash,
+                    AttackPattern::SwordThrust(3),
+                    AttackPattern::Vortex(2),
+                ],
+            ),
+            BossType::CorruptedWarden => (
+                vec![
+                    AttackPattern::ChainLightning(5),
+                    AttackPattern::FrostNova(3),
+                    AttackPattern::Fireball(3),
+                ],
+                vec![
+                    AttackPattern::ChainLightning(5),
+                    AttackPattern::MeteorShower(4, 2),
+                    AttackPattern::FrostNova(4),
+                    AttackPattern::Vortex(3),
+                ],
+            ),
+        };
+
+        [(BossPhase::Second, second), (BossPhase::Third, third)]
+            .into_iter()
+            .map(|(phase, patterns)| {
+                let damage =
+                    self.damage_with(phase.damage_multiplier() * phase.enrage_multiplier());
+                crate::model::enemy::AttackPhase {
+                    health_fraction: phase.health_threshold(),
+                    attacks: convert_attack_patterns_to_enemy_attacks(&patterns, damage),
+                }
+            })
+            .collect()
     }
 
     /// Get attack radius based on boss type
@@ -297,6 +416,34 @@ impl BossEnemy {
     }
 }
 
+/// Calls in reinforcements for the boss of a floor every `BOSS_SUMMON_INTERVAL` seconds
+#[derive(Clone, Debug, Default)]
+pub struct BossSummoner {
+    timer: IntervalTimer,
+}
+
+impl BossSummoner {
+    /// Advance the summon cooldown; once it is up, the living boss summons its adds
+    /// away from the player at `player`. Returns how many enemies were summoned.
+    pub fn update(
+        &mut self,
+        delta: f32,
+        floor: &mut Floor,
+        difficulty: &Difficulty,
+        player: (i32, i32),
+    ) -> usize {
+        if !floor.has_boss() {
+            self.timer.reset();
+            return 0;
+        }
+        if self.timer.tick(delta, BOSS_SUMMON_INTERVAL) {
+            floor.summon_boss_adds(difficulty, player)
+        } else {
+            0
+        }
+    }
+}
+
 /// Helper function to convert attack patterns to enemy attacks with reasonable defaults
 pub fn convert_attack_patterns_to_enemy_attacks(
     patterns: &[AttackPattern],
@@ -457,6 +604,7 @@ pub fn convert_attack_patterns_to_enemy_attacks(
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::model::enemy_type::EnemyRarity;
 
     #[test]
     fn test_boss_creation() {
@@ -485,6 +633,37 @@ mod tests {
         assert_eq!(boss.enrage_multiplier, 1.5);
     }
 
+    #[test]
+    fn test_boss_below_a_third_health_switches_attack_set() {
+        let mut boss = BossEnemy::new(0, 0, BossType::GoblinOverlord);
+        let damage = boss.get_effective_damage();
+        boss.base_enemy.attacks =
+            convert_attack_patterns_to_enemy_attacks(&boss.attack_patterns, damage);
+        boss.base_enemy.phases = boss.phase_attacks();
+        let names = |enemy: &Enemy| -> Vec<String> {
+            enemy.attacks.iter().map(|a| a.name.clone()).collect()
+        };
+        let opening = names(&boss.base_enemy);
+
+        // Still above both thresholds: nothing changes
+        boss.base_enemy.health = 100;
+        assert!(!boss.base_enemy.enter_next_phase());
+        assert_eq!(names(&boss.base_enemy), opening);
+
+        // Dropping straight below 33% skips to the final, hardest set
+        boss.base_enemy.health = 30;
+        assert!(boss.base_enemy.enter_next_phase());
+        assert_ne!(names(&boss.base_enemy), opening);
+        assert!(names(&boss.base_enemy).contains(&"Crescent Slash".to_string()));
+        assert!(boss.base_enemy.phases.is_empty());
+        assert_eq!(boss.base_enemy.current_attack_index, 0);
+
+        let hardest = boss.base_enemy.attacks[0].damage_max;
+        let first_phase = boss.phase_attacks();
+        assert!(hardest > first_phase[0].attacks[0].damage_max);
+        assert!(!boss.base_enemy.enter_next_phase());
+    }
+
     #[test]
     fn test_effective_damage_scaling() {
         let mut boss = BossEnemy::new(10, 15, BossType::FlameSorcerer);
@@ -530,6 +709,18 @@ mod tests {
         assert_eq!(sorcerer.get_attack_radius(), 4); // ranged
     }
 
+    #[test]
+    fn test_special_ability_cooldown_scales_with_game_speed() {
+        let mut boss = BossEnemy::new(0, 0, BossType::FlameSorcerer);
+        let used_at = Instant::now();
+        boss.special_ability_cooldown = Some(used_at);
+        let later = used_at + std::time::Duration::from_secs(5);
+
+        // Five real seconds are ten of game time at double speed, past the 8 second cooldown
+        assert!(!boss.can_use_special_ability(later, 1.0));
+        assert!(boss.can_use_special_ability(later, 2.0));
+    }
+
     #[test]
     fn test_experience_reward() {
         let warden = BossEnemy::new(0, 0, BossType::CorruptedWarden);
@@ -542,4 +733,73 @@ mod tests {
         let boss = BossEnemy::new(0, 0, BossType::ShadowAssassin);
         assert_eq!(boss.loot_multiplier, 3); // All bosses drop 3x loot
     }
+
+    #[test]
+    fn test_summon_spawns_adds_when_off_cooldown() {
+        let mut floor = Floor::from_layout(
+            &[
+                "#########",
+                "#.......#",
+                "#.......#",
+                "#.......#",
+                "#########",
+            ],
+            0,
+        );
+        floor
+            .enemies
+            .push(BossEnemy::new(4, 2, BossType::SkeletalKnight).base_enemy);
+        let mut summoner = BossSummoner::default();
+
+        // Still on cooldown: nobody answers the call
+        assert_eq!(
+            summoner.update(1.0, &mut floor, &Difficulty::Hard, (1, 1)),
+            0
+        );
+        assert_eq!(floor.enemies.len(), 1);
+
+        let summoned = summoner.update(BOSS_SUMMON_INTERVAL, &mut floor, &Difficulty::Hard, (1, 1));
+        assert_eq!(summoned, BOSS_SUMMON_COUNT);
+        let adds: Vec<_> = floor.enemies.iter().skip(1).collect();
+        assert!(adds
+            .iter()
+            .all(|add| !floor.get_tile(add.position.x, add.position.y)));
+        assert!(adds.iter().all(|add| add.rarity < EnemyRarity::Boss));
+
+        // Further calls stop at the cap of living adds
+        for _ in 0..5 {
+            summoner.update(BOSS_SUMMON_INTERVAL, &mut floor, &Difficulty::Hard, (1, 1));
+        }
+        assert_eq!(floor.enemies.len(), 1 + BOSS_MAX_ADDS);
+    }
+
+    #[test]
+    fn test_summoned_adds_stay_off_the_player_and_behind_walls() {
+        // The room right of the wall is within the summon radius but not walkable from the boss
+        let layout = [
+            "#########",
+            "#...#...#",
+            "#...#...#",
+            "#...#...#",
+            "#########",
+        ];
+        let player = (1, 2);
+        for _ in 0..20 {
+            let mut floor = Floor::from_layout(&layout, 0);
+            floor
+                .enemies
+                .push(BossEnemy::new(2, 2, BossType::GoblinOverlord).base_enemy);
+            let mut summoner = BossSummoner::default();
+            for _ in 0..3 {
+                summoner.update(BOSS_SUMMON_INTERVAL, &mut floor, &Difficulty::Hard, player);
+            }
+
+            assert_eq!(floor.enemies.len(), 1 + BOSS_MAX_ADDS);
+            for add in floor.enemies.iter().skip(1) {
+                let at = (add.position.x, add.position.y);
+                assert_ne!(at, player);
+                assert!(at.0 < 4, "add summoned past the wall at {:?}", at);
+            }
+        }
+    }
 }
@@ -0,0 +1,89 @@
+/// Camera position along one axis that keeps `player` inside a deadzone of
+/// `deadzone_percent` of the view around its centre. The camera only moves when the
+/// player leaves the deadzone, and then just far enough to bring them back to its
+/// edge. A player outside the view entirely (e.g. on a new floor) is re-centred.
+pub fn follow_axis(current: f32, player: f32, view: f32, deadzone_percent: u32) -> f32 {
+    let centered = player - view / 2.0;
+    if player < current || player >= current + view {
+        return centered;
+    }
+    let half = view * deadzone_percent.min(100) as f32 / 200.0;
+    current.clamp(centered - half, centered + half)
+}
+
+/// `follow_axis` for both axes of the viewport
+pub fn follow_target(
+    current: (f32, f32),
+    player: (i32, i32),
+    viewport: (f32, f32),
+    deadzone_percent: u32,
+) -> (f32, f32) {
+    (
+        follow_axis(current.0, player.0 as f32, viewport.0, deadzone_percent),
+        follow_axis(current.1, player.1 as f32, viewport.1, deadzone_percent),
+    )
+}
+
+/// One frame of camera movement from `offset` toward the already-clamped `target`.
+/// Eases a fraction of the way each frame, or jumps straight there when `snap` is set
+pub fn ease_toward(offset: (f32, f32), target: (f32, f32), snap: bool) -> (f32, f32) {
+    let dx = target.0 - offset.0;
+    let dy = target.1 - offset.1;
+    if snap || (dx.abs() <= 0.1 && dy.abs() <= 0.1) {
+        return target;
+    }
+    let ease = crate::constants::CAMERA_EASE_FACTOR;
+    (offset.0 + dx * ease, offset.1 + dy * ease)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_small_moves_inside_deadzone_keep_camera() {
+        // 80x40 view centred on the player at (100, 50), deadzone 30% of the view
+        let start = (60.0, 30.0);
+        for player in [(101, 50), (99, 51), (110, 55), (90, 45)] {
+            assert_eq!(follow_target(start, player, (80.0, 40.0), 30), start);
+        }
+    }
+
+    #[test]
+    fn test_leaving_deadzone_moves_camera_to_its_edge() {
+        let start = (60.0, 30.0);
+        // Deadzone spans x 88..=112 on screen; one step past it scrolls by one
+        assert_eq!(
+            follow_target(start, (113, 50), (80.0, 40.0), 30),
+            (61.0, 30.0)
+        );
+        assert_eq!(
+            follow_target(start, (87, 50), (80.0, 40.0), 30),
+            (59.0, 30.0)
+        );
+    }
+
+    #[test]
+    fn test_zero_deadzone_and_off_screen_player_recentre() {
+        let start = (60.0, 30.0);
+        assert_eq!(
+            follow_target(start, (101, 50), (80.0, 40.0), 0),
+            (61.0, 30.0)
+        );
+        assert_eq!(
+            follow_target(start, (300, 10), (80.0, 40.0), 30),
+            (260.0, -10.0)
+        );
+    }
+
+    #[test]
+    fn test_snap_reaches_target_in_one_update() {
+        let target = (42.0, 17.5);
+        assert_eq!(ease_toward((0.0, 0.0), target, true), target);
+
+        // Easing only covers part of the distance, then settles once close
+        let eased = ease_toward((0.0, 0.0), target, false);
+        assert!(eased.0 > 0.0 && eased.0 < target.0);
+        assert_eq!(ease_toward((41.95, 17.45), target, false), target);
+    }
+}
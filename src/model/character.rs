@@ -1,12 +1,15 @@
 use crate::constants::*;
-use crate::model::consumable::{ConsumableInventory, ConsumableType};
+use crate::model::combo::Combo;
+use crate::model::consumable::{Consumable, ConsumableInventory, ConsumableType, PotionBuff};
 use crate::model::cooldown::Cooldown;
+use crate::model::loadout::StartingClass;
 use crate::model::skill::{SkillTree, SkillType};
 use crate::model::skill_tree_path::SkillTreeManager;
 use crate::model::status_effect::{StatusEffect, StatusEffectManager, StatusEffectType};
-use crate::model::ultimate::Ultimate;
-use crate::model::ultimate_shop::UltimateShopInventory;
-use crate::model::weapon::WeaponInventory;
+use crate::model::ultimate::{Ultimate, UltimateBuff, UltimateType};
+use crate::model::ultimate_shop::{StatUpgradeType, UltimateShop, UltimateShopInventory};
+use crate::model::weapon::{Weapon, WeaponInventory, WeaponType};
+use std::collections::HashMap;
 use std::time::Instant;
 
 #[derive(Clone, Debug)]
@@ -20,6 +23,7 @@ pub struct Character {
     pub dash_cooldown: Cooldown,
 
     pub dash_distance: i32,
+    pub dash_iframes: f32, // Seconds of invulnerability left from the last dash
 
     // Health system
     pub health: i32,
@@ -36,6 +40,9 @@ pub struct Character {
     // Bow stats
     pub arrow_speed: f32, // Tiles per second
     pub bow_cooldown: Cooldown,
+    pub bow_charge_timer: f32, // Seconds the current shot has been held
+    pub bow_charging: bool,    // Attack is being held with a bow equipped
+    pub bow_charge_idle: f32,  // Seconds since the last hold input (key repeat)
 
     // Block stats
     pub block_cooldown: Cooldown,
@@ -45,6 +52,8 @@ pub struct Character {
 
     // Consumables
     pub consumable_inventory: ConsumableInventory,
+    pub consumable_cooldown: Cooldown, // Shared by every non-offensive consumable
+    pub consumable_type_cooldowns: HashMap<ConsumableType, Cooldown>,
 
     // Status effects
     pub status_effects: StatusEffectManager,
@@ -55,9 +64,16 @@ pub struct Character {
     // Ultimate charge (0.0 to 100.0)
     pub ultimate_charge: f32,
 
+    // Active Rage/Ghost buff window
+    pub ultimate_buff: Option<UltimateBuff>,
+    pub potion_buff: Option<PotionBuff>,
+
     // Ultimate Shop Inventory (owned ultimates and upgrades)
     pub shop_inventory: UltimateShopInventory,
 
+    // Hits chained in quick succession, boosting ultimate charge
+    pub combo: Combo,
+
     // Skill Tree
     pub skill_tree: SkillTree,
 
@@ -83,6 +99,7 @@ impl Default for Character {
             last_direction: (0, 0),
             dash_cooldown: Cooldown::new(PLAYER_DASH_COOLDOWN),
             dash_distance: PLAYER_DASH_DISTANCE,
+            dash_iframes: 0.0,
             health: PLAYER_BASE_HEALTH,
             health_max: PLAYER_BASE_HEALTH,
             attack_damage: PLAYER_BASE_DAMAGE,
@@ -92,13 +109,21 @@ impl Default for Character {
             last_attack_time: None,
             arrow_speed: PLAYER_ARROW_SPEED,
             bow_cooldown: Cooldown::new(PLAYER_BOW_COOLDOWN),
+            bow_charge_timer: 0.0,
+            bow_charging: false,
+            bow_charge_idle: 0.0,
             block_cooldown: Cooldown::new(PLAYER_BLOCK_COOLDOWN),
             weapon_inventory: WeaponInventory::default(),
             consumable_inventory: ConsumableInventory::default(),
+            consumable_cooldown: Cooldown::new(CONSUMABLE_GLOBAL_COOLDOWN),
+            consumable_type_cooldowns: HashMap::new(),
             status_effects: StatusEffectManager::default(),
             ultimate: Ultimate::default(),
             ultimate_charge: 0.0,
+            ultimate_buff: None,
+            potion_buff: None,
             shop_inventory: UltimateShopInventory::default(),
+            combo: Combo::default(),
             skill_tree: SkillTree::new(),
             skill_tree_path: SkillTreeManager::new(),
             gold: 0,
@@ -117,16 +142,26 @@ impl Character {
         }
     }
 
-    pub fn can_dash(&self) -> bool {
-        self.dash_cooldown.is_ready()
+    pub fn can_dash(&self, time_scale: f32) -> bool {
+        self.dash_cooldown.is_ready(time_scale)
     }
 
-    pub fn dash_cooldown_remaining(&self) -> f32 {
-        self.dash_cooldown.remaining_seconds()
+    pub fn dash_cooldown_remaining(&self, time_scale: f32) -> f32 {
+        self.dash_cooldown.remaining_seconds(time_scale)
     }
 
     pub fn start_dash_cooldown(&mut self) {
         self.dash_cooldown.trigger();
+        self.dash_iframes = PLAYER_DASH_IFRAMES;
+    }
+
+    pub fn update_dash_iframes(&mut self, delta: f32) {
+        self.dash_iframes = (self.dash_iframes - delta).max(0.0);
+    }
+
+    /// Player ignores incoming damage while dashing or in Ghost
+    pub fn is_invulnerable(&self) -> bool {
+        self.dash_iframes > 0.0 || self.is_ghost()
     }
 
     pub fn update_direction(&mut self, dx: i32, dy: i32) {
@@ -135,13 +170,34 @@ impl Character {
         }
     }
 
-    pub fn can_attack(&self) -> bool {
-        self.attack_cooldown.is_ready()
+    /// Swing time of the equipped weapon. The attack cooldown's own duration carries
+    /// attack speed upgrades and Rage relative to the base cooldown.
+    pub fn melee_cooldown(&self) -> f32 {
+        let weapon_cooldown = self
+            .weapon_inventory
+            .get_current_weapon()
+            .map_or(PLAYER_ATTACK_COOLDOWN, |weapon| weapon.cooldown);
+        weapon_cooldown * self.attack_cooldown.duration() / PLAYER_ATTACK_COOLDOWN
     }
 
-    #[allow(dead_code)]
-    pub fn attack_cooldown_remaining(&self) -> f32 {
-        self.attack_cooldown.remaining_seconds()
+    /// Time between bow shots: the equipped bow's, else the first bow carried
+    pub fn ranged_cooldown(&self) -> f32 {
+        let is_bow = |weapon: &&Weapon| weapon.weapon_type == WeaponType::Bow;
+        self.weapon_inventory
+            .get_current_weapon()
+            .filter(is_bow)
+            .or_else(|| self.weapon_inventory.weapons.iter().find(is_bow))
+            .map_or(self.bow_cooldown.duration(), |bow| bow.cooldown)
+    }
+
+    pub fn can_attack(&self, time_scale: f32) -> bool {
+        self.attack_cooldown
+            .is_ready_for(self.melee_cooldown(), time_scale)
+    }
+
+    pub fn attack_cooldown_remaining(&self, time_scale: f32) -> f32 {
+        self.attack_cooldown
+            .remaining_for(self.melee_cooldown(), time_scale)
     }
 
     pub fn start_attack_cooldown(&mut self) {
@@ -150,36 +206,141 @@ impl Character {
     }
 
     #[allow(dead_code)]
-    pub fn is_attack_animating(&self) -> bool {
+    pub fn is_attack_animating(&self, time_scale: f32) -> bool {
         if let Some(attack_time) = self.last_attack_time {
-            attack_time.elapsed().as_secs_f32() < PLAYER_ATTACK_ANIMATION_TIME
+            crate::model::cooldown::scaled_elapsed(attack_time, time_scale)
+                < PLAYER_ATTACK_ANIMATION_TIME
         } else {
             false
         }
     }
 
-    pub fn can_shoot(&self) -> bool {
-        self.bow_cooldown.is_ready()
+    pub fn can_shoot(&self, time_scale: f32) -> bool {
+        self.bow_cooldown
+            .is_ready_for(self.ranged_cooldown(), time_scale)
+    }
+
+    /// Cooldown of the equipped weapon's attack, the bow's for bows
+    pub fn current_weapon_cooldown(&self) -> f32 {
+        if self.holding_bow() {
+            self.ranged_cooldown()
+        } else {
+            self.melee_cooldown()
+        }
+    }
+
+    pub fn current_weapon_ready(&self, time_scale: f32) -> bool {
+        if self.holding_bow() {
+            self.can_shoot(time_scale)
+        } else {
+            self.can_attack(time_scale)
+        }
+    }
+
+    fn holding_bow(&self) -> bool {
+        self.weapon_inventory
+            .get_current_weapon()
+            .is_some_and(|weapon| weapon.weapon_type == WeaponType::Bow)
     }
 
     pub fn start_bow_cooldown(&mut self) {
         self.bow_cooldown.trigger();
     }
 
-    pub fn can_block(&self) -> bool {
-        self.block_cooldown.is_ready()
+    /// Begin charging a bow shot, or keep an existing charge held
+    pub fn hold_bow_charge(&mut self) {
+        if !self.bow_charging {
+            self.bow_charging = true;
+            self.bow_charge_timer = 0.0;
+        }
+        self.bow_charge_idle = 0.0;
+    }
+
+    /// Advance the charge; returns true once hold input has stopped arriving
+    pub fn update_bow_charge(&mut self, delta: f32) -> bool {
+        if !self.bow_charging {
+            return false;
+        }
+        self.bow_charge_timer = (self.bow_charge_timer + delta).min(BOW_CHARGE_TIME);
+        self.bow_charge_idle += delta;
+        self.bow_charge_idle >= BOW_CHARGE_RELEASE_GRACE
+    }
+
+    /// Charge progress as a fraction [0.0, 1.0]
+    pub fn bow_charge_fraction(&self) -> f32 {
+        if self.bow_charging {
+            (self.bow_charge_timer / BOW_CHARGE_TIME).clamp(0.0, 1.0)
+        } else {
+            0.0
+        }
+    }
+
+    /// End the charge and return how charged it was, if a charge was held
+    pub fn release_bow_charge(&mut self) -> Option<f32> {
+        if !self.bow_charging {
+            return None;
+        }
+        let fraction = self.bow_charge_fraction();
+        self.bow_charging = false;
+        self.bow_charge_timer = 0.0;
+        self.bow_charge_idle = 0.0;
+        Some(fraction)
     }
 
-    pub fn block_cooldown_remaining(&self) -> f32 {
-        self.block_cooldown.remaining_seconds()
+    pub fn can_block(&self, time_scale: f32) -> bool {
+        self.block_cooldown.is_ready(time_scale)
+    }
+
+    pub fn block_cooldown_remaining(&self, time_scale: f32) -> f32 {
+        self.block_cooldown.remaining_seconds(time_scale)
     }
 
     pub fn start_block_cooldown(&mut self) {
         self.block_cooldown.trigger();
     }
 
-    pub fn bow_cooldown_remaining(&self) -> f32 {
-        self.bow_cooldown.remaining_seconds()
+    /// Game seconds until a consumable of this type can be used again
+    pub fn consumable_cooldown_remaining(
+        &self,
+        consumable_type: &ConsumableType,
+        time_scale: f32,
+    ) -> f32 {
+        let own = self
+            .consumable_type_cooldowns
+            .get(consumable_type)
+            .map_or(0.0, |cooldown| cooldown.remaining_seconds(time_scale));
+        if consumable_type.is_offensive() {
+            own
+        } else {
+            own.max(self.consumable_cooldown.remaining_seconds(time_scale))
+        }
+    }
+
+    /// Take one item from the inventory slot if its cooldowns allow, and start them
+    pub fn use_consumable(&mut self, index: usize, time_scale: f32) -> Option<Consumable> {
+        let consumable_type = self
+            .consumable_inventory
+            .get_item(index)?
+            .consumable_type
+            .clone();
+        if self.consumable_cooldown_remaining(&consumable_type, time_scale) > 0.0 {
+            return None;
+        }
+        let consumable = self.consumable_inventory.use_item(index)?;
+
+        if !consumable_type.is_offensive() {
+            self.consumable_cooldown.trigger();
+        }
+        let mut cooldown = Cooldown::new(consumable_type.cooldown());
+        cooldown.trigger();
+        self.consumable_type_cooldowns
+            .insert(consumable_type, cooldown);
+        Some(consumable)
+    }
+
+    pub fn bow_cooldown_remaining(&self, time_scale: f32) -> f32 {
+        self.bow_cooldown
+            .remaining_for(self.ranged_cooldown(), time_scale)
     }
 
     pub fn heal(&mut self, amount: i32) {
@@ -191,17 +352,36 @@ impl Character {
         self.damaged_at = Some(Instant::now());
     }
 
+    /// Take an enemy hit that shoves the player along (dx, dy); `knockback_scale`
+    /// shrinks the shove, and at zero the player isn't moved at all
+    pub fn take_hit(&mut self, damage: i32, dx: f32, dy: f32, knockback_scale: f32) {
+        if knockback_scale > 0.0 {
+            self.apply_knockback(dx, dy, PLAYER_HIT_KNOCKBACK * knockback_scale);
+        }
+        self.take_damage(damage);
+    }
+
     /// Charge ultimate ability based on damage dealt
     pub fn charge_ultimate(&mut self, damage: i32) {
-        let charge_amount = self.ultimate.charge_on_hit(damage);
-        self.ultimate_charge = (self.ultimate_charge + charge_amount).min(100.0);
+        self.add_ultimate_charge(self.ultimate.charge_on_hit(damage));
+    }
+
+    /// Count a hit on an enemy toward the combo and charge the ultimate, scaled by the
+    /// combo multiplier
+    pub fn land_hit(&mut self, damage: i32) {
+        self.combo.register_hit();
+        self.add_ultimate_charge(self.ultimate.charge_on_hit(damage) * self.combo.multiplier());
     }
 
-    /// Use the ultimate ability if charged
-    pub fn use_ultimate(&mut self) -> bool {
-        if self.ultimate_charge >= 100.0 && self.ultimate.can_use() {
+    fn add_ultimate_charge(&mut self, amount: f32) {
+        self.ultimate_charge = (self.ultimate_charge + amount).min(100.0);
+    }
+
+    /// Use the ultimate ability if charged and off cooldown at game speed `time_scale`
+    pub fn use_ultimate(&mut self, time_scale: f32) -> bool {
+        if self.ultimate_charge >= 100.0 && self.ultimate.can_use(time_scale) {
             self.ultimate_charge = 0.0;
-            self.ultimate.activate();
+            self.activate_ultimate();
             self.ultimate.start_cooldown();
             true
         } else {
@@ -209,20 +389,112 @@ impl Character {
         }
     }
 
+    /// Fire the current ultimate and start its timed buff, if it has one
+    pub fn activate_ultimate(&mut self) {
+        self.ultimate.activate();
+        self.start_ultimate_buff(self.ultimate.current_type.clone());
+    }
+
+    /// Begin a Rage or Ghost buff window, replacing any buff already running
+    pub fn start_ultimate_buff(&mut self, ultimate_type: UltimateType) {
+        if ultimate_type == UltimateType::Shockwave {
+            return;
+        }
+        self.end_ultimate_buff();
+
+        let mut buff = UltimateBuff::new(ultimate_type);
+        if buff.ultimate_type == UltimateType::Rage {
+            // Rage: more damage per hit and a shorter attack cooldown
+            buff.damage_bonus = self.attack_damage * (RAGE_DAMAGE_MULTIPLIER - 1);
+            let cooldown = self.attack_cooldown.duration();
+            buff.cooldown_reduction = cooldown - cooldown / RAGE_ATTACK_SPEED_MULTIPLIER;
+
+            self.attack_damage += buff.damage_bonus;
+            self.attack_cooldown
+                .set_duration(cooldown - buff.cooldown_reduction);
+        }
+        self.ultimate_buff = Some(buff);
+    }
+
+    /// Tick the active ultimate buff. Returns true when it expires this tick.
+    pub fn update_ultimate_buff(&mut self, delta: f32) -> bool {
+        let Some(buff) = &mut self.ultimate_buff else {
+            return false;
+        };
+        buff.remaining -= delta;
+        if buff.is_expired() {
+            self.end_ultimate_buff();
+            return true;
+        }
+        false
+    }
+
+    /// Revert the stats changed by the active ultimate buff
+    pub fn end_ultimate_buff(&mut self) {
+        if let Some(buff) = self.ultimate_buff.take() {
+            self.attack_damage -= buff.damage_bonus;
+            let cooldown = self.attack_cooldown.duration();
+            self.attack_cooldown
+                .set_duration(cooldown + buff.cooldown_reduction);
+        }
+    }
+
+    /// Drink a buff potion, replacing any potion buff already running
+    pub fn start_potion_buff(&mut self, consumable: &Consumable) {
+        let Some((multiplier, duration)) = consumable.get_buff() else {
+            return;
+        };
+        self.end_potion_buff();
+
+        let damage_bonus = ((self.attack_damage as f32 * (multiplier - 1.0)).round() as i32).max(1);
+        self.attack_damage += damage_bonus;
+        self.potion_buff = Some(PotionBuff {
+            consumable_type: consumable.consumable_type.clone(),
+            remaining: duration,
+            damage_bonus,
+        });
+    }
+
+    /// Tick the active potion buff. Returns true when it expires this tick.
+    pub fn update_potion_buff(&mut self, delta: f32) -> bool {
+        let Some(buff) = &mut self.potion_buff else {
+            return false;
+        };
+        buff.remaining -= delta;
+        if buff.is_expired() {
+            self.end_potion_buff();
+            return true;
+        }
+        false
+    }
+
+    /// Revert the stats changed by the active potion buff
+    pub fn end_potion_buff(&mut self) {
+        if let Some(buff) = self.potion_buff.take() {
+            self.attack_damage -= buff.damage_bonus;
+        }
+    }
+
+    /// Attack damage without any active Rage or potion bonus, used when saving
+    pub fn base_attack_damage(&self) -> i32 {
+        self.attack_damage
+            - self.ultimate_buff.as_ref().map_or(0, |b| b.damage_bonus)
+            - self.potion_buff.as_ref().map_or(0, |b| b.damage_bonus)
+    }
+
     /// Check if player is in a Rage ultimate
-    #[allow(dead_code)]
     pub fn is_raging(&self) -> bool {
         matches!(
-            self.ultimate.get_active_type(),
-            Some(crate::model::ultimate::UltimateType::Rage)
+            &self.ultimate_buff,
+            Some(buff) if buff.ultimate_type == UltimateType::Rage
         )
     }
 
-    /// Check if player is in Ghost ultimate (invulnerable)
+    /// Check if player is in Ghost ultimate (invulnerable, passes through enemies)
     pub fn is_ghost(&self) -> bool {
         matches!(
-            self.ultimate.get_active_type(),
-            Some(crate::model::ultimate::UltimateType::Ghost)
+            &self.ultimate_buff,
+            Some(buff) if buff.ultimate_type == UltimateType::Ghost
         )
     }
 
@@ -230,17 +502,7 @@ impl Character {
     #[allow(dead_code)]
     pub fn get_ultimate_speed_multiplier(&self) -> f32 {
         if self.is_raging() {
-            2.0 // Double speed during Rage
-        } else {
-            1.0
-        }
-    }
-
-    /// Get damage multiplier based on active ultimate
-    #[allow(dead_code)]
-    pub fn get_ultimate_damage_multiplier(&self) -> f32 {
-        if self.is_raging() {
-            2.0 // Double damage during Rage
+            RAGE_ATTACK_SPEED_MULTIPLIER
         } else {
             1.0
         }
@@ -264,17 +526,19 @@ impl Character {
         }
     }
 
-    pub fn is_damaged_animating(&self) -> bool {
+    pub fn is_damaged_animating(&self, time_scale: f32) -> bool {
         if let Some(damaged_at) = self.damaged_at {
-            damaged_at.elapsed().as_secs_f32() < PLAYER_DAMAGE_ANIMATION_TIME
+            crate::model::cooldown::scaled_elapsed(damaged_at, time_scale)
+                < PLAYER_DAMAGE_ANIMATION_TIME
         } else {
             false
         }
     }
 
-    pub fn is_attacking_animating(&self) -> bool {
+    pub fn is_attacking_animating(&self, time_scale: f32) -> bool {
         if let Some(attack_time) = self.last_attack_time {
-            attack_time.elapsed().as_secs_f32() < PLAYER_ATTACK_ANIMATION_TIME
+            crate::model::cooldown::scaled_elapsed(attack_time, time_scale)
+                < PLAYER_ATTACK_ANIMATION_TIME
         } else {
             false
         }
@@ -296,6 +560,16 @@ impl Character {
         self.gold = self.gold.saturating_add(amount);
     }
 
+    /// Scrap the equipped weapon for gold. Returns the gold gained, or `None` when
+    /// no weapon is equipped.
+    pub fn scrap_current_weapon(&mut self) -> Option<u32> {
+        let slot = self.weapon_inventory.current_weapon_index;
+        let weapon = self.weapon_inventory.remove_weapon(slot)?;
+        let gold = weapon.scrap_value();
+        self.add_gold(gold);
+        Some(gold)
+    }
+
     pub fn spend_gold(&mut self, amount: u32) -> bool {
         if self.gold >= amount {
             self.gold -= amount;
@@ -345,6 +619,12 @@ impl Character {
                     stacks: 1,
                 });
             }
+            ConsumableType::ScryingEye => {
+                // Reveals the map; the game tracks the reveal window, not the character
+            }
+            ConsumableType::StrengthPotion => {
+                self.start_potion_buff(&Consumable::new(ConsumableType::StrengthPotion));
+            }
         }
     }
 
@@ -363,6 +643,86 @@ impl Character {
         }
     }
 
+    /// Apply one purchase of a stat upgrade
+    pub fn apply_stat_upgrade(&mut self, upgrade: StatUpgradeType, amount: f32) {
+        match upgrade {
+            StatUpgradeType::MaxHealth => {
+                self.health_max += amount as i32;
+                self.health = self.health_max;
+            }
+            StatUpgradeType::AttackDamage => {
+                self.attack_damage += amount as i32;
+            }
+            StatUpgradeType::AttackSpeed => {
+                // Reduce cooldown by this percentage (amount is already in percentage format)
+                let reduction = 1.0 - (amount / 100.0); // Convert to decimal
+                let current_duration = self.attack_cooldown.duration();
+                self.attack_cooldown
+                    .set_duration(current_duration * reduction);
+            }
+            StatUpgradeType::MovementSpeed => {
+                self.speed += amount;
+            }
+            StatUpgradeType::DashDistance => {
+                self.dash_distance += amount as i32;
+            }
+            StatUpgradeType::PickupRadius => {
+                // Derived from the purchase count in pickup_radius()
+            }
+        }
+    }
+
+    /// Fresh character for the next New Game+ cycle: the class loadout at floor one, plus
+    /// everything bought in the ultimate shop and a share of this run's gold
+    pub fn new_game_plus(&self, class: StartingClass) -> Character {
+        let mut next = Character {
+            name: self.name.clone(),
+            ..Character::default()
+        };
+        class.apply(&mut next);
+
+        next.shop_inventory = self.shop_inventory.clone();
+        next.ultimate.current_type = self.ultimate.current_type.clone();
+        for upgrade in UltimateShop::new().stat_upgrades {
+            for _ in 0..self.shop_inventory.get_upgrade_count(&upgrade.upgrade_type) {
+                next.apply_stat_upgrade(upgrade.upgrade_type, upgrade.stack_amount);
+            }
+        }
+        next.gold = (self.gold as f32 * NEW_GAME_PLUS_GOLD_KEPT) as u32;
+        next
+    }
+
+    /// Item magnet range in tiles from purchased pickup radius upgrades
+    pub fn pickup_radius(&self) -> i32 {
+        let upgrades = self
+            .shop_inventory
+            .get_upgrade_count(&StatUpgradeType::PickupRadius);
+        (upgrades as i32).min(MAX_PICKUP_RADIUS)
+    }
+
+    /// Weapon of the highest rarity currently held
+    pub fn best_weapon(&self) -> Option<&crate::model::weapon::Weapon> {
+        self.weapon_inventory
+            .weapons
+            .iter()
+            .max_by_key(|w| w.rarity.clone())
+    }
+
+    /// Overall run score from kills, gold, best weapon, skill path investment and levels cleared
+    pub fn run_score(&self, levels_cleared: u32) -> u32 {
+        let rarity_bonus = self
+            .best_weapon()
+            .map(|w| w.rarity.clone() as u32 * RUN_SCORE_PER_RARITY)
+            .unwrap_or(0);
+        let skill_levels: u32 = self.skill_tree_path.paths.iter().map(|p| p.level).sum();
+
+        self.enemies_killed * RUN_SCORE_PER_KILL
+            + self.gold
+            + rarity_bonus
+            + skill_levels * RUN_SCORE_PER_SKILL_LEVEL
+            + levels_cleared * RUN_SCORE_PER_LEVEL
+    }
+
     /// Get total attack damage including weapon enchants
     pub fn get_total_attack_damage(&self) -> i32 {
         let weapon_bonus = self
@@ -381,6 +741,11 @@ impl Character {
             .unwrap_or(0)
     }
 
+    /// Get total reach/radius bonus for attack patterns (RadiusIncrease enchants + skill tree area)
+    pub fn get_attack_area_bonus(&self) -> i32 {
+        self.get_attack_radius_bonus() + self.skill_tree_path.get_area_bonus()
+    }
+
     /// Calculate damage for ultimate ability (includes charge multiplier)
     pub fn calculate_ultimate_damage(&self) -> i32 {
         // Base ultimate damage plus charge bonus
@@ -509,18 +874,18 @@ mod tests {
     #[test]
     fn test_cooldown_ready() {
         let character = Character::default();
-        assert!(character.can_dash());
-        assert!(character.can_attack());
-        assert!(character.can_shoot());
-        assert!(character.can_block());
+        assert!(character.can_dash(1.0));
+        assert!(character.can_attack(1.0));
+        assert!(character.can_shoot(1.0));
+        assert!(character.can_block(1.0));
     }
 
     #[test]
     fn test_start_cooldown() {
         let mut character = Character::default();
-        assert!(character.can_dash());
+        assert!(character.can_dash(1.0));
         character.start_dash_cooldown();
-        assert!(!character.can_dash());
+        assert!(!character.can_dash(1.0));
     }
 
     #[test]
@@ -643,6 +1008,21 @@ mod tests {
         assert_eq!(character.knockback_velocity, (2.0, 0.0));
     }
 
+    #[test]
+    fn test_hit_without_knockback_leaves_player_in_place() {
+        let mut character = Character::default();
+        let health = character.health;
+        character.take_hit(5, 1.0, 0.0, 0.0);
+        assert_eq!(character.health, health - 5);
+        assert_eq!(character.knockback_velocity, (0.0, 0.0));
+
+        character.take_hit(5, 1.0, 0.0, 0.5);
+        assert_eq!(
+            character.knockback_velocity,
+            (PLAYER_HIT_KNOCKBACK * 0.5, 0.0)
+        );
+    }
+
     #[test]
     fn test_health_percentage() {
         let mut character = Character::default();
@@ -701,4 +1081,184 @@ mod tests {
         // Whirlwind should have larger AoE
         assert!(whirlwind_radius > slash_radius);
     }
+
+    #[test]
+    fn test_run_score_rewards_progress() {
+        let mut character = Character::default();
+        let base = character.run_score(0);
+
+        character.enemies_killed = 5;
+        character.add_gold(40);
+        let progressed = character.run_score(3);
+
+        assert_eq!(
+            progressed - base,
+            5 * RUN_SCORE_PER_KILL + 40 + 3 * RUN_SCORE_PER_LEVEL
+        );
+    }
+
+    #[test]
+    fn test_rage_buff_raises_damage_and_reverts() {
+        let mut character = Character::default();
+        let base_damage = character.get_effective_attack_damage();
+        let base_cooldown = character.attack_cooldown.duration();
+
+        character.start_ultimate_buff(UltimateType::Rage);
+        assert!(character.is_raging());
+        assert_eq!(
+            character.get_effective_attack_damage(),
+            base_damage * RAGE_DAMAGE_MULTIPLIER
+        );
+        assert!(character.attack_cooldown.duration() < base_cooldown);
+        assert_eq!(character.base_attack_damage(), PLAYER_BASE_DAMAGE);
+
+        // Still active partway through
+        assert!(!character.update_ultimate_buff(1.0));
+        assert!(character.is_raging());
+
+        // Expires and restores the original stats
+        assert!(character.update_ultimate_buff(UltimateType::Rage.effect_duration()));
+        assert!(!character.is_raging());
+        assert_eq!(character.get_effective_attack_damage(), base_damage);
+        assert!((character.attack_cooldown.duration() - base_cooldown).abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn test_strength_potion_raises_damage_and_reverts() {
+        let mut character = Character::default();
+        let base_damage = character.get_effective_attack_damage();
+        character
+            .consumable_inventory
+            .add(Consumable::new(ConsumableType::StrengthPotion));
+
+        let potion = character.use_consumable(0, 1.0).unwrap();
+        character.start_potion_buff(&potion);
+        assert!(character.get_effective_attack_damage() > base_damage);
+        assert_eq!(character.base_attack_damage(), PLAYER_BASE_DAMAGE);
+
+        // Still active partway through
+        assert!(!character.update_potion_buff(STRENGTH_POTION_DURATION / 2.0));
+        assert!(character.get_effective_attack_damage() > base_damage);
+
+        // Expires and restores the original damage
+        assert!(character.update_potion_buff(STRENGTH_POTION_DURATION));
+        assert!(character.potion_buff.is_none());
+        assert_eq!(character.get_effective_attack_damage(), base_damage);
+    }
+
+    #[test]
+    fn test_faster_weapon_attacks_sooner() {
+        let with_cooldown = |cooldown: f32| {
+            let mut weapon = Weapon::new_sword();
+            weapon.cooldown = cooldown;
+            let mut character = Character::default();
+            character.weapon_inventory.weapons = vec![weapon];
+            character.weapon_inventory.current_weapon_index = 0;
+            character
+        };
+        let mut fast = with_cooldown(0.2);
+        let mut slow = with_cooldown(0.8);
+        assert!((fast.melee_cooldown() - 0.2).abs() < f32::EPSILON);
+        assert!((slow.melee_cooldown() - 0.8).abs() < f32::EPSILON);
+
+        fast.start_attack_cooldown();
+        slow.start_attack_cooldown();
+        std::thread::sleep(std::time::Duration::from_millis(300));
+        assert!(fast.can_attack(1.0));
+        assert!(!slow.can_attack(1.0));
+        assert!(slow.attack_cooldown_remaining(1.0) > 0.0);
+
+        // Rage still shortens whichever weapon is equipped
+        slow.start_ultimate_buff(UltimateType::Rage);
+        assert!(slow.melee_cooldown() < 0.8);
+    }
+
+    #[test]
+    fn test_consumables_cannot_be_chugged() {
+        let mut character = Character::default();
+        let mut draughts = Consumable::new(ConsumableType::WeakHealingDraught);
+        draughts.quantity = 3;
+        character.consumable_inventory.items = vec![draughts];
+
+        assert!(character.use_consumable(0, 1.0).is_some());
+        assert!(character.use_consumable(0, 1.0).is_none());
+        assert_eq!(character.consumable_inventory.items[0].quantity, 2);
+        assert!(
+            character.consumable_cooldown_remaining(&ConsumableType::WeakHealingDraught, 1.0) > 0.0
+        );
+
+        // Offensive items skip the shared cooldown but still have their own
+        character
+            .consumable_inventory
+            .items
+            .push(Consumable::new(ConsumableType::FireOilFlask));
+        assert!(character.use_consumable(1, 1.0).is_some());
+        assert!(character.consumable_cooldown_remaining(&ConsumableType::FireOilFlask, 1.0) > 0.0);
+    }
+
+    #[test]
+    fn test_ghost_buff_expires() {
+        let mut character = Character::default();
+        character.start_ultimate_buff(UltimateType::Ghost);
+        assert!(character.is_ghost());
+        assert_eq!(character.attack_damage, PLAYER_BASE_DAMAGE);
+
+        assert!(character.update_ultimate_buff(UltimateType::Ghost.effect_duration()));
+        assert!(!character.is_ghost());
+    }
+
+    #[test]
+    fn test_new_game_plus_keeps_shop_purchases() {
+        let mut character = Character::default();
+        StartingClass::Warrior.apply(&mut character);
+        character.name = "Victor".to_string();
+        character.gold = 400;
+        character.enemies_killed = 80;
+        character.weapon_inventory.weapons.push(Weapon::new_bow());
+        character
+            .shop_inventory
+            .purchase_ultimate(UltimateType::Rage);
+        character.ultimate.current_type = UltimateType::Rage;
+        for _ in 0..2 {
+            character
+                .shop_inventory
+                .purchase_stat_upgrade(StatUpgradeType::MaxHealth);
+            character.apply_stat_upgrade(StatUpgradeType::MaxHealth, 10.0);
+        }
+        character.take_damage(50);
+
+        let next = character.new_game_plus(StartingClass::Warrior);
+        assert_eq!(next.name, "Victor");
+        assert!(next.shop_inventory.owns_ultimate(&UltimateType::Rage));
+        assert_eq!(next.ultimate.current_type, UltimateType::Rage);
+        // Warrior bonus plus both health upgrades, at full health
+        assert_eq!(next.health_max, PLAYER_BASE_HEALTH + 20 + 20);
+        assert_eq!(next.health, next.health_max);
+        assert_eq!(next.gold, 100);
+        assert_eq!(next.enemies_killed, 0);
+        assert_eq!(
+            next.weapon_inventory.weapons.len(),
+            StartingClass::Warrior.weapons().len()
+        );
+    }
+
+    #[test]
+    fn test_quick_hits_charge_ultimate_faster() {
+        let mut quick = Character::default();
+        let mut spread = Character::default();
+        for _ in 0..6 {
+            quick.land_hit(40);
+            quick.combo.update(COMBO_WINDOW * 0.25);
+            spread.land_hit(40);
+            spread.combo.update(COMBO_WINDOW + 0.5);
+        }
+        assert!(
+            quick.ultimate_charge > spread.ultimate_charge,
+            "{} vs {}",
+            quick.ultimate_charge,
+            spread.ultimate_charge
+        );
+        assert_eq!(spread.combo.count, 0);
+        assert_eq!(quick.combo.count, 6);
+    }
 }
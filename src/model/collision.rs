@@ -68,6 +68,27 @@ impl SpatialHash {
     }
 }
 
+/// Tile a move of `(dx, dy)` from `from` ends on, or `None` when it is blocked.
+/// With `slide` set, a blocked diagonal still moves along whichever single axis is
+/// open, horizontal first, instead of stopping dead against the wall.
+pub fn resolve_move(
+    from: (i32, i32),
+    (dx, dy): (i32, i32),
+    slide: bool,
+    walkable: impl Fn(i32, i32) -> bool,
+) -> Option<(i32, i32)> {
+    let target = (from.0 + dx, from.1 + dy);
+    if walkable(target.0, target.1) {
+        return Some(target);
+    }
+    if !slide || dx == 0 || dy == 0 {
+        return None;
+    }
+    [(from.0 + dx, from.1), (from.0, from.1 + dy)]
+        .into_iter()
+        .find(|&(x, y)| walkable(x, y))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -97,4 +118,23 @@ mod tests {
         assert!(results.contains(&(0, 0)));
         assert!(results.contains(&(5, 5)));
     }
+
+    #[test]
+    fn test_blocked_diagonal_slides_along_open_axis() {
+        // Wall everywhere except the row y = 0: moving down-right from (0, 0) is blocked
+        let walkable = |_x: i32, y: i32| y == 0;
+        assert_eq!(resolve_move((0, 0), (1, 1), false, walkable), None);
+        assert_eq!(resolve_move((0, 0), (1, 1), true, walkable), Some((1, 0)));
+
+        // Only the vertical axis open
+        let walkable = |x: i32, _y: i32| x == 0;
+        assert_eq!(resolve_move((0, 0), (1, 1), true, walkable), Some((0, 1)));
+
+        // Straight moves into a wall have nowhere to slide; open moves are unaffected
+        assert_eq!(resolve_move((0, 0), (1, 0), true, |x, _| x == 0), None);
+        assert_eq!(
+            resolve_move((0, 0), (1, 1), true, |_, _| true),
+            Some((1, 1))
+        );
+    }
 }
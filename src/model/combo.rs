@@ -0,0 +1,69 @@
+use crate::constants::{COMBO_CHARGE_STEP, COMBO_MAX_MULTIPLIER, COMBO_WINDOW};
+
+/// Run of hits landed in quick succession. Each hit within `COMBO_WINDOW` seconds of
+/// the last one grows the count; a gap longer than that drops it back to zero.
+#[derive(Clone, Debug, Default)]
+pub struct Combo {
+    pub count: u32,
+    pub since_last_hit: f32, // Seconds since the last hit landed
+}
+
+impl Combo {
+    pub fn register_hit(&mut self) {
+        self.count += 1;
+        self.since_last_hit = 0.0;
+    }
+
+    /// Advance the decay timer, ending the combo once no hit lands within the window
+    pub fn update(&mut self, delta: f32) {
+        if self.count == 0 {
+            return;
+        }
+        self.since_last_hit += delta;
+        if self.since_last_hit > COMBO_WINDOW {
+            self.reset();
+        }
+    }
+
+    pub fn reset(&mut self) {
+        self.count = 0;
+        self.since_last_hit = 0.0;
+    }
+
+    /// Ultimate charge multiplier: 1x for the first hit, growing with each chained hit
+    pub fn multiplier(&self) -> f32 {
+        let chained = self.count.saturating_sub(1) as f32;
+        (1.0 + chained * COMBO_CHARGE_STEP).min(COMBO_MAX_MULTIPLIER)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_combo_grows_and_decays() {
+        let mut combo = Combo::default();
+        assert_eq!(combo.multiplier(), 1.0);
+
+        combo.register_hit();
+        assert_eq!(combo.multiplier(), 1.0);
+        combo.update(COMBO_WINDOW * 0.5);
+        combo.register_hit();
+        assert_eq!(combo.count, 2);
+        assert!(combo.multiplier() > 1.0);
+
+        combo.update(COMBO_WINDOW + 0.1);
+        assert_eq!(combo.count, 0);
+        assert_eq!(combo.multiplier(), 1.0);
+    }
+
+    #[test]
+    fn test_multiplier_is_capped() {
+        let mut combo = Combo::default();
+        for _ in 0..100 {
+            combo.register_hit();
+        }
+        assert_eq!(combo.multiplier(), COMBO_MAX_MULTIPLIER);
+    }
+}
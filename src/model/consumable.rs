@@ -1,13 +1,15 @@
 use ratatui::prelude::Color;
 use serde::{Deserialize, Serialize};
 
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum ConsumableType {
     WeakHealingDraught, // Healing over 5 sec
     BandageRoll,        // Removes bleed + instant heal
     AntitoxinVial,      // Removes poison + immunity
     FireOilFlask,       // Throw damage + burn
     BlessedBread,       // Slow healing over 8 sec
+    ScryingEye,         // Briefly reveals the whole floor
+    StrengthPotion,     // Timed attack damage boost
 }
 
 impl ConsumableType {
@@ -19,6 +21,8 @@ impl ConsumableType {
             ConsumableType::AntitoxinVial => "⚗",      // Alembic / potion
             ConsumableType::FireOilFlask => "𐃯",       // Diamond as a flask placeholder
             ConsumableType::BlessedBread => "≡",       // Steam / cooked item
+            ConsumableType::ScryingEye => "◉",         // Staring eye
+            ConsumableType::StrengthPotion => "♠",     // Clenched, dark and heavy
         }
     }
 
@@ -32,9 +36,29 @@ impl ConsumableType {
             // Utility items: distinct colors for their function
             ConsumableType::AntitoxinVial => Color::Rgb(120, 220, 180), // Green-cyan (medicinal)
             ConsumableType::FireOilFlask => Color::Rgb(255, 120, 40),   // Hot ember orange
+            ConsumableType::ScryingEye => Color::Rgb(170, 140, 255),    // Pale arcane violet
+            ConsumableType::StrengthPotion => Color::Rgb(210, 50, 90),  // Deep crimson
         }
     }
 
+    /// Seconds before another item of this type can be used
+    pub fn cooldown(&self) -> f32 {
+        match self {
+            ConsumableType::WeakHealingDraught => 4.0,
+            ConsumableType::BandageRoll => 3.0,
+            ConsumableType::AntitoxinVial => 6.0,
+            ConsumableType::FireOilFlask => 1.5,
+            ConsumableType::BlessedBread => 8.0,
+            ConsumableType::ScryingEye => 12.0,
+            ConsumableType::StrengthPotion => 20.0,
+        }
+    }
+
+    /// Thrown items keep their own cadence and ignore the shared consumable cooldown
+    pub fn is_offensive(&self) -> bool {
+        matches!(self, ConsumableType::FireOilFlask)
+    }
+
     /// Get fade color for animations
     pub fn get_fade_color(&self) -> Color {
         match self {
@@ -43,6 +67,8 @@ impl ConsumableType {
             ConsumableType::BlessedBread => Color::Rgb(255, 240, 160),
             ConsumableType::AntitoxinVial => Color::Rgb(180, 255, 220), // Lighter, cleaner
             ConsumableType::FireOilFlask => Color::Rgb(255, 200, 80),   // Lighter orange/gold
+            ConsumableType::ScryingEye => Color::Rgb(215, 200, 255),
+            ConsumableType::StrengthPotion => Color::Rgb(255, 130, 160),
         }
     }
 }
@@ -89,6 +115,19 @@ impl Consumable {
                 name: "Blessed Bread".to_string(),
                 description: "Dry. Holy. Comforting.".to_string(),
             },
+            ConsumableType::ScryingEye => Self {
+                consumable_type,
+                quantity: 1,
+                name: "Scrying Eye".to_string(),
+                description: "It blinks once and shows you everything on the floor.".to_string(),
+            },
+            ConsumableType::StrengthPotion => Self {
+                consumable_type,
+                quantity: 1,
+                name: "Strength Potion".to_string(),
+                description: "Thick and red. Your arms feel borrowed from someone bigger."
+                    .to_string(),
+            },
         }
     }
 
@@ -109,6 +148,27 @@ impl Consumable {
             ConsumableType::AntitoxinVial => 0.0,      // Instant
             ConsumableType::FireOilFlask => 0.5,       // 0.5 second throw
             ConsumableType::BlessedBread => 1.0,       // 1 second
+            ConsumableType::ScryingEye => 0.0,         // Instant
+            ConsumableType::StrengthPotion => 0.0,     // Instant
+        }
+    }
+
+    /// Seconds the whole floor stays revealed after using this item
+    pub fn get_reveal_duration(&self) -> Option<f32> {
+        match self.consumable_type {
+            ConsumableType::ScryingEye => Some(crate::constants::SCRYING_EYE_REVEAL_SECS),
+            _ => None,
+        }
+    }
+
+    /// (attack damage multiplier, duration) of the timed buff this item grants
+    pub fn get_buff(&self) -> Option<(f32, f32)> {
+        use crate::constants::{STRENGTH_POTION_DAMAGE_MULTIPLIER, STRENGTH_POTION_DURATION};
+        match self.consumable_type {
+            ConsumableType::StrengthPotion => {
+                Some((STRENGTH_POTION_DAMAGE_MULTIPLIER, STRENGTH_POTION_DURATION))
+            }
+            _ => None,
         }
     }
 
@@ -119,10 +179,27 @@ impl Consumable {
                 | ConsumableType::BandageRoll
                 | ConsumableType::AntitoxinVial
                 | ConsumableType::BlessedBread
+                | ConsumableType::ScryingEye
+                | ConsumableType::StrengthPotion
         )
     }
 }
 
+/// Timed stat boost from a drunk potion, ticked by the game loop.
+/// Stores the bonus it applied so it can be taken back on expiry.
+#[derive(Clone, Debug)]
+pub struct PotionBuff {
+    pub consumable_type: ConsumableType,
+    pub remaining: f32,    // Game-time seconds left
+    pub damage_bonus: i32, // Added to attack_damage while active
+}
+
+impl PotionBuff {
+    pub fn is_expired(&self) -> bool {
+        self.remaining <= 0.0
+    }
+}
+
 #[derive(Clone, Debug, Default, Serialize, Deserialize)]
 pub struct ConsumableInventory {
     pub items: Vec<Consumable>,
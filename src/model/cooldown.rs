@@ -1,5 +1,187 @@
 use std::time::Instant;
 
+/// Game-time seconds since `start` at game speed `time_scale` (1.0 = normal speed)
+pub fn scaled_elapsed(start: Instant, time_scale: f32) -> f32 {
+    start.elapsed().as_secs_f32() * time_scale
+}
+
+/// Repeating timer driven by game-time deltas, e.g. for periodic auto-saves
+#[derive(Clone, Debug, Default)]
+pub struct IntervalTimer {
+    elapsed: f32,
+}
+
+impl IntervalTimer {
+    /// Advance the timer; returns true each time `interval` seconds have passed.
+    /// An interval of zero (or less) never fires.
+    pub fn tick(&mut self, delta: f32, interval: f32) -> bool {
+        if interval <= 0.0 {
+            self.elapsed = 0.0;
+            return false;
+        }
+        self.elapsed += delta;
+        if self.elapsed >= interval {
+            self.elapsed = 0.0;
+            return true;
+        }
+        false
+    }
+
+    pub fn reset(&mut self) {
+        self.elapsed = 0.0;
+    }
+}
+
+/// Auto-fire for a held key: once pressed, reports each time `interval` seconds have
+/// passed since the last shot, until released
+#[derive(Clone, Debug, Default)]
+pub struct HeldTrigger {
+    held: bool,
+    since_fire: f32,
+}
+
+impl HeldTrigger {
+    /// The key went down; the caller fires the first shot itself
+    pub fn press(&mut self) {
+        self.held = true;
+        self.since_fire = 0.0;
+    }
+
+    pub fn release(&mut self) {
+        self.held = false;
+    }
+
+    pub fn is_held(&self) -> bool {
+        self.held
+    }
+
+    /// Advance while held; true once `interval` seconds have passed since the last shot.
+    /// Keeps returning true until `fired` is called, so a shot the game refuses is retried.
+    pub fn tick(&mut self, delta: f32, interval: f32) -> bool {
+        if !self.held {
+            return false;
+        }
+        self.since_fire += delta;
+        self.since_fire >= interval
+    }
+
+    pub fn fired(&mut self) {
+        self.since_fire = 0.0;
+    }
+}
+
+/// One-shot countdown driven by game-time deltas, e.g. for temporary map reveals
+#[derive(Clone, Debug, Default)]
+pub struct CountdownTimer {
+    remaining: f32,
+}
+
+impl CountdownTimer {
+    /// Restart the countdown, keeping whichever window ends later
+    pub fn start(&mut self, duration: f32) {
+        self.remaining = self.remaining.max(duration);
+    }
+
+    pub fn tick(&mut self, delta: f32) {
+        self.remaining = (self.remaining - delta).max(0.0);
+    }
+
+    pub fn is_active(&self) -> bool {
+        self.remaining > 0.0
+    }
+
+    pub fn clear(&mut self) {
+        self.remaining = 0.0;
+    }
+}
+
+/// Freeze-frame on impactful hits: the simulation stops while rendering carries on.
+/// Hits landing together don't add up; the freeze lasts as long as the longest one asked for.
+#[derive(Clone, Debug, Default)]
+pub struct HitStop {
+    timer: CountdownTimer,
+}
+
+impl HitStop {
+    /// Freeze for `duration` seconds, capped at `HITSTOP_MAX_MS`
+    pub fn trigger(&mut self, duration: f32) {
+        let cap = crate::constants::HITSTOP_MAX_MS as f32 / 1000.0;
+        self.timer.start(duration.clamp(0.0, cap));
+    }
+
+    /// Advance by real (unscaled) time. Returns true while the simulation stays frozen.
+    pub fn tick(&mut self, delta: f32) -> bool {
+        let frozen = self.timer.is_active();
+        self.timer.tick(delta);
+        frozen
+    }
+
+    pub fn is_active(&self) -> bool {
+        self.timer.is_active()
+    }
+}
+
+/// Frame rate over a rolling window of real time, so it tracks current performance.
+/// Also keeps a fixed-size history of frame durations for the dev menu.
+#[derive(Clone, Debug, Default)]
+pub struct FpsCounter {
+    frames: std::collections::VecDeque<Instant>,
+    frame_times: std::collections::VecDeque<f32>, // Seconds per frame, oldest first
+}
+
+impl FpsCounter {
+    pub fn record_frame(&mut self, now: Instant) {
+        if let Some(&last) = self.frames.back() {
+            if self.frame_times.len() == crate::constants::FRAME_TIME_HISTORY {
+                self.frame_times.pop_front();
+            }
+            self.frame_times
+                .push_back(now.duration_since(last).as_secs_f32());
+        }
+        self.frames.push_back(now);
+        let window = std::time::Duration::from_secs_f32(crate::constants::FPS_WINDOW_SECS);
+        while self
+            .frames
+            .front()
+            .is_some_and(|&first| now.duration_since(first) > window)
+        {
+            self.frames.pop_front();
+        }
+    }
+
+    pub fn fps(&self) -> f32 {
+        match (self.frames.front(), self.frames.back()) {
+            (Some(&first), Some(&last)) if last > first => {
+                (self.frames.len() - 1) as f32 / last.duration_since(first).as_secs_f32()
+            }
+            _ => 0.0,
+        }
+    }
+
+    /// Frame rate implied by the most recent frame alone
+    pub fn instant_fps(&self) -> f32 {
+        match self.frame_times.back() {
+            Some(&secs) if secs > 0.0 => 1.0 / secs,
+            _ => 0.0,
+        }
+    }
+
+    pub fn frame_times(&self) -> &std::collections::VecDeque<f32> {
+        &self.frame_times
+    }
+
+    /// Average duration of the slowest 1% of recorded frames, in seconds
+    pub fn one_percent_low(&self) -> f32 {
+        if self.frame_times.is_empty() {
+            return 0.0;
+        }
+        let mut sorted: Vec<f32> = self.frame_times.iter().copied().collect();
+        sorted.sort_by(|a, b| b.total_cmp(a));
+        let count = sorted.len().div_ceil(100);
+        sorted[..count].iter().sum::<f32>() / count as f32
+    }
+}
+
 /// Generic cooldown tracker for abilities and actions
 /// Encapsulates the timing logic for managing ability cooldowns
 #[derive(Clone, Debug)]
@@ -19,32 +201,42 @@ impl Cooldown {
         }
     }
 
-    /// Check if the cooldown is ready (not on cooldown)
-    pub fn is_ready(&self) -> bool {
+    /// Check if the cooldown is ready (not on cooldown) at game speed `time_scale`
+    pub fn is_ready(&self, time_scale: f32) -> bool {
+        self.is_ready_for(self.duration, time_scale)
+    }
+
+    /// Check readiness against a different duration than the stored one
+    pub fn is_ready_for(&self, duration: f32, time_scale: f32) -> bool {
         match self.start_time {
             None => true,
-            Some(start) => start.elapsed().as_secs_f32() >= self.duration,
+            Some(start) => scaled_elapsed(start, time_scale) >= duration,
         }
     }
 
-    /// Get remaining cooldown time in seconds (0 if ready)
-    pub fn remaining_seconds(&self) -> f32 {
+    /// Get remaining cooldown time in game seconds (0 if ready)
+    pub fn remaining_seconds(&self, time_scale: f32) -> f32 {
+        self.remaining_for(self.duration, time_scale)
+    }
+
+    /// Remaining seconds measured against a different duration than the stored one
+    pub fn remaining_for(&self, duration: f32, time_scale: f32) -> f32 {
         match self.start_time {
             None => 0.0,
             Some(start) => {
-                let elapsed = start.elapsed().as_secs_f32();
-                (self.duration - elapsed).max(0.0)
+                let elapsed = scaled_elapsed(start, time_scale);
+                (duration - elapsed).max(0.0)
             }
         }
     }
 
     /// Get cooldown progress as a fraction [0.0, 1.0]
     /// Returns 1.0 when ready, 0.0 when just started
-    pub fn progress(&self) -> f32 {
+    pub fn progress(&self, time_scale: f32) -> f32 {
         match self.start_time {
             None => 1.0,
             Some(start) => {
-                let elapsed = start.elapsed().as_secs_f32();
+                let elapsed = scaled_elapsed(start, time_scale);
                 (elapsed / self.duration).min(1.0)
             }
         }
@@ -76,17 +268,58 @@ mod tests {
     use super::*;
     use std::thread;
 
+    #[test]
+    fn test_hitstop_freezes_briefly_without_stacking() {
+        let mut hitstop = HitStop::default();
+        assert!(!hitstop.tick(0.016));
+
+        // Three heavy hits on the same frame freeze no longer than one
+        for _ in 0..3 {
+            hitstop.trigger(0.06);
+        }
+        let mut frozen_frames = 0;
+        while hitstop.tick(0.016) {
+            frozen_frames += 1;
+        }
+        assert_eq!(frozen_frames, 4);
+        assert!(!hitstop.is_active());
+
+        // Overlong requests are capped
+        hitstop.trigger(10.0);
+        assert!(hitstop.tick(crate::constants::HITSTOP_MAX_MS as f32 / 1000.0));
+        assert!(!hitstop.tick(0.016));
+    }
+
+    #[test]
+    fn test_held_trigger_fires_at_interval() {
+        let mut trigger = HeldTrigger::default();
+        assert!(!trigger.tick(1.0, 0.5));
+
+        trigger.press();
+        let mut shots = 0;
+        for _ in 0..20 {
+            if trigger.tick(0.1, 0.5) {
+                trigger.fired();
+                shots += 1;
+            }
+        }
+        assert_eq!(shots, 4);
+
+        trigger.release();
+        assert!(!trigger.tick(1.0, 0.5));
+    }
+
     #[test]
     fn test_new_cooldown_is_ready() {
         let cooldown = Cooldown::new(1.0);
-        assert!(cooldown.is_ready());
+        assert!(cooldown.is_ready(1.0));
     }
 
     #[test]
     fn test_triggered_cooldown_not_ready() {
         let mut cooldown = Cooldown::new(0.1);
         cooldown.trigger();
-        assert!(!cooldown.is_ready());
+        assert!(!cooldown.is_ready(1.0));
     }
 
     #[test]
@@ -94,27 +327,80 @@ mod tests {
         let mut cooldown = Cooldown::new(0.1);
         cooldown.trigger();
         thread::sleep(std::time::Duration::from_millis(150));
-        assert!(cooldown.is_ready());
+        assert!(cooldown.is_ready(1.0));
     }
 
     #[test]
     fn test_reset_cooldown() {
         let mut cooldown = Cooldown::new(1.0);
         cooldown.trigger();
-        assert!(!cooldown.is_ready());
+        assert!(!cooldown.is_ready(1.0));
         cooldown.reset();
-        assert!(cooldown.is_ready());
+        assert!(cooldown.is_ready(1.0));
+    }
+
+    #[test]
+    fn test_fps_counter_uses_recent_frames_only() {
+        let start = Instant::now();
+        let mut counter = FpsCounter::default();
+        assert_eq!(counter.fps(), 0.0);
+
+        // A slow stretch at 10 FPS...
+        for i in 0..20 {
+            counter.record_frame(start + std::time::Duration::from_millis(i * 100));
+        }
+        assert!((counter.fps() - 10.0).abs() < 0.5);
+
+        // ...followed by a fast one at 50 FPS is reflected once the window has passed
+        let fast_start = start + std::time::Duration::from_secs(2);
+        for i in 0..100 {
+            counter.record_frame(fast_start + std::time::Duration::from_millis(i * 20));
+        }
+        assert!((counter.fps() - 50.0).abs() < 1.0);
+    }
+
+    #[test]
+    fn test_frame_time_history_is_fixed_size() {
+        let start = Instant::now();
+        let mut counter = FpsCounter::default();
+        for i in 0..300u64 {
+            // Two 100ms stutters among 20ms frames; 1% of 120 frames rounds up to two
+            let stutters = [250, 260].iter().filter(|&&s| i >= s).count() as u64;
+            let offset = i * 20 + stutters * 80;
+            counter.record_frame(start + std::time::Duration::from_millis(offset));
+        }
+
+        assert_eq!(
+            counter.frame_times().len(),
+            crate::constants::FRAME_TIME_HISTORY
+        );
+        assert!((counter.instant_fps() - 50.0).abs() < 1.0);
+        assert!((counter.one_percent_low() - 0.1).abs() < 0.001);
     }
 
     #[test]
     fn test_progress() {
         let cooldown = Cooldown::new(1.0);
-        assert_eq!(cooldown.progress(), 1.0);
+        assert_eq!(cooldown.progress(1.0), 1.0);
 
         let mut cooldown = Cooldown::new(0.2);
         cooldown.trigger();
         thread::sleep(std::time::Duration::from_millis(100));
-        let progress = cooldown.progress();
+        let progress = cooldown.progress(1.0);
         assert!(progress > 0.4 && progress < 0.6);
     }
+
+    #[test]
+    fn test_cooldown_scales_with_game_speed() {
+        let mut cooldown = Cooldown::new(0.2);
+        cooldown.trigger();
+        thread::sleep(std::time::Duration::from_millis(60));
+
+        // The same real time counts for more game time at a higher speed
+        assert!(cooldown.is_ready(4.0));
+        assert!(!cooldown.is_ready(1.0));
+        assert!(!cooldown.is_ready(0.5));
+        assert!(cooldown.remaining_seconds(0.5) > cooldown.remaining_seconds(1.0));
+        assert_eq!(cooldown.progress(4.0), 1.0);
+    }
 }
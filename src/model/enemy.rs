@@ -1,6 +1,6 @@
 use serde::{Deserialize, Serialize};
 use std::cmp::Ordering;
-use std::collections::{BinaryHeap, HashMap};
+use std::collections::{BinaryHeap, HashMap, HashSet};
 
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq, Hash)]
 pub struct Position {
@@ -16,10 +16,101 @@ impl Position {
     pub fn distance_to(&self, other: &Position) -> i32 {
         (self.x - other.x).abs() + (self.y - other.y).abs()
     }
+
+    /// Distance to `other` measured with the given metric
+    pub fn distance_with(&self, other: &Position, metric: DistanceMetric) -> i32 {
+        match metric {
+            DistanceMetric::Manhattan => self.distance_to(other),
+            DistanceMetric::Chebyshev => (self.x - other.x).abs().max((self.y - other.y).abs()),
+        }
+    }
+}
+
+/// How enemies measure their distance to the player for detection and melee range
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum DistanceMetric {
+    /// Steps along the grid; diagonal neighbours are 2 away
+    #[default]
+    Manhattan,
+    /// King moves; diagonal neighbours are adjacent
+    Chebyshev,
+}
+
+impl DistanceMetric {
+    pub fn name(&self) -> &'static str {
+        match self {
+            DistanceMetric::Manhattan => "Manhattan",
+            DistanceMetric::Chebyshev => "Chebyshev",
+        }
+    }
+
+    pub fn toggled(&self) -> Self {
+        match self {
+            DistanceMetric::Manhattan => DistanceMetric::Chebyshev,
+            DistanceMetric::Chebyshev => DistanceMetric::Manhattan,
+        }
+    }
+
+    /// Offsets exactly `radius` away from the origin: a diamond for Manhattan, a square
+    /// for Chebyshev
+    pub fn ring(&self, radius: i32) -> Vec<(i32, i32)> {
+        if radius <= 0 {
+            return vec![(0, 0)];
+        }
+        match self {
+            DistanceMetric::Manhattan => (-radius..=radius)
+                .flat_map(|dx| {
+                    let dy = radius - dx.abs();
+                    if dy == 0 {
+                        vec![(dx, 0)]
+                    } else {
+                        vec![(dx, -dy), (dx, dy)]
+                    }
+                })
+                .collect(),
+            DistanceMetric::Chebyshev => (-radius..=radius)
+                .flat_map(|d| [(d, -radius), (d, radius)])
+                .chain((1 - radius..radius).flat_map(|d| [(-radius, d), (radius, d)]))
+                .collect(),
+        }
+    }
+}
+
+/// An attack an enemy is winding up; it lands on whatever is inside `frames` when the timer runs out
+#[derive(Clone, Debug)]
+pub struct EnemyTelegraph {
+    pub pattern: crate::model::attack_pattern::AttackPattern,
+    pub frames: Vec<crate::model::attack_pattern::AnimationFrame>,
+    pub damage: i32,
+    pub direction: (f32, f32), // knockback direction toward the player
+    pub remaining: f32,        // seconds until the attack lands
+}
+
+impl EnemyTelegraph {
+    /// Every tile the attack will cover
+    pub fn tiles(&self) -> Vec<(i32, i32)> {
+        let mut tiles: Vec<(i32, i32)> = self
+            .frames
+            .iter()
+            .flat_map(|frame| frame.tiles.iter().copied())
+            .collect();
+        tiles.sort_unstable();
+        tiles.dedup();
+        tiles
+    }
+
+    /// Whether the attack hits (x, y) when it lands; the same tiles `tiles` shows as a warning
+    pub fn lands_on(&self, x: i32, y: i32) -> bool {
+        self.frames
+            .iter()
+            .any(|frame| frame.tiles.contains(&(x, y)))
+    }
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct Enemy {
+    #[serde(default)]
+    pub name: String, // template or boss name, shown when examined
     pub position: Position,
     pub speed: f32,                  // tiles per game tick (0.1 = 10 ticks per tile)
     pub max_range: Option<i32>,      // optional 5x5 nearby area (radius from spawn point)
@@ -32,6 +123,8 @@ pub struct Enemy {
     pub health: i32,                 // current health points
     pub max_health: i32,             // maximum health points
     pub rarity: crate::model::enemy_type::EnemyRarity, // enemy difficulty tier
+    #[serde(default)]
+    pub enemy_type: crate::model::enemy_type::EnemyType, // undead or ghost
     pub base_gold: u32,              // gold dropped on defeat
     #[serde(skip)]
     pub knockback_velocity: (f32, f32), // knockback direction and remaining force (dx, dy)
@@ -39,8 +132,54 @@ pub struct Enemy {
     pub damaged_at: Option<std::time::Instant>, // timestamp of when entity was last damaged
     pub detection_radius: i32,       // radius within which enemy detects and chases player
     pub attacks: Vec<crate::model::enemy_type::EnemyAttack>, // attack patterns this enemy can use
+    #[serde(default)]
+    pub buffs: Vec<crate::model::enemy_type::EnemyBuff>, // buff spells from its template
     #[serde(skip)]
     pub current_attack_index: usize, // tracks which attack pattern we're currently using
+    #[serde(skip)]
+    pub return_path: Vec<Position>, // remaining steps back to spawn after losing the player
+    #[serde(skip)]
+    pub telegraph: Option<EnemyTelegraph>, // attack being wound up, shown as danger tiles
+    #[serde(default)]
+    pub flee_threshold: Option<f32>, // runs from the player below this fraction of max health
+    #[serde(default)]
+    pub phases: Vec<AttackPhase>, // attack sets still to come, highest health threshold first
+    #[serde(default)]
+    pub dummy: Option<DummyRecord>, // set on dev training dummies, which never die or fight back
+}
+
+/// Damage a training dummy has soaked up since it was spawned
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct DummyRecord {
+    pub total_damage: i32,
+    pub hits: u32,
+    #[serde(skip)]
+    pub first_hit_at: Option<std::time::Instant>,
+}
+
+impl DummyRecord {
+    pub fn record(&mut self, damage: i32) {
+        self.total_damage += damage;
+        self.hits += 1;
+        self.first_hit_at
+            .get_or_insert_with(std::time::Instant::now);
+    }
+
+    /// Damage per second of game time at speed `time_scale` since the first hit, counting
+    /// at least one second
+    pub fn dps(&self, time_scale: f32) -> f32 {
+        self.first_hit_at.map_or(0.0, |first_hit| {
+            let elapsed = crate::model::cooldown::scaled_elapsed(first_hit, time_scale).max(1.0);
+            self.total_damage as f32 / elapsed
+        })
+    }
+}
+
+/// Attack set an enemy switches to once its health drops below `health_fraction` of max
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct AttackPhase {
+    pub health_fraction: f32,
+    pub attacks: Vec<crate::model::enemy_type::EnemyAttack>,
 }
 
 impl Enemy {
@@ -48,6 +187,7 @@ impl Enemy {
     pub fn new(x: i32, y: i32, speed: f32) -> Self {
         let pos = Position::new(x, y);
         Self {
+            name: String::new(),
             position: pos.clone(),
             speed,
             max_range: None,
@@ -60,23 +200,93 @@ impl Enemy {
             health: 10, // Default, will be set from template
             max_health: 10,
             rarity: crate::model::enemy_type::EnemyRarity::Fighter,
+            enemy_type: crate::model::enemy_type::EnemyType::Undead,
             base_gold: 10,
             knockback_velocity: (0.0, 0.0),
             damaged_at: None,
             detection_radius: 5, // Default, will be set from template
             attacks: vec![],
+            buffs: Vec::new(),
             current_attack_index: 0,
+            return_path: Vec::new(),
+            telegraph: None,
+            flee_threshold: None,
+            phases: Vec::new(),
+            dummy: None,
         }
     }
 
+    /// Training dummy for the dev menu: stands still, never attacks and records the
+    /// damage it takes instead of dying
+    pub fn training_dummy(x: i32, y: i32) -> Self {
+        let mut dummy = Self::new(x, y, 0.0);
+        dummy.name = "Training Dummy".to_string();
+        dummy.health = crate::constants::TRAINING_DUMMY_HEALTH;
+        dummy.max_health = crate::constants::TRAINING_DUMMY_HEALTH;
+        dummy.base_gold = 0;
+        dummy.detection_radius = 0;
+        dummy.dummy = Some(DummyRecord::default());
+        dummy
+    }
+
     /// Take damage and return whether enemy is still alive
     pub fn take_damage(&mut self, damage: i32) -> bool {
-        self.health = (self.health - damage).max(0);
         self.damaged_at = Some(std::time::Instant::now());
+        if let Some(record) = &mut self.dummy {
+            record.record(damage);
+            return true;
+        }
+        self.health = (self.health - damage).max(0);
         self.health > 0
     }
 
+    /// Tooltip text for examine mode: name, rarity and type, health, buffs and attacks
+    pub fn examine_lines(&self) -> Vec<String> {
+        let name = if self.name.is_empty() {
+            self.rarity.name()
+        } else {
+            &self.name
+        };
+        let buffs: Vec<&str> = self.buffs.iter().map(|buff| buff.name()).collect();
+        let attacks: Vec<&str> = self
+            .attacks
+            .iter()
+            .map(|attack| attack.name.as_str())
+            .collect();
+        vec![
+            name.to_string(),
+            format!("{} {}", self.rarity.name(), self.enemy_type.name()),
+            format!("HP: {}/{}", self.health, self.max_health),
+            if buffs.is_empty() {
+                "Buffs: none".to_string()
+            } else {
+                format!("Buffs: {}", buffs.join(", "))
+            },
+            if attacks.is_empty() {
+                "Attacks: none".to_string()
+            } else {
+                format!("Attacks: {}", attacks.join(", "))
+            },
+        ]
+    }
+
+    /// Whether `damage` just dealt to this enemy was heavy enough to freeze the action:
+    /// a killing blow or a big enough share of its max health. Bosses always count.
+    pub fn is_heavy_hit(&self, damage: i32) -> bool {
+        !self.is_alive()
+            || self.rarity == crate::model::enemy_type::EnemyRarity::Boss
+            || damage as f32
+                >= self.max_health as f32 * crate::constants::HITSTOP_HEAVY_HIT_FRACTION
+    }
+
+    /// Combined knockback multiplier from rarity resistance and enemy type
+    pub fn knockback_multiplier(&self) -> f32 {
+        self.rarity.knockback_multiplier() * self.enemy_type.knockback_multiplier()
+    }
+
     pub fn apply_knockback(&mut self, dx: f32, dy: f32, force: f32) {
+        let force = force * self.knockback_multiplier();
+
         // Normalize direction to prevent diagonal knockback from being stronger
         // Only apply knockback in the dominant direction
         let abs_dx = dx.abs();
@@ -102,6 +312,216 @@ impl Enemy {
         }
     }
 
+    /// Advance a wound-up attack; returns it once the wind-up is over and it should land
+    pub fn tick_telegraph(&mut self, delta: f32) -> Option<EnemyTelegraph> {
+        let telegraph = self.telegraph.as_mut()?;
+        telegraph.remaining -= delta;
+        if telegraph.remaining > 0.0 {
+            return None;
+        }
+        self.telegraph.take()
+    }
+
+    /// Whether the player is inside this enemy's detection radius
+    pub fn detects(&self, player: &Position, metric: DistanceMetric) -> bool {
+        self.position.distance_with(player, metric) <= self.detection_radius
+    }
+
+    /// Whether the enemy is hurt badly enough to run from the player
+    pub fn is_fleeing(&self) -> bool {
+        self.flee_threshold
+            .is_some_and(|threshold| (self.health as f32) < self.max_health as f32 * threshold)
+    }
+
+    /// Take one step toward the player, or straight away from them while fleeing
+    pub fn pursuit_step(&mut self, player: &Position, walkable: &HashSet<(i32, i32)>) {
+        self.step_relative((player.x, player.y), player, walkable, self.is_fleeing());
+    }
+
+    /// Take one step toward `target`, the tile next to the player this enemy's pack
+    /// assigned it, instead of straight at the player
+    pub fn pack_step(
+        &mut self,
+        target: (i32, i32),
+        player: &Position,
+        walkable: &HashSet<(i32, i32)>,
+    ) {
+        self.step_relative(target, player, walkable, false);
+    }
+
+    /// Step toward `goal`, or directly away when `away` is set. Never steps onto
+    /// the player. Stepping away only cuts a corner when one of the side tiles is open,
+    /// so the enemy can't slip out of the region the player can reach.
+    fn step_relative(
+        &mut self,
+        goal: (i32, i32),
+        player: &Position,
+        walkable: &HashSet<(i32, i32)>,
+        away: bool,
+    ) {
+        let toward = if away { -1 } else { 1 };
+        let dx = (goal.0 - self.position.x).signum() * toward;
+        let dy = (goal.1 - self.position.y).signum() * toward;
+        let (x, y) = (self.position.x, self.position.y);
+        let open = |tile: (i32, i32)| tile != (player.x, player.y) && walkable.contains(&tile);
+
+        let corner_ok = !away || dx == 0 || dy == 0 || open((x + dx, y)) || open((x, y + dy));
+        if open((x + dx, y + dy)) && corner_ok {
+            self.position.x += dx;
+            self.position.y += dy;
+        } else if dx != 0 && open((x + dx, y)) {
+            // Try moving along each axis separately
+            self.position.x += dx;
+        } else if dy != 0 && open((x, y + dy)) {
+            self.position.y += dy;
+        }
+    }
+
+    /// The long-reach attack this enemy shoots with, if it fights from range.
+    /// Bosses telegraph their attacks instead, so they never count as ranged.
+    pub fn ranged_attack(&self) -> Option<&crate::model::enemy_type::EnemyAttack> {
+        if self.rarity == crate::model::enemy_type::EnemyRarity::Boss {
+            return None;
+        }
+        self.attacks
+            .iter()
+            .find(|attack| attack.reach >= crate::constants::RANGED_ENEMY_MIN_REACH)
+    }
+
+    pub fn is_ranged(&self) -> bool {
+        self.ranged_attack().is_some()
+    }
+
+    /// Keep the player at arm's length: back off when they get close, close in when
+    /// they are out of range, and otherwise slide sideways until a shot lines up
+    pub fn ranged_step(&mut self, player: &Position, walkable: &HashSet<(i32, i32)>) {
+        use crate::constants::{RANGED_ENEMY_FIRE_RANGE, RANGED_ENEMY_PREFERRED_DISTANCE};
+
+        let (dx, dy) = (player.x - self.position.x, player.y - self.position.y);
+        let distance = dx.abs().max(dy.abs());
+        let goal = (player.x, player.y);
+        if distance < RANGED_ENEMY_PREFERRED_DISTANCE {
+            self.step_relative(goal, player, walkable, true);
+        } else if distance > RANGED_ENEMY_FIRE_RANGE {
+            self.step_relative(goal, player, walkable, false);
+        } else if !Self::lined_up(dx, dy) {
+            // Closing the shorter gap puts the player on a straight line
+            let (sx, sy) = if dx.abs() < dy.abs() {
+                (dx.signum(), 0)
+            } else {
+                (0, dy.signum())
+            };
+            let target = (self.position.x + sx, self.position.y + sy);
+            if target != (player.x, player.y) && walkable.contains(&target) {
+                self.position.x = target.0;
+                self.position.y = target.1;
+            }
+        }
+    }
+
+    /// Shoot at the player if they are lined up, in range and `clear_shot` says
+    /// nothing blocks the line. Returns the arrow to add to the world.
+    pub fn try_ranged_shot(
+        &mut self,
+        player: &Position,
+        clear_shot: bool,
+    ) -> Option<crate::model::arrow::Arrow> {
+        use crate::constants::{RANGED_ENEMY_FIRE_RANGE, RANGED_ENEMY_FIRE_TICKS};
+
+        let attack = self.ranged_attack()?;
+        let damage = attack.damage().max(attack.damage_min);
+        let (dx, dy) = (player.x - self.position.x, player.y - self.position.y);
+        let distance = dx.abs().max(dy.abs());
+        if self.attack_ticks < RANGED_ENEMY_FIRE_TICKS
+            || distance == 0
+            || distance > RANGED_ENEMY_FIRE_RANGE
+            || !Self::lined_up(dx, dy)
+            || !clear_shot
+        {
+            return None;
+        }
+
+        self.attack_ticks = 0.0;
+        Some(crate::model::arrow::Arrow::enemy_shot(
+            self.position.x as f32,
+            self.position.y as f32,
+            dx.signum(),
+            dy.signum(),
+            damage,
+        ))
+    }
+
+    /// Whether an offset lies on a straight or diagonal line, so an arrow can follow it
+    fn lined_up(dx: i32, dy: i32) -> bool {
+        dx == 0 || dy == 0 || dx.abs() == dy.abs()
+    }
+
+    /// Scale health and attack damage, e.g. for the difficulty. Values never drop below 1.
+    pub fn scale_stats(&mut self, multiplier: f32) {
+        let scale = |value: i32| ((value as f32 * multiplier).round() as i32).max(1);
+        self.health = scale(self.health);
+        self.max_health = scale(self.max_health);
+        let phase_attacks = self.phases.iter_mut().flat_map(|phase| &mut phase.attacks);
+        for attack in self.attacks.iter_mut().chain(phase_attacks) {
+            attack.damage_min = scale(attack.damage_min);
+            attack.damage_max = scale(attack.damage_max);
+        }
+    }
+
+    /// Multiply every attack's cooldown, including those of later phases
+    pub fn scale_cooldowns(&mut self, multiplier: f32) {
+        let phase_attacks = self.phases.iter_mut().flat_map(|phase| &mut phase.attacks);
+        for attack in self.attacks.iter_mut().chain(phase_attacks) {
+            attack.cooldown_duration *= multiplier;
+        }
+    }
+
+    /// Count down attack cooldowns by `delta` game-time seconds
+    pub fn tick_attack_cooldowns(&mut self, delta: f32) {
+        for attack in &mut self.attacks {
+            attack.update_cooldown(delta);
+        }
+    }
+
+    /// True once the last attack used has cooled down. Enemies without attack data
+    /// fall back to a fixed cooldown measured in attack ticks.
+    pub fn attack_ready(&self, tick_seconds: f32) -> bool {
+        if self.attacks.is_empty() {
+            return self.attack_ticks * tick_seconds
+                >= crate::constants::ENEMY_UNARMED_ATTACK_COOLDOWN;
+        }
+        self.attacks.iter().all(|attack| attack.is_available())
+    }
+
+    /// Put the attack just used on cooldown and move on to the next one
+    pub fn finish_attack(&mut self) {
+        self.attack_ticks = 0.0;
+        if let Some(attack) = self.attacks.get_mut(self.current_attack_index) {
+            attack.use_attack();
+        }
+        if !self.attacks.is_empty() {
+            self.current_attack_index = (self.current_attack_index + 1) % self.attacks.len();
+        }
+    }
+
+    /// Swap in the attack set of the last phase whose health threshold has been crossed.
+    /// Returns true when the attacks changed, so the caller can play the enrage cue.
+    pub fn enter_next_phase(&mut self) -> bool {
+        let fraction = self.health as f32 / self.max_health.max(1) as f32;
+        let mut entered = false;
+        while self
+            .phases
+            .first()
+            .is_some_and(|phase| fraction < phase.health_fraction)
+        {
+            let phase = self.phases.remove(0);
+            self.attacks = phase.attacks;
+            self.current_attack_index = 0;
+            entered = true;
+        }
+        entered
+    }
+
     /// Check if enemy is alive
     pub fn is_alive(&self) -> bool {
         self.health > 0
@@ -200,6 +620,69 @@ impl Enemy {
         false
     }
 
+    /// Whether the enemy is close enough to its spawn point to idle there
+    pub fn is_near_spawn(&self) -> bool {
+        self.position.distance_to(&self.spawn_point) <= crate::constants::ENEMY_LEASH_IDLE_RADIUS
+    }
+
+    /// Take one step back toward the spawn point along an A* path (greedy step if none).
+    /// `blocked` is a tile the enemy must not enter, such as the player's position.
+    /// Returns true if movement occurred
+    pub fn return_to_spawn(
+        &mut self,
+        floor: &crate::model::floor::Floor,
+        blocked: (i32, i32),
+    ) -> bool {
+        if self.position == self.spawn_point {
+            self.return_path.clear();
+            return false;
+        }
+
+        // Recompute the path if we were pushed off it
+        let on_path = self
+            .return_path
+            .first()
+            .is_some_and(|next| next.distance_to(&self.position) == 1);
+        if !on_path {
+            let (start, goal) = (self.position.clone(), self.spawn_point.clone());
+            self.return_path = self
+                .find_path(&start, &goal, floor)
+                .map(|path| path.into_iter().skip(1).collect())
+                .unwrap_or_default();
+        }
+
+        let next = match self.return_path.first() {
+            Some(next) => next.clone(),
+            None => {
+                // No path found - greedy step toward spawn
+                let dx = (self.spawn_point.x - self.position.x).signum();
+                let dy = (self.spawn_point.y - self.position.y).signum();
+                let candidates = [
+                    Position::new(self.position.x + dx, self.position.y),
+                    Position::new(self.position.x, self.position.y + dy),
+                ];
+                match candidates
+                    .into_iter()
+                    .find(|p| *p != self.position && self.can_move_to(p.x, p.y, floor))
+                {
+                    Some(step) => step,
+                    None => return false,
+                }
+            }
+        };
+
+        if (next.x, next.y) == blocked || !self.can_move_to(next.x, next.y, floor) {
+            return false;
+        }
+
+        if !self.return_path.is_empty() {
+            self.return_path.remove(0);
+        }
+        self.position = next;
+        self.is_wandering = true;
+        true
+    }
+
     /// A* pathfinding algorithm
     /// Returns the path from start to goal, or None if no path exists
     fn find_path(
@@ -414,6 +897,71 @@ impl Enemy {
     }
 }
 
+/// Spread packs of chasing melee enemies around the player. An enemy is in a pack when
+/// another chaser of the same type is within `PACK_RADIUS` of it. Pack members claim
+/// the open tiles the player can be hit from, nearest enemy first and each taking the
+/// free tile closest to it, so no two head for the same spot. Returns enemy index to
+/// claimed tile; enemies left out keep chasing the player directly.
+pub fn assign_pack_targets(
+    enemies: &[Enemy],
+    player: &Position,
+    walkable: &HashSet<(i32, i32)>,
+    metric: DistanceMetric,
+) -> HashMap<usize, (i32, i32)> {
+    use crate::constants::PACK_RADIUS;
+
+    let chasers: Vec<usize> = enemies
+        .iter()
+        .enumerate()
+        .filter(|(_, enemy)| {
+            enemy.is_alive()
+                && enemy.telegraph.is_none()
+                && !enemy.is_fleeing()
+                && !enemy.is_ranged()
+                && enemy.position.distance_with(player, metric) <= enemy.detection_radius
+        })
+        .map(|(i, _)| i)
+        .collect();
+    let mut pack: Vec<usize> = chasers
+        .iter()
+        .copied()
+        .filter(|&i| {
+            chasers.iter().any(|&j| {
+                j != i
+                    && enemies[j].enemy_type == enemies[i].enemy_type
+                    && enemies[j]
+                        .position
+                        .distance_with(&enemies[i].position, DistanceMetric::Chebyshev)
+                        <= PACK_RADIUS
+            })
+        })
+        .collect();
+    pack.sort_by_key(|&i| (enemies[i].position.distance_with(player, metric), i));
+
+    // Tiles an enemy can attack from; diagonals only count for king-move distance
+    let mut free: Vec<(i32, i32)> = (-1..=1)
+        .flat_map(|dy| (-1..=1).map(move |dx| (dx, dy)))
+        .filter(|&offset| offset != (0, 0))
+        .map(|(dx, dy)| Position::new(player.x + dx, player.y + dy))
+        .filter(|tile| tile.distance_with(player, metric) == 1)
+        .filter(|tile| walkable.contains(&(tile.x, tile.y)))
+        .map(|tile| (tile.x, tile.y))
+        .collect();
+
+    let mut targets = HashMap::new();
+    for i in pack {
+        let here = &enemies[i].position;
+        let Some(slot) = (0..free.len()).min_by_key(|&slot| {
+            let (x, y) = free[slot];
+            Position::new(x, y).distance_with(here, DistanceMetric::Chebyshev)
+        }) else {
+            break;
+        };
+        targets.insert(i, free.swap_remove(slot));
+    }
+    targets
+}
+
 /// Helper struct for A* priority queue
 #[derive(Clone, Debug)]
 #[allow(dead_code)] // Used in A* implementation
@@ -457,6 +1005,42 @@ mod tests {
         assert!(!enemy.collision_with_player); // Default is no collision with player
     }
 
+    #[test]
+    fn test_training_dummy_records_hits_without_dying() {
+        let mut dummy = Enemy::training_dummy(5, 5);
+        assert!(dummy.take_damage(40));
+        assert!(dummy.take_damage(dummy.max_health * 2));
+
+        let record = dummy.dummy.as_ref().unwrap();
+        assert_eq!(record.total_damage, 40 + dummy.max_health * 2);
+        assert_eq!(record.hits, 2);
+        assert!(record.dps(1.0) > 0.0);
+        assert!(dummy.is_alive());
+        assert_eq!(dummy.health, dummy.max_health);
+    }
+
+    #[test]
+    fn test_examine_lists_template_buffs() {
+        let template = crate::model::enemy_type::all_enemy_templates()
+            .into_iter()
+            .find(|template| !template.buffs.is_empty())
+            .unwrap();
+        let enemy = crate::model::floor::Floor::enemy_from_template(
+            &template,
+            0,
+            0,
+            &crate::model::item_tier::Difficulty::Normal,
+        );
+        let text = enemy.examine_lines().join("\n");
+        assert!(text.contains(&template.name));
+        for buff in &template.buffs {
+            assert!(text.contains(buff.name()));
+        }
+        for attack in &template.attacks {
+            assert!(text.contains(&attack.name));
+        }
+    }
+
     #[test]
     fn test_position_distance() {
         let pos1 = Position::new(0, 0);
@@ -464,6 +1048,70 @@ mod tests {
         assert_eq!(pos1.distance_to(&pos2), 7); // Manhattan distance
     }
 
+    #[test]
+    fn test_diagonal_enemy_is_adjacent_only_with_chebyshev() {
+        let player = Position::new(5, 5);
+        let enemy = Position::new(6, 6);
+        assert_eq!(enemy.distance_with(&player, DistanceMetric::Manhattan), 2);
+        assert_eq!(enemy.distance_with(&player, DistanceMetric::Chebyshev), 1);
+
+        // Straight neighbours are adjacent either way
+        let beside = Position::new(5, 6);
+        assert_eq!(beside.distance_with(&player, DistanceMetric::Manhattan), 1);
+        assert_eq!(beside.distance_with(&player, DistanceMetric::Chebyshev), 1);
+
+        // Detection radius shrinks to a square under Chebyshev
+        let far = Position::new(8, 9);
+        assert_eq!(far.distance_with(&player, DistanceMetric::Manhattan), 7);
+        assert_eq!(far.distance_with(&player, DistanceMetric::Chebyshev), 4);
+    }
+
+    #[test]
+    fn test_shorter_cooldown_attacks_twice_as_often() {
+        use crate::model::attack_pattern::AttackPattern;
+        use crate::model::enemy_type::{AttackType, EnemyAttack};
+
+        let with_cooldown = |cooldown: f32| {
+            let mut enemy = Enemy::new(0, 0, 1.0);
+            enemy.attacks = vec![EnemyAttack {
+                name: "Slash".to_string(),
+                damage_min: 1,
+                damage_max: 1,
+                attack_type: AttackType::Physical,
+                reach: 1,
+                area_radius: 0,
+                effect: None,
+                cooldown_duration: cooldown,
+                cooldown_remaining: 0.0,
+                pattern: AttackPattern::BasicSlash,
+            }];
+            enemy
+        };
+        let attacks_in = |enemy: &mut Enemy, seconds: f32| {
+            let delta = 0.016;
+            let mut count = 0;
+            for _ in 0..(seconds / delta) as usize {
+                enemy.tick_attack_cooldowns(delta);
+                enemy.attack_ticks += 1.0;
+                if enemy.attack_ready(delta) {
+                    enemy.finish_attack();
+                    count += 1;
+                }
+            }
+            count
+        };
+
+        let fast = attacks_in(&mut with_cooldown(1.0), 10.0);
+        let slow = attacks_in(&mut with_cooldown(2.0), 10.0);
+        assert_eq!(fast, 10);
+        assert_eq!(slow, 5);
+
+        // Harder difficulties shorten cooldowns
+        let mut hard = with_cooldown(2.0);
+        hard.scale_cooldowns(0.5);
+        assert_eq!(attacks_in(&mut hard, 10.0), fast);
+    }
+
     #[test]
     fn test_max_range() {
         let mut enemy = Enemy::new(5, 5, 0.1);
@@ -531,4 +1179,226 @@ mod tests {
         enemy.base_gold = EnemyRarity::Boss.base_gold();
         assert_eq!(enemy.base_gold, 150);
     }
+
+    #[test]
+    fn test_knockback_resistance_by_rarity() {
+        use crate::model::enemy_type::EnemyRarity;
+        let mut fighter = Enemy::new(5, 5, 0.1);
+        let mut boss = Enemy::new(5, 5, 0.1);
+        boss.rarity = EnemyRarity::Boss;
+
+        fighter.apply_knockback(1.0, 0.0, 1.0);
+        boss.apply_knockback(1.0, 0.0, 1.0);
+
+        // First knockback step moves the fighter a full tile, the boss stays put
+        let fighter_x = (fighter.position.x as f32 + fighter.knockback_velocity.0).round() as i32;
+        let boss_x = (boss.position.x as f32 + boss.knockback_velocity.0).round() as i32;
+        assert_eq!(fighter_x, 6);
+        assert_eq!(boss_x, 5);
+        assert!(boss.knockback_velocity.0 < 0.1);
+    }
+
+    #[test]
+    fn test_abandoned_enemy_returns_to_spawn() {
+        let mut floor = crate::model::floor::Floor::new(40, 20, 7);
+        floor.enemies.clear();
+        // Open corridor between the spawn and where the player dragged the enemy
+        for x in 2..30 {
+            floor.set_wall(x, 10, false);
+        }
+
+        let mut enemy = Enemy::new(5, 10, 0.1);
+        enemy.position = Position::new(25, 10);
+        let start_distance = enemy.position.distance_to(&enemy.spawn_point);
+
+        assert!(enemy.return_to_spawn(&floor, (0, 0)));
+        assert!(enemy.position.distance_to(&enemy.spawn_point) < start_distance);
+
+        for _ in 0..start_distance {
+            enemy.return_to_spawn(&floor, (0, 0));
+        }
+        assert_eq!(enemy.position, enemy.spawn_point);
+        assert!(!enemy.return_to_spawn(&floor, (0, 0)));
+    }
+
+    #[test]
+    fn test_telegraph_lands_after_windup() {
+        use crate::model::attack_pattern::AttackPattern;
+
+        let mut enemy = Enemy::new(5, 5, 0.1);
+        let pattern = AttackPattern::BasicSlash;
+        enemy.telegraph = Some(EnemyTelegraph {
+            frames: pattern.get_animation_frames(5, 5, 1, 0),
+            pattern,
+            damage: 20,
+            direction: (1.0, 0.0),
+            remaining: 0.5,
+        });
+
+        assert!(enemy.tick_telegraph(0.3).is_none());
+        let landed = enemy.tick_telegraph(0.3).expect("wind-up should be over");
+        assert_eq!(landed.damage, 20);
+        assert!(!landed.tiles().is_empty());
+        assert!(enemy.telegraph.is_none());
+    }
+
+    #[test]
+    fn test_low_health_enemy_flees_from_player() {
+        let walkable: HashSet<(i32, i32)> =
+            (0..12).flat_map(|x| (0..12).map(move |y| (x, y))).collect();
+        let player = Position::new(3, 4);
+
+        let mut shade = Enemy::new(6, 6, 0.1);
+        shade.flee_threshold = Some(0.3);
+        shade.max_health = 20;
+        shade.health = 20;
+        assert!(!shade.is_fleeing());
+        let before = shade.position.distance_to(&player);
+        shade.pursuit_step(&player, &walkable);
+        assert!(shade.position.distance_to(&player) < before);
+
+        // Badly hurt, it runs the other way
+        shade.health = 5;
+        assert!(shade.is_fleeing());
+        let before = shade.position.distance_to(&player);
+        shade.pursuit_step(&player, &walkable);
+        assert!(shade.position.distance_to(&player) > before);
+
+        // Backed into a corner it stays inside the open area
+        shade.position = Position::new(11, 11);
+        shade.pursuit_step(&player, &walkable);
+        assert_eq!(shade.position, Position::new(11, 11));
+    }
+
+    #[test]
+    fn test_ranged_enemy_fires_at_player_in_range() {
+        let mut haunt = Enemy::new(2, 5, 0.1);
+        haunt.attacks = crate::model::enemy_type::create_lantern_haunt().attacks;
+        assert!(haunt.is_ranged());
+        assert!(!Enemy::new(2, 5, 0.1).is_ranged());
+
+        // Lined up five tiles away: the arrow flies straight at the player
+        let player = Position::new(7, 5);
+        haunt.attack_ticks = crate::constants::RANGED_ENEMY_FIRE_TICKS;
+        assert!(haunt.try_ranged_shot(&player, false).is_none()); // Wall in the way
+        let arrow = haunt.try_ranged_shot(&player, true).expect("should fire");
+        assert_eq!((arrow.dx, arrow.dy), (1, 0));
+        assert_eq!(arrow.get_position(), (2, 5));
+        assert!(matches!(
+            arrow.owner,
+            crate::model::arrow::ArrowOwner::Enemy { damage } if damage > 0
+        ));
+
+        // It has to reload before the next shot
+        assert!(haunt.try_ranged_shot(&player, true).is_none());
+
+        // A player who closes in gets backed away from
+        let walkable: HashSet<(i32, i32)> =
+            (0..12).flat_map(|x| (0..12).map(move |y| (x, y))).collect();
+        let close = Position::new(3, 5);
+        let before = haunt.position.distance_to(&close);
+        haunt.ranged_step(&close, &walkable);
+        assert!(haunt.position.distance_to(&close) > before);
+    }
+
+    #[test]
+    fn test_heavy_hits_are_big_or_killing_blows() {
+        let mut enemy = Enemy::new(0, 0, 1.0);
+        enemy.max_health = 100;
+        enemy.health = 100;
+
+        enemy.take_damage(5);
+        assert!(!enemy.is_heavy_hit(5));
+        enemy.take_damage(30);
+        assert!(enemy.is_heavy_hit(30));
+        enemy.take_damage(65);
+        assert!(enemy.is_heavy_hit(65));
+    }
+
+    #[test]
+    fn test_telegraph_lands_only_on_shown_tiles() {
+        use crate::model::attack_pattern::AttackPattern;
+
+        // Enemy at (5, 5) slashing toward a player at (6, 5)
+        let pattern = AttackPattern::BasicSlash;
+        let telegraph = EnemyTelegraph {
+            frames: pattern.get_animation_frames(5, 5, 1, 0),
+            pattern,
+            damage: 4,
+            direction: (1.0, 0.0),
+            remaining: crate::constants::ENEMY_TELEGRAPH_TIME,
+        };
+
+        let shown = telegraph.tiles();
+        assert!(shown.contains(&(6, 5)));
+        for x in 3..9 {
+            for y in 3..8 {
+                assert_eq!(telegraph.lands_on(x, y), shown.contains(&(x, y)));
+            }
+        }
+        // Stepping out of the warning dodges the hit
+        assert!(!telegraph.lands_on(5, 5));
+        assert!(!telegraph.lands_on(7, 5));
+    }
+
+    #[test]
+    fn test_pack_members_pick_different_tiles_around_player() {
+        let walkable: HashSet<(i32, i32)> =
+            (0..12).flat_map(|x| (0..12).map(move |y| (x, y))).collect();
+        let player = Position::new(6, 6);
+
+        // Two of a kind coming down the same lane would otherwise queue up
+        let enemies = vec![Enemy::new(6, 2, 0.1), Enemy::new(6, 1, 0.1)];
+        let targets = assign_pack_targets(&enemies, &player, &walkable, DistanceMetric::Manhattan);
+        assert_eq!(targets.len(), 2);
+        assert_ne!(targets[&0], targets[&1]);
+        for &(x, y) in targets.values() {
+            assert_eq!(Position::new(x, y).distance_to(&player), 1);
+        }
+        // The closer enemy gets the tile on its side
+        assert_eq!(targets[&0], (6, 5));
+
+        // Stepping toward the claimed tiles splits the pair up
+        let mut pair = enemies.clone();
+        for _ in 0..6 {
+            for (i, enemy) in pair.iter_mut().enumerate() {
+                enemy.pack_step(targets[&i], &player, &walkable);
+            }
+        }
+        assert_eq!((pair[0].position.x, pair[0].position.y), targets[&0]);
+        assert_eq!((pair[1].position.x, pair[1].position.y), targets[&1]);
+
+        // A lone enemy, or one of another type, chases the player directly
+        let mut mixed = enemies.clone();
+        mixed[1].enemy_type = crate::model::enemy_type::EnemyType::Ghost;
+        assert!(
+            assign_pack_targets(&mixed, &player, &walkable, DistanceMetric::Manhattan).is_empty()
+        );
+    }
+
+    #[test]
+    fn test_detection_ring_matches_metric() {
+        let origin = Position::new(0, 0);
+        for metric in [DistanceMetric::Manhattan, DistanceMetric::Chebyshev] {
+            for radius in 1..6 {
+                let ring = metric.ring(radius);
+                let unique: HashSet<(i32, i32)> = ring.iter().copied().collect();
+                assert_eq!(unique.len(), ring.len(), "{} r{radius}", metric.name());
+                let expected = match metric {
+                    DistanceMetric::Manhattan => 4 * radius,
+                    DistanceMetric::Chebyshev => 8 * radius,
+                };
+                assert_eq!(ring.len() as i32, expected, "{} r{radius}", metric.name());
+                for (x, y) in ring {
+                    assert_eq!(Position::new(x, y).distance_with(&origin, metric), radius);
+                }
+            }
+        }
+
+        let mut enemy = Enemy::new(0, 0, 0.1);
+        enemy.detection_radius = 3;
+        assert!(enemy.detects(&Position::new(2, 1), DistanceMetric::Manhattan));
+        assert!(!enemy.detects(&Position::new(2, 2), DistanceMetric::Manhattan));
+        assert!(enemy.detects(&Position::new(2, 2), DistanceMetric::Chebyshev));
+    }
 }
@@ -4,7 +4,7 @@ use ratatui::prelude::{Color, Modifier, Style};
 use serde::{Deserialize, Serialize};
 
 /// Enemy rarity determines number of attacks, ultimate availability, and buff spells
-#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum EnemyRarity {
     Fighter,  // 1 attack, no ultimate, no buffs
     Guard,    // 1 attack, no ultimate, no buffs
@@ -14,6 +14,16 @@ pub enum EnemyRarity {
 }
 
 impl EnemyRarity {
+    pub fn name(&self) -> &'static str {
+        match self {
+            EnemyRarity::Fighter => "Fighter",
+            EnemyRarity::Guard => "Guard",
+            EnemyRarity::Champion => "Champion",
+            EnemyRarity::Elite => "Elite",
+            EnemyRarity::Boss => "Boss",
+        }
+    }
+
     pub fn num_attacks(&self) -> usize {
         match self {
             EnemyRarity::Fighter | EnemyRarity::Guard => 1,
@@ -79,6 +89,18 @@ impl EnemyRarity {
         (base * multiplier).ceil() as i32
     }
 
+    /// Fraction of incoming knockback force this tier actually takes
+    /// Heavier tiers resist being shoved so bosses can't be wall-locked
+    pub fn knockback_multiplier(&self) -> f32 {
+        match self {
+            EnemyRarity::Fighter => 1.0, // Full knockback
+            EnemyRarity::Guard => 0.9,
+            EnemyRarity::Champion => 0.75,
+            EnemyRarity::Elite => 0.55,
+            EnemyRarity::Boss => 0.05, // Barely budges
+        }
+    }
+
     /// Get unique ASCII art representation for this enemy type
     pub fn get_glyph(&self) -> &'static str {
         match self {
@@ -101,14 +123,15 @@ impl EnemyRarity {
         }
     }
 
-    /// Get text style for this enemy rarity with modifier for threat indication
-    pub fn get_style(&self) -> Style {
+    /// Get text style for this enemy rarity with modifier for threat indication.
+    /// Low detail mode drops the blink, which is slow or unreadable on some terminals
+    pub fn get_style(&self, low_detail: bool) -> Style {
         let base = Style::default()
             .fg(self.get_color())
             .add_modifier(Modifier::BOLD);
 
         match self {
-            EnemyRarity::Elite | EnemyRarity::Boss => {
+            EnemyRarity::Elite | EnemyRarity::Boss if !low_detail => {
                 // Elites and Bosses get rapid blink to indicate high threat
                 base.add_modifier(Modifier::RAPID_BLINK)
             }
@@ -118,13 +141,21 @@ impl EnemyRarity {
 }
 
 /// Whether enemy is undead (physical, fire weak) or ghost (ethereal, magic weak)
-#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq, Hash, Default)]
 pub enum EnemyType {
+    #[default]
     Undead,
     Ghost,
 }
 
 impl EnemyType {
+    pub fn name(&self) -> &'static str {
+        match self {
+            EnemyType::Undead => "Undead",
+            EnemyType::Ghost => "Ghost",
+        }
+    }
+
     /// Ghosts take reduced physical damage and no wall collision
     pub fn damage_multiplier(&self, damage_type: &AttackType) -> f32 {
         match self {
@@ -149,6 +180,14 @@ impl EnemyType {
     pub fn passes_through_walls(&self) -> bool {
         matches!(self, EnemyType::Ghost)
     }
+
+    /// Ghosts are floaty and get pushed further than solid undead
+    pub fn knockback_multiplier(&self) -> f32 {
+        match self {
+            EnemyType::Undead => 1.0,
+            EnemyType::Ghost => 1.4,
+        }
+    }
 }
 
 /// Attack type determines damage and effects
@@ -177,9 +216,12 @@ pub struct EnemyAttack {
 }
 
 impl EnemyAttack {
+    /// Roll damage uniformly within `damage_min..=damage_max`. A max below the min
+    /// (e.g. after rounding when scaling) always rolls the min
     pub fn damage(&self) -> i32 {
-        let diff = self.damage_max - self.damage_min;
-        self.damage_min + (rand::random::<i32>() % (diff + 1).max(1))
+        use rand::RngExt;
+        let mut rng = rand::rng();
+        rng.random_range(self.damage_min..=self.damage_max.max(self.damage_min))
     }
 
     pub fn is_available(&self) -> bool {
@@ -322,6 +364,7 @@ pub fn create_rotting_footsoldier() -> EnemyTemplate {
         }],
         ultimate: None,
         buffs: vec![],
+        flee_threshold: None,
     }
 }
 
@@ -347,6 +390,7 @@ pub fn create_grave_scrabbler() -> EnemyTemplate {
         }],
         ultimate: None,
         buffs: vec![],
+        flee_threshold: None,
     }
 }
 
@@ -372,6 +416,7 @@ pub fn create_whispering_shade() -> EnemyTemplate {
         }],
         ultimate: None,
         buffs: vec![],
+        flee_threshold: Some(0.3),
     }
 }
 
@@ -397,6 +442,7 @@ pub fn create_crypt_sentinel() -> EnemyTemplate {
         }],
         ultimate: None,
         buffs: vec![EnemyBuff::Armor(30)],
+        flee_threshold: None,
     }
 }
 
@@ -422,6 +468,7 @@ pub fn create_tomb_watcher() -> EnemyTemplate {
         }],
         ultimate: None,
         buffs: vec![],
+        flee_threshold: None,
     }
 }
 
@@ -447,6 +494,7 @@ pub fn create_wailing_doorwarden() -> EnemyTemplate {
         }],
         ultimate: None,
         buffs: vec![],
+        flee_threshold: None,
     }
 }
 
@@ -496,6 +544,7 @@ pub fn create_blight_captain() -> EnemyTemplate {
             pattern: AttackPattern::Fireball(3),
         }),
         buffs: vec![EnemyBuff::Armor(15), EnemyBuff::Sharpness(10)],
+        flee_threshold: None,
     }
 }
 
@@ -545,6 +594,7 @@ pub fn create_veilbound_duelist() -> EnemyTemplate {
             pattern: AttackPattern::BasicSlash,
         }),
         buffs: vec![EnemyBuff::Speed(20), EnemyBuff::PhaseShift],
+        flee_threshold: None,
     }
 }
 
@@ -606,6 +656,7 @@ pub fn create_corpse_abomination() -> EnemyTemplate {
             pattern: AttackPattern::Fireball(2),
         }),
         buffs: vec![EnemyBuff::Regeneration(2), EnemyBuff::Armor(20)],
+        flee_threshold: None,
     }
 }
 
@@ -667,6 +718,7 @@ pub fn create_lantern_haunt() -> EnemyTemplate {
             pattern: AttackPattern::MeteorShower(5, 2),
         }),
         buffs: vec![EnemyBuff::EchoAmplification, EnemyBuff::Speed(15)],
+        flee_threshold: Some(0.3),
     }
 }
 
@@ -756,6 +808,7 @@ pub fn create_ossuary_king() -> EnemyTemplate {
             EnemyBuff::Regeneration(3),
             EnemyBuff::Sharpness(20),
         ],
+        flee_threshold: None,
     }
 }
 
@@ -845,6 +898,7 @@ pub fn create_mourning_bell() -> EnemyTemplate {
             EnemyBuff::Speed(25),
             EnemyBuff::PhaseShift,
         ],
+        flee_threshold: None,
     }
 }
 
@@ -860,6 +914,25 @@ pub struct EnemyTemplate {
     pub attacks: Vec<EnemyAttack>,
     pub ultimate: Option<EnemyUltimate>,
     pub buffs: Vec<EnemyBuff>,
+    pub flee_threshold: Option<f32>, // Runs from the player below this fraction of max health
+}
+
+/// Every enemy template, weakest first, for the dev menu spawner
+pub fn all_enemy_templates() -> Vec<EnemyTemplate> {
+    vec![
+        create_rotting_footsoldier(),
+        create_grave_scrabbler(),
+        create_whispering_shade(),
+        create_crypt_sentinel(),
+        create_tomb_watcher(),
+        create_wailing_doorwarden(),
+        create_blight_captain(),
+        create_veilbound_duelist(),
+        create_corpse_abomination(),
+        create_lantern_haunt(),
+        create_ossuary_king(),
+        create_mourning_bell(),
+    ]
 }
 
 /// Helper to get all available enemy templates scaled by difficulty
@@ -969,4 +1042,51 @@ mod tests {
         let boss = create_ossuary_king();
         assert_eq!(boss.health, 120);
     }
+
+    #[test]
+    fn test_low_detail_style_does_not_blink() {
+        let boss = EnemyRarity::Boss;
+        assert!(boss
+            .get_style(false)
+            .add_modifier
+            .contains(Modifier::RAPID_BLINK));
+        assert!(!boss
+            .get_style(true)
+            .add_modifier
+            .contains(Modifier::RAPID_BLINK));
+        assert!(boss.get_style(true).add_modifier.contains(Modifier::BOLD));
+    }
+
+    #[test]
+    fn test_damage_rolls_stay_in_range() {
+        for attack in all_enemy_templates()
+            .into_iter()
+            .flat_map(|template| template.attacks)
+        {
+            for _ in 0..500 {
+                let damage = attack.damage();
+                assert!(
+                    (attack.damage_min..=attack.damage_max).contains(&damage),
+                    "{} rolled {} outside {}..={}",
+                    attack.name,
+                    damage,
+                    attack.damage_min,
+                    attack.damage_max
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_damage_roll_covers_both_ends() {
+        let mut attack = create_rotting_footsoldier().attacks[0].clone();
+        attack.damage_min = 3;
+        attack.damage_max = 5;
+        let rolls: Vec<i32> = (0..500).map(|_| attack.damage()).collect();
+        assert!(rolls.contains(&3) && rolls.contains(&5));
+
+        // A max below the min is treated as a fixed roll
+        attack.damage_max = 1;
+        assert!((0..100).all(|_| attack.damage() == 3));
+    }
 }
@@ -1,9 +1,11 @@
+use crate::model::floor_theme::FloorTheme;
+use crate::model::hazard::SpikeTrap;
 use crate::model::item::ItemDrop;
 use rand::rngs::StdRng;
-use rand::{Rng, RngExt, SeedableRng};
+use rand::{RngExt, SeedableRng};
 use ratatui::style::{Color, Style};
 use serde::{Deserialize, Serialize};
-use std::collections::VecDeque;
+use std::collections::{HashMap, HashSet, VecDeque};
 
 #[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, Serialize, Deserialize)]
 pub struct RoomId(pub usize);
@@ -15,6 +17,88 @@ pub struct Room {
     pub tiles: Vec<(i32, i32)>,
 }
 
+/// How connect_caves joins separate caves
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum TunnelStyle {
+    /// Horizontal run then vertical run
+    #[default]
+    LShaped,
+    /// Direct line between cave centers
+    Straight,
+}
+
+impl TunnelStyle {
+    pub fn name(&self) -> &'static str {
+        match self {
+            TunnelStyle::LShaped => "L-Shaped",
+            TunnelStyle::Straight => "Straight",
+        }
+    }
+
+    pub fn toggled(&self) -> Self {
+        match self {
+            TunnelStyle::LShaped => TunnelStyle::Straight,
+            TunnelStyle::Straight => TunnelStyle::LShaped,
+        }
+    }
+}
+
+/// Tunable inputs to floor generation
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct FloorGenParams {
+    pub width: i32,
+    pub height: i32,
+    pub fill_probability: u32, // Percentage of tiles seeded as open floor
+    pub iterations: u32,       // Cellular automata smoothing passes
+    #[serde(default)]
+    pub tunnel_style: TunnelStyle,
+    #[serde(default = "default_tunnel_width")]
+    pub tunnel_width: u32, // Tiles across each carved tunnel, 1 = no widening
+}
+
+fn default_tunnel_width() -> u32 {
+    crate::constants::TUNNEL_WIDTH
+}
+
+impl Default for FloorGenParams {
+    fn default() -> Self {
+        Self {
+            width: crate::constants::FLOOR_WIDTH,
+            height: crate::constants::FLOOR_HEIGHT,
+            fill_probability: crate::constants::FILL_PROBABILITY,
+            iterations: crate::constants::CELLULAR_AUTOMATA_ITERATIONS,
+            tunnel_style: TunnelStyle::LShaped,
+            tunnel_width: default_tunnel_width(),
+        }
+    }
+}
+
+impl FloorGenParams {
+    /// Clamp to values that still generate a playable floor. The floor is never
+    /// smaller than the `viewport` it is drawn into, so the camera has room to work.
+    pub fn clamped(&self, viewport: (u16, u16)) -> Self {
+        use crate::constants::{
+            FILL_PROBABILITY_MAX, FILL_PROBABILITY_MIN, FLOOR_MAX_HEIGHT, FLOOR_MAX_WIDTH,
+            FLOOR_MIN_HEIGHT, FLOOR_MIN_WIDTH, MAX_CELLULAR_AUTOMATA_ITERATIONS, MAX_TUNNEL_WIDTH,
+        };
+
+        let min_width = FLOOR_MIN_WIDTH.max(viewport.0 as i32).min(FLOOR_MAX_WIDTH);
+        let min_height = FLOOR_MIN_HEIGHT
+            .max(viewport.1 as i32)
+            .min(FLOOR_MAX_HEIGHT);
+        Self {
+            width: self.width.clamp(min_width, FLOOR_MAX_WIDTH),
+            height: self.height.clamp(min_height, FLOOR_MAX_HEIGHT),
+            fill_probability: self
+                .fill_probability
+                .clamp(FILL_PROBABILITY_MIN, FILL_PROBABILITY_MAX),
+            iterations: self.iterations.min(MAX_CELLULAR_AUTOMATA_ITERATIONS),
+            tunnel_style: self.tunnel_style,
+            tunnel_width: self.tunnel_width.clamp(1, MAX_TUNNEL_WIDTH),
+        }
+    }
+}
+
 #[derive(Clone, Serialize, Deserialize, Debug)]
 pub struct Floor {
     pub width: i32,
@@ -25,13 +109,68 @@ pub struct Floor {
     pub tile_to_room: Vec<Option<RoomId>>,
     pub items: Vec<ItemDrop>,
     pub enemies: Vec<crate::model::enemy::Enemy>,
+    #[serde(default)]
+    pub stairs: Option<(i32, i32)>, // Exit tile, placed once all enemies are defeated
+    #[serde(default)]
+    pub treasure_room: Option<RoomId>, // Room stocked with guaranteed loot, farthest from the player
+    #[serde(default)]
+    pub theme: FloorTheme, // Color palette of walls and floors
+    #[serde(default)]
+    pub spike_traps: Vec<SpikeTrap>, // Walkable tiles that periodically hurt whoever stands on them
     #[serde(skip)]
     pub styled_tile_cache: Vec<(char, u8)>, // (glyph, color_index) - cache to avoid re-computing every frame
+    #[serde(skip)]
+    pub explored: Vec<bool>, // Fog of war: tiles the player has seen at least once
+    #[serde(skip)]
+    pub visible: Vec<bool>, // Fog of war: tiles in view from the last player position
+    #[serde(skip)]
+    pub enemy_grid: HashMap<(i32, i32), Vec<usize>>, // Alive enemy indices by tile, rebuilt each tick
 }
 
 impl Floor {
+    /// Floor of the given size with the default generation settings
+    #[allow(dead_code)]
     pub fn new(width: i32, height: i32, seed: u64) -> Self {
-        let mut floor = Self {
+        Self::with_params(
+            &FloorGenParams {
+                width,
+                height,
+                ..FloorGenParams::default()
+            },
+            seed,
+        )
+    }
+
+    pub fn with_params(params: &FloorGenParams, seed: u64) -> Self {
+        let mut floor = Self::solid(params.width, params.height, seed);
+        floor.generate(params);
+        floor.detect_rooms();
+        floor.rebuild_styled_tile_cache();
+        floor
+    }
+
+    /// Hand-authored floor: `#` is wall and every other character is open floor.
+    /// Short rows are padded with wall.
+    pub fn from_layout(rows: &[&str], seed: u64) -> Self {
+        let width = rows
+            .iter()
+            .map(|row| row.chars().count())
+            .max()
+            .unwrap_or(0) as i32;
+        let mut floor = Self::solid(width, rows.len() as i32, seed);
+        for (y, row) in rows.iter().enumerate() {
+            for (x, ch) in row.chars().enumerate() {
+                floor.tiles[y * width as usize + x] = ch == '#';
+            }
+        }
+        floor.detect_rooms();
+        floor.rebuild_styled_tile_cache();
+        floor
+    }
+
+    /// All-wall floor with empty item, enemy and fog state, ready to be carved out
+    fn solid(width: i32, height: i32, seed: u64) -> Self {
+        Self {
             width,
             height,
             tiles: vec![true; (width * height) as usize],
@@ -40,12 +179,15 @@ impl Floor {
             tile_to_room: vec![None; (width * height) as usize],
             items: Vec::new(),
             enemies: Vec::new(),
+            stairs: None,
+            treasure_room: None,
+            theme: FloorTheme::default(),
+            spike_traps: Vec::new(),
             styled_tile_cache: Vec::new(), // Will be populated after generation
-        };
-        floor.generate();
-        floor.detect_rooms();
-        floor.rebuild_styled_tile_cache();
-        floor
+            explored: vec![false; (width * height) as usize],
+            visible: vec![false; (width * height) as usize],
+            enemy_grid: HashMap::new(),
+        }
     }
 
     #[allow(dead_code)]
@@ -61,10 +203,10 @@ impl Floor {
         result
     }
 
-    fn generate(&mut self) {
+    fn generate(&mut self, params: &FloorGenParams) {
         let mut rng = StdRng::seed_from_u64(self.seed);
 
-        let fill_probability = 45;
+        let fill_probability = params.fill_probability as i32;
         for y in 0..self.height {
             for x in 0..self.width {
                 if y == 0 || y == self.height - 1 || x == 0 || x == self.width - 1 {
@@ -77,8 +219,8 @@ impl Floor {
             }
         }
 
-        let total_iterations = 5;
-        let cutoff_big_area = 3;
+        let total_iterations = params.iterations;
+        let cutoff_big_area = crate::constants::CUTOFF_BIG_AREA as u32;
 
         for iteration in 0..total_iterations {
             let mut new_tiles = self.tiles.clone();
@@ -102,7 +244,7 @@ impl Floor {
             self.tiles = new_tiles;
         }
 
-        self.connect_caves();
+        self.connect_caves(params);
     }
 
     pub fn get_tile(&self, x: i32, y: i32) -> bool {
@@ -137,7 +279,7 @@ impl Floor {
         count
     }
 
-    fn connect_caves(&mut self) {
+    fn connect_caves(&mut self, params: &FloorGenParams) {
         let map_sections = self.get_map_sections();
 
         if map_sections.len() <= 1 {
@@ -145,6 +287,7 @@ impl Floor {
         }
 
         let mut union_find = UnionFind::new(map_sections.len());
+        let mut carved = Vec::new();
 
         while union_find.count() > 1 {
             for i in 0..map_sections.len() {
@@ -153,11 +296,13 @@ impl Floor {
                 if closest_idx != i && !union_find.connected(i, closest_idx) {
                     let (x1, y1) = map_sections[i].center();
                     let (x2, y2) = map_sections[closest_idx].center();
-                    self.carve_tunnel(x1, y1, x2, y2);
+                    carved.extend(self.carve_tunnel(x1, y1, x2, y2, params.tunnel_style));
                     union_find.union(i, closest_idx);
                 }
             }
         }
+
+        self.widen_tunnels(&carved, params.tunnel_width);
     }
 
     fn get_map_sections(&self) -> Vec<MapSection> {
@@ -231,14 +376,70 @@ impl Floor {
         (x1 - x2).abs() + (y1 - y2).abs()
     }
 
-    fn carve_tunnel(&mut self, mut x1: i32, mut y1: i32, x2: i32, y2: i32) {
-        while x1 != x2 {
-            self.set_tile(x1, y1, false);
-            x1 += if x1 < x2 { 1 } else { -1 };
+    /// Open a tunnel between two points and return the tiles it carved
+    fn carve_tunnel(
+        &mut self,
+        mut x1: i32,
+        mut y1: i32,
+        x2: i32,
+        y2: i32,
+        style: TunnelStyle,
+    ) -> Vec<(i32, i32)> {
+        let mut path = Vec::new();
+        match style {
+            TunnelStyle::LShaped => {
+                while x1 != x2 {
+                    path.push((x1, y1));
+                    x1 += if x1 < x2 { 1 } else { -1 };
+                }
+                while y1 != y2 {
+                    path.push((x1, y1));
+                    y1 += if y1 < y2 { 1 } else { -1 };
+                }
+            }
+            TunnelStyle::Straight => {
+                // Bresenham line; diagonal steps also open the side tile so the
+                // tunnel stays walkable with 4-way movement
+                let (dx, dy) = ((x2 - x1).abs(), -(y2 - y1).abs());
+                let (sx, sy) = ((x2 - x1).signum(), (y2 - y1).signum());
+                let mut err = dx + dy;
+                while (x1, y1) != (x2, y2) {
+                    path.push((x1, y1));
+                    let e2 = 2 * err;
+                    let step_x = e2 >= dy;
+                    let step_y = e2 <= dx;
+                    if step_x {
+                        err += dy;
+                        x1 += sx;
+                    }
+                    if step_y {
+                        if step_x {
+                            path.push((x1, y1));
+                        }
+                        err += dx;
+                        y1 += sy;
+                    }
+                }
+            }
         }
-        while y1 != y2 {
-            self.set_tile(x1, y1, false);
-            y1 += if y1 < y2 { 1 } else { -1 };
+        for &(x, y) in &path {
+            self.set_tile(x, y, false);
+        }
+        path
+    }
+
+    /// Clear walls beside carved tunnel tiles so each tunnel is `width` tiles across.
+    /// The outer boundary wall is never touched.
+    fn widen_tunnels(&mut self, carved: &[(i32, i32)], width: u32) {
+        let extra = width.saturating_sub(1) as i32;
+        for &(x, y) in carved {
+            for ny in y..=y + extra {
+                for nx in x..=x + extra {
+                    if nx > 0 && nx < self.width - 1 && ny > 0 && ny < self.height - 1 {
+                        self.set_tile(nx, ny, false);
+                    }
+                }
+            }
         }
     }
 
@@ -265,59 +466,137 @@ impl Floor {
         true
     }
 
+    /// Split the cave into rooms: open chambers grow outward from their cores until
+    /// they meet, so every walkable tile belongs to exactly one room and narrow
+    /// passages are shared out between the chambers they join
     fn detect_rooms(&mut self) {
-        let mut visited = vec![false; (self.width * self.height) as usize];
-        let mut room_list = Vec::new();
+        use crate::constants::{ROOM_CORE_WALL_DISTANCE, ROOM_MIN_CORE_TILES};
 
-        for y in 0..self.height {
-            for x in 0..self.width {
-                let idx = (y * self.width + x) as usize;
-                if !self.tiles[idx] && !visited[idx] {
-                    let mut room_tiles = Vec::new();
-                    let mut queue = VecDeque::new();
+        let size = (self.width * self.height) as usize;
+        let neighbours = [(0, 1), (0, -1), (1, 0), (-1, 0)];
+
+        // Distance from every floor tile to the nearest wall
+        let mut wall_distance = vec![0u32; size];
+        let mut queue = VecDeque::new();
+        for (idx, &is_wall) in self.tiles.iter().enumerate() {
+            if is_wall {
+                queue.push_back(idx);
+            } else {
+                wall_distance[idx] = u32::MAX;
+            }
+        }
+        while let Some(idx) = queue.pop_front() {
+            let (x, y) = ((idx as i32) % self.width, (idx as i32) / self.width);
+            for dy in -1..=1 {
+                for dx in -1..=1 {
+                    let (nx, ny) = (x + dx, y + dy);
+                    if nx < 0 || nx >= self.width || ny < 0 || ny >= self.height {
+                        continue;
+                    }
+                    let n_idx = (ny * self.width + nx) as usize;
+                    if wall_distance[n_idx] > wall_distance[idx] + 1 {
+                        wall_distance[n_idx] = wall_distance[idx] + 1;
+                        queue.push_back(n_idx);
+                    }
+                }
+            }
+        }
 
-                    queue.push_back((x, y));
-                    visited[idx] = true;
+        // Seed a room from each sizeable patch of tiles far from any wall
+        let is_core = |idx: usize| wall_distance[idx] >= ROOM_CORE_WALL_DISTANCE;
+        let mut room_of: Vec<Option<usize>> = vec![None; size];
+        let mut seen = vec![false; size];
+        let mut room_count = 0;
+        let mut growth = VecDeque::new();
+        for start in 0..size {
+            if seen[start] || !is_core(start) {
+                continue;
+            }
+            seen[start] = true;
+            let mut core = vec![start];
+            let mut i = 0;
+            while i < core.len() {
+                let (x, y) = ((core[i] as i32) % self.width, (core[i] as i32) / self.width);
+                for (dx, dy) in neighbours {
+                    let (nx, ny) = (x + dx, y + dy);
+                    if nx < 0 || nx >= self.width || ny < 0 || ny >= self.height {
+                        continue;
+                    }
+                    let n_idx = (ny * self.width + nx) as usize;
+                    if !seen[n_idx] && is_core(n_idx) {
+                        seen[n_idx] = true;
+                        core.push(n_idx);
+                    }
+                }
+                i += 1;
+            }
 
-                    while let Some((cx, cy)) = queue.pop_front() {
-                        room_tiles.push((cx, cy));
+            // Smaller patches are left for a neighbouring room to absorb
+            if core.len() >= ROOM_MIN_CORE_TILES {
+                for &idx in &core {
+                    room_of[idx] = Some(room_count);
+                }
+                growth.extend(core);
+                room_count += 1;
+            }
+        }
 
-                        for (dx, dy) in &[(0, 1), (0, -1), (1, 0), (-1, 0)] {
-                            let nx = cx + dx;
-                            let ny = cy + dy;
-                            if nx >= 0 && nx < self.width && ny >= 0 && ny < self.height {
-                                let n_idx = (ny * self.width + nx) as usize;
-                                if !self.tiles[n_idx] && !visited[n_idx] {
-                                    visited[n_idx] = true;
-                                    queue.push_back((nx, ny));
-                                }
-                            }
-                        }
+        // Grow the rooms over the remaining floor, and give any pocket that had
+        // no core a room of its own
+        let mut pending = growth;
+        let mut next_pocket = 0;
+        loop {
+            while let Some(idx) = pending.pop_front() {
+                let (x, y) = ((idx as i32) % self.width, (idx as i32) / self.width);
+                for (dx, dy) in neighbours {
+                    let (nx, ny) = (x + dx, y + dy);
+                    if nx < 0 || nx >= self.width || ny < 0 || ny >= self.height {
+                        continue;
                     }
-
-                    if !room_tiles.is_empty() {
-                        let sum_x: i32 = room_tiles.iter().map(|(x, _)| x).sum();
-                        let sum_y: i32 = room_tiles.iter().map(|(_, y)| y).sum();
-                        let center = (
-                            sum_x / room_tiles.len() as i32,
-                            sum_y / room_tiles.len() as i32,
-                        );
-
-                        let room_id = RoomId(room_list.len());
-                        let room = Room {
-                            id: room_id,
-                            center,
-                            tiles: room_tiles.clone(),
-                        };
-                        room_list.push(room);
-
-                        for (tx, ty) in room_tiles {
-                            let tile_idx = (ty * self.width + tx) as usize;
-                            self.tile_to_room[tile_idx] = Some(room_id);
-                        }
+                    let n_idx = (ny * self.width + nx) as usize;
+                    if !self.tiles[n_idx] && room_of[n_idx].is_none() {
+                        room_of[n_idx] = room_of[idx];
+                        pending.push_back(n_idx);
                     }
                 }
             }
+            match (next_pocket..size).find(|&idx| !self.tiles[idx] && room_of[idx].is_none()) {
+                Some(idx) => {
+                    room_of[idx] = Some(room_count);
+                    room_count += 1;
+                    pending.push_back(idx);
+                    next_pocket = idx + 1;
+                }
+                None => break,
+            }
+        }
+
+        let mut room_tiles: Vec<Vec<(i32, i32)>> = vec![Vec::new(); room_count];
+        for y in 0..self.height {
+            for x in 0..self.width {
+                let idx = (y * self.width + x) as usize;
+                if let Some(room) = room_of[idx] {
+                    room_tiles[room].push((x, y));
+                }
+            }
+        }
+
+        let mut room_list = Vec::new();
+        for tiles in room_tiles.into_iter().filter(|tiles| !tiles.is_empty()) {
+            let sum_x: i32 = tiles.iter().map(|(x, _)| x).sum();
+            let sum_y: i32 = tiles.iter().map(|(_, y)| y).sum();
+            let center = (sum_x / tiles.len() as i32, sum_y / tiles.len() as i32);
+
+            let room_id = RoomId(room_list.len());
+            for &(tx, ty) in &tiles {
+                let tile_idx = (ty * self.width + tx) as usize;
+                self.tile_to_room[tile_idx] = Some(room_id);
+            }
+            room_list.push(Room {
+                id: room_id,
+                center,
+                tiles,
+            });
         }
 
         self.rooms = room_list;
@@ -360,44 +639,56 @@ impl Floor {
         None
     }
 
+    /// Switch to another color palette and restyle every tile
+    pub fn set_theme(&mut self, theme: FloorTheme) {
+        self.theme = theme;
+        self.rebuild_styled_tile_cache();
+    }
+
     /// Pre-compute all styled tiles (glyph + color) to cache sprite generation
     fn rebuild_styled_tile_cache(&mut self) {
         let size = (self.width * self.height) as usize;
-        self.styled_tile_cache.clear();
-        self.styled_tile_cache.reserve(size);
-
-        for idx in 0..size {
-            let x = (idx as i32) % self.width;
-            let y = (idx as i32) / self.width;
-            let is_wall = self.tiles[idx];
-
-            let (ch, color_idx) = if is_wall {
-                let wall_chars = ['$', '#', '+', '*', '%', '=', '÷', '×'];
-                let seed = (x as u64) << 32 | (y as u64);
-                let mut rng = StdRng::seed_from_u64(seed);
-                let ch = wall_chars[rng.random_range(0..wall_chars.len())];
-
-                let nearby_floors = self.count_nearby_floors(x, y, 2);
-                let color_idx = match nearby_floors {
-                    0..=1 => 236,
-                    2..=3 => 238,
-                    4..=5 => 240,
-                    _ => 242,
-                };
-                (ch, color_idx)
-            } else {
-                let wall_proximity = self.count_walls_near(x, y, 1);
-                let color_idx = match wall_proximity {
-                    0..=2 => 246,
-                    3..=4 => 244,
-                    5..=6 => 242,
-                    7..=8 => 240,
-                    _ => 238,
-                };
-                ('.', color_idx)
-            };
+        self.styled_tile_cache = (0..size)
+            .map(|idx| self.compute_styled_tile(idx as i32 % self.width, idx as i32 / self.width))
+            .collect();
+    }
 
-            self.styled_tile_cache.push((ch, color_idx));
+    /// Glyph and color index for a tile; walls shade by nearby floor, floors by nearby walls
+    fn compute_styled_tile(&self, x: i32, y: i32) -> (char, u8) {
+        if self.get_tile(x, y) {
+            let wall_chars = ['$', '#', '+', '*', '%', '=', '÷', '×'];
+
+            // Stable per-tile RNG (no flicker, still "random")
+            let seed = (x as u64) << 32 | (y as u64);
+            let mut rng = StdRng::seed_from_u64(seed);
+            let ch = wall_chars[rng.random_range(0..wall_chars.len())];
+
+            let nearby_floors = self.count_nearby_floors(x, y, 2);
+            (ch, self.theme.wall_color(nearby_floors))
+        } else {
+            // Treasure room floors carry a faint gold tint
+            if self.treasure_room.is_some() && self.room_at(x, y) == self.treasure_room {
+                return ('.', crate::constants::TREASURE_FLOOR_COLOR);
+            }
+            let wall_proximity = self.count_walls_near(x, y, 1);
+            ('.', self.theme.floor_color(wall_proximity))
+        }
+    }
+
+    /// Change a tile after generation, refreshing the cached styles it affects
+    #[allow(dead_code)]
+    pub fn set_wall(&mut self, x: i32, y: i32, is_wall: bool) {
+        self.set_tile(x, y, is_wall);
+        if self.styled_tile_cache.len() != self.tiles.len() {
+            self.rebuild_styled_tile_cache();
+            return;
+        }
+        // Wall shading looks two tiles out, so that is as far as a change can reach
+        for ny in (y - 2).max(0)..=(y + 2).min(self.height - 1) {
+            for nx in (x - 2).max(0)..=(x + 2).min(self.width - 1) {
+                let idx = (ny * self.width + nx) as usize;
+                self.styled_tile_cache[idx] = self.compute_styled_tile(nx, ny);
+            }
         }
     }
 
@@ -415,6 +706,74 @@ impl Floor {
         }
     }
 
+    /// Bresenham line check - walls block sight, but the target tile itself may be a wall
+    pub fn has_line_of_sight(&self, x0: i32, y0: i32, x1: i32, y1: i32) -> bool {
+        let dx = (x1 - x0).abs();
+        let dy = -(y1 - y0).abs();
+        let sx = if x0 < x1 { 1 } else { -1 };
+        let sy = if y0 < y1 { 1 } else { -1 };
+        let mut err = dx + dy;
+        let (mut x, mut y) = (x0, y0);
+
+        while (x, y) != (x1, y1) {
+            if (x, y) != (x0, y0) && self.get_tile(x, y) {
+                return false;
+            }
+            let e2 = 2 * err;
+            if e2 >= dy {
+                err += dy;
+                x += sx;
+            }
+            if e2 <= dx {
+                err += dx;
+                y += sy;
+            }
+        }
+        true
+    }
+
+    /// Recompute visible tiles around the player and mark them explored
+    /// Only call this when the player moves - it walks a line per tile in range
+    pub fn update_visibility(&mut self, px: i32, py: i32, radius: i32) {
+        let size = (self.width * self.height) as usize;
+        if self.explored.len() != size {
+            self.explored = vec![false; size];
+        }
+        self.visible.clear();
+        self.visible.resize(size, false);
+
+        for y in (py - radius).max(0)..=(py + radius).min(self.height - 1) {
+            for x in (px - radius).max(0)..=(px + radius).min(self.width - 1) {
+                let (dx, dy) = (x - px, y - py);
+                if dx * dx + dy * dy <= radius * radius && self.has_line_of_sight(px, py, x, y) {
+                    let idx = (y * self.width + x) as usize;
+                    self.visible[idx] = true;
+                    self.explored[idx] = true;
+                }
+            }
+        }
+    }
+
+    pub fn is_visible(&self, x: i32, y: i32) -> bool {
+        if x < 0 || x >= self.width || y < 0 || y >= self.height {
+            return false;
+        }
+        self.visible
+            .get((y * self.width + x) as usize)
+            .copied()
+            .unwrap_or(false)
+    }
+
+    pub fn is_explored(&self, x: i32, y: i32) -> bool {
+        if x < 0 || x >= self.width || y < 0 || y >= self.height {
+            return false;
+        }
+        self.explored
+            .get((y * self.width + x) as usize)
+            .copied()
+            .unwrap_or(false)
+    }
+
     pub fn styled_grid(&self) -> Vec<(i32, i32, char, Style)> {
         let mut result = Vec::new();
 
@@ -424,24 +783,12 @@ impl Floor {
 
             let ch = if is_wall { '█' } else { '.' };
 
-            let style = if is_wall {
-                let nearby_floors = self.count_nearby_floors(x, y, 2);
-                match nearby_floors {
-                    0..=1 => Style::new().fg(Color::Indexed(236)),
-                    2..=3 => Style::new().fg(Color::Indexed(238)),
-                    4..=5 => Style::new().fg(Color::Indexed(240)),
-                    _ => Style::new().fg(Color::Indexed(242)),
-                }
+            let color_idx = if is_wall {
+                self.theme.wall_color(self.count_nearby_floors(x, y, 2))
             } else {
-                let wall_proximity = self.count_walls_near(x, y, 1);
-                match wall_proximity {
-                    0..=2 => Style::new().fg(Color::Indexed(246)),
-                    3..=4 => Style::new().fg(Color::Indexed(244)),
-                    5..=6 => Style::new().fg(Color::Indexed(242)),
-                    7..=8 => Style::new().fg(Color::Indexed(240)),
-                    _ => Style::new().fg(Color::Indexed(238)),
-                }
+                self.theme.floor_color(self.count_walls_near(x, y, 1))
             };
+            let style = Style::new().fg(Color::Indexed(color_idx));
 
             result.push((x, y, ch, style));
         });
@@ -453,42 +800,8 @@ impl Floor {
         if x < 0 || x >= self.width || y < 0 || y >= self.height {
             return None;
         }
-
-        let is_wall = self.get_tile(x, y);
-
-        if is_wall {
-            let wall_chars = ['$', '#', '+', '*', '%', '=', '÷', '×'];
-
-            // Stable per-tile RNG (no flicker, still "random")
-            let seed = (x as u64) << 32 | (y as u64);
-            let mut rng = StdRng::seed_from_u64(seed);
-
-            let ch = wall_chars[rng.random_range(0..wall_chars.len())];
-            let nearby_floors = self.count_nearby_floors(x, y, 2);
-            // Consistent wall color
-            let color = match nearby_floors {
-                0..=1 => Color::Indexed(236),
-                2..=3 => Color::Indexed(238),
-                4..=5 => Color::Indexed(240),
-                _ => Color::Indexed(242),
-            };
-
-            let style: Style = Style::new().fg(color);
-            Some((ch, style))
-        } else {
-            let wall_proximity = self.count_walls_near(x, y, 1);
-            let ch = '.';
-            let color = match wall_proximity {
-                0..=2 => Color::Indexed(246),
-                3..=4 => Color::Indexed(244),
-                5..=6 => Color::Indexed(242),
-                7..=8 => Color::Indexed(240),
-                _ => Color::Indexed(238),
-            };
-
-            let style = Style::new().fg(color);
-            Some((ch, style))
-        }
+        let (ch, color_idx) = self.compute_styled_tile(x, y);
+        Some((ch, Style::new().fg(Color::Indexed(color_idx))))
     }
 
     fn count_nearby_floors(&self, x: i32, y: i32, distance: i32) -> i32 {
@@ -539,6 +852,22 @@ impl Floor {
         self.items.push(item);
     }
 
+    /// Add an item, folding gold into an existing gold pile on the same tile
+    pub fn add_item_merged(&mut self, item: ItemDrop) {
+        use crate::model::item::ItemDropType;
+        if let ItemDropType::Gold(amount) = item.item_type {
+            if let Some(pile) = self
+                .items
+                .iter_mut()
+                .find(|other| other.x == item.x && other.y == item.y && other.item_type.is_gold())
+            {
+                pile.merge_gold(amount, item.pile_count);
+                return;
+            }
+        }
+        self.items.push(item);
+    }
+
     pub fn items_at(&self, x: i32, y: i32) -> Vec<&ItemDrop> {
         self.items
             .iter()
@@ -554,12 +883,23 @@ impl Floor {
             .collect()
     }
 
+    #[allow(dead_code)]
     pub fn pickup_item(&mut self, x: i32, y: i32) -> Option<ItemDrop> {
+        self.pickup_item_where(x, y, |_| true)
+    }
+
+    /// Remove and return the first item at (x, y) that `wanted` accepts
+    pub fn pickup_item_where(
+        &mut self,
+        x: i32,
+        y: i32,
+        wanted: impl Fn(&ItemDrop) -> bool,
+    ) -> Option<ItemDrop> {
         // Find the actual index in self.items of an item at (x, y)
         if let Some(pos) = self
             .items
             .iter()
-            .position(|item| item.x == x && item.y == y)
+            .position(|item| item.x == x && item.y == y && wanted(item))
         {
             Some(self.items.remove(pos))
         } else {
@@ -602,10 +942,22 @@ impl Floor {
     /// Ensures the player spawns:
     /// - In a walkable space (not a wall)
     /// - In the largest connected region of the map (to avoid isolated pockets)
-    /// - At a random location (not always the same spot)
+    /// - At a random location (not always the same spot), fixed by the floor's seed
     pub fn find_player_spawn(&self) -> Option<(i32, i32)> {
-        use rand::{Rng, RngExt};
+        let region_tiles = self.largest_region_tiles();
+
+        // Spawn in a random location within the largest region
+        if !region_tiles.is_empty() {
+            let mut rng = StdRng::seed_from_u64(self.seed.wrapping_add(4242));
+            let idx = rng.random_range(0..region_tiles.len());
+            return Some(region_tiles[idx]);
+        }
+
+        None
+    }
 
+    /// All walkable tiles of the largest 4-connected region of the map
+    pub fn largest_region_tiles(&self) -> Vec<(i32, i32)> {
         // Find all walkable tiles and identify the largest connected region
         let mut visited = vec![false; (self.width * self.height) as usize];
         let mut largest_region_start = None;
@@ -649,9 +1001,9 @@ impl Floor {
         }
 
         // Now that we know the start of the largest region, do another BFS to collect positions
+        let mut region_tiles = Vec::new();
         if let Some((start_x, start_y)) = largest_region_start {
             let mut visited2 = vec![false; (self.width * self.height) as usize];
-            let mut region_tiles = Vec::new();
             let mut queue = VecDeque::new();
 
             let start_idx = (start_y * self.width + start_x) as usize;
@@ -673,33 +1025,32 @@ impl Floor {
                     }
                 }
             }
-
-            // Spawn in a random location within the largest region
-            if !region_tiles.is_empty() {
-                let mut rng = rand::rng();
-                let idx = rng.random_range(0..region_tiles.len());
-                return Some(region_tiles[idx]);
-            }
         }
 
-        None
+        region_tiles
     }
 
+    /// Scatter `count` consumables over the floor, only on tiles reachable from
+    /// `player_spawn` so none end up sealed in a pocket the player can't get to
     pub fn spawn_random_items(
         &mut self,
         count: i32,
         difficulty: &crate::model::item_tier::Difficulty,
+        player_spawn: Option<(i32, i32)>,
     ) {
         use crate::model::consumable::{Consumable, ConsumableType};
         use crate::model::item_tier::ItemTier;
 
         let mut rng = StdRng::seed_from_u64(self.seed.wrapping_add(999));
+        let region = self.spawn_region(player_spawn);
         let consumable_types = [
             ConsumableType::WeakHealingDraught,
             ConsumableType::BandageRoll,
             ConsumableType::AntitoxinVial,
             ConsumableType::FireOilFlask,
             ConsumableType::BlessedBread,
+            ConsumableType::ScryingEye,
+            ConsumableType::StrengthPotion,
         ];
 
         let all_tiers = [
@@ -723,8 +1074,8 @@ impl Floor {
                 let x = rng.random_range(1..self.width - 1);
                 let y = rng.random_range(1..self.height - 1);
 
-                if !self.get_tile(x, y) && !self.item_exists_at(x, y) {
-                    // It's a floor tile with no item, spawn item here
+                if region.contains(&(x, y)) && !self.item_exists_at(x, y) {
+                    // It's a reachable floor tile with no item, spawn item here
 
                     // Determine tier based on difficulty drop chances
                     let tier = self.determine_tier(&mut rng, difficulty, &all_tiers);
@@ -742,28 +1093,84 @@ impl Floor {
         }
     }
 
-    /// Spawn enemies scaled by difficulty
-    /// Spawns 5-15 enemies depending on difficulty
-    /// Ensures no overlaps with items, other enemies, or walls
-    pub fn spawn_enemies(&mut self, difficulty: &crate::model::item_tier::Difficulty) {
+    /// How many enemies a floor with `open_tiles` in its largest region should get.
+    /// Scales with the open area and difficulty, but never leaves fewer than
+    /// MIN_OPEN_TILES_PER_ENEMY tiles per enemy. `jitter` varies the count per floor.
+    pub fn enemy_spawn_count(
+        open_tiles: usize,
+        difficulty: &crate::model::item_tier::Difficulty,
+        jitter: f32,
+    ) -> usize {
+        use crate::constants::{ENEMY_SPAWN_TILES_PER_ENEMY, MIN_OPEN_TILES_PER_ENEMY};
+
+        let cap = open_tiles / MIN_OPEN_TILES_PER_ENEMY;
+        let target = open_tiles as f32 / ENEMY_SPAWN_TILES_PER_ENEMY as f32
+            * difficulty.enemy_density_multiplier()
+            * jitter;
+        (target.round() as usize).max(1).min(cap)
+    }
+
+    /// Every floor tile connected to `start` by 4-way steps, ignoring enemies and items
+    pub fn reachable_from(&self, start: (i32, i32)) -> HashSet<(i32, i32)> {
+        let mut reachable = HashSet::new();
+        if self.get_tile(start.0, start.1) {
+            return reachable;
+        }
+
+        let mut queue = VecDeque::from([start]);
+        reachable.insert(start);
+        while let Some((x, y)) = queue.pop_front() {
+            for (dx, dy) in [(0, -1), (0, 1), (-1, 0), (1, 0)] {
+                let next = (x + dx, y + dy);
+                if !self.get_tile(next.0, next.1) && reachable.insert(next) {
+                    queue.push_back(next);
+                }
+            }
+        }
+        reachable
+    }
+
+    /// Tiles enemies and items may spawn on: those the player can walk to, or the
+    /// largest region when the player's spawn isn't known yet
+    fn spawn_region(&self, player_spawn: Option<(i32, i32)>) -> HashSet<(i32, i32)> {
+        match player_spawn {
+            Some(start) => self.reachable_from(start),
+            None => self.largest_region_tiles().into_iter().collect(),
+        }
+    }
+
+    /// Spawn enemies scaled by difficulty and by the size of the player's open region
+    /// Ensures no overlaps with items, other enemies, or walls, that every enemy
+    /// can be reached from `player_spawn` so the floor can always be cleared, and that
+    /// none start close enough to land a hit before the player has moved
+    pub fn spawn_enemies(
+        &mut self,
+        difficulty: &crate::model::item_tier::Difficulty,
+        player_spawn: Option<(i32, i32)>,
+    ) {
         use crate::model::enemy_type;
 
         let mut rng = StdRng::seed_from_u64(self.seed.wrapping_add(1337));
-
-        // Determine number of enemies based on difficulty
-        let enemy_count = match difficulty {
-            crate::model::item_tier::Difficulty::Easy => rng.random_range(5..8),
-            crate::model::item_tier::Difficulty::Normal => rng.random_range(8..12),
-            crate::model::item_tier::Difficulty::Hard => rng.random_range(12..16),
-            crate::model::item_tier::Difficulty::Death => rng.random_range(15..20),
+        let region = self.spawn_region(player_spawn);
+        let near_player = |x: i32, y: i32| {
+            player_spawn.is_some_and(|(px, py)| {
+                (x - px).abs().max((y - py).abs()) <= crate::constants::ENEMY_SPAWN_SAFE_RADIUS
+            })
         };
 
+        // Determine number of enemies based on open space and difficulty
+        let open_tiles = region.len();
+        let jitter = rng.random_range(
+            1.0 - crate::constants::ENEMY_SPAWN_JITTER..1.0 + crate::constants::ENEMY_SPAWN_JITTER,
+        );
+        let enemy_count = Self::enemy_spawn_count(open_tiles, difficulty, jitter);
+
         // Get available enemy templates for this difficulty
         let templates = enemy_type::get_enemies_for_difficulty(difficulty);
 
         let mut spawned = 0;
         let mut total_attempts = 0;
-        let max_total_attempts = (enemy_count as usize) * 100;
+        let max_total_attempts = enemy_count * 100;
 
         while spawned < enemy_count && total_attempts < max_total_attempts {
             total_attempts += 1;
@@ -773,23 +1180,17 @@ impl Floor {
                 let x = rng.random_range(1..self.width - 1);
                 let y = rng.random_range(1..self.height - 1);
 
-                // Check if position is valid: floor tile, no item, no other enemy
-                if !self.get_tile(x, y) && !self.item_exists_at(x, y) && !self.enemy_exists_at(x, y)
+                // Check if position is valid: reachable floor tile away from the player,
+                // no item, no other enemy
+                if region.contains(&(x, y))
+                    && !near_player(x, y)
+                    && !self.item_exists_at(x, y)
+                    && !self.enemy_exists_at(x, y)
                 {
                     // Pick random enemy template
                     let template_idx = rng.random_range(0..templates.len());
-                    let template = templates[template_idx].clone();
-
-                    // Create enemy from template with speed multiplier applied
-                    let adjusted_speed = template.speed * crate::constants::ENEMY_SPEED_MULTIPLIER;
-                    let mut enemy = crate::model::enemy::Enemy::new(x, y, adjusted_speed);
-                    enemy.health = template.health;
-                    enemy.max_health = template.health;
-                    enemy.rarity = template.rarity.clone();
-                    enemy.base_gold = template.rarity.calculate_gold_drop(difficulty);
-                    enemy.detection_radius = template.rarity.calculate_detection_radius(difficulty);
-                    enemy.attacks = template.attacks.clone();
-
+                    let enemy =
+                        Self::enemy_from_template(&templates[template_idx], x, y, difficulty);
                     self.enemies.push(enemy);
                     spawned += 1;
                     break;
@@ -799,6 +1200,324 @@ impl Floor {
         }
     }
 
+    /// Create an enemy from a template with the global speed multiplier applied, and
+    /// gold, detection radius, stats and cooldowns for `difficulty`
+    pub fn enemy_from_template(
+        template: &crate::model::enemy_type::EnemyTemplate,
+        x: i32,
+        y: i32,
+        difficulty: &crate::model::item_tier::Difficulty,
+    ) -> crate::model::enemy::Enemy {
+        let adjusted_speed = template.speed * crate::constants::ENEMY_SPEED_MULTIPLIER;
+        let mut enemy = crate::model::enemy::Enemy::new(x, y, adjusted_speed);
+        enemy.name = template.name.clone();
+        enemy.health = template.health;
+        enemy.max_health = template.health;
+        enemy.rarity = template.rarity.clone();
+        enemy.enemy_type = template.enemy_type.clone();
+        enemy.base_gold = template.rarity.calculate_gold_drop(difficulty);
+        enemy.detection_radius = template.rarity.calculate_detection_radius(difficulty);
+        enemy.attacks = template.attacks.clone();
+        enemy.buffs = template.buffs.clone();
+        enemy.flee_threshold = template.flee_threshold;
+        enemy.scale_stats(difficulty.enemy_stat_multiplier());
+        enemy.scale_cooldowns(difficulty.enemy_cooldown_multiplier());
+        enemy
+    }
+
+    /// Toughen every enemy on the floor for New Game+ `cycle`; cycle 0 is a normal run
+    pub fn scale_enemies_for_new_game_plus(&mut self, cycle: u32) {
+        if cycle == 0 {
+            return;
+        }
+        self.scale_enemies(1.0 + crate::constants::NEW_GAME_PLUS_ENEMY_SCALING * cycle as f32);
+    }
+
+    /// Multiply the health and damage of every enemy on the floor
+    pub fn scale_enemies(&mut self, multiplier: f32) {
+        for enemy in &mut self.enemies {
+            enemy.scale_stats(multiplier);
+        }
+    }
+
+    /// Spawn enemies as groups of guards posted in rooms, with the strongest available
+    /// template leading each large room. The room around `player_spawn` gets a smaller
+    /// share and nothing spawns right next to the player.
+    pub fn spawn_enemies_in_rooms(
+        &mut self,
+        difficulty: &crate::model::item_tier::Difficulty,
+        player_spawn: Option<(i32, i32)>,
+    ) {
+        use crate::constants::{
+            MIN_OPEN_TILES_PER_ENEMY, ROOM_CHAMPION_MIN_TILES, ROOM_MAX_GUARDS,
+            ROOM_PLAYER_CLEARANCE, ROOM_PLAYER_ROOM_WEIGHT,
+        };
+        use crate::model::enemy_type;
+
+        let mut rng = StdRng::seed_from_u64(self.seed.wrapping_add(1337));
+        let region = self.spawn_region(player_spawn);
+
+        let open_tiles = region.len();
+        let jitter = rng.random_range(
+            1.0 - crate::constants::ENEMY_SPAWN_JITTER..1.0 + crate::constants::ENEMY_SPAWN_JITTER,
+        );
+        let enemy_count = Self::enemy_spawn_count(open_tiles, difficulty, jitter);
+
+        let templates = enemy_type::get_enemies_for_difficulty(difficulty);
+        let Some(champion) = templates.iter().max_by_key(|t| t.rarity.clone()) else {
+            return;
+        };
+
+        let player_room = player_spawn.and_then(|(x, y)| self.room_at(x, y));
+        let near_player = |x: i32, y: i32| {
+            player_spawn
+                .is_some_and(|(px, py)| (x - px).abs().max((y - py).abs()) <= ROOM_PLAYER_CLEARANCE)
+        };
+
+        // Share enemies out between rooms in proportion to their size, one at a time
+        // to whichever room is furthest below its share
+        let weights: Vec<f32> = self
+            .rooms
+            .iter()
+            .map(|room| {
+                let weight = room.tiles.len() as f32;
+                if Some(room.id) == player_room {
+                    weight * ROOM_PLAYER_ROOM_WEIGHT
+                } else {
+                    weight
+                }
+            })
+            .collect();
+        let capacity: Vec<usize> = self
+            .rooms
+            .iter()
+            .map(|room| {
+                // Rooms never straddle separate caves, so one tile decides reachability
+                if !region.contains(&room.tiles[0]) {
+                    return 0;
+                }
+                let leader = usize::from(room.tiles.len() >= ROOM_CHAMPION_MIN_TILES);
+                (room.tiles.len() / MIN_OPEN_TILES_PER_ENEMY).min(ROOM_MAX_GUARDS + leader)
+            })
+            .collect();
+        let mut quota = vec![0usize; self.rooms.len()];
+        for _ in 0..enemy_count {
+            let next = (0..self.rooms.len())
+                .filter(|&i| quota[i] < capacity[i])
+                .max_by(|&a, &b| {
+                    let share = |i: usize| weights[i] / (quota[i] + 1) as f32;
+                    share(a).total_cmp(&share(b))
+                });
+            match next {
+                Some(i) => quota[i] += 1,
+                None => break,
+            }
+        }
+
+        for (room_idx, &count) in quota.iter().enumerate() {
+            if count == 0 {
+                continue;
+            }
+            let room = self.rooms[room_idx].clone();
+
+            // Guards gather around a post somewhere in the room, away from the player
+            let posts: Vec<(i32, i32)> = room
+                .tiles
+                .iter()
+                .copied()
+                .filter(|&(x, y)| !near_player(x, y))
+                .collect();
+            if posts.is_empty() {
+                continue;
+            }
+            let post = posts[rng.random_range(0..posts.len())];
+            let has_champion =
+                room.tiles.len() >= ROOM_CHAMPION_MIN_TILES && Some(room.id) != player_room;
+
+            let mut placed = 0;
+            let mut visited = vec![false; (self.width * self.height) as usize];
+            visited[(post.1 * self.width + post.0) as usize] = true;
+            let mut queue = VecDeque::from([post]);
+            while let Some((x, y)) = queue.pop_front() {
+                if placed == count {
+                    break;
+                }
+                let crowded = self.enemies.iter().any(|enemy| {
+                    (enemy.position.x - x).abs() <= 1 && (enemy.position.y - y).abs() <= 1
+                });
+                if !crowded && !near_player(x, y) && !self.item_exists_at(x, y) {
+                    let template = if has_champion && placed == 0 {
+                        champion
+                    } else {
+                        &templates[rng.random_range(0..templates.len())]
+                    };
+                    let enemy = Self::enemy_from_template(template, x, y, difficulty);
+                    self.enemies.push(enemy);
+                    placed += 1;
+                }
+
+                for (dx, dy) in [(0, -1), (0, 1), (-1, 0), (1, 0)] {
+                    let (nx, ny) = (x + dx, y + dy);
+                    if self.room_at(nx, ny) == Some(room.id) {
+                        let idx = (ny * self.width + nx) as usize;
+                        if !visited[idx] {
+                            visited[idx] = true;
+                            queue.push_back((nx, ny));
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    /// Mark the room whose center is farthest from the player's spawn as the treasure room
+    pub fn choose_treasure_room(&mut self, player_spawn: (i32, i32)) -> Option<RoomId> {
+        let player_room = self.room_at(player_spawn.0, player_spawn.1);
+        let distance = |(x, y): (i32, i32)| {
+            let (dx, dy) = (x - player_spawn.0, y - player_spawn.1);
+            dx * dx + dy * dy
+        };
+
+        self.treasure_room = self
+            .rooms
+            .iter()
+            .filter(|room| Some(room.id) != player_room)
+            .max_by_key(|room| distance(room.center))
+            .map(|room| room.id);
+        if self.treasure_room.is_some() {
+            self.rebuild_styled_tile_cache();
+        }
+        self.treasure_room
+    }
+
+    /// Fill the treasure room with a weapon above Common and a few gold piles,
+    /// optionally guarded by a toughened copy of the strongest enemy for the difficulty
+    pub fn stock_treasure_room(
+        &mut self,
+        difficulty: &crate::model::item_tier::Difficulty,
+        guarded: bool,
+    ) {
+        use crate::constants::{
+            TREASURE_GOLD_PER_PILE, TREASURE_GOLD_PILES, TREASURE_GUARD_HEALTH_MULTIPLIER,
+        };
+
+        let Some(room) = self
+            .treasure_room
+            .and_then(|id| self.rooms.get(id.0))
+            .cloned()
+        else {
+            return;
+        };
+
+        // Loot goes on the free tiles closest to the room's center
+        let (cx, cy) = room.center;
+        let mut spots: Vec<(i32, i32)> = room
+            .tiles
+            .iter()
+            .copied()
+            .filter(|&(x, y)| !self.item_exists_at(x, y) && !self.enemy_exists_at(x, y))
+            .collect();
+        spots.sort_by_key(|&(x, y)| (x - cx) * (x - cx) + (y - cy) * (y - cy));
+        let mut spots = spots.into_iter();
+
+        let Some((wx, wy)) = spots.next() else {
+            return;
+        };
+        let mut rng = StdRng::seed_from_u64(self.seed.wrapping_add(2024));
+        let weapon = crate::model::weapon::Weapon::random_for_rarity_with(
+            &difficulty.treasure_weapon_rarity(),
+            &mut rng,
+        );
+        self.add_item(ItemDrop::weapon(weapon, wx, wy));
+
+        for (x, y) in spots.by_ref().take(TREASURE_GOLD_PILES) {
+            self.add_item(ItemDrop::gold(TREASURE_GOLD_PER_PILE, x, y));
+        }
+
+        if guarded {
+            let templates = crate::model::enemy_type::get_enemies_for_difficulty(difficulty);
+            if let (Some(template), Some((x, y))) = (
+                templates.iter().max_by_key(|t| t.rarity.clone()),
+                spots.next(),
+            ) {
+                let mut guard = Self::enemy_from_template(template, x, y, difficulty);
+                guard.max_health =
+                    (guard.max_health as f32 * TREASURE_GUARD_HEALTH_MULTIPLIER) as i32;
+                guard.health = guard.max_health;
+                self.enemies.push(guard);
+            }
+        }
+    }
+
+    /// Room containing this tile, if it is walkable floor
+    pub fn room_at(&self, x: i32, y: i32) -> Option<RoomId> {
+        if x < 0 || x >= self.width || y < 0 || y >= self.height {
+            return None;
+        }
+        self.tile_to_room[(y * self.width + x) as usize]
+    }
+
+    /// Place the stairs on a reachable tile near (x, y) if not already placed
+    /// Walks outward over floor tiles so the exit is never sealed off from the player
+    pub fn reveal_stairs(&mut self, x: i32, y: i32) -> Option<(i32, i32)> {
+        if self.stairs.is_some() {
+            return self.stairs;
+        }
+
+        let mut visited = vec![false; (self.width * self.height) as usize];
+        let mut queue = VecDeque::new();
+        let mut fallback = None;
+        if x >= 0 && x < self.width && y >= 0 && y < self.height {
+            visited[(y * self.width + x) as usize] = true;
+            queue.push_back((x, y, 0usize));
+        }
+
+        while let Some((cx, cy, dist)) = queue.pop_front() {
+            if (cx, cy) != (x, y) && !self.item_exists_at(cx, cy) {
+                if dist >= crate::constants::STAIRS_MIN_DISTANCE {
+                    self.stairs = Some((cx, cy));
+                    return self.stairs;
+                }
+                fallback = Some((cx, cy));
+            }
+
+            for (dx, dy) in [(0, -1), (0, 1), (-1, 0), (1, 0)] {
+                let nx = cx + dx;
+                let ny = cy + dy;
+                if nx < 0 || nx >= self.width || ny < 0 || ny >= self.height {
+                    continue;
+                }
+                let idx = (ny * self.width + nx) as usize;
+                if !visited[idx] && !self.get_tile(nx, ny) {
+                    visited[idx] = true;
+                    queue.push_back((nx, ny, dist + 1));
+                }
+            }
+        }
+
+        // Tiny pocket - settle for any nearby free tile
+        self.stairs = fallback;
+        self.stairs
+    }
+
+    /// Check if the stairs are at this position
+    pub fn is_stairs_at(&self, x: i32, y: i32) -> bool {
+        self.stairs == Some((x, y))
+    }
+
+    /// Position of the closest living enemy within `radius` that is in line of sight
+    pub fn nearest_enemy(&self, x: i32, y: i32, radius: i32) -> Option<(i32, i32)> {
+        self.enemies
+            .iter()
+            .filter(|enemy| enemy.is_alive())
+            .map(|enemy| (enemy.position.x, enemy.position.y))
+            .filter(|&(ex, ey)| {
+                let (dx, dy) = (ex - x, ey - y);
+                dx * dx + dy * dy <= radius * radius && self.has_line_of_sight(x, y, ex, ey)
+            })
+            .min_by_key(|&(ex, ey)| (ex - x) * (ex - x) + (ey - y) * (ey - y))
+    }
+
     /// Check if an enemy already exists at this position
     pub fn enemy_exists_at(&self, x: i32, y: i32) -> bool {
         self.enemies
@@ -806,6 +1525,61 @@ impl Floor {
             .any(|enemy| enemy.position.x == x && enemy.position.y == y)
     }
 
+    /// Index alive enemies by tile so collision checks are a lookup per position.
+    /// Call after enemies move; the grid is not updated as they move or die.
+    pub fn rebuild_enemy_grid(&mut self) {
+        self.enemy_grid.clear();
+        for (idx, enemy) in self.enemies.iter().enumerate() {
+            if enemy.is_alive() {
+                self.enemy_grid
+                    .entry((enemy.position.x, enemy.position.y))
+                    .or_default()
+                    .push(idx);
+            }
+        }
+    }
+
+    /// Alive enemies on a tile as of the last grid rebuild, lowest index first
+    pub fn enemies_at(&self, x: i32, y: i32) -> &[usize] {
+        self.enemy_grid.get(&(x, y)).map_or(&[], Vec::as_slice)
+    }
+
+    /// Alive enemies standing on any of `tiles` as of the last grid rebuild, in index order
+    pub fn enemies_in_area(&self, tiles: &[(i32, i32)]) -> Vec<usize> {
+        let mut hits: Vec<usize> = tiles
+            .iter()
+            .flat_map(|&(x, y)| self.enemies_at(x, y).iter().copied())
+            .collect();
+        hits.sort_unstable();
+        hits.dedup();
+        hits
+    }
+
+    /// Where a dash from `from` in direction `dir` lands. The dash passes over enemies
+    /// but stops at walls, landing on the furthest reached tile no enemy occupies.
+    pub fn dash_landing(
+        &self,
+        from: (i32, i32),
+        dir: (i32, i32),
+        distance: i32,
+    ) -> Option<(i32, i32)> {
+        let mut landing = None;
+        for step in 1..=distance {
+            let (x, y) = (from.0 + dir.0 * step, from.1 + dir.1 * step);
+            if self.get_tile(x, y) {
+                break;
+            }
+            let occupied = self
+                .enemies
+                .iter()
+                .any(|enemy| enemy.is_alive() && enemy.position.x == x && enemy.position.y == y);
+            if !occupied {
+                landing = Some((x, y));
+            }
+        }
+        landing
+    }
+
     /// Check if an item already exists at this position
     pub fn item_exists_at(&self, x: i32, y: i32) -> bool {
         self.items.iter().any(|item| item.x == x && item.y == y)
@@ -836,16 +1610,52 @@ impl Floor {
         ItemTier::Common
     }
 
-    pub fn update_items(&mut self, delta: f32) {
-        for item in &mut self.items {
-            item.update(delta);
+    /// Age items and drift magnetic ones within `pickup_radius` one tile at a time toward the player.
+    /// Returns true if any item arrived on the player's tile.
+    pub fn update_items(&mut self, delta: f32, player: (i32, i32), pickup_radius: i32) -> bool {
+        let (px, py) = player;
+        let mut arrived = false;
+
+        for i in 0..self.items.len() {
+            self.items[i].update(delta);
+
+            let (ix, iy) = (self.items[i].x, self.items[i].y);
+            let in_range = (ix - px).abs().max((iy - py).abs()) <= pickup_radius;
+            if pickup_radius <= 0 || !self.items[i].is_magnetic() || !in_range {
+                self.items[i].magnet_progress = 0.0;
+                continue;
+            }
+            if (ix, iy) == (px, py) {
+                arrived = true;
+                continue;
+            }
+
+            self.items[i].magnet_progress += delta;
+            if self.items[i].magnet_progress < crate::constants::ITEM_MAGNET_STEP_TIME {
+                continue;
+            }
+            self.items[i].magnet_progress = 0.0;
+
+            // Step diagonally when possible, otherwise slide along one axis around walls
+            let (sx, sy) = ((px - ix).signum(), (py - iy).signum());
+            let step = [(ix + sx, iy + sy), (ix + sx, iy), (ix, iy + sy)]
+                .into_iter()
+                .find(|&(nx, ny)| (nx, ny) != (ix, iy) && !self.get_tile(nx, ny));
+            if let Some((nx, ny)) = step {
+                self.items[i].x = nx;
+                self.items[i].y = ny;
+                arrived |= (nx, ny) == (px, py);
+            }
         }
+        arrived
     }
 
-    /// Spawn a boss on the floor at a strategic location
+    /// Spawn a boss on the floor at a strategic location, with health and damage
+    /// scaled to the difficulty
     pub fn spawn_boss(
         &mut self,
         boss_type: crate::model::boss::BossType,
+        difficulty: &crate::model::item_tier::Difficulty,
     ) -> Option<crate::model::boss::BossEnemy> {
         use crate::model::boss::BossEnemy;
 
@@ -894,22 +1704,147 @@ impl Floor {
             }
 
             if let Some((x, y)) = best_pos {
-                let boss = BossEnemy::new(x, y, boss_type);
+                let mut boss = BossEnemy::new(x, y, boss_type);
                 // Convert boss attack patterns to enemy attacks with appropriate damage
                 let base_damage = boss.get_effective_damage();
-                let attacks = crate::model::boss::convert_attack_patterns_to_enemy_attacks(
-                    &boss.attack_patterns,
-                    base_damage,
-                );
-                let mut base_enemy = boss.base_enemy.clone();
-                base_enemy.attacks = attacks;
-                self.enemies.push(base_enemy);
+                boss.base_enemy.attacks =
+                    crate::model::boss::convert_attack_patterns_to_enemy_attacks(
+                        &boss.attack_patterns,
+                        base_damage,
+                    );
+                boss.base_enemy.phases = boss.phase_attacks();
+                boss.base_enemy
+                    .scale_stats(difficulty.enemy_stat_multiplier());
+                boss.base_enemy
+                    .scale_cooldowns(difficulty.enemy_cooldown_multiplier());
+                boss.max_health_base = boss.base_enemy.max_health;
+                self.enemies.push(boss.base_enemy.clone());
                 return Some(boss);
             }
         }
 
         None
     }
+
+    /// Scatter `count` spike traps over open floor reachable from `player_spawn`, none on
+    /// items or right next to the spawn. Each starts at a random point in its cycle.
+    pub fn place_spike_traps(&mut self, count: usize, player_spawn: Option<(i32, i32)>) {
+        let mut rng = StdRng::seed_from_u64(self.seed.wrapping_add(7331));
+        let mut candidates: Vec<(i32, i32)> = self
+            .spawn_region(player_spawn)
+            .into_iter()
+            .filter(|&(x, y)| {
+                !self.item_exists_at(x, y)
+                    && !player_spawn.is_some_and(|(px, py)| {
+                        (x - px).abs().max((y - py).abs())
+                            <= crate::constants::ENEMY_SPAWN_SAFE_RADIUS
+                    })
+            })
+            .collect();
+        // HashSet order is random; sort so a seed always gives the same traps
+        candidates.sort_unstable();
+
+        self.spike_traps.clear();
+        while self.spike_traps.len() < count && !candidates.is_empty() {
+            let (x, y) = candidates.swap_remove(rng.random_range(0..candidates.len()));
+            let phase = rng.random_range(0.0..crate::constants::SPIKE_CYCLE);
+            self.spike_traps.push(SpikeTrap::new(x, y, phase));
+        }
+    }
+
+    /// Advance every spike trap. Enemies standing on a trap that strikes take the damage
+    /// here; the damage due to the player at `player` is returned for the caller to apply.
+    pub fn update_hazards(&mut self, delta: f32, player: (i32, i32)) -> i32 {
+        let mut player_damage = 0;
+        for trap in &mut self.spike_traps {
+            if !trap.update(delta) {
+                continue;
+            }
+            if (trap.x, trap.y) == player {
+                player_damage += crate::constants::SPIKE_DAMAGE;
+            }
+            for enemy in &mut self.enemies {
+                if enemy.position.x == trap.x && enemy.position.y == trap.y && enemy.is_alive() {
+                    enemy.take_damage(crate::constants::SPIKE_DAMAGE);
+                }
+            }
+        }
+        player_damage
+    }
+
+    /// Whether a boss is still alive on this floor
+    pub fn has_boss(&self) -> bool {
+        self.enemies
+            .iter()
+            .any(|enemy| enemy.rarity == crate::model::enemy_type::EnemyRarity::Boss)
+    }
+
+    /// Summon up to `BOSS_SUMMON_COUNT` of the weakest enemies for `difficulty` on free
+    /// tiles the boss can walk to, never on the player at `player` and never exceeding
+    /// `BOSS_MAX_ADDS` adds alive at once. Returns how many were summoned.
+    pub fn summon_boss_adds(
+        &mut self,
+        difficulty: &crate::model::item_tier::Difficulty,
+        player: (i32, i32),
+    ) -> usize {
+        use crate::constants::{BOSS_MAX_ADDS, BOSS_SUMMON_COUNT, BOSS_SUMMON_RADIUS};
+        use crate::model::enemy_type::{get_enemies_for_difficulty, EnemyRarity};
+        let Some(boss) = self
+            .enemies
+            .iter()
+            .find(|enemy| enemy.rarity == EnemyRarity::Boss)
+        else {
+            return 0;
+        };
+        let (bx, by) = (boss.position.x, boss.position.y);
+
+        let alive_adds = self
+            .enemies
+            .iter()
+            .filter(|enemy| enemy.rarity != EnemyRarity::Boss)
+            .count();
+        let count = BOSS_SUMMON_COUNT.min(BOSS_MAX_ADDS.saturating_sub(alive_adds));
+
+        // Fighters where the difficulty has them, otherwise its weakest tier
+        let templates = get_enemies_for_difficulty(difficulty);
+        let Some(weakest) = templates.iter().map(|t| t.rarity.clone()).min() else {
+            return 0;
+        };
+        let templates: Vec<_> = templates
+            .into_iter()
+            .filter(|template| template.rarity == weakest)
+            .collect();
+
+        // Flood out from the boss so adds never appear behind a wall it can't get past
+        let mut free_tiles: Vec<(i32, i32)> = Vec::new();
+        let mut seen = HashSet::from([(bx, by)]);
+        let mut queue = VecDeque::from([((bx, by), 0)]);
+        while let Some(((x, y), steps)) = queue.pop_front() {
+            if (x, y) != player && self.is_walkable(x, y) {
+                free_tiles.push((x, y));
+            }
+            if steps == BOSS_SUMMON_RADIUS {
+                continue;
+            }
+            for (dx, dy) in [(0, -1), (0, 1), (-1, 0), (1, 0)] {
+                let next = (x + dx, y + dy);
+                if !self.get_tile(next.0, next.1) && seen.insert(next) {
+                    queue.push_back((next, steps + 1));
+                }
+            }
+        }
+
+        let mut rng = rand::rng();
+        let mut summoned = 0;
+        while summoned < count && !free_tiles.is_empty() {
+            let (x, y) = free_tiles.swap_remove(rng.random_range(0..free_tiles.len()));
+            let template = &templates[rng.random_range(0..templates.len())];
+            self.enemies
+                .push(Self::enemy_from_template(template, x, y, difficulty));
+            summoned += 1;
+        }
+        summoned
+    }
 }
 
 #[derive(Clone, Debug)]
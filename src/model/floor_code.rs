@@ -0,0 +1,83 @@
+use crate::model::item_tier::Difficulty;
+
+const PREFIX: &str = "RLD";
+
+/// Shareable description of a generated floor: paste it into the dev menu to rebuild
+/// the same floor for a bug report or a challenge
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FloorCode {
+    pub seed: u64,
+    pub difficulty: Difficulty,
+    pub floor_level: u32,
+}
+
+impl FloorCode {
+    /// Render as `RLD-<difficulty>-<floor level>-<seed>`
+    pub fn encode(&self) -> String {
+        format!(
+            "{}-{}-{}-{}",
+            PREFIX,
+            self.difficulty.name(),
+            self.floor_level,
+            self.seed
+        )
+    }
+
+    /// Parse a code produced by `encode`, ignoring surrounding whitespace
+    pub fn parse(code: &str) -> Result<Self, String> {
+        let parts: Vec<&str> = code.trim().split('-').collect();
+        let [prefix, difficulty, level, seed] = parts[..] else {
+            return Err(format!("Expected {}-<difficulty>-<level>-<seed>", PREFIX));
+        };
+        if prefix != PREFIX {
+            return Err(format!("Not a floor code: {}", code.trim()));
+        }
+
+        let parsed_difficulty = Difficulty::from_name(difficulty);
+        if parsed_difficulty.name() != difficulty {
+            return Err(format!("Unknown difficulty: {}", difficulty));
+        }
+        let floor_level = level
+            .parse::<u32>()
+            .ok()
+            .filter(|&level| level > 0)
+            .ok_or_else(|| format!("Bad floor level: {}", level))?;
+        let seed = seed
+            .parse::<u64>()
+            .map_err(|_| format!("Bad seed: {}", seed))?;
+
+        Ok(Self {
+            seed,
+            difficulty: parsed_difficulty,
+            floor_level,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_code_round_trips() {
+        let code = FloorCode {
+            seed: u64::MAX,
+            difficulty: Difficulty::Hard,
+            floor_level: 4,
+        };
+        let text = code.encode();
+        assert_eq!(text, format!("RLD-Hard-4-{}", u64::MAX));
+        assert_eq!(FloorCode::parse(&text), Ok(code));
+        assert!(FloorCode::parse(&format!("  {}\n", text)).is_ok());
+    }
+
+    #[test]
+    fn test_rejects_malformed_codes() {
+        assert!(FloorCode::parse("").is_err());
+        assert!(FloorCode::parse("RLD-Hard-4").is_err());
+        assert!(FloorCode::parse("XYZ-Hard-4-12").is_err());
+        assert!(FloorCode::parse("RLD-Nightmare-4-12").is_err());
+        assert!(FloorCode::parse("RLD-Hard-0-12").is_err());
+        assert!(FloorCode::parse("RLD-Hard-4-seed").is_err());
+    }
+}
@@ -0,0 +1,91 @@
+use serde::{Deserialize, Serialize};
+
+/// Look of a floor. Themes cycle with the floor level and only change the colors
+/// walls and floors are shaded with.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum FloorTheme {
+    #[default]
+    Crypt, // Grey stone
+    Cavern, // Brown earth
+    Frozen, // Blue ice
+}
+
+impl FloorTheme {
+    pub const ALL: [FloorTheme; 3] = [FloorTheme::Crypt, FloorTheme::Cavern, FloorTheme::Frozen];
+
+    /// Theme of floor `level`, counting from one
+    pub fn for_level(level: u32) -> Self {
+        Self::ALL[level.saturating_sub(1) as usize % Self::ALL.len()]
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            FloorTheme::Crypt => "Crypt",
+            FloorTheme::Cavern => "Cavern",
+            FloorTheme::Frozen => "Frozen",
+        }
+    }
+
+    /// Number of spike traps scattered over a floor of this theme
+    pub fn spike_traps(&self) -> usize {
+        match self {
+            FloorTheme::Crypt => 4,
+            FloorTheme::Cavern => 8,
+            FloorTheme::Frozen => 2,
+        }
+    }
+
+    /// Wall color index, brighter the more open floor lies within two tiles
+    pub fn wall_color(&self, nearby_floors: i32) -> u8 {
+        let ramp: [u8; 4] = match self {
+            FloorTheme::Crypt => [236, 238, 240, 242],
+            FloorTheme::Cavern => [58, 94, 95, 137],
+            FloorTheme::Frozen => [17, 18, 24, 31],
+        };
+        match nearby_floors {
+            0..=1 => ramp[0],
+            2..=3 => ramp[1],
+            4..=5 => ramp[2],
+            _ => ramp[3],
+        }
+    }
+
+    /// Floor color index, darker the more walls hem the tile in
+    pub fn floor_color(&self, wall_proximity: i32) -> u8 {
+        let ramp: [u8; 5] = match self {
+            FloorTheme::Crypt => [246, 244, 242, 240, 238],
+            FloorTheme::Cavern => [180, 138, 137, 95, 94],
+            FloorTheme::Frozen => [153, 117, 110, 67, 60],
+        };
+        match wall_proximity {
+            0..=2 => ramp[0],
+            3..=4 => ramp[1],
+            5..=6 => ramp[2],
+            7..=8 => ramp[3],
+            _ => ramp[4],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_themes_shade_the_same_tile_differently() {
+        for (i, a) in FloorTheme::ALL.iter().enumerate() {
+            for b in &FloorTheme::ALL[i + 1..] {
+                assert_ne!(a.wall_color(3), b.wall_color(3));
+                assert_ne!(a.floor_color(3), b.floor_color(3));
+            }
+        }
+    }
+
+    #[test]
+    fn test_themes_cycle_by_level() {
+        assert_eq!(FloorTheme::for_level(1), FloorTheme::Crypt);
+        assert_eq!(FloorTheme::for_level(2), FloorTheme::Cavern);
+        assert_eq!(FloorTheme::for_level(3), FloorTheme::Frozen);
+        assert_eq!(FloorTheme::for_level(4), FloorTheme::Crypt);
+    }
+}
@@ -72,6 +72,59 @@ impl GameDifficulty {
             GameDifficulty::Death => 1.8,     // 180% of normal
         }
     }
+
+    /// Fraction of the player's gold left in a recovery pile on death
+    pub fn death_gold_drop_fraction(&self) -> f32 {
+        let multiplier = match self {
+            GameDifficulty::Easy => 0.5,
+            GameDifficulty::Normal => 1.0,
+            GameDifficulty::Hard => 1.5,
+            GameDifficulty::Death => 2.0,
+        };
+        (crate::constants::DEATH_GOLD_DROP_FRACTION * multiplier).min(1.0)
+    }
+
+    /// Tier of the weapon guaranteed in each floor's treasure room, a step above
+    /// the best an ordinary enemy drops
+    pub fn treasure_weapon_rarity(&self) -> crate::model::item_rarity::ItemRarity {
+        use crate::model::item_rarity::ItemRarity;
+        match self {
+            GameDifficulty::Easy => ItemRarity::Epic,
+            GameDifficulty::Normal => ItemRarity::Exotic,
+            GameDifficulty::Hard => ItemRarity::Legendary,
+            GameDifficulty::Death => ItemRarity::Mythic,
+        }
+    }
+
+    /// Scales spawned enemies' health and attack damage
+    pub fn enemy_stat_multiplier(&self) -> f32 {
+        match self {
+            GameDifficulty::Easy => 0.8,
+            GameDifficulty::Normal => 1.0,
+            GameDifficulty::Hard => 1.4,
+            GameDifficulty::Death => 2.0,
+        }
+    }
+
+    /// Scales the cooldown of every enemy attack; lower means enemies strike more often
+    pub fn enemy_cooldown_multiplier(&self) -> f32 {
+        match self {
+            GameDifficulty::Easy => 1.25,
+            GameDifficulty::Normal => 1.0,
+            GameDifficulty::Hard => 0.85,
+            GameDifficulty::Death => 0.7,
+        }
+    }
+
+    /// Scales how many enemies spawn per open tile on a floor
+    pub fn enemy_density_multiplier(&self) -> f32 {
+        match self {
+            GameDifficulty::Easy => 0.65,
+            GameDifficulty::Normal => 1.0,
+            GameDifficulty::Hard => 1.4,
+            GameDifficulty::Death => 1.75,
+        }
+    }
 }
 
 #[cfg(test)]
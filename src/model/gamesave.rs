@@ -1,9 +1,19 @@
+use crate::model::status_effect::{StatusEffect, StatusEffectManager, StatusEffectType};
 use serde::{Deserialize, Serialize};
 use std::fs;
 use std::path::Path;
 
+/// Save format written by this build. Bump it and add a step to `GameSave::migrate`
+/// whenever a change to the save shape needs old files rewritten.
+pub const SAVE_VERSION: u32 = 3;
+
+/// Version assumed for files written before saves carried one
+const UNVERSIONED_SAVE: u32 = 1;
+
 #[derive(Serialize, Deserialize, Clone, Debug)]
 pub struct GameSave {
+    #[serde(default = "default_version")]
+    pub version: u32, // Save format version, see SAVE_VERSION
     pub player_name: String,
     pub player_stats: PlayerStats,
     pub inventory_data: InventoryData,
@@ -15,6 +25,62 @@ pub struct GameSave {
     pub position_y: i32,
     pub difficulty: String,
     pub time_elapsed: f32,
+    #[serde(default = "default_run_mode")]
+    pub run_mode: String,
+    #[serde(default)]
+    pub best_score: u32, // Highest victory score recorded for this save
+    #[serde(default)]
+    pub gold_recovery: Option<GoldRecovery>, // Gold left behind on the last death, if unrecovered
+    #[serde(default = "default_starting_class")]
+    pub starting_class: String, // Class picked at character creation
+    #[serde(default)]
+    pub new_game_plus: u32, // New Game+ cycle, 0 for a first run
+    #[serde(default)]
+    pub status_effects: Vec<StatusEffectData>, // Effects running on the player when saved
+    #[serde(default)]
+    pub adaptive_difficulty: crate::model::adaptive_difficulty::AdaptiveDifficulty, // Run's adaptive enemy scaling
+}
+
+/// Gold dropped where the player died, restored when the floor is retried
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct GoldRecovery {
+    pub floor_level: u32,
+    pub x: i32,
+    pub y: i32,
+    pub amount: u32,
+}
+
+impl GoldRecovery {
+    /// Split the difficulty's share off `gold` into a pile at the death position.
+    /// Returns None when there is nothing to drop.
+    pub fn on_death(
+        gold: u32,
+        floor_level: u32,
+        position: (i32, i32),
+        difficulty: &crate::model::game_difficulty::GameDifficulty,
+    ) -> Option<Self> {
+        let amount = (gold as f32 * difficulty.death_gold_drop_fraction()).floor() as u32;
+        (amount > 0).then_some(Self {
+            floor_level,
+            x: position.0,
+            y: position.1,
+            amount,
+        })
+    }
+}
+
+fn default_version() -> u32 {
+    UNVERSIONED_SAVE
+}
+
+fn default_run_mode() -> String {
+    "Checkpoint".to_string()
+}
+
+fn default_starting_class() -> String {
+    crate::model::loadout::StartingClass::default()
+        .name()
+        .to_string()
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug)]
@@ -29,6 +95,51 @@ pub struct PlayerStats {
     pub enemies_killed: u32,
     pub speed: f32,
     pub ultimate_charge: f32,
+    pub arrow_speed: f32, // Tiles per second, including the class bonus
+}
+
+/// A status effect still running on the player when the game was saved
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct StatusEffectData {
+    pub effect_type: String,
+    pub duration: f32,       // Seconds remaining
+    pub damage_per_sec: f32, // Per stack; negative heals
+    pub stacks: u32,
+}
+
+impl StatusEffectData {
+    pub fn capture(manager: &StatusEffectManager) -> Vec<Self> {
+        manager
+            .effects
+            .iter()
+            .map(|effect| Self {
+                effect_type: format!("{:?}", effect.effect_type),
+                duration: effect.duration,
+                damage_per_sec: effect.damage_per_sec,
+                stacks: effect.stacks,
+            })
+            .collect()
+    }
+
+    /// Rebuild the saved effects, skipping unknown types. Nothing counts as newly
+    /// applied, so loading plays no status sounds.
+    pub fn restore(saved: &[Self]) -> StatusEffectManager {
+        let effects = saved
+            .iter()
+            .filter_map(|data| {
+                Some(StatusEffect {
+                    effect_type: StatusEffectType::from_name(&data.effect_type)?,
+                    duration: data.duration,
+                    damage_per_sec: data.damage_per_sec,
+                    stacks: data.stacks,
+                })
+            })
+            .collect();
+        StatusEffectManager {
+            effects,
+            ..Default::default()
+        }
+    }
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug)]
@@ -98,6 +209,7 @@ impl Default for PlayerStats {
             enemies_killed: 0,
             speed: 100.0,
             ultimate_charge: 0.0,
+            arrow_speed: crate::constants::PLAYER_ARROW_SPEED,
         }
     }
 }
@@ -134,6 +246,7 @@ impl Default for UltimateShopData {
 impl Default for GameSave {
     fn default() -> Self {
         Self {
+            version: SAVE_VERSION,
             player_name: "Player".to_string(),
             player_stats: PlayerStats::default(),
             inventory_data: InventoryData::default(),
@@ -145,6 +258,13 @@ impl Default for GameSave {
             position_y: 0,
             difficulty: "Normal".to_string(),
             time_elapsed: 0.0,
+            run_mode: default_run_mode(),
+            starting_class: default_starting_class(),
+            best_score: 0,
+            gold_recovery: None,
+            new_game_plus: 0,
+            status_effects: Vec::new(),
+            adaptive_difficulty: Default::default(),
         }
     }
 }
@@ -180,10 +300,114 @@ impl GameSave {
             .map(|c| if c.is_alphanumeric() { c } else { '_' })
             .collect::<String>();
         let path = format!("saves/{}.json", safe_name);
-        fs::read_to_string(&path).and_then(|data| {
-            serde_json::from_str(&data)
-                .map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e))
-        })
+        Self::load_from(Path::new(&path))
+    }
+
+    /// Load a save file. A file that isn't a readable save is copied next to itself
+    /// with a `.bak` suffix so it survives being overwritten by the next save.
+    pub fn load_from(path: &Path) -> std::io::Result<Self> {
+        match Self::from_json(&fs::read_to_string(path)?) {
+            Err(e) if e.kind() == std::io::ErrorKind::InvalidData => {
+                let mut backup = path.as_os_str().to_owned();
+                backup.push(".bak");
+                fs::copy(path, &backup)?;
+                Err(std::io::Error::new(
+                    std::io::ErrorKind::InvalidData,
+                    format!(
+                        "corrupt save ({}), backed up to {}",
+                        e,
+                        Path::new(&backup).display()
+                    ),
+                ))
+            }
+            result => result,
+        }
+    }
+
+    /// Parse save file contents, upgrading older formats to the current one.
+    /// Fails with `Unsupported` on files from a newer build instead of guessing at
+    /// their fields, and with `InvalidData` on anything that isn't a save.
+    pub fn from_json(data: &str) -> std::io::Result<Self> {
+        let invalid = |e: String| std::io::Error::new(std::io::ErrorKind::InvalidData, e);
+
+        let mut value: serde_json::Value =
+            serde_json::from_str(data).map_err(|e| invalid(e.to_string()))?;
+        let version = match value.get("version") {
+            None => UNVERSIONED_SAVE,
+            Some(v) => v
+                .as_u64()
+                .and_then(|v| u32::try_from(v).ok())
+                .ok_or_else(|| invalid(format!("Bad save version: {}", v)))?,
+        };
+        if version > SAVE_VERSION {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::Unsupported,
+                format!(
+                    "Save version {} is newer than this game supports ({})",
+                    version, SAVE_VERSION
+                ),
+            ));
+        }
+
+        for from in version..SAVE_VERSION {
+            Self::migrate(&mut value, from);
+        }
+        let mut save: Self = serde_json::from_value(value).map_err(|e| invalid(e.to_string()))?;
+        save.version = SAVE_VERSION;
+        Ok(save)
+    }
+
+    /// Upgrade a save's JSON from version `from` to `from + 1`
+    fn migrate(value: &mut serde_json::Value, from: u32) {
+        let Some(fields) = value.as_object_mut() else {
+            return;
+        };
+        if from == 1 {
+            // Run mode, best score, gold recovery and starting class were added
+            // without a version bump: fill whichever are missing with defaults
+            let defaults = Self::default();
+            let added = [
+                ("run_mode", serde_json::json!(defaults.run_mode)),
+                ("best_score", serde_json::json!(defaults.best_score)),
+                ("gold_recovery", serde_json::json!(defaults.gold_recovery)),
+                ("starting_class", serde_json::json!(defaults.starting_class)),
+            ];
+            for (key, default) in added {
+                fields.entry(key).or_insert(default);
+            }
+        }
+        if from == 2 {
+            // Arrow speed was saved from here on: older saves get their class's speed back
+            let class = fields
+                .get("starting_class")
+                .and_then(|class| class.as_str())
+                .map(crate::model::loadout::StartingClass::from_name)
+                .unwrap_or_default();
+            let mut character = crate::model::character::Character::default();
+            class.apply(&mut character);
+            if let Some(stats) = fields
+                .get_mut("player_stats")
+                .and_then(|stats| stats.as_object_mut())
+            {
+                stats
+                    .entry("arrow_speed")
+                    .or_insert(serde_json::json!(character.arrow_speed));
+            }
+        }
+        fields.insert("version".to_string(), serde_json::json!(from + 1));
+    }
+
+    /// Delete the save for a player (used by permadeath)
+    pub fn delete_save(player_name: &str) -> std::io::Result<()> {
+        let safe_name = player_name
+            .chars()
+            .map(|c| if c.is_alphanumeric() { c } else { '_' })
+            .collect::<String>();
+        let path = format!("saves/{}.json", safe_name);
+        if Path::new(&path).exists() {
+            fs::remove_file(path)?;
+        }
+        Ok(())
     }
 
     /// List all available saves
@@ -0,0 +1,121 @@
+use crate::constants::{SPIKE_CYCLE, SPIKE_RAISED_TIME, SPIKE_STRIKE_INTERVAL, SPIKE_WARNING_TIME};
+use serde::{Deserialize, Serialize};
+
+/// Where a spike trap is in its cycle
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SpikeState {
+    Retracted, // Safe to cross
+    Rising,    // About to come up: the warning before it strikes
+    Raised,    // Hurts whoever stands on it
+}
+
+/// Timed spike trap. The tile stays walkable for the player and enemies alike; the
+/// spikes come up every `SPIKE_CYCLE` seconds after a short warning.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct SpikeTrap {
+    pub x: i32,
+    pub y: i32,
+    elapsed: f32, // Seconds into the current cycle
+    #[serde(skip)]
+    strike_timer: f32, // Seconds since the last strike while raised
+}
+
+impl SpikeTrap {
+    /// Trap at (x, y) starting `phase` seconds into its cycle, so neighbours don't fire in step
+    pub fn new(x: i32, y: i32, phase: f32) -> Self {
+        Self {
+            x,
+            y,
+            elapsed: phase.rem_euclid(SPIKE_CYCLE),
+            strike_timer: 0.0,
+        }
+    }
+
+    pub fn state(&self) -> SpikeState {
+        let raised_at = SPIKE_CYCLE - SPIKE_RAISED_TIME;
+        if self.elapsed >= raised_at {
+            SpikeState::Raised
+        } else if self.elapsed >= raised_at - SPIKE_WARNING_TIME {
+            SpikeState::Rising
+        } else {
+            SpikeState::Retracted
+        }
+    }
+
+    /// Advance the cycle. Returns true when the spikes strike this tick: as they come up,
+    /// then every `SPIKE_STRIKE_INTERVAL` while they stay up.
+    pub fn update(&mut self, delta: f32) -> bool {
+        let was_raised = self.state() == SpikeState::Raised;
+        self.elapsed = (self.elapsed + delta) % SPIKE_CYCLE;
+        if self.state() != SpikeState::Raised {
+            return false;
+        }
+        if !was_raised {
+            self.strike_timer = 0.0;
+            return true;
+        }
+        self.strike_timer += delta;
+        if self.strike_timer >= SPIKE_STRIKE_INTERVAL {
+            self.strike_timer -= SPIKE_STRIKE_INTERVAL;
+            return true;
+        }
+        false
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::enemy::Enemy;
+    use crate::model::floor::Floor;
+
+    #[test]
+    fn test_warns_before_striking() {
+        let mut trap = SpikeTrap::new(0, 0, 0.0);
+        assert_eq!(trap.state(), SpikeState::Retracted);
+
+        let mut states = vec![trap.state()];
+        let mut first_strike = None;
+        for _ in 0..(SPIKE_CYCLE * 100.0) as usize {
+            if trap.update(0.01) && first_strike.is_none() {
+                first_strike = Some(trap.state());
+            }
+            if states.last() != Some(&trap.state()) {
+                states.push(trap.state());
+            }
+        }
+        assert_eq!(first_strike, Some(SpikeState::Raised));
+        assert_eq!(
+            &states[..3],
+            &[
+                SpikeState::Retracted,
+                SpikeState::Rising,
+                SpikeState::Raised
+            ]
+        );
+    }
+
+    #[test]
+    fn test_standing_on_active_spikes_hurts() {
+        let mut floor = Floor::from_layout(&["#####", "#...#", "#####"], 0);
+        floor.spike_traps.push(SpikeTrap::new(1, 1, 0.0));
+        floor.spike_traps.push(SpikeTrap::new(3, 1, 0.0));
+        floor.enemies.push(Enemy::new(3, 1, 1.0));
+        let enemy_health = floor.enemies[0].health;
+        assert!(floor.is_walkable(1, 1));
+
+        let mut player_damage = 0;
+        for _ in 0..(SPIKE_CYCLE * 10.0) as usize {
+            player_damage += floor.update_hazards(0.1, (1, 1));
+        }
+        assert!(player_damage > 0);
+        assert!(floor.enemies[0].health < enemy_health);
+
+        // Nothing happens to a player standing next to the traps
+        let mut missed = 0;
+        for _ in 0..(SPIKE_CYCLE * 10.0) as usize {
+            missed += floor.update_hazards(0.1, (2, 1));
+        }
+        assert_eq!(missed, 0);
+    }
+}
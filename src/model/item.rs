@@ -17,6 +17,22 @@ pub struct ItemDrop {
     pub time_on_ground: f32, // For despawn logic later
     pub tier: ItemTier,
     pub stackable: bool,
+    #[serde(skip)]
+    pub magnet_progress: f32, // Time accumulated toward the next drift step
+    #[serde(default = "default_pile_count")]
+    pub pile_count: u32, // Number of drops merged into this pile
+    #[serde(default)]
+    pub recovery: bool, // Gold the player dropped on death
+}
+
+fn default_pile_count() -> u32 {
+    1
+}
+
+impl ItemDropType {
+    pub fn is_gold(&self) -> bool {
+        matches!(self, ItemDropType::Gold(_))
+    }
 }
 
 impl ItemDrop {
@@ -28,6 +44,9 @@ impl ItemDrop {
             time_on_ground: 0.0,
             tier: ItemTier::Common,
             stackable: true, // Consumables are stackable
+            magnet_progress: 0.0,
+            pile_count: 1,
+            recovery: false,
         }
     }
 
@@ -39,6 +58,9 @@ impl ItemDrop {
             time_on_ground: 0.0,
             tier,
             stackable: true,
+            magnet_progress: 0.0,
+            pile_count: 1,
+            recovery: false,
         }
     }
 
@@ -50,6 +72,17 @@ impl ItemDrop {
             time_on_ground: 0.0,
             tier: ItemTier::Common,
             stackable: true, // Gold always stacks
+            magnet_progress: 0.0,
+            pile_count: 1,
+            recovery: false,
+        }
+    }
+
+    /// Gold left behind on death, waiting to be picked back up
+    pub fn recovered_gold(amount: u32, x: i32, y: i32) -> Self {
+        Self {
+            recovery: true,
+            ..Self::gold(amount, x, y)
         }
     }
 
@@ -62,12 +95,22 @@ impl ItemDrop {
             time_on_ground: 0.0,
             tier: rarity,
             stackable: false, // Weapons don't stack
+            magnet_progress: 0.0,
+            pile_count: 1,
+            recovery: false,
         }
     }
 
     pub fn get_glyph(&self) -> &'static str {
         match &self.item_type {
             ItemDropType::Consumable(c) => c.consumable_type.get_glyph(),
+            // Merged piles show how many drops they hold
+            ItemDropType::Gold(_) if self.pile_count > 1 => {
+                ["2", "3", "4", "5", "6", "7", "8", "9"]
+                    .get(self.pile_count as usize - 2)
+                    .copied()
+                    .unwrap_or("+")
+            }
             ItemDropType::Gold(_) => "¤",
             ItemDropType::Weapon(w) => w.weapon_type.get_glyph(),
         }
@@ -76,6 +119,9 @@ impl ItemDrop {
     pub fn get_description(&self) -> String {
         match &self.item_type {
             ItemDropType::Consumable(c) => c.name.clone(),
+            ItemDropType::Gold(amount) if self.pile_count > 1 => {
+                format!("{} gold ({} piles)", amount, self.pile_count)
+            }
             ItemDropType::Gold(amount) => format!("{} gold", amount),
             ItemDropType::Weapon(w) => {
                 let mut desc = format!("{} ({})", w.name, w.damage);
@@ -107,7 +153,9 @@ impl ItemDrop {
         match &self.item_type {
             // Consumables use function-aware coloring (not tier-based)
             ItemDropType::Consumable(c) => c.consumable_type.get_color(),
-            // Gold is always gray
+            // Gold left behind on death stands out so the player can find it
+            ItemDropType::Gold(_) if self.recovery => Color::Yellow,
+            // Gold is otherwise always gray
             ItemDropType::Gold(_) => Color::DarkGray,
             // Weapons use tier-based coloring
             ItemDropType::Weapon(_) => match self.tier {
@@ -122,6 +170,19 @@ impl ItemDrop {
         }
     }
 
+    /// Whether the item magnet can pull this item (weapons need an exact-tile pickup)
+    pub fn is_magnetic(&self) -> bool {
+        !matches!(self.item_type, ItemDropType::Weapon(_))
+    }
+
+    /// Fold another gold drop into this pile
+    pub fn merge_gold(&mut self, amount: u32, count: u32) {
+        if let ItemDropType::Gold(total) = &mut self.item_type {
+            *total += amount;
+            self.pile_count += count;
+        }
+    }
+
     pub fn update(&mut self, delta: f32) {
         self.time_on_ground += delta;
     }
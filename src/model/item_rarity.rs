@@ -26,6 +26,19 @@ impl ItemRarity {
         }
     }
 
+    /// Multiplier on the gold a weapon of this rarity is scrapped for
+    pub fn scrap_multiplier(&self) -> u32 {
+        match self {
+            ItemRarity::Common => 1,
+            ItemRarity::Rare => 2,
+            ItemRarity::Epic => 3,
+            ItemRarity::Exotic => 5,
+            ItemRarity::Legendary => 8,
+            ItemRarity::Mythic => 12,
+            ItemRarity::Godly => 20,
+        }
+    }
+
     /// Get rarity color for UI rendering (base fade progression)
     pub fn get_color(&self) -> Color {
         match self {
@@ -0,0 +1,83 @@
+use crate::model::character::Character;
+use crate::model::weapon::{Weapon, WeaponInventory};
+
+/// Class picked at character creation. It decides the starting weapons and
+/// nudges a few base stats.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum StartingClass {
+    #[default]
+    Warrior,
+    Archer,
+    Mage,
+}
+
+impl StartingClass {
+    pub const ALL: [StartingClass; 3] = [
+        StartingClass::Warrior,
+        StartingClass::Archer,
+        StartingClass::Mage,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            StartingClass::Warrior => "Warrior",
+            StartingClass::Archer => "Archer",
+            StartingClass::Mage => "Mage",
+        }
+    }
+
+    /// Parse a class from its saved name, falling back to Warrior
+    pub fn from_name(name: &str) -> Self {
+        Self::ALL
+            .into_iter()
+            .find(|class| class.name() == name)
+            .unwrap_or_default()
+    }
+
+    pub fn description(&self) -> &'static str {
+        match self {
+            StartingClass::Warrior => "Sword and mace, +20 max health",
+            StartingClass::Archer => "Bow only, faster arrows and longer dashes",
+            StartingClass::Mage => "Quarterstaff, +3 damage, -10 max health",
+        }
+    }
+
+    pub fn next(&self) -> Self {
+        let idx = Self::ALL.iter().position(|c| c == self).unwrap_or(0);
+        Self::ALL[(idx + 1) % Self::ALL.len()]
+    }
+
+    pub fn previous(&self) -> Self {
+        let idx = Self::ALL.iter().position(|c| c == self).unwrap_or(0);
+        Self::ALL[(idx + Self::ALL.len() - 1) % Self::ALL.len()]
+    }
+
+    pub fn weapons(&self) -> Vec<Weapon> {
+        match self {
+            StartingClass::Warrior => vec![Weapon::new_sword(), Weapon::new_mace()],
+            StartingClass::Archer => vec![Weapon::new_bow()],
+            StartingClass::Mage => vec![Weapon::quarterstaff()],
+        }
+    }
+
+    /// Give a fresh character this class's weapons and stat tweaks
+    pub fn apply(&self, character: &mut Character) {
+        character.weapon_inventory = WeaponInventory {
+            weapons: self.weapons(),
+            current_weapon_index: 0,
+        };
+
+        match self {
+            StartingClass::Warrior => character.health_max += 20,
+            StartingClass::Archer => {
+                character.arrow_speed += 2.0;
+                character.dash_distance += 1;
+            }
+            StartingClass::Mage => {
+                character.health_max -= 10;
+                character.attack_damage += 3;
+            }
+        }
+        character.health = character.health_max;
+    }
+}
@@ -0,0 +1,87 @@
+/// Index of the previous row for which `selectable` holds, wrapping from the top to the bottom.
+/// Returns `current` unchanged when nothing is selectable.
+pub fn previous_selectable(
+    current: usize,
+    total: usize,
+    selectable: impl Fn(usize) -> bool,
+) -> usize {
+    if total == 0 {
+        return current;
+    }
+    let current = current.min(total - 1);
+    (1..=total)
+        .map(|step| (current + total - step) % total)
+        .find(|&i| selectable(i))
+        .unwrap_or(current)
+}
+
+/// Index of the next row for which `selectable` holds, wrapping from the bottom to the top.
+/// Returns `current` unchanged when nothing is selectable.
+pub fn next_selectable(current: usize, total: usize, selectable: impl Fn(usize) -> bool) -> usize {
+    if total == 0 {
+        return current;
+    }
+    let current = current.min(total - 1);
+    (1..=total)
+        .map(|step| (current + step) % total)
+        .find(|&i| selectable(i))
+        .unwrap_or(current)
+}
+
+/// Scroll offset that keeps `selected` inside a window of `visible` rows, moving the
+/// window as little as possible from `offset`
+pub fn scroll_to_show(selected: usize, offset: usize, visible: usize) -> usize {
+    let visible = visible.max(1);
+    if selected < offset {
+        selected
+    } else if selected >= offset + visible {
+        selected + 1 - visible
+    } else {
+        offset
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_wraps_at_both_ends() {
+        assert_eq!(previous_selectable(0, 5, |_| true), 4);
+        assert_eq!(next_selectable(4, 5, |_| true), 0);
+        assert_eq!(next_selectable(1, 5, |_| true), 2);
+        assert_eq!(previous_selectable(3, 5, |_| true), 2);
+    }
+
+    #[test]
+    fn test_skips_unselectable_rows() {
+        // Row 2 is a separator, row 4 the last entry
+        let selectable = |i: usize| i != 2;
+        assert_eq!(next_selectable(1, 5, selectable), 3);
+        assert_eq!(previous_selectable(3, 5, selectable), 1);
+
+        // Unselectable rows at the ends are skipped when wrapping too
+        let selectable = |i: usize| i != 0 && i != 4;
+        assert_eq!(next_selectable(3, 5, selectable), 1);
+        assert_eq!(previous_selectable(1, 5, selectable), 3);
+    }
+
+    #[test]
+    fn test_empty_and_unselectable_lists_keep_selection() {
+        assert_eq!(next_selectable(0, 0, |_| true), 0);
+        assert_eq!(previous_selectable(0, 0, |_| true), 0);
+        assert_eq!(next_selectable(2, 3, |_| false), 2);
+    }
+
+    #[test]
+    fn test_scrolling_to_last_item_shows_it() {
+        // Ten rows, four visible: jumping to the last one slides the window down to it
+        let offset = scroll_to_show(9, 0, 4);
+        assert_eq!(offset, 6);
+        assert!((offset..offset + 4).contains(&9));
+
+        // Wrapping back to the top scrolls up again; moves inside the window don't scroll
+        assert_eq!(scroll_to_show(0, offset, 4), 0);
+        assert_eq!(scroll_to_show(7, offset, 4), offset);
+    }
+}
@@ -1,27 +1,37 @@
+pub mod adaptive_difficulty;
 pub mod arrow;
 pub mod attack_pattern;
 pub mod audio;
 pub mod boss;
+pub mod camera;
 pub mod character;
 pub mod collision;
+pub mod combo;
 pub mod consumable;
 pub mod cooldown;
 pub mod damage_calculator;
 pub mod enemy;
 pub mod enemy_type;
 pub mod floor;
+pub mod floor_code;
+pub mod floor_theme;
 pub mod game_difficulty;
 pub mod gamesave;
+pub mod hazard;
 pub mod item;
 pub mod item_rarity;
 pub mod item_tier;
+pub mod loadout;
+pub mod menu;
 pub mod particle;
 pub mod pathfinding_cache;
 pub mod reach_shape;
+pub mod run_history;
 pub mod settings;
 pub mod skill;
 pub mod skill_tree_path;
 pub mod status_effect;
+pub mod tutorial;
 pub mod ultimate;
 pub mod ultimate_shop;
 pub mod weapon;
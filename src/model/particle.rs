@@ -7,7 +7,8 @@ pub struct Particle {
     pub glyph: char,
     pub color: ratatui::prelude::Color,
     pub created_at: Instant,
-    pub lifetime: f32, // seconds
+    pub lifetime: f32,            // seconds
+    pub fade_glyph: Option<char>, // Shown instead of `glyph` for the second half of its life
 }
 
 impl Particle {
@@ -19,6 +20,7 @@ impl Particle {
             color,
             created_at: Instant::now(),
             lifetime,
+            fade_glyph: None,
         }
     }
 
@@ -32,20 +34,48 @@ impl Particle {
     }
 }
 
-#[derive(Clone, Debug, Default)]
+/// Colour of the puff a ghost leaves when it dies
+pub const GHOST_PUFF_COLOR: ratatui::prelude::Color = ratatui::prelude::Color::LightBlue;
+
+/// Colour of the bone shards an undead shatters into
+pub const BONE_DEBRIS_COLOR: ratatui::prelude::Color = ratatui::prelude::Color::Indexed(187);
+
+#[derive(Clone, Debug)]
 pub struct ParticleSystem {
     pub particles: Vec<Particle>,
+    pub density: f32, // 0.0 - 1.0: share of emitted particles kept, and of the live particle cap
+}
+
+impl Default for ParticleSystem {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl ParticleSystem {
     pub fn new() -> Self {
         Self {
             particles: Vec::new(),
+            density: 1.0,
         }
     }
 
     #[allow(dead_code)] // Part of particle emission API
     pub fn emit(&mut self, particle: Particle) {
+        self.push(particle);
+    }
+
+    /// Live particles allowed at the current density
+    pub fn cap(&self) -> usize {
+        (crate::constants::MAX_PARTICLES as f32 * self.density.clamp(0.0, 1.0)).ceil() as usize
+    }
+
+    /// Every emission goes through here: at lower densities particles are randomly
+    /// dropped, and none are added past the cap. Density 0 adds nothing at all.
+    fn push(&mut self, particle: Particle) {
+        if self.particles.len() >= self.cap() || rand::random::<f32>() >= self.density {
+            return;
+        }
         self.particles.push(particle);
     }
 
@@ -64,7 +94,22 @@ impl ParticleSystem {
                 ratatui::prelude::Color::Yellow,
                 0.5,
             );
-            self.particles.push(particle);
+            self.push(particle);
+        }
+    }
+
+    /// Burst of red marks around a boss entering a new phase
+    pub fn emit_enrage(&mut self, x: f32, y: f32) {
+        for i in 0..8 {
+            let angle = (i as f32 / 8.0) * std::f32::consts::TAU;
+            let particle = Particle::new(
+                x + angle.cos() * 1.5,
+                y + angle.sin() * 1.5,
+                '!',
+                ratatui::prelude::Color::Red,
+                0.8,
+            );
+            self.push(particle);
         }
     }
 
@@ -79,7 +124,7 @@ impl ParticleSystem {
                 ratatui::prelude::Color::Green,
                 0.4,
             );
-            self.particles.push(particle);
+            self.push(particle);
         }
     }
 
@@ -93,7 +138,7 @@ impl ParticleSystem {
 
             let glyph = glyphs[i % glyphs.len()];
             let particle = Particle::new(x + dx, y + dy, glyph, color, 0.3);
-            self.particles.push(particle);
+            self.push(particle);
         }
     }
 
@@ -104,11 +149,168 @@ impl ParticleSystem {
         }
     }
 
+    /// Death effect for an enemy: bosses burst apart, ghosts dissipate in a blue puff and
+    /// undead shatter into bone debris. Skipped when the particle cap is reached.
+    pub fn emit_death(
+        &mut self,
+        x: f32,
+        y: f32,
+        enemy_type: &crate::model::enemy_type::EnemyType,
+        rarity: &crate::model::enemy_type::EnemyRarity,
+    ) {
+        use crate::model::enemy_type::{EnemyRarity, EnemyType};
+
+        if self.particles.len() >= self.cap() {
+            return;
+        }
+        match (rarity, enemy_type) {
+            (EnemyRarity::Boss, _) => self.emit_boss_death(x, y),
+            (_, EnemyType::Ghost) => self.emit_ghost_puff(x, y),
+            (_, EnemyType::Undead) => self.emit_bone_debris(x, y),
+        }
+    }
+
+    /// Soft blue wisps drifting up from where a ghost vanished
+    fn emit_ghost_puff(&mut self, x: f32, y: f32) {
+        let lifetime = crate::constants::DEATH_PARTICLE_LIFETIME;
+        for (i, glyph) in ['░', '∙', '░', '∙', '°'].into_iter().enumerate() {
+            let dx = i as f32 - 2.0;
+            let mut particle = Particle::new(
+                x + dx * 0.6,
+                y - (i % 2) as f32,
+                glyph,
+                GHOST_PUFF_COLOR,
+                lifetime,
+            );
+            particle.fade_glyph = Some('·');
+            self.push(particle);
+        }
+    }
+
+    /// Bone-coloured shards scattered around a shattered undead
+    fn emit_bone_debris(&mut self, x: f32, y: f32) {
+        let lifetime = crate::constants::DEATH_PARTICLE_LIFETIME;
+        for (i, glyph) in ['%', ',', '\'', '%', '`', ','].into_iter().enumerate() {
+            let angle = (i as f32 / 6.0) * std::f32::consts::TAU;
+            let particle = Particle::new(
+                x + angle.cos() * 1.2,
+                y + angle.sin() * 0.8,
+                glyph,
+                BONE_DEBRIS_COLOR,
+                lifetime,
+            );
+            self.push(particle);
+        }
+    }
+
+    /// Large burst of expanding debris rings where a boss fell
+    fn emit_boss_death(&mut self, x: f32, y: f32) {
+        use ratatui::prelude::Color;
+
+        let lifetime = crate::constants::DEATH_PARTICLE_LIFETIME * 2.0;
+        for ring in 1..=crate::constants::BOSS_DEATH_RING_COUNT {
+            let radius = ring as f32 * 1.5;
+            let (glyph, color) = match ring % 3 {
+                1 => ('✦', Color::LightMagenta),
+                2 => ('*', Color::Yellow),
+                _ => ('·', Color::Red),
+            };
+            for i in 0..12 {
+                let angle = (i as f32 / 12.0) * std::f32::consts::TAU;
+                let particle = Particle::new(
+                    x + angle.cos() * radius * 2.0, // Cells are about twice as tall as wide
+                    y + angle.sin() * radius,
+                    glyph,
+                    color,
+                    lifetime,
+                );
+                self.push(particle);
+            }
+        }
+    }
+
+    /// Leave a fading trail mark behind a fast-moving arrow or dash.
+    /// Skipped once the trail cap is reached so crowded fights stay cheap.
+    pub fn emit_trail(&mut self, x: f32, y: f32, color: ratatui::prelude::Color) {
+        let trail_count = self
+            .particles
+            .iter()
+            .filter(|p| p.fade_glyph.is_some())
+            .count();
+        if trail_count >= crate::constants::MAX_TRAIL_PARTICLES {
+            return;
+        }
+
+        let mut particle = Particle::new(x, y, '•', color, crate::constants::TRAIL_LIFETIME);
+        particle.fade_glyph = Some('·');
+        self.push(particle);
+    }
+
     pub fn get_active_particles(&self) -> Vec<(i32, i32, char, ratatui::prelude::Color)> {
         self.particles
             .iter()
             .filter(|p| p.is_alive())
-            .map(|p| (p.x.round() as i32, p.y.round() as i32, p.glyph, p.color))
+            .map(|p| {
+                let glyph = match p.fade_glyph {
+                    Some(faded) if p.get_alpha() < 0.5 => faded,
+                    _ => p.glyph,
+                };
+                (p.x.round() as i32, p.y.round() as i32, glyph, p.color)
+            })
             .collect()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::enemy_type::{EnemyRarity, EnemyType};
+
+    #[test]
+    fn test_ghost_death_emits_ghost_puff() {
+        let mut system = ParticleSystem::new();
+        system.emit_death(10.0, 10.0, &EnemyType::Ghost, &EnemyRarity::Guard);
+        assert!(!system.particles.is_empty());
+        assert!(system.particles.iter().all(|p| p.color == GHOST_PUFF_COLOR));
+
+        let mut system = ParticleSystem::new();
+        system.emit_death(10.0, 10.0, &EnemyType::Undead, &EnemyRarity::Guard);
+        assert!(system
+            .particles
+            .iter()
+            .all(|p| p.color == BONE_DEBRIS_COLOR));
+    }
+
+    #[test]
+    fn test_death_bursts_respect_particle_cap() {
+        let mut system = ParticleSystem::new();
+        system.emit_death(0.0, 0.0, &EnemyType::Undead, &EnemyRarity::Boss);
+        let boss_burst = system.particles.len();
+        assert!(boss_burst > 6, "bosses get a bigger burst");
+
+        for _ in 0..1000 {
+            system.emit_death(0.0, 0.0, &EnemyType::Undead, &EnemyRarity::Boss);
+        }
+        assert!(system.particles.len() < crate::constants::MAX_PARTICLES + boss_burst);
+    }
+
+    #[test]
+    fn test_zero_density_emits_nothing() {
+        let mut system = ParticleSystem::new();
+        system.density = 0.0;
+        system.emit_crit(5.0, 5.0);
+        system.emit_glint(5.0, 5.0, ratatui::prelude::Color::Yellow);
+        system.emit_trail(5.0, 5.0, ratatui::prelude::Color::White);
+        system.emit_death(5.0, 5.0, &EnemyType::Undead, &EnemyRarity::Boss);
+        assert!(system.particles.is_empty());
+        assert!(system.get_active_particles().is_empty());
+
+        // Half density halves the cap
+        system.density = 0.5;
+        assert_eq!(system.cap(), crate::constants::MAX_PARTICLES / 2);
+        for _ in 0..1000 {
+            system.emit_crit(5.0, 5.0);
+        }
+        assert!(system.particles.len() <= system.cap());
+    }
+}
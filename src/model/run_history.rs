@@ -0,0 +1,117 @@
+use crate::constants::RUN_HISTORY_MAX_RECORDS;
+use crate::model::character::Character;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::Path;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// How a recorded run ended
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum RunOutcome {
+    Victory,
+    Death,
+}
+
+impl RunOutcome {
+    pub fn name(&self) -> &'static str {
+        match self {
+            RunOutcome::Victory => "Victory",
+            RunOutcome::Death => "Death",
+        }
+    }
+}
+
+/// One finished run, as listed on the Records screen
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct RunRecord {
+    pub timestamp: u64, // Seconds since the Unix epoch when the run ended
+    pub character_name: String,
+    pub difficulty: String,
+    pub floors_cleared: u32,
+    pub time_elapsed: f32, // Seconds
+    pub enemies_killed: u32,
+    pub outcome: RunOutcome,
+    pub score: u32,
+}
+
+impl RunRecord {
+    /// Record `character`'s run as it ends now, scored the same way as the victory screen
+    pub fn new(
+        character: &Character,
+        difficulty: &str,
+        floors_cleared: u32,
+        time_elapsed: f32,
+        outcome: RunOutcome,
+    ) -> Self {
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |since| since.as_secs());
+        Self {
+            timestamp,
+            character_name: character.name.clone(),
+            difficulty: difficulty.to_string(),
+            floors_cleared,
+            time_elapsed,
+            enemies_killed: character.enemies_killed,
+            outcome,
+            score: character.run_score(floors_cleared),
+        }
+    }
+
+    /// Day the run ended as YYYY-MM-DD (UTC)
+    pub fn date(&self) -> String {
+        // Civil-from-days: shift the epoch to 0000-03-01 so leap days end each era
+        let days = (self.timestamp / 86_400) as i64 + 719_468;
+        let era = days.div_euclid(146_097);
+        let day_of_era = days.rem_euclid(146_097);
+        let year_of_era =
+            (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+        let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+        let shifted_month = (5 * day_of_year + 2) / 153;
+        let day = day_of_year - (153 * shifted_month + 2) / 5 + 1;
+        let month = if shifted_month < 10 {
+            shifted_month + 3
+        } else {
+            shifted_month - 9
+        };
+        let year = year_of_era + era * 400 + i64::from(month <= 2);
+        format!("{:04}-{:02}-{:02}", year, month, day)
+    }
+}
+
+/// Past runs, oldest first, kept in a JSON file beside the settings
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct RunHistory {
+    pub records: Vec<RunRecord>,
+}
+
+impl RunHistory {
+    /// Read the history at `path`; a missing or unreadable file reads as no runs yet
+    pub fn load_from(path: impl AsRef<Path>) -> Self {
+        fs::read_to_string(path)
+            .ok()
+            .and_then(|data| serde_json::from_str(&data).ok())
+            .unwrap_or_default()
+    }
+
+    /// Add `record` to the history at `path`, dropping the oldest runs beyond
+    /// `RUN_HISTORY_MAX_RECORDS`
+    pub fn append_to(path: impl AsRef<Path>, record: RunRecord) -> std::io::Result<()> {
+        let mut history = Self::load_from(&path);
+        history.records.push(record);
+        let excess = history
+            .records
+            .len()
+            .saturating_sub(RUN_HISTORY_MAX_RECORDS);
+        history.records.drain(..excess);
+        let data = serde_json::to_string_pretty(&history).map_err(std::io::Error::other)?;
+        fs::write(path, data)
+    }
+
+    /// Records best score first; ties go to the more recent run
+    pub fn by_score(&self) -> Vec<&RunRecord> {
+        let mut records: Vec<&RunRecord> = self.records.iter().collect();
+        records.sort_by(|a, b| b.score.cmp(&a.score).then(b.timestamp.cmp(&a.timestamp)));
+        records
+    }
+}
@@ -1,8 +1,307 @@
+use crate::model::enemy::DistanceMetric;
+use crate::model::floor::FloorGenParams;
 use crate::model::item_tier::Difficulty;
 use serde::{Deserialize, Serialize};
 use std::fs;
+use std::path::Path;
 
-#[derive(Serialize, Deserialize, Clone, Debug)]
+/// Settings format written by this build. Bump it and add a step to `Settings::migrate`
+/// whenever a changed default needs values from older files rewritten.
+pub const SETTINGS_VERSION: u32 = 1;
+
+/// Shortcuts the game handles itself, outside the rebindable actions. `H` also opens
+/// help, but gives way to any action bound to it, so it is not listed.
+pub const FIXED_KEYS: [(&str, &str); 3] = [
+    ("Examine Enemy", "V"),
+    ("Drop Other Weapons", "X"),
+    ("Scrap Weapon", "Z"),
+];
+
+/// What happens to the save when the player dies
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Default)]
+pub enum RunMode {
+    /// Death deletes the save; only a new run is offered
+    Permadeath,
+    /// Death allows retrying the floor from the last auto-save
+    #[default]
+    Checkpoint,
+}
+
+impl RunMode {
+    pub fn name(&self) -> &'static str {
+        match self {
+            RunMode::Permadeath => "Permadeath",
+            RunMode::Checkpoint => "Checkpoint",
+        }
+    }
+
+    /// Parse run mode from string
+    pub fn from_name(name: &str) -> Self {
+        match name {
+            "Permadeath" => RunMode::Permadeath,
+            _ => RunMode::Checkpoint,
+        }
+    }
+
+    pub fn toggled(&self) -> Self {
+        match self {
+            RunMode::Permadeath => RunMode::Checkpoint,
+            RunMode::Checkpoint => RunMode::Permadeath,
+        }
+    }
+}
+
+/// Named set of key bindings the settings menu applies in one go. Bindings that match
+/// none of them, e.g. after rebinding single keys, count as `Custom`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum KeyPreset {
+    Wasd,
+    Arrows,
+    Vim,
+    Custom,
+}
+
+impl KeyPreset {
+    pub const NAMED: [KeyPreset; 3] = [KeyPreset::Wasd, KeyPreset::Arrows, KeyPreset::Vim];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            KeyPreset::Wasd => "WASD",
+            KeyPreset::Arrows => "Arrow Keys",
+            KeyPreset::Vim => "Vim (hjkl)",
+            KeyPreset::Custom => "Custom",
+        }
+    }
+
+    /// Keys of the preset in `Settings::bindings()` order; `Custom` has none
+    pub fn keys(&self) -> Option<[&'static str; 18]> {
+        match self {
+            KeyPreset::Wasd => Some([
+                "W",
+                "A",
+                "S",
+                "D",
+                "LeftClick",
+                "Space",
+                "F",
+                "C",
+                "E",
+                "Up",
+                "Down",
+                "Return",
+                "P",
+                "Q",
+                "T",
+                "Y",
+                "K",
+                "G",
+            ]),
+            // Movement on the arrows, actions under the left hand
+            KeyPreset::Arrows => Some([
+                "Up", "Left", "Down", "Right", "A", "Space", "D", "C", "E", "W", "S", "Return",
+                "P", "Q", "T", "Y", "K", "G",
+            ]),
+            // Quicksave moves off K, which walks up
+            KeyPreset::Vim => Some([
+                "K", "H", "J", "L", "F", "Space", "D", "I", "E", "Up", "Down", "Return", "P", "Q",
+                "T", "Y", "S", "G",
+            ]),
+            KeyPreset::Custom => None,
+        }
+    }
+
+    /// Next named preset in `direction`, wrapping; from `Custom` either end
+    pub fn cycled(&self, direction: i32) -> Self {
+        let len = Self::NAMED.len() as i32;
+        let next = match Self::NAMED.iter().position(|preset| preset == self) {
+            Some(idx) => (idx as i32 + direction).rem_euclid(len),
+            None if direction > 0 => 0,
+            None => len - 1,
+        };
+        Self::NAMED[next as usize]
+    }
+}
+
+/// One row of a settings menu. Menus list their rows once and derive both
+/// rendering and navigation from that list.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SettingsRow {
+    KeyPreset,
+    Rebind(usize), // Index into Settings::bindings()
+    Difficulty,
+    DefaultDifficulty,
+    MusicVolume,
+    SoundVolume,
+    MuteAudio,
+    SkipLogoAnimation,
+    RunMode,
+    FogOfWar,
+    AutoFaceNearest,
+    AutoFaceRadius,
+    MergeGoldPiles,
+    GameSpeed,
+    ParticleDensity,
+    HitStop,
+    HoldMoveRate,
+    AutoFire,
+    ConfirmQuit,
+    CameraDeadzone,
+    CameraSnap,
+    CornerSliding,
+    PlayerKnockback,
+    AdaptiveDifficulty,
+    ComboResetOnDamage,
+    MovementTrails,
+    AutosaveInterval,
+    DropGoldOnDeath,
+    ShowFps,
+    LowDetail,
+    RoomEnemyPlacement,
+    AutoPickup,
+    EnemyDistance,
+    FloorWidth,
+    FloorHeight,
+    FillProbability,
+    CaveSmoothing,
+    TunnelStyle,
+    TunnelWidth,
+    Export,
+    Import,
+    Separator,
+    Save,
+    Discard,
+    Reset,
+}
+
+impl SettingsRow {
+    fn rebind_rows() -> impl Iterator<Item = SettingsRow> {
+        (0..Settings::default().bindings().len()).map(SettingsRow::Rebind)
+    }
+
+    /// Rows of the settings screen reached from the main menu
+    pub fn settings_screen() -> Vec<SettingsRow> {
+        std::iter::once(SettingsRow::KeyPreset)
+            .chain(Self::rebind_rows())
+            .chain([
+                SettingsRow::Difficulty,
+                SettingsRow::DefaultDifficulty,
+                SettingsRow::AdaptiveDifficulty,
+                SettingsRow::MusicVolume,
+                SettingsRow::SoundVolume,
+                SettingsRow::MuteAudio,
+                SettingsRow::SkipLogoAnimation,
+                SettingsRow::RunMode,
+                SettingsRow::FogOfWar,
+                SettingsRow::AutoFaceNearest,
+                SettingsRow::AutoFaceRadius,
+                SettingsRow::MergeGoldPiles,
+                SettingsRow::GameSpeed,
+                SettingsRow::ParticleDensity,
+                SettingsRow::HitStop,
+                SettingsRow::HoldMoveRate,
+                SettingsRow::AutoFire,
+                SettingsRow::ConfirmQuit,
+                SettingsRow::CameraDeadzone,
+                SettingsRow::CameraSnap,
+                SettingsRow::CornerSliding,
+                SettingsRow::PlayerKnockback,
+                SettingsRow::ComboResetOnDamage,
+                SettingsRow::MovementTrails,
+                SettingsRow::AutosaveInterval,
+                SettingsRow::DropGoldOnDeath,
+                SettingsRow::ShowFps,
+                SettingsRow::LowDetail,
+                SettingsRow::RoomEnemyPlacement,
+                SettingsRow::AutoPickup,
+                SettingsRow::EnemyDistance,
+                SettingsRow::FloorWidth,
+                SettingsRow::FloorHeight,
+                SettingsRow::FillProbability,
+                SettingsRow::CaveSmoothing,
+                SettingsRow::TunnelStyle,
+                SettingsRow::TunnelWidth,
+                SettingsRow::Export,
+                SettingsRow::Import,
+                SettingsRow::Separator,
+                SettingsRow::Save,
+                SettingsRow::Discard,
+                SettingsRow::Reset,
+            ])
+            .collect()
+    }
+
+    /// Rows of the settings submenu in the pause menu
+    pub fn pause_menu() -> Vec<SettingsRow> {
+        Self::rebind_rows()
+            .chain([
+                SettingsRow::Difficulty,
+                SettingsRow::DefaultDifficulty,
+                SettingsRow::Separator,
+                SettingsRow::Save,
+                SettingsRow::Discard,
+                SettingsRow::Reset,
+            ])
+            .collect()
+    }
+
+    pub fn is_selectable(&self) -> bool {
+        *self != SettingsRow::Separator
+    }
+}
+
+fn default_skill_tree_key() -> String {
+    "T".into()
+}
+
+fn default_ultimate_shop_key() -> String {
+    "Y".into()
+}
+
+fn default_auto_face_radius() -> i32 {
+    crate::constants::AUTO_FACE_DEFAULT_RADIUS
+}
+
+fn default_game_speed() -> f32 {
+    1.0
+}
+
+fn default_particle_density() -> f32 {
+    1.0
+}
+
+fn default_player_knockback() -> f32 {
+    1.0
+}
+
+fn default_quicksave_key() -> String {
+    "K".into()
+}
+
+fn default_interact_key() -> String {
+    "G".into()
+}
+
+fn default_autosave_interval() -> u32 {
+    crate::constants::DEFAULT_AUTOSAVE_INTERVAL_SECS
+}
+
+fn default_hitstop_ms() -> u32 {
+    crate::constants::HITSTOP_DEFAULT_MS
+}
+
+fn default_camera_deadzone() -> u32 {
+    crate::constants::CAMERA_DEADZONE_DEFAULT
+}
+
+fn default_hold_move_rate() -> u32 {
+    crate::constants::DEFAULT_HOLD_MOVE_RATE
+}
+
+fn default_true() -> bool {
+    true
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
 pub struct Settings {
     pub move_up: String,
     pub move_left: String,
@@ -18,12 +317,78 @@ pub struct Settings {
     pub inventory_down: String,
     pub item_describe: String,
     pub pause: String,
+    #[serde(default = "default_skill_tree_key")]
+    pub skill_tree: String,
+    #[serde(default = "default_ultimate_shop_key")]
+    pub ultimate_shop: String,
+    #[serde(default = "default_quicksave_key")]
+    pub quicksave: String,
+    #[serde(default = "default_interact_key")]
+    pub interact: String,
     pub difficulty: Difficulty,
     pub default_difficulty: Difficulty,
     pub player_speed: f32, // Multiplier for player movement speed (1.0 = default)
     pub music_volume: f32, // Music volume 0.0 - 1.0
     pub sound_volume: f32, // Sound effects volume 0.0 - 1.0
+    #[serde(default)]
+    pub mute_audio: bool, // Never open the sound device: no music or effects at all
     pub skip_logo_animation: bool, // Skip the logo loading animation
+    #[serde(default)]
+    pub run_mode: RunMode, // Run mode applied to new runs
+    #[serde(default)]
+    pub fog_of_war: bool, // Hide unexplored tiles and dim out-of-sight ones
+    #[serde(default)]
+    pub auto_face_nearest: bool, // Aim at the nearest enemy when attacking without moving
+    #[serde(default = "default_auto_face_radius")]
+    pub auto_face_radius: i32, // Search radius for the auto-face assist
+    #[serde(default = "default_true")]
+    pub merge_gold_piles: bool, // Combine gold dropped on the same tile into one pile
+    #[serde(default = "default_game_speed")]
+    pub game_speed: f32, // Simulation speed multiplier (0.5x - 2x)
+    #[serde(default = "default_particle_density")]
+    pub particle_density: f32, // 0.0 - 1.0 share of particles emitted, 0 = none
+    #[serde(default = "default_hold_move_rate")]
+    pub hold_move_rate: u32, // Tiles per second while a direction key is held, 0 = terminal key repeat
+    #[serde(default)]
+    pub auto_fire: bool, // Holding attack keeps attacking at the weapon's cooldown
+    #[serde(default = "default_true")]
+    pub confirm_quit: bool, // Ask again before the pause menu's Quit ends a run
+    #[serde(default = "default_hitstop_ms")]
+    pub hitstop_ms: u32, // Milliseconds the action freezes on a heavy hit, 0 = off
+    #[serde(default = "default_camera_deadzone")]
+    pub camera_deadzone: u32, // Percent of the view around its centre the player moves in without scrolling, 0 = always centred
+    #[serde(default)]
+    pub camera_snap: bool, // Jump the camera to its target instead of easing toward it
+    #[serde(default)]
+    pub corner_sliding: bool, // Blocked diagonal moves slide along the wall on the open axis
+    #[serde(default)]
+    pub adaptive_difficulty: bool, // Nudge enemy stats up or down based on how the player is doing
+    #[serde(default = "default_player_knockback")]
+    pub player_knockback: f32, // 0.0 - 1.0 share of the usual knockback enemy hits give the player, 0 = none
+    #[serde(default = "default_true")]
+    pub combo_resets_on_damage: bool, // Taking a hit ends the current combo
+    #[serde(default = "default_true")]
+    pub movement_trails: bool, // Fading trails behind arrows and dashes
+    #[serde(default = "default_autosave_interval")]
+    pub autosave_interval_secs: u32, // Seconds between auto-saves, 0 = off
+    #[serde(default = "default_true")]
+    pub drop_gold_on_death: bool, // Leave a recoverable gold pile where the player died
+    #[serde(default)]
+    pub show_fps: bool, // FPS counter in the top-right corner during play
+    #[serde(default)]
+    pub low_detail: bool, // No blinking, plain walls and no particles for slow terminals
+    #[serde(default = "default_true")]
+    pub room_enemy_placement: bool, // Cluster enemies into rooms instead of scattering them
+    #[serde(default)]
+    pub auto_pickup: bool, // Walk over items and stairs to take them; otherwise only gold is automatic
+    #[serde(default)]
+    pub enemy_distance: DistanceMetric, // Metric for enemy detection and melee range
+    #[serde(default)]
+    pub floor_gen: FloorGenParams, // Size and shape of newly generated floors
+    #[serde(default = "default_true")]
+    pub tutorial_completed: bool, // Tutorial finished or skipped; older settings files count as done
+    #[serde(default)]
+    pub version: u32, // Settings format version, see SETTINGS_VERSION; 0 before versioning
 }
 
 impl Default for Settings {
@@ -35,7 +400,7 @@ impl Default for Settings {
             move_right: "D".into(),
             attack: "LeftClick".into(),
             dash: "Space".into(),
-            block: "RightClick".into(),
+            block: "F".into(),
             toggle_inv: "C".into(),
             use_consumable: "E".into(),
             special_item: "Q".into(),
@@ -43,12 +408,45 @@ impl Default for Settings {
             inventory_down: "Down".into(),
             item_describe: "Return".into(),
             pause: "P".into(),
+            skill_tree: default_skill_tree_key(),
+            ultimate_shop: default_ultimate_shop_key(),
+            quicksave: default_quicksave_key(),
+            interact: default_interact_key(),
             difficulty: Difficulty::Normal,
             default_difficulty: Difficulty::Normal,
             player_speed: 1.0,          // Default speed multiplier
             music_volume: 0.5,          // Default music volume
             sound_volume: 0.5,          // Default sound effects volume
             skip_logo_animation: false, // Don't skip by default
+            mute_audio: false,
+            run_mode: RunMode::Checkpoint,
+            fog_of_war: false,
+            auto_face_nearest: false,
+            auto_face_radius: default_auto_face_radius(),
+            merge_gold_piles: true,
+            game_speed: default_game_speed(),
+            particle_density: default_particle_density(),
+            hold_move_rate: default_hold_move_rate(),
+            auto_fire: false,
+            confirm_quit: true,
+            hitstop_ms: default_hitstop_ms(),
+            camera_deadzone: default_camera_deadzone(),
+            camera_snap: false,
+            corner_sliding: false,
+            adaptive_difficulty: false,
+            player_knockback: default_player_knockback(),
+            combo_resets_on_damage: true,
+            movement_trails: true,
+            autosave_interval_secs: default_autosave_interval(),
+            drop_gold_on_death: true,
+            show_fps: false,
+            low_detail: false,
+            room_enemy_placement: true,
+            auto_pickup: false,
+            enemy_distance: DistanceMetric::Manhattan,
+            floor_gen: FloorGenParams::default(),
+            tutorial_completed: false, // No settings file yet: first launch
+            version: SETTINGS_VERSION,
         }
     }
 }
@@ -56,7 +454,11 @@ impl Default for Settings {
 impl Settings {
     pub fn load() -> Self {
         fs::read_to_string("settings.json")
-            .and_then(|data| serde_json::from_str(&data).map_err(|e| e.into()))
+            .and_then(|data| serde_json::from_str::<Self>(&data).map_err(|e| e.into()))
+            .map(|mut settings| {
+                settings.migrate();
+                settings
+            })
             .unwrap_or_else(|_| {
                 let s = Self::default();
                 let _ = s.save();
@@ -64,8 +466,306 @@ impl Settings {
             })
     }
 
+    /// Every rebindable action paired with its current key, in settings menu order
+    pub fn bindings(&self) -> Vec<(&'static str, &str)> {
+        vec![
+            ("Move Up", &self.move_up),
+            ("Move Left", &self.move_left),
+            ("Move Down", &self.move_down),
+            ("Move Right", &self.move_right),
+            ("Attack", &self.attack),
+            ("Dash", &self.dash),
+            ("Block", &self.block),
+            ("Inventory Focus", &self.toggle_inv),
+            ("Use Consumable", &self.use_consumable),
+            ("Inv Up", &self.inventory_up),
+            ("Inv Down", &self.inventory_down),
+            ("Item Describe", &self.item_describe),
+            ("Pause", &self.pause),
+            ("Special", &self.special_item),
+            ("Skill Tree", &self.skill_tree),
+            ("Ultimate Shop", &self.ultimate_shop),
+            ("Quicksave", &self.quicksave),
+            ("Interact", &self.interact),
+        ]
+    }
+
+    /// Mutable key for the action at `index` in bindings() order
+    pub fn binding_mut(&mut self, index: usize) -> Option<&mut String> {
+        match index {
+            0 => Some(&mut self.move_up),
+            1 => Some(&mut self.move_left),
+            2 => Some(&mut self.move_down),
+            3 => Some(&mut self.move_right),
+            4 => Some(&mut self.attack),
+            5 => Some(&mut self.dash),
+            6 => Some(&mut self.block),
+            7 => Some(&mut self.toggle_inv),
+            8 => Some(&mut self.use_consumable),
+            9 => Some(&mut self.inventory_up),
+            10 => Some(&mut self.inventory_down),
+            11 => Some(&mut self.item_describe),
+            12 => Some(&mut self.pause),
+            13 => Some(&mut self.special_item),
+            14 => Some(&mut self.skill_tree),
+            15 => Some(&mut self.ultimate_shop),
+            16 => Some(&mut self.quicksave),
+            17 => Some(&mut self.interact),
+            _ => None,
+        }
+    }
+
+    /// Named preset the current bindings match, or `Custom`
+    pub fn key_preset(&self) -> KeyPreset {
+        let keys: Vec<String> = self
+            .bindings()
+            .into_iter()
+            .map(|(_, key)| Self::normalize_key(key))
+            .collect();
+        KeyPreset::NAMED
+            .into_iter()
+            .find(|preset| {
+                preset.keys().is_some_and(|preset_keys| {
+                    preset_keys
+                        .iter()
+                        .map(|key| Self::normalize_key(key))
+                        .eq(keys.iter().cloned())
+                })
+            })
+            .unwrap_or(KeyPreset::Custom)
+    }
+
+    /// Overwrite every binding with `preset`'s keys; `Custom` leaves them as they are
+    pub fn apply_key_preset(&mut self, preset: KeyPreset) {
+        let Some(keys) = preset.keys() else {
+            return;
+        };
+        for (index, key) in keys.into_iter().enumerate() {
+            if let Some(binding) = self.binding_mut(index) {
+                *binding = key.to_string();
+            }
+        }
+    }
+
+    /// Step the auto-save interval through the preset options
+    pub fn cycle_autosave_interval(&mut self, direction: i32) {
+        let options = crate::constants::AUTOSAVE_INTERVAL_OPTIONS;
+        let current = options
+            .iter()
+            .position(|&secs| secs == self.autosave_interval_secs)
+            .unwrap_or(0) as i32;
+        let next = (current + direction).clamp(0, options.len() as i32 - 1);
+        self.autosave_interval_secs = options[next as usize];
+    }
+
+    /// Step the held-key movement rate through the preset options
+    pub fn cycle_hold_move_rate(&mut self, direction: i32) {
+        let options = crate::constants::HOLD_MOVE_RATE_OPTIONS;
+        let current = options
+            .iter()
+            .position(|&rate| rate == self.hold_move_rate)
+            .unwrap_or(0) as i32;
+        let next = (current + direction).clamp(0, options.len() as i32 - 1);
+        self.hold_move_rate = options[next as usize];
+    }
+
+    pub fn hitstop_label(&self) -> String {
+        match self.hitstop_ms {
+            0 => "Off".to_string(),
+            ms => format!("{}ms", ms),
+        }
+    }
+
+    pub fn hold_move_rate_label(&self) -> String {
+        match self.hold_move_rate {
+            0 => "Key Repeat".to_string(),
+            rate => format!("{}/s", rate),
+        }
+    }
+
+    pub fn autosave_interval_label(&self) -> String {
+        match self.autosave_interval_secs {
+            0 => "Off".to_string(),
+            secs => format!("{}s", secs),
+        }
+    }
+
+    /// Canonical form of a stored key for comparing bindings ("t", "T" and "Char('t')" are equal)
+    pub fn normalize_key(key: &str) -> String {
+        let key = key
+            .strip_prefix("Char('")
+            .and_then(|k| k.strip_suffix("')"))
+            .unwrap_or(key);
+        match key {
+            " " => "SPACE".to_string(),
+            "Return" => "ENTER".to_string(),
+            _ => key.to_uppercase(),
+        }
+    }
+
+    /// Whether any rebindable action is bound to `key`
+    pub fn binds_key(&self, key: &str) -> bool {
+        let key = Self::normalize_key(key);
+        self.bindings()
+            .into_iter()
+            .any(|(_, bound)| Self::normalize_key(bound) == key)
+    }
+
+    /// Pairs of actions bound to the same key, including clashes with `FIXED_KEYS`; mouse
+    /// buttons are handled separately and skipped
+    pub fn find_conflicts(&self) -> Vec<(&'static str, &'static str)> {
+        let bindings: Vec<(&'static str, String)> = self
+            .bindings()
+            .into_iter()
+            .filter(|(_, key)| !matches!(*key, "LeftClick" | "RightClick"))
+            .map(|(action, key)| (action, Self::normalize_key(key)))
+            .collect();
+
+        let mut conflicts = Vec::new();
+        for (i, (action, key)) in bindings.iter().enumerate() {
+            for (other_action, other_key) in &bindings[i + 1..] {
+                if key == other_key {
+                    conflicts.push((*action, *other_action));
+                }
+            }
+            for (fixed_action, fixed_key) in FIXED_KEYS {
+                if key == fixed_key {
+                    conflicts.push((*action, fixed_action));
+                }
+            }
+        }
+        conflicts
+    }
+
     pub fn save(&self) -> std::io::Result<()> {
-        let data = serde_json::to_string_pretty(self).unwrap();
-        fs::write("settings.json", data)
+        self.export_to("settings.json")
+    }
+
+    /// Write these settings as pretty-printed JSON to `path`, for backups and sharing
+    pub fn export_to(&self, path: impl AsRef<Path>) -> std::io::Result<()> {
+        let data = serde_json::to_string_pretty(self).map_err(std::io::Error::other)?;
+        fs::write(path, data)
+    }
+
+    /// Read settings exported with `export_to`. Fields that are missing, malformed or out
+    /// of range keep their defaults; their names are returned alongside the settings.
+    pub fn import_from(path: impl AsRef<Path>) -> std::io::Result<(Self, Vec<String>)> {
+        let data = fs::read_to_string(path)?;
+        let value: serde_json::Value = serde_json::from_str(&data)
+            .map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e))?;
+        let serde_json::Value::Object(imported) = value else {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::InvalidData,
+                "settings file is not a JSON object",
+            ));
+        };
+
+        let version = imported
+            .get("version")
+            .and_then(serde_json::Value::as_u64)
+            .unwrap_or(0) as u32;
+
+        // Take each field on its own so one bad value doesn't discard the rest
+        let mut fields = serde_json::to_value(Self::default())?;
+        let mut rejected = Vec::new();
+        for (key, field) in imported {
+            let Some(slot) = fields.get_mut(&key) else {
+                continue;
+            };
+            let previous = std::mem::replace(slot, field);
+            if serde_json::from_value::<Self>(fields.clone()).is_err() {
+                fields[&key] = previous;
+                rejected.push(key);
+            }
+        }
+
+        let mut settings: Self = serde_json::from_value(fields)?;
+        settings.version = version;
+        settings.migrate();
+        rejected.extend(settings.reset_out_of_range());
+        Ok((settings, rejected))
+    }
+
+    /// Upgrade settings read from a file older than `SETTINGS_VERSION`
+    fn migrate(&mut self) {
+        if self.version < 1 && self.block == "RightClick" {
+            // Block was fixed to F before it could be rebound, so the old default
+            // "RightClick" was never in effect: keep the key the player actually used
+            self.block = Self::default().block;
+        }
+        self.version = SETTINGS_VERSION;
+    }
+
+    /// Put values outside what the menus allow back to their defaults, returning the field names
+    fn reset_out_of_range(&mut self) -> Vec<String> {
+        use crate::constants::{
+            AUTOSAVE_INTERVAL_OPTIONS, AUTO_FACE_MAX_RADIUS, CAMERA_DEADZONE_MAX,
+        };
+        use crate::constants::{
+            GAME_SPEED_MAX, GAME_SPEED_MIN, HITSTOP_MAX_MS, HOLD_MOVE_RATE_OPTIONS,
+        };
+
+        let defaults = Self::default();
+        let mut reset = Vec::new();
+        let mut check = |valid: bool, name: &str| {
+            if !valid {
+                reset.push(name.to_string());
+            }
+            valid
+        };
+
+        if !check((0.0..=1.0).contains(&self.music_volume), "music_volume") {
+            self.music_volume = defaults.music_volume;
+        }
+        if !check((0.0..=1.0).contains(&self.sound_volume), "sound_volume") {
+            self.sound_volume = defaults.sound_volume;
+        }
+        if !check(self.player_speed > 0.0, "player_speed") {
+            self.player_speed = defaults.player_speed;
+        }
+        let speed_range = GAME_SPEED_MIN..=GAME_SPEED_MAX;
+        if !check(speed_range.contains(&self.game_speed), "game_speed") {
+            self.game_speed = defaults.game_speed;
+        }
+        if !check(
+            (0.0..=1.0).contains(&self.particle_density),
+            "particle_density",
+        ) {
+            self.particle_density = defaults.particle_density;
+        }
+        if !check(
+            (0.0..=1.0).contains(&self.player_knockback),
+            "player_knockback",
+        ) {
+            self.player_knockback = defaults.player_knockback;
+        }
+        let radius_range = 1..=AUTO_FACE_MAX_RADIUS;
+        if !check(
+            radius_range.contains(&self.auto_face_radius),
+            "auto_face_radius",
+        ) {
+            self.auto_face_radius = defaults.auto_face_radius;
+        }
+        let interval = self.autosave_interval_secs;
+        if !check(
+            AUTOSAVE_INTERVAL_OPTIONS.contains(&interval),
+            "autosave_interval_secs",
+        ) {
+            self.autosave_interval_secs = defaults.autosave_interval_secs;
+        }
+        if !check(
+            self.camera_deadzone <= CAMERA_DEADZONE_MAX,
+            "camera_deadzone",
+        ) {
+            self.camera_deadzone = defaults.camera_deadzone;
+        }
+        let rate = self.hold_move_rate;
+        if !check(HOLD_MOVE_RATE_OPTIONS.contains(&rate), "hold_move_rate") {
+            self.hold_move_rate = defaults.hold_move_rate;
+        }
+        if !check(self.hitstop_ms <= HITSTOP_MAX_MS, "hitstop_ms") {
+            self.hitstop_ms = defaults.hitstop_ms;
+        }
+        reset
     }
 }
@@ -147,6 +147,15 @@ impl SkillPathNode {
     pub fn get_next_cost(&self) -> u32 {
         self.path_type.cost_at_level(self.level)
     }
+
+    /// Extra reach/radius tiles granted to attack patterns by this path
+    pub fn area_bonus(&self) -> i32 {
+        match self.path_type {
+            PathType::Mage => (self.level / 2) as i32, // +1 every 2 levels
+            PathType::Balanced => (self.level / 3) as i32, // +1 every 3 levels
+            PathType::Warrior | PathType::Rogue => 0,
+        }
+    }
 }
 
 /// Complete skill tree system for the player
@@ -213,6 +222,11 @@ impl SkillTreeManager {
         total
     }
 
+    /// Get combined attack area bonus from all unlocked paths
+    pub fn get_area_bonus(&self) -> i32 {
+        self.paths.iter().map(|node| node.area_bonus()).sum()
+    }
+
     /// Get available paths to purchase (not blocked)
     pub fn get_available_paths(&self) -> Vec<PathType> {
         PathType::all_paths()
@@ -302,4 +316,17 @@ mod tests {
         node.try_upgrade(&mut gold); // Level 2
         assert!((node.stat_bonus.damage_multiplier - 1.40).abs() < 0.01);
     }
+
+    #[test]
+    fn test_area_bonus() {
+        let mut manager = SkillTreeManager::new();
+        let mut gold = 1000;
+        assert_eq!(manager.get_area_bonus(), 0);
+
+        manager.purchase_upgrade(PathType::Mage, &mut gold); // Level 1
+        assert_eq!(manager.get_area_bonus(), 0);
+
+        manager.purchase_upgrade(PathType::Mage, &mut gold); // Level 2
+        assert_eq!(manager.get_area_bonus(), 1);
+    }
 }
@@ -1,3 +1,4 @@
+use ratatui::style::Color;
 use serde::{Deserialize, Serialize};
 
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
@@ -12,6 +13,45 @@ pub enum StatusEffectType {
     Healing,        // Restores health per second (damage_per_sec is negative)
 }
 
+impl StatusEffectType {
+    pub const ALL: [StatusEffectType; 8] = [
+        StatusEffectType::Bleed,
+        StatusEffectType::Poison,
+        StatusEffectType::Burn,
+        StatusEffectType::Stun,
+        StatusEffectType::Cripple,
+        StatusEffectType::Fear,
+        StatusEffectType::PoisonImmunity,
+        StatusEffectType::Healing,
+    ];
+
+    /// Parse a type from its `Debug` name, as written to saves
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|effect_type| format!("{:?}", effect_type) == name)
+    }
+
+    /// Icon shown beside the health panel, for effects worth flagging to the player
+    pub fn icon(&self) -> Option<char> {
+        match self {
+            StatusEffectType::Bleed => Some('🩸'),
+            StatusEffectType::Poison => Some('💧'),
+            StatusEffectType::Burn => Some('🔥'),
+            _ => None,
+        }
+    }
+
+    pub fn color(&self) -> Color {
+        match self {
+            StatusEffectType::Bleed => Color::Red,
+            StatusEffectType::Poison => Color::Green,
+            StatusEffectType::Burn => Color::Rgb(255, 140, 0),
+            _ => Color::White,
+        }
+    }
+}
+
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct StatusEffect {
     pub effect_type: StatusEffectType,
@@ -90,10 +130,15 @@ impl StatusEffect {
 #[derive(Clone, Debug, Default, Serialize, Deserialize)]
 pub struct StatusEffectManager {
     pub effects: Vec<StatusEffect>,
+    #[serde(skip)]
+    pub newly_applied: Vec<StatusEffectType>, // Effect types gained since last drained (for audio cues)
 }
 
 impl StatusEffectManager {
     pub fn add(&mut self, effect: StatusEffect) {
+        if !self.has_effect(&effect.effect_type) {
+            self.newly_applied.push(effect.effect_type.clone());
+        }
         // Special handling for certain effects
         match effect.effect_type {
             StatusEffectType::Bleed => {
@@ -132,6 +177,11 @@ impl StatusEffectManager {
         }
     }
 
+    /// Take the effect types applied since the last call
+    pub fn take_newly_applied(&mut self) -> Vec<StatusEffectType> {
+        std::mem::take(&mut self.newly_applied)
+    }
+
     pub fn remove_type(&mut self, effect_type: &StatusEffectType) {
         self.effects.retain(|e| e.effect_type != *effect_type);
     }
@@ -147,6 +197,42 @@ impl StatusEffectManager {
             .sum()
     }
 
+    /// Icon-bearing effect types with their longest remaining duration, in display order
+    pub fn indicators(&self) -> Vec<(StatusEffectType, f32)> {
+        let mut indicators: Vec<(StatusEffectType, f32)> = Vec::new();
+        for effect in self
+            .effects
+            .iter()
+            .filter(|e| e.effect_type.icon().is_some())
+        {
+            match indicators
+                .iter_mut()
+                .find(|(t, _)| *t == effect.effect_type)
+            {
+                Some((_, remaining)) => *remaining = remaining.max(effect.duration),
+                None => indicators.push((effect.effect_type.clone(), effect.duration)),
+            }
+        }
+        indicators.sort_by_key(|(t, _)| match t {
+            StatusEffectType::Bleed => 0,
+            StatusEffectType::Poison => 1,
+            _ => 2,
+        });
+        indicators
+    }
+
+    /// The icon-bearing effect dealing the most damage per second, used to tint the player
+    pub fn most_severe(&self) -> Option<&StatusEffectType> {
+        self.effects
+            .iter()
+            .filter(|e| e.effect_type.icon().is_some())
+            .max_by(|a, b| {
+                (a.damage_per_sec * a.stacks as f32)
+                    .total_cmp(&(b.damage_per_sec * b.stacks as f32))
+            })
+            .map(|e| &e.effect_type)
+    }
+
     pub fn update(&mut self, delta: f32) {
         for effect in &mut self.effects {
             effect.duration -= delta;
@@ -154,3 +240,32 @@ impl StatusEffectManager {
         self.effects.retain(|e| e.duration > 0.0);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_indicators_map_to_icons() {
+        let mut manager = StatusEffectManager::default();
+        manager.add(StatusEffect::burn(4.0));
+        manager.add(StatusEffect::stun(1.0));
+        manager.add(StatusEffect::poison(6.0));
+        manager.add(StatusEffect::burn(2.0));
+
+        let icons: Vec<char> = manager
+            .indicators()
+            .iter()
+            .filter_map(|(t, _)| t.icon())
+            .collect();
+        assert_eq!(icons, vec!['💧', '🔥']);
+
+        let burn = manager.indicators()[1].1;
+        assert_eq!(burn, 4.0);
+        assert_eq!(manager.most_severe(), Some(&StatusEffectType::Burn));
+
+        manager.add(StatusEffect::bleed_with_stacks(5));
+        assert_eq!(manager.indicators()[0].0, StatusEffectType::Bleed);
+        assert_eq!(manager.most_severe(), Some(&StatusEffectType::Bleed));
+    }
+}
@@ -0,0 +1,182 @@
+use crate::model::enemy_type::create_rotting_footsoldier;
+use crate::model::floor::Floor;
+use crate::model::item::ItemDrop;
+use crate::model::item_tier::Difficulty;
+use crate::model::settings::Settings;
+use crate::model::weapon::Weapon;
+
+/// Hand-authored tutorial floor: `@` is where the player starts, `B` a bow waiting to be
+/// picked up and `z` a weak enemy. Everything else reads as in `Floor::from_layout`.
+const LAYOUT: &[&str] = &[
+    "############################################",
+    "#..........#######.........................#",
+    "#...........#####..........................#",
+    "#...@......................#####...........#",
+    "#..........................#####......z....#",
+    "#...........#####..........#####...........#",
+    "#...B......#######.....z...................#",
+    "#.........#########........................#",
+    "############################################",
+];
+
+/// Lessons of the tutorial floor, taught one at a time in this order
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TutorialStep {
+    Move,
+    Attack,
+    Dash,
+    Block,
+    PickUp,
+    SwitchWeapon,
+    Finish, // Every lesson done: clear the floor and take the stairs
+}
+
+impl TutorialStep {
+    pub const LESSONS: [TutorialStep; 6] = [
+        TutorialStep::Move,
+        TutorialStep::Attack,
+        TutorialStep::Dash,
+        TutorialStep::Block,
+        TutorialStep::PickUp,
+        TutorialStep::SwitchWeapon,
+    ];
+
+    fn next(&self) -> Self {
+        let idx = Self::LESSONS.iter().position(|lesson| lesson == self);
+        idx.and_then(|idx| Self::LESSONS.get(idx + 1))
+            .copied()
+            .unwrap_or(TutorialStep::Finish)
+    }
+
+    /// Instruction shown while this step is current, using the player's own key bindings
+    pub fn prompt(&self, settings: &Settings) -> String {
+        match self {
+            TutorialStep::Move => format!(
+                "Move around with {} / {} / {} / {}",
+                settings.move_up, settings.move_left, settings.move_down, settings.move_right
+            ),
+            TutorialStep::Attack => format!("Press {} to swing your sword", settings.attack),
+            TutorialStep::Dash => format!("Press {} to dash the way you last moved", settings.dash),
+            TutorialStep::Block => format!("Press {} to raise your guard", settings.block),
+            TutorialStep::PickUp if settings.auto_pickup => {
+                "Walk over the bow to pick it up".into()
+            }
+            TutorialStep::PickUp => format!(
+                "Stand on the bow and press {} to pick it up",
+                settings.interact
+            ),
+            TutorialStep::SwitchWeapon => "Press 2 to draw the bow; 1-9 pick a weapon slot".into(),
+            TutorialStep::Finish => "Defeat the enemies, then take the stairs to finish".into(),
+        }
+    }
+}
+
+/// Progress through the tutorial. Lessons the player performs ahead of time are
+/// remembered and skipped once their turn comes.
+#[derive(Clone, Debug)]
+pub struct Tutorial {
+    pub step: TutorialStep,
+    learned: Vec<TutorialStep>,
+}
+
+impl Default for Tutorial {
+    fn default() -> Self {
+        Self {
+            step: TutorialStep::Move,
+            learned: Vec::new(),
+        }
+    }
+}
+
+impl Tutorial {
+    /// Note that the player performed `lesson`. Returns true when this moved the
+    /// tutorial on to a new step.
+    pub fn record(&mut self, lesson: TutorialStep) -> bool {
+        if !self.learned.contains(&lesson) {
+            self.learned.push(lesson);
+        }
+        let before = self.step;
+        while self.step != TutorialStep::Finish && self.learned.contains(&self.step) {
+            self.step = self.step.next();
+        }
+        self.step != before
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.step == TutorialStep::Finish
+    }
+
+    /// Number of the current lesson counting from one, or `None` once all are done
+    pub fn lesson_number(&self) -> Option<usize> {
+        TutorialStep::LESSONS
+            .iter()
+            .position(|lesson| *lesson == self.step)
+            .map(|idx| idx + 1)
+    }
+
+    /// Build the tutorial floor with its bow and weak enemies, and the player's start
+    pub fn floor() -> (Floor, (i32, i32)) {
+        let mut floor = Floor::from_layout(LAYOUT, 0);
+        let mut spawn = (1, 1);
+        let template = create_rotting_footsoldier();
+
+        for (y, row) in LAYOUT.iter().enumerate() {
+            for (x, ch) in row.chars().enumerate() {
+                let (x, y) = (x as i32, y as i32);
+                match ch {
+                    '@' => spawn = (x, y),
+                    'B' => floor.add_item(ItemDrop::weapon(Weapon::new_bow(), x, y)),
+                    'z' => floor.enemies.push(Floor::enemy_from_template(
+                        &template,
+                        x,
+                        y,
+                        &Difficulty::Easy,
+                    )),
+                    _ => {}
+                }
+            }
+        }
+        (floor, spawn)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_steps_advance_in_order() {
+        let mut tutorial = Tutorial::default();
+        assert!(!tutorial.record(TutorialStep::Attack));
+        assert_eq!(tutorial.step, TutorialStep::Move);
+        assert_eq!(tutorial.lesson_number(), Some(1));
+
+        // Moving completes the first lesson, and the attack done early skips the second
+        assert!(tutorial.record(TutorialStep::Move));
+        assert_eq!(tutorial.step, TutorialStep::Dash);
+
+        for lesson in TutorialStep::LESSONS {
+            tutorial.record(lesson);
+        }
+        assert!(tutorial.is_finished());
+        assert_eq!(tutorial.lesson_number(), None);
+    }
+
+    #[test]
+    fn test_floor_has_everything_reachable() {
+        let (floor, spawn) = Tutorial::floor();
+        assert!(floor.is_walkable(spawn.0, spawn.1));
+        assert_eq!(floor.enemies.len(), 2);
+        assert_eq!(floor.items.len(), 1);
+
+        let reachable = floor.reachable_from(spawn);
+        assert!(floor
+            .items
+            .iter()
+            .all(|item| reachable.contains(&(item.x, item.y))));
+        assert!(floor
+            .enemies
+            .iter()
+            .all(|enemy| reachable.contains(&(enemy.position.x, enemy.position.y))));
+    }
+}
@@ -1,3 +1,4 @@
+use crate::model::cooldown::scaled_elapsed;
 use serde::{Deserialize, Serialize};
 use std::time::Instant;
 
@@ -37,6 +38,15 @@ impl UltimateType {
         }
     }
 
+    /// Fraction of the ultimate's damage dealt to enemies in the area when it resolves
+    pub fn area_damage_multiplier(&self) -> f32 {
+        match self {
+            UltimateType::Rage => 0.5, // Battle cry; the real payoff is the damage buff
+            UltimateType::Shockwave => 1.0,
+            UltimateType::Ghost => 0.0,
+        }
+    }
+
     /// How long the ultimate effect lasts
     pub fn effect_duration(&self) -> f32 {
         match self {
@@ -71,16 +81,41 @@ impl ActiveUltimate {
         }
     }
 
-    pub fn is_active(&self) -> bool {
-        self.started_at.elapsed().as_secs_f32() < self.duration
+    pub fn is_active(&self, time_scale: f32) -> bool {
+        scaled_elapsed(self.started_at, time_scale) < self.duration
     }
 
-    pub fn remaining_time(&self) -> f32 {
-        let elapsed = self.started_at.elapsed().as_secs_f32();
+    pub fn remaining_time(&self, time_scale: f32) -> f32 {
+        let elapsed = scaled_elapsed(self.started_at, time_scale);
         (self.duration - elapsed).max(0.0)
     }
 }
 
+/// Timed buff granted by Rage or Ghost, ticked by the game loop.
+/// Stores the stat changes it applied so they can be reverted on expiry.
+#[derive(Clone, Debug)]
+pub struct UltimateBuff {
+    pub ultimate_type: UltimateType,
+    pub remaining: f32,          // Game-time seconds left
+    pub damage_bonus: i32,       // Added to attack_damage while active
+    pub cooldown_reduction: f32, // Seconds removed from the attack cooldown while active
+}
+
+impl UltimateBuff {
+    pub fn new(ultimate_type: UltimateType) -> Self {
+        Self {
+            remaining: ultimate_type.effect_duration(),
+            ultimate_type,
+            damage_bonus: 0,
+            cooldown_reduction: 0.0,
+        }
+    }
+
+    pub fn is_expired(&self) -> bool {
+        self.remaining <= 0.0
+    }
+}
+
 #[derive(Clone, Debug)]
 pub struct Ultimate {
     pub radius: i32,             // Distance/radius of ultimate effect
@@ -91,6 +126,7 @@ pub struct Ultimate {
     pub animation: Option<UltimateAnimation>,
     pub current_type: UltimateType,
     pub active_ultimate: Option<ActiveUltimate>,
+    pub telegraph_start: Option<Instant>, // Wind-up in progress before the ultimate fires
 }
 
 impl Default for Ultimate {
@@ -104,6 +140,7 @@ impl Default for Ultimate {
             animation: None,
             current_type: UltimateType::Shockwave,
             active_ultimate: None,
+            telegraph_start: None,
         }
     }
 }
@@ -124,6 +161,7 @@ impl Ultimate {
             animation: None,
             current_type: ultimate_type,
             active_ultimate: None,
+            telegraph_start: None,
         }
     }
 
@@ -138,10 +176,11 @@ impl Ultimate {
         };
     }
 
-    pub fn can_use(&self) -> bool {
+    /// Whether the cooldown has run out at game speed `time_scale`
+    pub fn can_use(&self, time_scale: f32) -> bool {
         match self.cooldown_start {
             None => true,
-            Some(start_time) => start_time.elapsed().as_secs_f32() >= self.cooldown_duration,
+            Some(start_time) => scaled_elapsed(start_time, time_scale) >= self.cooldown_duration,
         }
     }
 
@@ -150,28 +189,41 @@ impl Ultimate {
         self.cooldown_duration = self.current_type.cooldown_duration();
     }
 
-    #[allow(dead_code)]
     pub fn activate(&mut self) {
         self.active_ultimate = Some(ActiveUltimate::new(self.current_type.clone()));
         self.start_animation();
     }
 
     #[allow(dead_code)]
-    pub fn is_active(&self) -> bool {
+    pub fn is_active(&self, time_scale: f32) -> bool {
         if let Some(active) = &self.active_ultimate {
-            active.is_active()
+            active.is_active(time_scale)
         } else {
             false
         }
     }
 
-    pub fn get_active_type(&self) -> Option<&UltimateType> {
-        if let Some(active) = &self.active_ultimate {
-            if active.is_active() {
-                return Some(&active.ultimate_type);
+    /// Begin the wind-up that previews the affected area before the ultimate fires
+    pub fn start_telegraph(&mut self) {
+        self.telegraph_start = Some(Instant::now());
+    }
+
+    pub fn is_telegraphing(&self) -> bool {
+        self.telegraph_start.is_some()
+    }
+
+    /// Returns true once when the wind-up has finished and the ultimate should resolve
+    pub fn finish_telegraph(&mut self, time_scale: f32) -> bool {
+        match self.telegraph_start {
+            Some(start)
+                if scaled_elapsed(start, time_scale)
+                    >= crate::constants::ULTIMATE_TELEGRAPH_TIME =>
+            {
+                self.telegraph_start = None;
+                true
             }
+            _ => false,
         }
-        None
     }
 
     pub fn start_animation(&mut self) {
@@ -182,17 +234,17 @@ impl Ultimate {
         });
     }
 
-    pub fn is_animating(&self) -> bool {
+    pub fn is_animating(&self, time_scale: f32) -> bool {
         if let Some(anim) = &self.animation {
-            anim.created_at.elapsed().as_secs_f32() < anim.duration
+            scaled_elapsed(anim.created_at, time_scale) < anim.duration
         } else {
             false
         }
     }
 
-    pub fn get_animation_radius(&self) -> i32 {
+    pub fn get_animation_radius(&self, time_scale: f32) -> i32 {
         if let Some(anim) = &self.animation {
-            let elapsed = anim.created_at.elapsed().as_secs_f32();
+            let elapsed = scaled_elapsed(anim.created_at, time_scale);
             let progress = (elapsed / anim.duration).clamp(0.0, 1.0);
             ((anim.max_radius as f32) * progress).ceil() as i32
         } else {
@@ -201,19 +253,32 @@ impl Ultimate {
     }
 
     #[allow(dead_code)]
-    pub fn cooldown_remaining(&self) -> f32 {
+    pub fn cooldown_remaining(&self, time_scale: f32) -> f32 {
         match self.cooldown_start {
             None => 0.0,
             Some(start_time) => {
-                let elapsed = start_time.elapsed().as_secs_f32();
+                let elapsed = scaled_elapsed(start_time, time_scale);
                 (self.cooldown_duration - elapsed).max(0.0)
             }
         }
     }
 
-    pub fn get_affected_area(&self, player_x: i32, player_y: i32) -> Vec<(i32, i32)> {
+    pub fn get_affected_area(
+        &self,
+        player_x: i32,
+        player_y: i32,
+        time_scale: f32,
+    ) -> Vec<(i32, i32)> {
+        Self::area_within(self.get_animation_radius(time_scale), player_x, player_y)
+    }
+
+    /// Full area the ultimate will cover, shown during the telegraph
+    pub fn get_telegraph_area(&self, player_x: i32, player_y: i32) -> Vec<(i32, i32)> {
+        Self::area_within(self.radius, player_x, player_y)
+    }
+
+    fn area_within(current_radius: i32, player_x: i32, player_y: i32) -> Vec<(i32, i32)> {
         let mut area = Vec::new();
-        let current_radius = self.get_animation_radius();
 
         for dx in -current_radius..=current_radius {
             for dy in -current_radius..=current_radius {
@@ -234,6 +299,40 @@ impl Ultimate {
         area
     }
 
+    /// Damage every alive enemy inside the ultimate's full radius around the player.
+    /// Shockwave also knocks them away. Returns the positions and damage of each hit.
+    pub fn strike(
+        &self,
+        enemies: &mut [crate::model::enemy::Enemy],
+        player_x: i32,
+        player_y: i32,
+    ) -> Vec<((i32, i32), i32)> {
+        let area = self.get_telegraph_area(player_x, player_y);
+        let damage = (self.damage as f32 * self.current_type.area_damage_multiplier()) as i32;
+        let mut hits = Vec::new();
+
+        for enemy in enemies.iter_mut().filter(|e| e.is_alive()) {
+            let pos = (enemy.position.x, enemy.position.y);
+            if !area.contains(&pos) {
+                continue;
+            }
+            if damage > 0 {
+                enemy.take_damage(damage);
+            }
+            if self.current_type == UltimateType::Shockwave {
+                let (dx, dy) = ((pos.0 - player_x) as f32, (pos.1 - player_y) as f32);
+                let length = (dx * dx + dy * dy).sqrt().max(1.0);
+                enemy.apply_knockback(
+                    dx / length,
+                    dy / length,
+                    crate::constants::ULTIMATE_SHOCKWAVE_KNOCKBACK,
+                );
+            }
+            hits.push((pos, damage));
+        }
+        hits
+    }
+
     /// Get shockwave radius - expands until it hits walls
     #[allow(dead_code)]
     pub fn get_shockwave_reach(
@@ -241,11 +340,12 @@ impl Ultimate {
         player_x: i32,
         player_y: i32,
         max_reach: i32,
+        time_scale: f32,
     ) -> Vec<(i32, i32)> {
         // Returns all tiles in expanding radius from player
         // Collision checking should be done by the caller
         let mut area = Vec::new();
-        let current_radius = self.get_animation_radius().min(max_reach);
+        let current_radius = self.get_animation_radius(time_scale).min(max_reach);
 
         for dx in -current_radius..=current_radius {
             for dy in -current_radius..=current_radius {
@@ -1,4 +1,5 @@
 use crate::model::ultimate::UltimateType;
+use crate::model::weapon::{Enchant, EnchantType, Weapon};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
@@ -50,6 +51,7 @@ pub enum StatUpgradeType {
     AttackSpeed,
     MovementSpeed,
     DashDistance,
+    PickupRadius,
 }
 
 impl StatUpgradeType {
@@ -60,6 +62,7 @@ impl StatUpgradeType {
             StatUpgradeType::AttackSpeed => "Attack Speed",
             StatUpgradeType::MovementSpeed => "Movement Speed",
             StatUpgradeType::DashDistance => "Dash Distance",
+            StatUpgradeType::PickupRadius => "Item Magnet",
         }
     }
 
@@ -70,6 +73,7 @@ impl StatUpgradeType {
             StatUpgradeType::AttackSpeed => "Reduces attack cooldown by 5%",
             StatUpgradeType::MovementSpeed => "Increases movement speed by 0.1",
             StatUpgradeType::DashDistance => "Increases dash distance by 1",
+            StatUpgradeType::PickupRadius => "Pulls gold and consumables from 1 tile further",
         }
     }
 }
@@ -111,6 +115,43 @@ impl ShopStatUpgrade {
     }
 }
 
+/// Represents an enchant the shop can add to the equipped weapon
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ShopEnchant {
+    pub enchant_type: EnchantType,
+    pub base_cost: u32, // Price on a bare weapon; each enchant already on it adds this again
+    pub value: i32,
+}
+
+impl ShopEnchant {
+    pub fn new(enchant_type: EnchantType, base_cost: u32, value: i32) -> Self {
+        Self {
+            enchant_type,
+            base_cost,
+            value,
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self.enchant_type {
+            EnchantType::DamageIncrease => "Sharpen",
+            EnchantType::RadiusIncrease => "Broaden",
+        }
+    }
+
+    pub fn description(&self) -> String {
+        match self.enchant_type {
+            EnchantType::DamageIncrease => format!("+{} weapon damage", self.value),
+            EnchantType::RadiusIncrease => format!("+{} attack reach", self.value),
+        }
+    }
+
+    /// Price for a weapon that already carries `existing_enchants` enchants
+    pub fn cost_for(&self, existing_enchants: usize) -> u32 {
+        self.base_cost * (existing_enchants as u32 + 1)
+    }
+}
+
 /// Tracks owned ultimates and upgrade purchases
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct UltimateShopInventory {
@@ -157,6 +198,7 @@ impl UltimateShopInventory {
 pub struct UltimateShop {
     pub ultimates: Vec<ShopUltimate>,
     pub stat_upgrades: Vec<ShopStatUpgrade>,
+    pub enchants: Vec<ShopEnchant>,
 }
 
 impl UltimateShop {
@@ -173,6 +215,17 @@ impl UltimateShop {
                 ShopStatUpgrade::new(StatUpgradeType::AttackSpeed, 150, 2, 0, 0.05),
                 ShopStatUpgrade::new(StatUpgradeType::MovementSpeed, 140, 2, 0, 0.1),
                 ShopStatUpgrade::new(StatUpgradeType::DashDistance, 110, 1, 0, 1.0),
+                ShopStatUpgrade::new(
+                    StatUpgradeType::PickupRadius,
+                    90,
+                    1,
+                    crate::constants::MAX_PICKUP_RADIUS as u32,
+                    1.0,
+                ),
+            ],
+            enchants: vec![
+                ShopEnchant::new(EnchantType::DamageIncrease, 80, 3),
+                ShopEnchant::new(EnchantType::RadiusIncrease, 150, 1),
             ],
         };
         shop
@@ -284,6 +337,42 @@ impl UltimateShop {
             times_purchased + 1
         ))
     }
+
+    /// Add an enchant to `weapon`. Each enchant already on the weapon raises the price,
+    /// and no weapon takes more than `MAX_WEAPON_ENCHANTS`.
+    pub fn purchase_enchant(
+        &self,
+        gold: &mut u32,
+        weapon: Option<&mut Weapon>,
+        enchant_type: &EnchantType,
+    ) -> Result<String, String> {
+        // Find the enchant in shop
+        let shop_enchant = self
+            .enchants
+            .iter()
+            .find(|e| &e.enchant_type == enchant_type)
+            .ok_or("Enchant not found in shop")?;
+
+        let weapon = weapon.ok_or("No weapon equipped")?;
+        let existing = weapon.enchants.len();
+        if existing >= crate::constants::MAX_WEAPON_ENCHANTS {
+            return Err("Max enchants reached".to_string());
+        }
+
+        // Check gold
+        let cost = shop_enchant.cost_for(existing);
+        if !self.can_afford(cost, *gold) {
+            return Err(format!("Need {} gold", cost - *gold));
+        }
+
+        // Purchase
+        *gold -= cost;
+        weapon.add_enchant(Enchant {
+            enchant_type: shop_enchant.enchant_type.clone(),
+            value: shop_enchant.value,
+        });
+        Ok(format!("{} {}", shop_enchant.name(), weapon.name))
+    }
 }
 
 impl Default for UltimateShop {
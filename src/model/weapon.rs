@@ -544,8 +544,13 @@ impl Weapon {
 
     /// Generate a random weapon of a given rarity tier
     pub fn random_for_rarity(rarity: &ItemTier) -> Self {
-        use rand::{Rng, RngExt};
-        let mut rng = rand::rng();
+        Self::random_for_rarity_with(rarity, &mut rand::rng())
+    }
+
+    /// Generate a random weapon of a given rarity tier from `rng`, so seeded
+    /// floors always stock the same weapon
+    pub fn random_for_rarity_with(rarity: &ItemTier, rng: &mut impl rand::Rng) -> Self {
+        use rand::RngExt;
 
         match rarity {
             ItemTier::Common => match rng.random_range(0..3) {
@@ -682,10 +687,16 @@ impl Weapon {
         bonus
     }
 
-    #[allow(dead_code)] // Will be used when enchantments are applied
     pub fn add_enchant(&mut self, enchant: Enchant) {
         self.enchants.push(enchant);
     }
+
+    /// Gold received for scrapping this weapon, scaled by its damage and rarity
+    pub fn scrap_value(&self) -> u32 {
+        self.damage.max(1) as u32
+            * crate::constants::WEAPON_SCRAP_GOLD_PER_DAMAGE
+            * self.rarity.scrap_multiplier()
+    }
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
@@ -708,6 +719,10 @@ impl WeaponInventory {
         self.weapons.get(self.current_weapon_index)
     }
 
+    pub fn get_current_weapon_mut(&mut self) -> Option<&mut Weapon> {
+        self.weapons.get_mut(self.current_weapon_index)
+    }
+
     pub fn switch_weapon(&mut self, slot: usize) {
         if slot < self.weapons.len() {
             self.current_weapon_index = slot;
@@ -727,6 +742,49 @@ impl WeaponInventory {
         self.weapons.len() >= 9
     }
 
+    /// Put `weapon` into `slot`, returning the weapon it replaced
+    pub fn swap_weapon(&mut self, slot: usize, weapon: Weapon) -> Option<Weapon> {
+        self.weapons
+            .get_mut(slot)
+            .map(|current| std::mem::replace(current, weapon))
+    }
+
+    /// Hand every weapon but the equipped one to `drop`, keeping those it returns false
+    /// for (no room to put them down). Returns how many were dropped.
+    pub fn drop_all_but_current(&mut self, mut drop: impl FnMut(&Weapon) -> bool) -> usize {
+        let current = self.current_weapon_index;
+        let before = self.weapons.len();
+        let mut kept = Vec::new();
+        for (idx, weapon) in std::mem::take(&mut self.weapons).into_iter().enumerate() {
+            if idx == current {
+                self.current_weapon_index = kept.len();
+                kept.push(weapon);
+            } else if !drop(&weapon) {
+                kept.push(weapon);
+            }
+        }
+        self.weapons = kept;
+        before - self.weapons.len()
+    }
+
+    /// Swap the weapon in `slot` with its neighbour `delta` slots away; the equipped
+    /// weapon stays equipped wherever it ends up. Returns false at either end of the list.
+    pub fn move_weapon(&mut self, slot: usize, delta: isize) -> bool {
+        let Some(target) = slot
+            .checked_add_signed(delta)
+            .filter(|&target| target < self.weapons.len() && slot < self.weapons.len())
+        else {
+            return false;
+        };
+        self.weapons.swap(slot, target);
+        if self.current_weapon_index == slot {
+            self.current_weapon_index = target;
+        } else if self.current_weapon_index == target {
+            self.current_weapon_index = slot;
+        }
+        true
+    }
+
     pub fn remove_weapon(&mut self, slot: usize) -> Option<Weapon> {
         if slot < self.weapons.len() {
             let weapon = self.weapons.remove(slot);
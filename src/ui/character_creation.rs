@@ -23,6 +23,7 @@ pub fn draw(f: &mut Frame, app: &mut App, area: Rect, pulse_color: Color) {
         .constraints([
             Constraint::Length(6),
             Constraint::Length(4),
+            Constraint::Length(4),
             Constraint::Length(3),
             Constraint::Min(0),
         ])
@@ -124,8 +125,42 @@ pub fn draw(f: &mut Frame, app: &mut App, area: Rect, pulse_color: Color) {
 
     f.render_widget(difficulty_widget, content_chunks[1]);
 
+    // Starting class selection
+    let class_label = if app.char_creation_selection == 2 {
+        "[ CLASS ] ← Use Arrow Keys →"
+    } else {
+        "CLASS"
+    };
+
+    let class_style = if app.char_creation_selection == 2 {
+        Style::default()
+            .fg(pulse_color)
+            .add_modifier(Modifier::BOLD)
+    } else {
+        Style::default().fg(Color::Gray)
+    };
+
+    let class_display = vec![
+        Line::from(Span::styled(
+            format!("◄ {} ►", app.starting_class.name().to_uppercase()),
+            class_style,
+        )),
+        Line::from(app.starting_class.description()),
+    ];
+
+    let class_block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(class_style)
+        .title(class_label);
+
+    let class_widget = Paragraph::new(class_display)
+        .block(class_block)
+        .alignment(Alignment::Center);
+
+    f.render_widget(class_widget, content_chunks[2]);
+
     // Start button
-    let start_style = if app.char_creation_selection == 2 {
+    let start_style = if app.char_creation_selection == 3 {
         Style::default()
             .fg(Color::Black)
             .bg(pulse_color)
@@ -143,7 +178,7 @@ pub fn draw(f: &mut Frame, app: &mut App, area: Rect, pulse_color: Color) {
         .alignment(Alignment::Center)
         .style(start_style);
 
-    f.render_widget(start_widget, content_chunks[2]);
+    f.render_widget(start_widget, content_chunks[3]);
 
     // Instructions
     let instructions = Paragraph::new(
@@ -151,5 +186,5 @@ pub fn draw(f: &mut Frame, app: &mut App, area: Rect, pulse_color: Color) {
     )
     .alignment(Alignment::Center)
     .style(Style::default().fg(Color::DarkGray));
-    f.render_widget(instructions, content_chunks[3]);
+    f.render_widget(instructions, content_chunks[4]);
 }
@@ -62,6 +62,13 @@ pub fn draw(f: &mut Frame, app: &mut App, area: Rect) {
 
     lines.push(Line::from(""));
 
+    if app.run_mode == crate::model::settings::RunMode::Permadeath {
+        lines.push(Line::from(Span::styled(
+            "PERMADEATH - this save is gone",
+            Style::default().fg(Color::DarkGray),
+        )));
+    }
+
     let death_block = Block::default()
         .borders(Borders::ALL)
         .title(" ☠️  DEATH SCREEN ☠️ ")
@@ -76,11 +83,13 @@ pub fn draw(f: &mut Frame, app: &mut App, area: Rect) {
 
     // Bottom control hints - only show if fade is complete
     if alpha_progress > 0.7 {
-        let hints = vec![
-            ("R", "Retry Floor", Some(Color::Green)),
+        let mut hints = vec![
             ("N", "New Game", Some(Color::Yellow)),
             ("ESC", "Main Menu", Some(Color::Red)),
         ];
+        if app.run_mode == crate::model::settings::RunMode::Checkpoint {
+            hints.insert(0, ("R", "Retry Floor", Some(Color::Green)));
+        }
 
         super::drawing::render_key_hints(f, area, hints);
     }
@@ -90,11 +99,16 @@ pub fn handle_input(app: &mut App, key: crossterm::event::KeyCode) {
     use crossterm::event::KeyCode;
 
     match key {
-        KeyCode::Char('r') | KeyCode::Char('R') => {
+        KeyCode::Char('r') | KeyCode::Char('R')
+            if app.run_mode == crate::model::settings::RunMode::Checkpoint =>
+        {
             // Retry current floor with reset health and position
             app.retry_current_floor();
         }
         KeyCode::Char('n') | KeyCode::Char('N') => {
+            if app.run_mode == crate::model::settings::RunMode::Checkpoint {
+                app.record_death();
+            }
             // Start a completely new game
             app.state = crate::app::AppState::CharacterCreation;
             app.char_name = String::new();
@@ -103,6 +117,9 @@ pub fn handle_input(app: &mut App, key: crossterm::event::KeyCode) {
             app.settings.difficulty = app.settings.default_difficulty.clone();
         }
         KeyCode::Esc => {
+            if app.run_mode == crate::model::settings::RunMode::Checkpoint {
+                app.record_death();
+            }
             app.state = crate::app::AppState::MainMenu;
             app.main_menu_state.select(Some(0));
         }
@@ -1,11 +1,33 @@
 use crate::app::App;
+use crate::model::attack_pattern::AttackPattern;
 use ratatui::{prelude::*, widgets::*};
 
+/// Facings the pattern preview rotates through, clockwise from north
+const PREVIEW_DIRECTIONS: [((i32, i32), &str); 8] = [
+    ((0, -1), "N"),
+    ((1, -1), "NE"),
+    ((1, 0), "E"),
+    ((1, 1), "SE"),
+    ((0, 1), "S"),
+    ((-1, 1), "SW"),
+    ((-1, 0), "W"),
+    ((-1, -1), "NW"),
+];
+
+/// Largest distance from the origin the preview grid shows
+const PREVIEW_MAX_RADIUS: i32 = 9;
+
 pub fn draw(f: &mut Frame, app: &mut App, area: Rect) {
+    if app.dev_pattern_preview {
+        draw_pattern_preview(f, app, area);
+        return;
+    }
+
     let chunks = Layout::default()
         .direction(Direction::Vertical)
         .constraints([
-            Constraint::Length(6),
+            Constraint::Length(7),
+            Constraint::Length(5),
             Constraint::Min(0),
             Constraint::Length(1),
         ])
@@ -18,32 +40,71 @@ pub fn draw(f: &mut Frame, app: &mut App, area: Rect) {
 
     let dev_info = if let Some(floor) = &app.current_floor {
         format!(
-            "🎮 SEED: {} | 👥 Enemies: {} | 💰 Gold: {} | ⚔️ Difficulty: {:?}",
+            "🎮 SEED: {} | Theme: {} | 👥 Enemies: {} | 💰 Gold: {} | ⚔️ Difficulty: {:?} | Advance: {}",
             floor.seed,
+            floor.theme.name(),
             floor.enemies.len(),
             app.character.gold,
-            app.settings.difficulty
+            app.settings.difficulty,
+            if app.dev_instant_advance {
+                "Instant"
+            } else {
+                "Stairs"
+            }
         )
     } else {
         "🔷 No floor loaded - Generate one first".to_string()
     };
 
-    let input_text = if app.dev_seed_input.is_empty() {
-        "[Commands: R=Random | ENTER=Generate | E=Spawn Enemy | D=Damage Test | G=Add Gold | K=Victory | H=Pattern | ESC=Back]"
+    let input_text = if let Some(status) = &app.dev_status {
+        status.clone()
+    } else if app.dev_seed_input.is_empty() {
+        "[Commands: R=Random | ENTER=Generate | E=Spawn Enemy | [/]=Pick Enemy | N=Spawn Picked | T=Training Dummy | C=Clear Enemies | D=Damage Test | G=Add Gold | K=Victory | H=Pattern | O=Preview | A=Advance Mode | I=God Mode | F=Freeze | L=Detection | X=Export | V=Import | ESC=Back]"
             .to_string()
     } else {
         format!("Seed Input: {}", app.dev_seed_input)
     };
 
     let quick_stats = format!(
-        "🧙 Player HP: {}/{} | Weapon: {:?} | Damage: +{}",
+        "🧙 Player HP: {}/{} | Weapon: {:?} | Damage: +{} | God Mode: {} | Enemies: {} | Detection: {}",
         app.character.health,
         app.character.health_max,
         app.character
             .weapon_inventory
             .get_current_weapon()
             .map(|w| format!("{:?}", w.weapon_type)),
-        crate::constants::PLAYER_BASE_DAMAGE
+        crate::constants::PLAYER_BASE_DAMAGE,
+        if app.dev_god_mode { "ON" } else { "OFF" },
+        if app.dev_freeze_enemies {
+            "Frozen"
+        } else {
+            "Active"
+        },
+        if app.dev_show_detection { "ON" } else { "OFF" }
+    );
+
+    let templates = crate::model::enemy_type::all_enemy_templates();
+    let template = &templates[app.dev_enemy_template % templates.len()];
+    let spawner = format!(
+        "👹 Spawner [{}/{}]: {} ({:?}, {:?}) | HP: {} | Speed: {} | Attacks: {}",
+        app.dev_enemy_template % templates.len() + 1,
+        templates.len(),
+        template.name,
+        template.rarity,
+        template.enemy_type,
+        template.health,
+        template.speed,
+        template
+            .attacks
+            .iter()
+            .map(|attack| {
+                format!(
+                    "{} {}-{}",
+                    attack.name, attack.damage_min, attack.damage_max
+                )
+            })
+            .collect::<Vec<_>>()
+            .join(", ")
     );
 
     let input = Paragraph::new(vec![
@@ -54,17 +115,20 @@ pub fn draw(f: &mut Frame, app: &mut App, area: Rect) {
             quick_stats,
             Style::default().fg(Color::Magenta),
         )),
+        Line::from(Span::styled(spawner, Style::default().fg(Color::LightRed))),
     ])
     .block(input_block);
 
     f.render_widget(input, chunks[0]);
 
+    draw_frame_times(f, app, chunks[1]);
+
     // Floor preview and enemy list
     if let Some(floor) = &app.current_floor {
         let preview_chunks = Layout::default()
             .direction(Direction::Horizontal)
             .constraints([Constraint::Percentage(70), Constraint::Percentage(30)])
-            .split(chunks[1]);
+            .split(chunks[2]);
 
         // Map preview
         let styled_tiles = floor.styled_grid();
@@ -138,22 +202,26 @@ pub fn draw(f: &mut Frame, app: &mut App, area: Rect) {
                 crate::model::enemy_type::EnemyRarity::Boss => Color::LightMagenta,
             };
 
-            let rarity_short = match enemy.rarity {
-                crate::model::enemy_type::EnemyRarity::Fighter => "Fighter",
-                crate::model::enemy_type::EnemyRarity::Guard => "Guard",
-                crate::model::enemy_type::EnemyRarity::Champion => "Champion",
-                crate::model::enemy_type::EnemyRarity::Elite => "Elite",
-                crate::model::enemy_type::EnemyRarity::Boss => "Boss",
-            };
+            let rarity_short = enemy.rarity.name();
 
-            let enemy_info = format!(
-                "{}. [{}] {} {}{:2}💰",
-                idx + 1,
-                rarity_short,
-                health_bar,
-                if enemy.health <= 0 { "DEAD " } else { "" },
-                enemy.base_gold
-            );
+            let enemy_info = if let Some(record) = &enemy.dummy {
+                format!(
+                    "{}. [Dummy] {} dmg in {} hits, {:.1} DPS",
+                    idx + 1,
+                    record.total_damage,
+                    record.hits,
+                    record.dps(app.settings.game_speed)
+                )
+            } else {
+                format!(
+                    "{}. [{}] {} {}{:2}💰",
+                    idx + 1,
+                    rarity_short,
+                    health_bar,
+                    if enemy.health <= 0 { "DEAD " } else { "" },
+                    enemy.base_gold
+                )
+            };
             enemy_lines.push(Line::from(Span::styled(
                 enemy_info,
                 Style::default().fg(color),
@@ -191,26 +259,64 @@ pub fn draw(f: &mut Frame, app: &mut App, area: Rect) {
         )
         .style(Style::default().fg(Color::DarkGray));
 
-        f.render_widget(placeholder, chunks[1]);
+        f.render_widget(placeholder, chunks[2]);
     }
 
     let hints = vec![
         ("R", "Random", Some(Color::Cyan)),
         ("ENTER", "Generate", Some(Color::Green)),
         ("E", "Enemy", Some(Color::Yellow)),
+        ("[/]", "Pick", Some(Color::LightRed)),
+        ("N", "Spawn", Some(Color::LightRed)),
+        ("T", "Dummy", Some(Color::LightRed)),
+        ("C", "Clear", Some(Color::LightRed)),
         ("D", "Damage", Some(Color::LightRed)),
         ("G", "Gold", Some(Color::LightYellow)),
         ("W", "Weapons", Some(Color::Magenta)),
         ("K", "Victory", Some(Color::Green)),
+        ("O", "Pattern Preview", Some(Color::LightYellow)),
+        ("A", "Advance", Some(Color::LightCyan)),
+        ("I", "God Mode", Some(Color::LightGreen)),
+        ("F", "Freeze", Some(Color::LightBlue)),
+        ("L", "Detection", Some(Color::LightRed)),
+        ("X/V", "Export/Import", Some(Color::LightMagenta)),
         ("ESC", "Back", Some(Color::Red)),
     ];
 
     super::drawing::render_key_hints(f, area, hints);
 }
 
+/// Frame-time sparkline with current/average FPS and the 1% low, for spotting stutters
+fn draw_frame_times(f: &mut Frame, app: &App, area: Rect) {
+    let counter = &app.fps_counter;
+    let title = format!(
+        " ⏱ FPS: {:.0} (avg {:.1}) | 1% low: {:.1} ms ",
+        counter.instant_fps(),
+        counter.fps(),
+        counter.one_percent_low() * 1000.0
+    );
+    // Show the newest frames that fit, in tenths of a millisecond
+    let width = area.width.saturating_sub(2) as usize;
+    let times = counter.frame_times();
+    let data: Vec<u64> = times
+        .iter()
+        .skip(times.len().saturating_sub(width))
+        .map(|secs| (secs * 10_000.0) as u64)
+        .collect();
+
+    let sparkline = Sparkline::default()
+        .block(Block::default().borders(Borders::ALL).title(title))
+        .data(&data)
+        .style(Style::default().fg(Color::Green));
+    f.render_widget(sparkline, area);
+}
+
 pub fn handle_input(app: &mut App, key: crossterm::event::KeyCode) {
     use crossterm::event::KeyCode;
 
+    // Any key press replaces the last export/import message
+    app.dev_status = None;
+
     match key {
         KeyCode::Char('r') | KeyCode::Char('R') => {
             // Random seed
@@ -227,6 +333,20 @@ pub fn handle_input(app: &mut App, key: crossterm::event::KeyCode) {
                 }
             }
         }
+        KeyCode::Char('[') => app.cycle_dev_enemy_template(-1),
+        KeyCode::Char(']') => app.cycle_dev_enemy_template(1),
+        KeyCode::Char('n') | KeyCode::Char('N') => {
+            // Spawn the picked template in front of the player
+            app.dev_spawn_selected_enemy();
+        }
+        KeyCode::Char('t') | KeyCode::Char('T') => {
+            // Place or remove the training dummy for DPS checks
+            app.dev_toggle_training_dummy();
+        }
+        KeyCode::Char('c') | KeyCode::Char('C') => {
+            // Remove every enemy from the floor
+            app.dev_clear_enemies();
+        }
         KeyCode::Char('d') | KeyCode::Char('D') => {
             // Damage test - hit all enemies for 5 damage
             if let Some(floor) = &mut app.current_floor {
@@ -250,6 +370,34 @@ pub fn handle_input(app: &mut App, key: crossterm::event::KeyCode) {
             // Cycle attack pattern for testing
             app.cycle_dev_attack_pattern();
         }
+        KeyCode::Char('o') | KeyCode::Char('O') => {
+            // Open the static attack pattern preview
+            app.dev_pattern_preview = true;
+        }
+        KeyCode::Char('a') | KeyCode::Char('A') => {
+            // Toggle between stairs exit and old instant-advance behavior
+            app.dev_instant_advance = !app.dev_instant_advance;
+        }
+        KeyCode::Char('i') | KeyCode::Char('I') => {
+            // Toggle god mode for floors played from the dev menu
+            app.dev_god_mode = !app.dev_god_mode;
+        }
+        KeyCode::Char('f') | KeyCode::Char('F') => {
+            // Toggle freezing enemies in place for floors played from the dev menu
+            app.dev_freeze_enemies = !app.dev_freeze_enemies;
+        }
+        KeyCode::Char('l') | KeyCode::Char('L') => {
+            // Toggle detection radius rings for floors played from the dev menu
+            app.dev_show_detection = !app.dev_show_detection;
+        }
+        KeyCode::Char('x') | KeyCode::Char('X') => {
+            // Export the current floor as a shareable code
+            app.export_floor_code();
+        }
+        KeyCode::Char('v') | KeyCode::Char('V') => {
+            // Import a floor code and rebuild that floor
+            app.import_floor_code();
+        }
         KeyCode::Backspace => {
             // Edit seed
             app.dev_seed_input.pop();
@@ -264,6 +412,139 @@ pub fn handle_input(app: &mut App, key: crossterm::event::KeyCode) {
     }
 }
 
+/// Keys while the pattern preview is open. Only the preview selection changes: no
+/// animation, floor or player state is touched.
+pub fn handle_preview_input(app: &mut App, key: crossterm::event::KeyCode) {
+    use crossterm::event::KeyCode;
+
+    let count = PREVIEW_DIRECTIONS.len();
+    match key {
+        KeyCode::Esc | KeyCode::Char('o') | KeyCode::Char('O') => {
+            app.dev_pattern_preview = false;
+        }
+        KeyCode::Char('h') | KeyCode::Char('H') | KeyCode::Enter => {
+            app.step_dev_attack_pattern();
+        }
+        KeyCode::Right | KeyCode::Char('d') | KeyCode::Char('D') => {
+            app.dev_preview_direction = (app.dev_preview_direction + 1) % count;
+        }
+        KeyCode::Left | KeyCode::Char('a') | KeyCode::Char('A') => {
+            app.dev_preview_direction = (app.dev_preview_direction + count - 1) % count;
+        }
+        _ => {}
+    }
+}
+
+/// Static grid of the tiles the selected attack pattern hits from a fixed origin
+fn draw_pattern_preview(f: &mut Frame, app: &App, area: Rect) {
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(5),
+            Constraint::Min(0),
+            Constraint::Length(1),
+        ])
+        .split(area);
+
+    let pattern = &app.dev_attack_pattern;
+    let ((dir_x, dir_y), dir_name) =
+        PREVIEW_DIRECTIONS[app.dev_preview_direction % PREVIEW_DIRECTIONS.len()];
+    let tiles = pattern.get_affected_tiles(0, 0, dir_x, dir_y);
+    let index = AttackPattern::ALL
+        .iter()
+        .position(|p| p == pattern)
+        .map_or("?".to_string(), |i| (i + 1).to_string());
+
+    let info = Paragraph::new(vec![
+        Line::from(Span::styled(
+            format!(
+                "{} [{}/{}] | {} | Facing: {}",
+                pattern.name(),
+                index,
+                AttackPattern::ALL.len(),
+                pattern.weapon_type(),
+                dir_name
+            ),
+            Style::default()
+                .fg(Color::Yellow)
+                .add_modifier(Modifier::BOLD),
+        )),
+        Line::from(Span::styled(
+            pattern.description(),
+            Style::default().fg(Color::Cyan),
+        )),
+        Line::from(Span::styled(
+            format!("Tiles hit: {}", tiles.len()),
+            Style::default().fg(Color::Magenta),
+        )),
+    ])
+    .block(
+        Block::default()
+            .borders(Borders::ALL)
+            .title(" 🎯 Attack Pattern Preview ")
+            .style(Style::default().fg(Color::Yellow)),
+    );
+    f.render_widget(info, chunks[0]);
+
+    // Size the grid to the pattern's reach so long shots are not cut off
+    let radius = tiles
+        .iter()
+        .map(|&(x, y)| x.abs().max(y.abs()))
+        .max()
+        .unwrap_or(0)
+        .clamp(2, PREVIEW_MAX_RADIUS);
+    let hit_style = Style::default()
+        .fg(Color::LightRed)
+        .add_modifier(Modifier::BOLD);
+    let origin_style = Style::default()
+        .fg(Color::LightGreen)
+        .add_modifier(Modifier::BOLD);
+    let empty_style = Style::default().fg(Color::DarkGray);
+
+    let mut lines = Vec::new();
+    for y in -radius..=radius {
+        let spans: Vec<Span> = (-radius..=radius)
+            .map(|x| {
+                // Two columns per tile keep the grid roughly square
+                if (x, y) == (0, 0) {
+                    Span::styled("@ ", origin_style)
+                } else if (x, y) == (dir_x, dir_y) && !tiles.contains(&(x, y)) {
+                    Span::styled("> ", origin_style)
+                } else if tiles.contains(&(x, y)) {
+                    Span::styled("██", hit_style)
+                } else {
+                    Span::styled("· ", empty_style)
+                }
+            })
+            .collect();
+        lines.push(Line::from(spans));
+    }
+    let hidden = tiles
+        .iter()
+        .filter(|&&(x, y)| x.abs().max(y.abs()) > radius)
+        .count();
+    if hidden > 0 {
+        lines.push(Line::from(Span::styled(
+            format!("{} tiles beyond the grid", hidden),
+            empty_style,
+        )));
+    }
+
+    let grid = Paragraph::new(lines).alignment(Alignment::Center).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .title(" Affected Tiles "),
+    );
+    f.render_widget(grid, chunks[1]);
+
+    let hints = vec![
+        ("H/ENTER", "Next Pattern", Some(Color::Yellow)),
+        ("←/→", "Rotate", Some(Color::Cyan)),
+        ("O/ESC", "Close", Some(Color::Red)),
+    ];
+    super::drawing::render_key_hints(f, area, hints);
+}
+
 /// Find an empty position to spawn a test enemy
 fn find_empty_spawn_position(floor: &crate::model::floor::Floor) -> Option<(i32, i32)> {
     use rand::{Rng, RngExt};
@@ -296,14 +577,8 @@ fn spawn_test_enemy(
 
     use rand::{Rng, RngExt};
     let mut rng = rand::rng();
-    let template = templates[rng.random_range(0..templates.len())].clone();
-
-    let mut enemy = crate::model::enemy::Enemy::new(x, y, template.speed);
-    enemy.health = template.health;
-    enemy.max_health = template.health;
-    enemy.rarity = template.rarity.clone();
-    enemy.base_gold = template.rarity.calculate_gold_drop(difficulty);
-
+    let template = &templates[rng.random_range(0..templates.len())];
+    let enemy = crate::model::floor::Floor::enemy_from_template(template, x, y, difficulty);
     floor.enemies.push(enemy);
 }
 
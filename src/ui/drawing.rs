@@ -1,5 +1,5 @@
 use ratatui::prelude::*;
-use ratatui::widgets::{Block, Borders, Clear, Padding, Paragraph};
+use ratatui::widgets::{Block, Borders, Clear, Padding, Paragraph, Wrap};
 
 pub fn calculate_pulse_color(duration_secs: f32) -> Color {
     let pulse_val = ((duration_secs * 15.0).sin() + 1.0) / 2.0;
@@ -71,12 +71,18 @@ pub fn render_key_hints(f: &mut Frame, area: Rect, hints: Vec<(&str, &str, Optio
     f.render_widget(hint_bar, chunks[1]);
 }
 
-pub fn render_character(f: &mut Frame, area: Rect, position: (i32, i32), is_damaged: bool) {
+pub fn render_character(
+    f: &mut Frame,
+    area: Rect,
+    position: (i32, i32),
+    is_damaged: bool,
+    tint: Option<Color>,
+) {
     let character_art = "@";
     let color = if is_damaged {
         Color::Red
     } else {
-        Color::Yellow
+        tint.unwrap_or(Color::Yellow)
     };
     let style = Style::default().fg(color);
 
@@ -153,11 +159,11 @@ pub fn render_dash_cooldown_bar(
 pub fn render_arrows(
     f: &mut Frame,
     area: Rect,
-    arrows: &[(f32, f32, &str)],
+    arrows: &[(f32, f32, &str, Color)],
     offset_x: i32,
     offset_y: i32,
 ) {
-    for (x, y, glyph) in arrows {
+    for (x, y, glyph, color) in arrows {
         let world_x = x.round() as i32;
         let world_y = y.round() as i32;
         let screen_x = (world_x - offset_x) as u16;
@@ -165,11 +171,8 @@ pub fn render_arrows(
 
         if screen_x < area.width && screen_y < area.height {
             let pos_area = Rect::new(area.x + screen_x, area.y + screen_y, 1, 1);
-            let indicator = Paragraph::new(*glyph).style(
-                Style::default()
-                    .fg(Color::White)
-                    .add_modifier(Modifier::BOLD),
-            );
+            let indicator = Paragraph::new(*glyph)
+                .style(Style::default().fg(*color).add_modifier(Modifier::BOLD));
             f.render_widget(indicator, pos_area);
         }
     }
@@ -178,19 +181,18 @@ pub fn render_arrows(
 pub fn render_enemies(
     f: &mut Frame,
     area: Rect,
-    enemies: &[(i32, i32, String, Color)],
+    enemies: &[(i32, i32, String, Style)],
     offset_x: i32,
     offset_y: i32,
 ) {
-    for (x, y, glyph, color) in enemies {
+    for (x, y, glyph, style) in enemies {
         let screen_x = (x - offset_x) as u16;
         let screen_y = (y - offset_y) as u16;
         let glyph_width = if glyph.contains(' ') { 2 } else { 1 };
 
         if screen_x < area.width && screen_y < area.height {
             let pos_area = Rect::new(area.x + screen_x, area.y + screen_y, glyph_width as u16, 1);
-            let enemy_indicator = Paragraph::new(glyph.to_string())
-                .style(Style::default().fg(*color).add_modifier(Modifier::BOLD));
+            let enemy_indicator = Paragraph::new(glyph.to_string()).style(*style);
             f.render_widget(enemy_indicator, pos_area);
         }
     }
@@ -239,6 +241,95 @@ pub fn render_ultimate_area(
     }
 }
 
+/// Faint rings showing how far each enemy detects the player, tinted by whether it
+/// currently does. Only tints the background so the map stays readable underneath.
+/// Rings entirely off screen are skipped, so huge boss radii cost nothing.
+pub fn render_detection_rings(
+    f: &mut Frame,
+    area: Rect,
+    rings: &[(i32, i32, i32, bool)],
+    metric: crate::model::enemy::DistanceMetric,
+    offset_x: i32,
+    offset_y: i32,
+) {
+    let (width, height) = (area.width as i32, area.height as i32);
+    let buf = f.buffer_mut();
+    for &(x, y, radius, detecting) in rings {
+        let (center_x, center_y) = (x - offset_x, y - offset_y);
+        if center_x + radius < 0
+            || center_y + radius < 0
+            || center_x - radius >= width
+            || center_y - radius >= height
+        {
+            continue;
+        }
+
+        let tint = if detecting {
+            Color::Indexed(52) // Dark red
+        } else {
+            Color::Indexed(17) // Dark blue
+        };
+        for (dx, dy) in metric.ring(radius) {
+            let screen_x = (center_x + dx) as u16;
+            let screen_y = (center_y + dy) as u16;
+
+            if screen_x < area.width && screen_y < area.height {
+                buf[(area.x + screen_x, area.y + screen_y)].set_bg(tint);
+            }
+        }
+    }
+}
+
+/// Brighten the whole game view for a frame-long flash, keeping the glyphs on it
+pub fn render_screen_flash(f: &mut Frame, area: Rect) {
+    let buf = f.buffer_mut();
+    for y in area.top()..area.bottom() {
+        for x in area.left()..area.right() {
+            buf[(x, y)].set_bg(Color::Gray);
+        }
+    }
+}
+
+/// Warning markers for an area attack that is about to land
+pub fn render_telegraph(
+    f: &mut Frame,
+    area: Rect,
+    positions: &[(i32, i32)],
+    color: Color,
+    offset_x: i32,
+    offset_y: i32,
+) {
+    for &(x, y) in positions {
+        let screen_x = (x - offset_x) as u16;
+        let screen_y = (y - offset_y) as u16;
+
+        if screen_x < area.width && screen_y < area.height {
+            let pos_area = Rect::new(area.x + screen_x, area.y + screen_y, 1, 1);
+            let indicator = Paragraph::new("◇").style(Style::default().fg(color));
+            f.render_widget(indicator, pos_area);
+        }
+    }
+}
+
+/// Highlight the background of revealed enemies and treasure so they stand out
+pub fn render_reveal_marks(
+    f: &mut Frame,
+    area: Rect,
+    positions: &[(i32, i32)],
+    offset_x: i32,
+    offset_y: i32,
+) {
+    let mark = Color::Indexed(crate::constants::SCRYING_MARK_COLOR);
+    for &(x, y) in positions {
+        let screen_x = (x - offset_x) as u16;
+        let screen_y = (y - offset_y) as u16;
+
+        if screen_x < area.width && screen_y < area.height {
+            f.buffer_mut()[(area.x + screen_x, area.y + screen_y)].set_bg(mark);
+        }
+    }
+}
+
 pub fn render_weapon_info(f: &mut Frame, area: Rect, weapon_name: &str) {
     // Truncate long weapon names to fit in the panel
     let max_len = 18;
@@ -272,6 +363,33 @@ pub fn render_health_info(f: &mut Frame, area: Rect, health: i32, health_max: i3
     f.render_widget(health_widget, area);
 }
 
+/// Icons for poison/bleed/burn with a bar showing how long each has left
+pub fn render_status_indicators(
+    f: &mut Frame,
+    area: Rect,
+    indicators: &[(crate::model::status_effect::StatusEffectType, f32)],
+) {
+    let bar_width = crate::constants::STATUS_BAR_WIDTH as usize;
+    let mut spans = Vec::new();
+    for (effect_type, remaining) in indicators {
+        let Some(icon) = effect_type.icon() else {
+            continue;
+        };
+        let fraction = (remaining / crate::constants::STATUS_BAR_FULL_DURATION).clamp(0.0, 1.0);
+        let filled = ((fraction * bar_width as f32).ceil() as usize).min(bar_width);
+        let style = Style::default().fg(effect_type.color());
+        spans.push(Span::styled(icon.to_string(), style));
+        spans.push(Span::styled("█".repeat(filled), style));
+        spans.push(Span::styled(
+            "░".repeat(bar_width - filled),
+            Style::default().fg(Color::DarkGray),
+        ));
+        spans.push(Span::raw(" "));
+    }
+
+    f.render_widget(Paragraph::new(Line::from(spans)), area);
+}
+
 pub fn render_gold_info(f: &mut Frame, area: Rect, gold: u32) {
     let gold_text = format!("¤ {}", gold);
     let gold_widget = Paragraph::new(gold_text)
@@ -284,11 +402,12 @@ pub fn render_gold_info(f: &mut Frame, area: Rect, gold: u32) {
 pub fn render_consumables_info(
     f: &mut Frame,
     area: Rect,
-    consumables: &crate::model::consumable::ConsumableInventory,
+    character: &crate::model::character::Character,
     inventory_focused: bool,
     scroll_index: usize,
     settings: &crate::model::settings::Settings,
 ) {
+    let consumables = &character.consumable_inventory;
     let items = &consumables.items;
 
     // Build list of items with quantities
@@ -307,8 +426,15 @@ pub fn render_consumables_info(
                     item.name.clone()
                 };
 
-                let text = format!("{}{} x{}", prefix, name_display, item.quantity);
-                let style = if selected {
+                let mut text = format!("{}{} x{}", prefix, name_display, item.quantity);
+                let cooldown = character
+                    .consumable_cooldown_remaining(&item.consumable_type, settings.game_speed);
+                if cooldown > 0.0 {
+                    text.push_str(&format!(" {:.1}s", cooldown));
+                }
+                let style = if cooldown > 0.0 {
+                    Style::default().fg(Color::DarkGray)
+                } else if selected {
                     Style::default()
                         .fg(Color::Yellow)
                         .add_modifier(Modifier::BOLD)
@@ -346,14 +472,26 @@ pub fn render_consumables_info(
         " INVENTORY "
     };
 
+    let mut block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(border_color))
+        .title(title)
+        .title_alignment(Alignment::Left);
+    // Time left on a drunk potion, in the potion's color
+    if let Some(buff) = &character.potion_buff {
+        block = block.title(
+            Line::from(format!(
+                " {} {:.0}s ",
+                buff.consumable_type.get_glyph(),
+                buff.remaining.ceil()
+            ))
+            .style(Style::default().fg(buff.consumable_type.get_color()))
+            .right_aligned(),
+        );
+    }
+
     let inventory_widget = Paragraph::new(visible_lines)
-        .block(
-            Block::default()
-                .borders(Borders::ALL)
-                .border_style(Style::default().fg(border_color))
-                .title(title)
-                .title_alignment(Alignment::Left),
-        )
+        .block(block)
         .alignment(Alignment::Left)
         .style(Style::default().fg(Color::White));
 
@@ -414,11 +552,54 @@ pub fn render_vertical_cooldown_bar(
     f.render_widget(bar_widget, area);
 }
 
-pub fn render_horizontal_ultimate_bar(f: &mut Frame, area: Rect, charge: f32) {
+pub fn render_horizontal_ultimate_bar(
+    f: &mut Frame,
+    area: Rect,
+    charge: f32,
+    buff: Option<&crate::model::ultimate::UltimateBuff>,
+    combo: &crate::model::combo::Combo,
+) {
     if area.height < 1 {
         return;
     }
 
+    // Chained hits claim the right end of the bar
+    let area = if combo.count >= 2 {
+        let text = format!(
+            " x{} Combo ({:.1}x charge)",
+            combo.count,
+            combo.multiplier()
+        );
+        let width = (crate::emoji::display_width(&text) as u16).min(area.width / 2);
+        let combo_area = Rect::new(area.x + area.width - width, area.y, width, 1);
+        let combo_widget = Paragraph::new(text).alignment(Alignment::Right).style(
+            Style::default()
+                .fg(Color::LightMagenta)
+                .add_modifier(Modifier::BOLD),
+        );
+        f.render_widget(combo_widget, combo_area);
+        Rect::new(area.x, area.y, area.width - width, area.height)
+    } else {
+        area
+    };
+
+    // While Rage/Ghost is running, show its remaining time instead of the charge
+    if let Some(buff) = buff {
+        let color = match buff.ultimate_type {
+            crate::model::ultimate::UltimateType::Rage => Color::Red,
+            _ => Color::Cyan,
+        };
+        let buff_widget = Paragraph::new(format!(
+            "{} active: {:.1}s",
+            buff.ultimate_type.name(),
+            buff.remaining.max(0.0)
+        ))
+        .alignment(Alignment::Left)
+        .style(Style::default().fg(color).add_modifier(Modifier::BOLD));
+        f.render_widget(buff_widget, area);
+        return;
+    }
+
     let bar_width = area.width as usize;
     let filled_width = ((bar_width as f32) * (charge / 100.0)).ceil() as usize;
     let empty_width = bar_width.saturating_sub(filled_width);
@@ -498,6 +679,17 @@ pub fn render_item_description_popup(
     description_lines.push(Line::from(selected_item.description.clone()).fg(Color::Gray));
     description_lines.push(Line::from(""));
 
+    if let Some((multiplier, duration)) = selected_item.get_buff() {
+        description_lines.push(
+            Line::from(format!(
+                "Effect: +{:.0}% attack damage for {:.0}s",
+                (multiplier - 1.0) * 100.0,
+                duration
+            ))
+            .fg(Color::LightRed),
+        );
+    }
+
     // Add quantity
     description_lines
         .push(Line::from(format!("Quantity: {}", selected_item.quantity)).fg(Color::Cyan));
@@ -556,6 +748,235 @@ pub fn render_item_description_popup(
     f.render_widget(hint, hint_area);
 }
 
+/// Controls overlay listing the live bindings from settings plus the fixed shortcuts
+pub fn render_help_overlay(f: &mut Frame, area: Rect, settings: &crate::model::settings::Settings) {
+    let movement = format!(
+        "{} / {} / {} / {}",
+        settings.move_up, settings.move_left, settings.move_down, settings.move_right
+    );
+    let pause_keys = format!("{} / Esc", settings.pause);
+    let move_weapon_keys = format!(
+        "Shift + {} / {} (inventory focused)",
+        settings.inventory_up, settings.inventory_down
+    );
+    // H only opens help while no action is bound to it
+    let help_keys = if settings.binds_key("H") {
+        "?"
+    } else {
+        "? / H"
+    };
+    let bindings: Vec<(&str, &str)> = vec![
+        ("Move", &movement),
+        ("Attack / Shoot", &settings.attack),
+        ("Dash", &settings.dash),
+        ("Block", &settings.block),
+        ("Pick Up / Stairs", &settings.interact),
+        ("Use Consumable", &settings.use_consumable),
+        ("Ultimate", &settings.special_item),
+        ("Focus Inventory", &settings.toggle_inv),
+        ("Inventory Up", &settings.inventory_up),
+        ("Inventory Down", &settings.inventory_down),
+        ("Describe Item", &settings.item_describe),
+        ("Pause", &pause_keys),
+        ("Skill Tree", &settings.skill_tree),
+        ("Ultimate Shop", &settings.ultimate_shop),
+        ("Quicksave", &settings.quicksave),
+        ("Switch Weapon", "1-9"),
+        ("Use Consumable Slot", "Shift + 1-9"),
+        ("Drop Weapon", "Ctrl + 1-9"),
+        ("Drop Other Weapons", "X (inventory focused)"),
+        ("Scrap Weapon", "Z (inventory focused)"),
+        ("Move Weapon Slot", &move_weapon_keys),
+        ("Examine Enemy", "V / mouse over"),
+        ("Help", help_keys),
+    ];
+
+    let mut lines = vec![];
+    for (action, keys) in bindings {
+        lines.push(Line::from(vec![
+            Span::styled(format!("{:<20}", action), Style::default().fg(Color::Gray)),
+            Span::styled(
+                keys.to_string(),
+                Style::default()
+                    .fg(Color::Yellow)
+                    .add_modifier(Modifier::BOLD),
+            ),
+        ]));
+    }
+
+    let popup_width = 48.min(area.width);
+    let popup_height = (lines.len() as u16 + 4).min(area.height);
+    let popup_area = Rect {
+        x: area.x + (area.width.saturating_sub(popup_width)) / 2,
+        y: area.y + (area.height.saturating_sub(popup_height)) / 2,
+        width: popup_width,
+        height: popup_height,
+    };
+
+    f.render_widget(Clear, popup_area);
+
+    let popup = Paragraph::new(lines)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(Color::Cyan))
+                .title(" Controls ")
+                .title_alignment(Alignment::Center)
+                .title_bottom(Line::from(" any key to close ").centered())
+                .padding(Padding::new(1, 1, 1, 0))
+                .style(Style::default().bg(Color::Black)),
+        )
+        .style(Style::default().fg(Color::White).bg(Color::Black));
+
+    f.render_widget(popup, popup_area);
+}
+
+/// Bordered tooltip beside an examined enemy: the first line is its title, the rest its
+/// stats. `anchor` is the enemy's cell relative to `area`; the box flips to the other
+/// side or above it when it would run off the game area.
+pub fn render_examine_tooltip(f: &mut Frame, area: Rect, lines: &[String], anchor: (i32, i32)) {
+    let Some((title, body)) = lines.split_first() else {
+        return;
+    };
+    let content_width = lines
+        .iter()
+        .map(|line| line.chars().count())
+        .max()
+        .unwrap_or(0);
+    let box_width = (content_width as u16 + 4).min(area.width);
+    let box_height = (body.len() as u16 + 2).min(area.height);
+    let (width, height) = (box_width as i32, box_height as i32);
+    let (ax, ay) = anchor;
+
+    let x = if ax + 2 + width <= area.width as i32 {
+        ax + 2
+    } else {
+        ax - 1 - width
+    };
+    let y = if ay + 1 + height <= area.height as i32 {
+        ay + 1
+    } else {
+        ay - height
+    };
+    let tooltip_area = Rect {
+        x: area.x + x.clamp(0, area.width as i32 - width) as u16,
+        y: area.y + y.clamp(0, area.height as i32 - height) as u16,
+        width: box_width,
+        height: box_height,
+    };
+
+    f.render_widget(Clear, tooltip_area);
+    let body: Vec<Line> = body.iter().map(|line| Line::from(line.as_str())).collect();
+    let tooltip = Paragraph::new(body)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(Color::Yellow))
+                .title(format!(" {} ", title))
+                .padding(Padding::horizontal(1))
+                .style(Style::default().bg(Color::Black)),
+        )
+        .style(Style::default().fg(Color::White).bg(Color::Black));
+    f.render_widget(tooltip, tooltip_area);
+}
+
+/// Small toast in the top right corner, e.g. "Saved" after a quicksave
+pub fn render_toast(f: &mut Frame, area: Rect, text: &str, color: Color) {
+    let box_width = (text.chars().count() as u16 + 4).max(12).min(area.width);
+    let toast_area = Rect {
+        x: area.x + area.width.saturating_sub(box_width + 1),
+        y: area.y + 1,
+        width: box_width,
+        height: 3.min(area.height),
+    };
+
+    f.render_widget(Clear, toast_area);
+    let toast = Paragraph::new(text)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(color))
+                .style(Style::default().bg(Color::Black)),
+        )
+        .alignment(Alignment::Center)
+        .style(Style::default().fg(color).add_modifier(Modifier::BOLD));
+    f.render_widget(toast, toast_area);
+}
+
+/// Current tutorial instruction, boxed along the bottom of the game view
+pub fn render_tutorial_prompt(
+    f: &mut Frame,
+    area: Rect,
+    tutorial: &crate::model::tutorial::Tutorial,
+    settings: &crate::model::settings::Settings,
+) {
+    let title = match tutorial.lesson_number() {
+        Some(lesson) => format!(
+            " Tutorial {}/{} ",
+            lesson,
+            crate::model::tutorial::TutorialStep::LESSONS.len()
+        ),
+        None => " Tutorial complete ".to_string(),
+    };
+    let box_width = 56.min(area.width);
+    let box_height = 4.min(area.height);
+    let prompt_area = Rect {
+        x: area.x + (area.width - box_width) / 2,
+        y: area.y + area.height.saturating_sub(box_height + 1),
+        width: box_width,
+        height: box_height,
+    };
+
+    let lines = vec![
+        Line::from(Span::styled(
+            tutorial.step.prompt(settings),
+            Style::default()
+                .fg(Color::White)
+                .add_modifier(Modifier::BOLD),
+        )),
+        Line::from(Span::styled(
+            format!("{} / Esc, then Quit: back to the main menu", settings.pause),
+            Style::default().fg(Color::DarkGray),
+        )),
+    ];
+
+    f.render_widget(Clear, prompt_area);
+    let prompt = Paragraph::new(lines)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(Color::Yellow))
+                .title(title)
+                .style(Style::default().bg(Color::Black)),
+        )
+        .alignment(Alignment::Center);
+    f.render_widget(prompt, prompt_area);
+}
+
+/// Centered notice shown in place of the game while the terminal is below the minimum size
+pub fn render_terminal_too_small(f: &mut Frame, area: Rect) {
+    let message = format!(
+        "Terminal too small (need at least {}x{}, have {}x{})",
+        crate::constants::MIN_TERMINAL_WIDTH,
+        crate::constants::MIN_TERMINAL_HEIGHT,
+        area.width,
+        area.height
+    );
+    let notice = Paragraph::new(message)
+        .wrap(Wrap { trim: true })
+        .alignment(Alignment::Center)
+        .style(Style::default().fg(Color::Yellow));
+
+    let height = 3.min(area.height);
+    let notice_area = Rect {
+        x: area.x,
+        y: area.y + area.height.saturating_sub(height) / 2,
+        width: area.width,
+        height,
+    };
+    f.render_widget(notice, notice_area);
+}
+
 pub fn render_pause_indicator(f: &mut Frame, area: Rect) {
     let pause_text = "⏸ PAUSED ⏸ | Press [T] for Skill Tree";
     let pause_paragraph = Paragraph::new(pause_text)
@@ -0,0 +1,234 @@
+use crate::model::floor::Floor;
+use crate::model::hazard::SpikeState;
+use ratatui::prelude::*;
+use std::collections::HashSet;
+
+/// Draws the visible part of the floor straight into the frame buffer, one cell per tile,
+/// so a frame allocates no Strings or Spans per tile
+pub struct FloorView<'a> {
+    pub floor: &'a Floor,
+    pub camera: (i32, i32),
+    pub attack_area: &'a HashSet<(i32, i32)>,
+    pub is_attacking: bool,
+    pub fog_enabled: bool,
+    pub low_detail: bool,
+}
+
+impl Widget for FloorView<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let floor = self.floor;
+        let attack_style = Style::default().fg(Color::Red).add_modifier(Modifier::BOLD);
+
+        for screen_y in 0..area.height {
+            let world_y = self.camera.1 + screen_y as i32;
+            for screen_x in 0..area.width {
+                let world_x = self.camera.0 + screen_x as i32;
+                let cell = &mut buf[(area.x + screen_x, area.y + screen_y)];
+
+                let Some((ch, color_idx)) = floor.get_styled_tile_cached(world_x, world_y) else {
+                    cell.set_char(' ');
+                    continue;
+                };
+
+                // Fog of war: hide unexplored tiles, dim remembered ones
+                let in_view = !self.fog_enabled || floor.is_visible(world_x, world_y);
+                if !in_view && !floor.is_explored(world_x, world_y) {
+                    cell.set_char(' ');
+                    continue;
+                }
+                let color_idx = if in_view {
+                    color_idx
+                } else {
+                    crate::constants::FOG_EXPLORED_COLOR
+                };
+
+                let glyph = if ch == ' ' {
+                    '·'
+                } else if self.low_detail && floor.get_tile(world_x, world_y) {
+                    // One wall glyph instead of the decorative mix
+                    '#'
+                } else {
+                    ch
+                };
+                cell.set_char(glyph);
+
+                // Highlight attack area in red if attacking
+                let is_attacked =
+                    in_view && self.is_attacking && self.attack_area.contains(&(world_x, world_y));
+                if is_attacked {
+                    cell.set_style(attack_style);
+                } else {
+                    cell.set_style(Style::default().fg(Color::Indexed(color_idx)));
+                }
+            }
+        }
+
+        // Spike traps over the floor, yellow while they are about to come up
+        for trap in &floor.spike_traps {
+            let screen_x = trap.x - self.camera.0;
+            let screen_y = trap.y - self.camera.1;
+            if screen_x < 0
+                || screen_y < 0
+                || screen_x >= area.width as i32
+                || screen_y >= area.height as i32
+            {
+                continue;
+            }
+            if self.fog_enabled && !floor.is_visible(trap.x, trap.y) {
+                continue;
+            }
+            let (glyph, style) = match trap.state() {
+                SpikeState::Retracted => ('^', Style::default().fg(Color::DarkGray)),
+                SpikeState::Rising => ('^', Style::default().fg(Color::Yellow)),
+                SpikeState::Raised if self.low_detail => ('^', Style::default().fg(Color::Red)),
+                SpikeState::Raised => (
+                    '▲',
+                    Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
+                ),
+            };
+            let cell = &mut buf[(area.x + screen_x as u16, area.y + screen_y as u16)];
+            cell.set_char(glyph);
+            cell.set_style(style);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ratatui::widgets::Paragraph;
+    use std::alloc::{GlobalAlloc, Layout, System};
+    use std::cell::Cell;
+
+    /// Counts heap allocations per thread so a single render can be measured
+    struct CountingAllocator;
+
+    thread_local! {
+        static ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+    }
+
+    unsafe impl GlobalAlloc for CountingAllocator {
+        unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+            let _ = ALLOCATIONS.try_with(|count| count.set(count.get() + 1));
+            unsafe { System.alloc(layout) }
+        }
+
+        unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+            unsafe { System.dealloc(ptr, layout) }
+        }
+    }
+
+    #[global_allocator]
+    static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+    fn allocations_during(render: impl FnOnce()) -> usize {
+        let before = ALLOCATIONS.with(Cell::get);
+        render();
+        ALLOCATIONS.with(Cell::get) - before
+    }
+
+    /// The map loop FloorView replaced: a String and Span per tile, drawn as a Paragraph
+    fn render_with_spans(view: &FloorView, area: Rect, buf: &mut Buffer) {
+        let floor = view.floor;
+        let mut lines = Vec::new();
+        for screen_y in 0..area.height as i32 {
+            let world_y = view.camera.1 + screen_y;
+            let mut current_line = Vec::with_capacity(area.width as usize);
+            for screen_x in 0..area.width as i32 {
+                let world_x = view.camera.0 + screen_x;
+                let Some((ch, color_idx)) = floor.get_styled_tile_cached(world_x, world_y) else {
+                    current_line.push(Span::raw(" "));
+                    continue;
+                };
+                let in_view = !view.fog_enabled || floor.is_visible(world_x, world_y);
+                if !in_view && !floor.is_explored(world_x, world_y) {
+                    current_line.push(Span::raw(" "));
+                    continue;
+                }
+                let color_idx = if in_view {
+                    color_idx
+                } else {
+                    crate::constants::FOG_EXPLORED_COLOR
+                };
+                let glyph = if ch == ' ' {
+                    "·".to_string()
+                } else {
+                    ch.to_string()
+                };
+                if in_view && view.is_attacking && view.attack_area.contains(&(world_x, world_y)) {
+                    current_line.push(Span::styled(
+                        glyph,
+                        Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
+                    ));
+                } else {
+                    current_line.push(Span::styled(
+                        glyph,
+                        Style::default().fg(Color::Indexed(color_idx)),
+                    ));
+                }
+            }
+            lines.push(Line::from(current_line));
+        }
+        Paragraph::new(lines).render(area, buf);
+    }
+
+    #[test]
+    fn test_floor_view_matches_span_rendering() {
+        let mut floor = Floor::new(120, 40, 7);
+        // Traps are drawn over the tiles afterwards and weren't part of the old loop
+        floor.spike_traps.clear();
+        let (px, py) = floor.find_player_spawn().unwrap();
+        // Leave a remembered area behind the current view so fog dimming shows up
+        floor.update_visibility(px, py, 12);
+        floor.update_visibility(px + 3, py, 6);
+        let attack_area: HashSet<(i32, i32)> = (-2..=2)
+            .flat_map(|dx| (-1..=1).map(move |dy| (px + 3 + dx, py + dy)))
+            .collect();
+        let area = Rect::new(0, 0, 80, 30);
+
+        // A view hanging off the top-left corner, and one centred on the player
+        for camera in [(-5, -3), (px - 40, py - 15)] {
+            for fog_enabled in [false, true] {
+                for is_attacking in [false, true] {
+                    let view = FloorView {
+                        floor: &floor,
+                        camera,
+                        attack_area: &attack_area,
+                        is_attacking,
+                        fog_enabled,
+                        low_detail: false,
+                    };
+                    let mut expected = Buffer::empty(area);
+                    render_with_spans(&view, area, &mut expected);
+                    let mut actual = Buffer::empty(area);
+                    view.render(area, &mut actual);
+                    assert_eq!(actual, expected);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_floor_view_allocates_nothing_per_tile() {
+        let mut floor = Floor::new(180, 60, 11);
+        floor.spike_traps.clear();
+        let attack_area = HashSet::new();
+        let area = Rect::new(0, 0, 180, 50);
+        let view = || FloorView {
+            floor: &floor,
+            camera: (0, 0),
+            attack_area: &attack_area,
+            is_attacking: false,
+            fog_enabled: false,
+            low_detail: false,
+        };
+
+        let mut buf = Buffer::empty(area);
+        let before = allocations_during(|| render_with_spans(&view(), area, &mut buf));
+        let mut buf = Buffer::empty(area);
+        let after = allocations_during(|| view().render(area, &mut buf));
+
+        assert!(before >= (area.width * area.height) as usize);
+        assert_eq!(after, 0);
+    }
+}
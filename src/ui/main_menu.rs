@@ -1,6 +1,17 @@
 use crate::app::App;
 use ratatui::{prelude::*, widgets::*};
 
+/// Main menu entries; input handling indexes into the same list
+pub const MENU_ITEMS: &[&str] = &[
+    "Start Game",
+    "Load Save",
+    "Tutorial",
+    "Records",
+    "Settings",
+    "Dev Tools",
+    "Exit",
+];
+
 pub fn draw(f: &mut Frame, app: &mut App, area: Rect, pulse_color: Color) {
     app.update_auto_scroll();
 
@@ -32,8 +43,7 @@ pub fn draw(f: &mut Frame, app: &mut App, area: Rect, pulse_color: Color) {
         area.height,
     );
 
-    let items = ["Start Game", "Load Save", "Settings", "Dev Tools", "Exit"];
-    let list_items: Vec<ListItem> = items
+    let list_items: Vec<ListItem> = MENU_ITEMS
         .iter()
         .map(|i| ListItem::new(*i).style(Style::default().fg(Color::Gray)))
         .collect();
@@ -49,7 +59,7 @@ pub fn draw(f: &mut Frame, app: &mut App, area: Rect, pulse_color: Color) {
         .highlight_symbol(" >> ");
 
     let list_width = 30;
-    let list_height = (items.len() as u16) + 2;
+    let list_height = (MENU_ITEMS.len() as u16) + 2;
     let horizontal_padding = 2;
 
     let menu_area = Rect::new(
@@ -170,6 +180,19 @@ pub fn draw_save_selection(f: &mut Frame, app: &mut App, area: Rect, pulse_color
         };
 
         f.render_stateful_widget(list, list_area, &mut app.save_selection_state);
+
+        if let Some(error) = &app.save_load_error {
+            let error_line = Paragraph::new(error.as_str())
+                .style(Style::default().fg(Color::Red))
+                .alignment(Alignment::Center);
+            let error_area = Rect {
+                x: area.x,
+                y: (list_area.y + list_area.height + 1).min(area.bottom().saturating_sub(2)),
+                width: area.width,
+                height: 1,
+            };
+            f.render_widget(error_line, error_area);
+        }
     }
 
     // Draw hints
@@ -180,3 +203,108 @@ pub fn draw_save_selection(f: &mut Frame, app: &mut App, area: Rect, pulse_color
     ];
     super::drawing::render_key_hints(f, area, hints);
 }
+
+/// Records screen: past runs best score first, one line each
+pub fn draw_records(f: &mut Frame, app: &mut App, area: Rect, pulse_color: Color) {
+    f.render_widget(Clear, area);
+    f.render_widget(
+        Block::default().style(Style::default().bg(Color::Black)),
+        area,
+    );
+
+    let title = Paragraph::new("RECORDS")
+        .style(
+            Style::default()
+                .fg(Color::Yellow)
+                .add_modifier(Modifier::BOLD),
+        )
+        .alignment(Alignment::Center);
+    let title_area = Rect {
+        x: area.x,
+        y: area.y + 2,
+        width: area.width,
+        height: 1,
+    };
+    f.render_widget(title, title_area);
+
+    let records = app.run_history.by_score();
+    if records.is_empty() {
+        let no_runs = Paragraph::new("No finished runs yet")
+            .style(Style::default().fg(Color::Red))
+            .alignment(Alignment::Center);
+        let no_runs_area = Rect {
+            x: area.x,
+            y: area.y + area.height / 2,
+            width: area.width,
+            height: 1,
+        };
+        f.render_widget(no_runs, no_runs_area);
+    } else {
+        let list_items: Vec<ListItem> = records
+            .iter()
+            .enumerate()
+            .map(|(rank, record)| {
+                let outcome_color = match record.outcome {
+                    crate::model::run_history::RunOutcome::Victory => Color::Green,
+                    crate::model::run_history::RunOutcome::Death => Color::Red,
+                };
+                let time = record.time_elapsed as u32;
+                ListItem::new(Line::from(vec![
+                    Span::styled(
+                        format!("{:>2}. {:>6}  ", rank + 1, record.score),
+                        Style::default().fg(Color::Yellow),
+                    ),
+                    Span::styled(
+                        format!("{:<12} {:<7} ", record.character_name, record.difficulty),
+                        Style::default().fg(Color::Gray),
+                    ),
+                    Span::styled(
+                        format!("{:<8}", record.outcome.name()),
+                        Style::default().fg(outcome_color),
+                    ),
+                    Span::styled(
+                        format!(
+                            "F{:<3} {:>3}m{:02}s {:>4} kills  {}",
+                            record.floors_cleared,
+                            time / 60,
+                            time % 60,
+                            record.enemies_killed,
+                            record.date()
+                        ),
+                        Style::default().fg(Color::Gray),
+                    ),
+                ]))
+            })
+            .collect();
+
+        let list = List::new(list_items)
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .title(" BEST RUNS ")
+                    .title_alignment(Alignment::Center),
+            )
+            .highlight_style(
+                Style::default()
+                    .bg(pulse_color)
+                    .add_modifier(Modifier::BOLD),
+            )
+            .highlight_symbol(" >> ");
+
+        let list_width = 84.min(area.width.saturating_sub(4));
+        let list_height = (records.len() as u16 + 2).min(area.height.saturating_sub(8));
+        let list_area = Rect {
+            x: (area.width.saturating_sub(list_width)) / 2,
+            y: area.y + 5,
+            width: list_width,
+            height: list_height,
+        };
+        f.render_stateful_widget(list, list_area, &mut app.records_state);
+    }
+
+    let hints = vec![
+        ("W/S", "Navigate", Some(Color::Yellow)),
+        ("ESC", "Back", Some(Color::Red)),
+    ];
+    super::drawing::render_key_hints(f, area, hints);
+}
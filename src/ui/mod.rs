@@ -2,6 +2,7 @@ pub mod character_creation;
 pub mod death_screen;
 pub mod dev_menu;
 pub mod drawing;
+pub mod floor_view;
 pub mod main_menu;
 pub mod pause_menu;
 pub mod settings;
@@ -18,6 +19,15 @@ pub fn draw(f: &mut Frame, app: &mut App) {
     let duration = app.start_time.elapsed().as_secs_f32();
     let pulse_color = drawing::calculate_pulse_color(duration);
 
+    if matches!(app.state, AppState::Game | AppState::UltimateShop) {
+        app.update_terminal_size(area.width, area.height);
+        if app.terminal_too_small() {
+            f.render_widget(Clear, area);
+            drawing::render_terminal_too_small(f, area);
+            return;
+        }
+    }
+
     match app.state {
         AppState::MainMenu => main_menu::draw(f, app, area, pulse_color),
         AppState::SaveSelection => {
@@ -50,7 +60,7 @@ pub fn draw(f: &mut Frame, app: &mut App) {
 
             ultimate_shop::draw(
                 f,
-                app.character.gold,
+                &app.character,
                 app.floor_level,
                 &app.ultimate_shop,
                 &mut app.ultimate_shop_ui,
@@ -61,23 +71,19 @@ pub fn draw(f: &mut Frame, app: &mut App) {
             f.render_widget(Clear, area);
 
             // Draw FPS counter in top-right corner
-            let elapsed = app.start_time.elapsed().as_secs_f32();
-            let fps = if elapsed > 0.0 {
-                app.frame_count as f32 / elapsed
-            } else {
-                0.0
-            };
-            let fps_text = format!("FPS: {:.1}", fps);
-            let fps_para = Paragraph::new(fps_text)
-                .style(Style::default().fg(Color::Green))
-                .alignment(Alignment::Right);
-            let fps_area = Rect {
-                x: area.right().saturating_sub(12),
-                y: area.top(),
-                width: 12,
-                height: 1,
-            };
-            f.render_widget(fps_para, fps_area);
+            if app.settings.show_fps {
+                let fps_text = format!("FPS: {:.1}", app.fps_counter.fps());
+                let fps_para = Paragraph::new(fps_text)
+                    .style(Style::default().fg(Color::Green))
+                    .alignment(Alignment::Right);
+                let fps_area = Rect {
+                    x: area.right().saturating_sub(12),
+                    y: area.top(),
+                    width: 12,
+                    height: 1,
+                };
+                f.render_widget(fps_para, fps_area);
+            }
 
             app.update_terminal_size(area.width, area.height);
 
@@ -107,71 +113,24 @@ pub fn draw(f: &mut Frame, app: &mut App) {
             let right_panel_area = horizontal_chunks[1];
 
             if let Some(floor) = &app.current_floor {
-                let mut lines = Vec::new();
-
-                let viewport_width = game_area.width as i32;
-                let viewport_height = game_area.height as i32;
-                let camera_x = app.camera_offset.0.floor() as i32;
-                let camera_y = app.camera_offset.1.floor() as i32;
-
                 // Get attack area for highlighting - convert to HashSet for O(1) lookups
                 let attack_area_set: std::collections::HashSet<_> =
                     app.get_attack_area().into_iter().collect();
-                let is_attacking = app.character.is_attack_animating();
-
-                // Pre-allocate cached strings for common glyphs to avoid repeated allocations
-                let space_bullet = "·".to_string();
-                let dot_str = ".".to_string();
-
-                for screen_y in 0..viewport_height {
-                    let world_y = camera_y + screen_y;
-                    let mut current_line = Vec::with_capacity(viewport_width as usize);
-
-                    for screen_x in 0..viewport_width {
-                        let world_x = camera_x + screen_x;
-
-                        if let Some((ch, color_idx)) =
-                            floor.get_styled_tile_cached(world_x, world_y)
-                        {
-                            // Highlight attack area in red if attacking
-                            let is_attacked =
-                                is_attacking && attack_area_set.contains(&(world_x, world_y));
-
-                            if is_attacked {
-                                // Only allocate string if we need red highlighting
-                                let glyph_str = if ch == ' ' {
-                                    "·".to_string()
-                                } else {
-                                    ch.to_string()
-                                };
-                                current_line.push(Span::styled(
-                                    glyph_str,
-                                    Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
-                                ));
-                            } else {
-                                // Use pre-rendered strings for non-highlighted tiles
-                                let glyph = if ch == ' ' {
-                                    space_bullet.clone()
-                                } else if ch == '.' {
-                                    dot_str.clone()
-                                } else {
-                                    ch.to_string()
-                                };
-                                current_line.push(Span::styled(
-                                    glyph,
-                                    Style::default().fg(Color::Indexed(color_idx)),
-                                ));
-                            }
-                        } else {
-                            current_line.push(Span::raw(" "));
-                        }
-                    }
 
-                    lines.push(Line::from(current_line));
-                }
-
-                let map_widget = Paragraph::new(lines);
-                f.render_widget(map_widget, game_area);
+                f.render_widget(
+                    floor_view::FloorView {
+                        floor,
+                        camera: (
+                            app.camera_offset.0.floor() as i32,
+                            app.camera_offset.1.floor() as i32,
+                        ),
+                        attack_area: &attack_area_set,
+                        is_attacking: app.character.is_attack_animating(app.settings.game_speed),
+                        fog_enabled: app.fog_hides_map(),
+                        low_detail: app.settings.low_detail,
+                    },
+                    game_area,
+                );
             }
 
             let (px, py) = app.character_position;
@@ -181,37 +140,78 @@ pub fn draw(f: &mut Frame, app: &mut App) {
             );
 
             // Render arrows
-            let arrows: Vec<(f32, f32, &str)> = app
+            let arrows: Vec<(f32, f32, &str, Color)> = app
                 .arrows
                 .iter()
-                .map(|arrow| (arrow.x, arrow.y, arrow.get_glyph()))
+                .map(|arrow| (arrow.x, arrow.y, arrow.get_glyph(), arrow.color()))
                 .collect();
             drawing::render_arrows(f, game_area, &arrows, cx, cy);
 
-            // Render particles
-            let particles = app.particle_system.get_active_particles();
-            drawing::render_particles(f, game_area, &particles, cx, cy);
+            // Render particles (skipped entirely in low detail mode)
+            if !app.settings.low_detail {
+                let particles = app.particle_system.get_active_particles();
+                drawing::render_particles(f, game_area, &particles, cx, cy);
+            }
 
-            // Render items on the floor
+            // Render items on the floor (stairs first so loot on top stays visible)
             if let Some(floor) = &app.current_floor {
+                let fog_enabled = app.fog_hides_map();
                 let items: Vec<(i32, i32, &str, Color)> = floor
-                    .items
-                    .iter()
-                    .map(|item| (item.x, item.y, item.get_glyph(), item.get_glyph_color()))
+                    .stairs
+                    .map(|(sx, sy)| (sx, sy, crate::constants::STAIRS_GLYPH, Color::LightCyan))
+                    .into_iter()
+                    .chain(
+                        floor
+                            .items
+                            .iter()
+                            .map(|item| (item.x, item.y, item.get_glyph(), item.get_glyph_color())),
+                    )
+                    .filter(|(x, y, _, _)| !fog_enabled || floor.is_visible(*x, *y))
                     .collect();
                 drawing::render_items(f, game_area, &items, cx, cy);
             }
 
             // Render enemies
             if let Some(floor) = &app.current_floor {
-                let enemies: Vec<(i32, i32, String, Color)> = floor
+                let fog_enabled = app.fog_hides_map();
+                let metric = app.settings.enemy_distance;
+                let player = crate::model::enemy::Position::new(px, py);
+                let show_detection = app.detection_overlay_active();
+
+                // Dev overlay: detection radius rings under the enemies
+                if show_detection {
+                    let rings: Vec<(i32, i32, i32, bool)> = floor
+                        .enemies
+                        .iter()
+                        .filter(|e| e.is_alive())
+                        .map(|e| {
+                            (
+                                e.position.x,
+                                e.position.y,
+                                e.detection_radius,
+                                e.detects(&player, metric),
+                            )
+                        })
+                        .collect();
+                    drawing::render_detection_rings(f, game_area, &rings, metric, cx, cy);
+                }
+
+                let enemies: Vec<(i32, i32, String, Style)> = floor
                     .enemies
                     .iter()
                     .filter(|e| e.is_alive())
+                    .filter(|e| !fog_enabled || floor.is_visible(e.position.x, e.position.y))
                     .map(|enemy| {
                         // If damaged, render in red
                         let color = if enemy.is_damaged_animating() {
                             Color::Red
+                        } else if show_detection {
+                            // Dev overlay: red once the player is spotted, blue otherwise
+                            if enemy.detects(&player, metric) {
+                                Color::LightRed
+                            } else {
+                                Color::LightBlue
+                            }
                         } else {
                             match enemy.rarity {
                                 crate::model::enemy_type::EnemyRarity::Fighter => Color::White,
@@ -221,19 +221,72 @@ pub fn draw(f: &mut Frame, app: &mut App) {
                                 crate::model::enemy_type::EnemyRarity::Boss => Color::LightMagenta,
                             }
                         };
+                        let style = enemy.rarity.get_style(app.settings.low_detail).fg(color);
                         let glyph = enemy.rarity.get_glyph();
-                        (enemy.position.x, enemy.position.y, glyph.to_string(), color)
+                        (enemy.position.x, enemy.position.y, glyph.to_string(), style)
                     })
                     .collect();
                 drawing::render_enemies(f, game_area, &enemies, cx, cy);
+
+                // Scrying Eye: mark every enemy and the treasure room's loot
+                if app.map_reveal.is_active() {
+                    let marks: Vec<(i32, i32)> = floor
+                        .enemies
+                        .iter()
+                        .filter(|e| e.is_alive())
+                        .map(|e| (e.position.x, e.position.y))
+                        .chain(
+                            floor
+                                .items
+                                .iter()
+                                .filter(|item| {
+                                    floor.treasure_room.is_some()
+                                        && floor.room_at(item.x, item.y) == floor.treasure_room
+                                })
+                                .map(|item| (item.x, item.y)),
+                        )
+                        .collect();
+                    drawing::render_reveal_marks(f, game_area, &marks, cx, cy);
+                }
+            }
+
+            // Telegraphs: the player's ultimate wind-up, and danger tiles for enemy
+            // attacks about to land
+            let mut ultimate_positions: Vec<(i32, i32)> = Vec::new();
+            let mut danger_positions: Vec<(i32, i32)> = Vec::new();
+            if app.character.ultimate.is_telegraphing() {
+                ultimate_positions.extend(app.character.ultimate.get_telegraph_area(px, py));
+            }
+            if let Some(floor) = &app.current_floor {
+                for enemy in floor.enemies.iter().filter(|e| e.is_alive()) {
+                    if let Some(telegraph) = &enemy.telegraph {
+                        danger_positions.extend(telegraph.tiles());
+                    }
+                }
+                ultimate_positions.retain(|(x, y)| floor.is_walkable(*x, *y));
+                danger_positions.retain(|(x, y)| floor.is_walkable(*x, *y));
+            }
+            drawing::render_telegraph(
+                f,
+                game_area,
+                &ultimate_positions,
+                Color::LightYellow,
+                cx,
+                cy,
+            );
+            drawing::render_telegraph(f, game_area, &danger_positions, Color::LightRed, cx, cy);
+
+            // Boss kill flash (left out in low detail mode)
+            if app.screen_flash_timer > 0.0 && !app.settings.low_detail {
+                drawing::render_screen_flash(f, game_area);
             }
 
             // Render ultimate ability area (only while animating)
-            if app.character.ultimate.is_animating() {
+            if app.character.ultimate.is_animating(app.settings.game_speed) {
                 let ultimate_positions: Vec<(i32, i32)> = app
                     .character
                     .ultimate
-                    .get_affected_area(px, py)
+                    .get_affected_area(px, py, app.settings.game_speed)
                     .into_iter()
                     .filter(|(x, y)| {
                         if let Some(floor) = &app.current_floor {
@@ -262,7 +315,11 @@ pub fn draw(f: &mut Frame, app: &mut App) {
                     f,
                     game_area,
                     (screen_x, screen_y),
-                    app.character.is_damaged_animating(),
+                    app.character.is_damaged_animating(app.settings.game_speed),
+                    app.character
+                        .status_effects
+                        .most_severe()
+                        .map(|effect| effect.color()),
                 );
             }
             // Render cooldown bars in right panel
@@ -276,13 +333,22 @@ pub fn draw(f: &mut Frame, app: &mut App) {
                 ])
                 .split(right_panel_area);
 
-            // Health info
+            // Health info, with active status effects beside it
+            let health_chunks = Layout::default()
+                .direction(Direction::Horizontal)
+                .constraints([Constraint::Length(12), Constraint::Min(0)])
+                .split(panel_chunks[0]);
             drawing::render_health_info(
                 f,
-                panel_chunks[0],
+                health_chunks[0],
                 app.character.health,
                 app.character.health_max,
             );
+            drawing::render_status_indicators(
+                f,
+                health_chunks[1],
+                &app.character.status_effects.indicators(),
+            );
 
             // Gold info
             drawing::render_gold_info(f, panel_chunks[1], app.character.gold);
@@ -300,7 +366,9 @@ pub fn draw(f: &mut Frame, app: &mut App) {
                 .split(cooldown_area);
 
             // Dash cooldown (vertical)
-            let remaining_dash_cooldown = app.character.dash_cooldown_remaining();
+            let remaining_dash_cooldown = app
+                .character
+                .dash_cooldown_remaining(app.settings.game_speed);
             drawing::render_vertical_cooldown_bar(
                 f,
                 bar_chunks[0],
@@ -311,29 +379,46 @@ pub fn draw(f: &mut Frame, app: &mut App) {
             );
 
             // Attack cooldown (vertical)
-            let remaining_attack_cooldown = app.character.attack_cooldown_remaining();
+            let remaining_attack_cooldown = app
+                .character
+                .attack_cooldown_remaining(app.settings.game_speed);
             drawing::render_vertical_cooldown_bar(
                 f,
                 bar_chunks[1],
                 "ATK",
                 remaining_attack_cooldown,
-                app.character.attack_cooldown.duration(),
+                app.character.melee_cooldown(),
                 Color::Red,
             );
 
-            // Bow cooldown (vertical)
-            let remaining_bow_cooldown = app.character.bow_cooldown_remaining();
-            drawing::render_vertical_cooldown_bar(
-                f,
-                bar_chunks[2],
-                "BOW",
-                remaining_bow_cooldown,
-                app.character.bow_cooldown.duration(),
-                Color::Cyan,
-            );
+            // Bow cooldown (vertical), showing charge progress while a shot is held
+            if app.character.bow_charging {
+                drawing::render_vertical_cooldown_bar(
+                    f,
+                    bar_chunks[2],
+                    "BOW",
+                    1.0 - app.character.bow_charge_fraction(),
+                    1.0,
+                    Color::LightYellow,
+                );
+            } else {
+                let remaining_bow_cooldown = app
+                    .character
+                    .bow_cooldown_remaining(app.settings.game_speed);
+                drawing::render_vertical_cooldown_bar(
+                    f,
+                    bar_chunks[2],
+                    "BOW",
+                    remaining_bow_cooldown,
+                    app.character.ranged_cooldown(),
+                    Color::Cyan,
+                );
+            }
 
             // Block cooldown (vertical)
-            let remaining_block_cooldown = app.character.block_cooldown_remaining();
+            let remaining_block_cooldown = app
+                .character
+                .block_cooldown_remaining(app.settings.game_speed);
             drawing::render_vertical_cooldown_bar(
                 f,
                 bar_chunks[3],
@@ -347,7 +432,7 @@ pub fn draw(f: &mut Frame, app: &mut App) {
             drawing::render_consumables_info(
                 f,
                 panel_chunks[3],
-                &app.character.consumable_inventory,
+                &app.character,
                 app.inventory_focused,
                 app.inventory_scroll_index,
                 &app.settings,
@@ -358,6 +443,8 @@ pub fn draw(f: &mut Frame, app: &mut App) {
                 f,
                 ultimate_bar_area,
                 app.character.ultimate_charge,
+                app.character.ultimate_buff.as_ref(),
+                &app.character.combo,
             );
 
             // Render weapon slots bar
@@ -426,17 +513,57 @@ pub fn draw(f: &mut Frame, app: &mut App) {
                 f.render_widget(notification, notification_area);
             }
 
+            pause_menu::draw_weapon_swap_prompt(f, app, area);
+            pause_menu::draw_drop_all_prompt(f, app, area);
+            pause_menu::draw_scrap_prompt(f, app, area);
+
+            if app.save_toast_timer > 0.0 {
+                drawing::render_toast(f, area, "Saved", Color::Green);
+            } else if let Some((gold, _)) = app.scrap_toast {
+                drawing::render_toast(f, area, &format!("+{} gold", gold), Color::Yellow);
+            } else if let Some(record) = app
+                .current_floor
+                .as_ref()
+                .and_then(|floor| floor.enemies.iter().find_map(|enemy| enemy.dummy.as_ref()))
+            {
+                let text = format!(
+                    "Dummy: {} dmg | {:.1} DPS",
+                    record.total_damage,
+                    record.dps(app.settings.game_speed)
+                );
+                drawing::render_toast(f, area, &text, Color::LightRed);
+            }
+
+            if let Some(enemy) = app.examined_enemy((game_area.x, game_area.y)) {
+                drawing::render_examine_tooltip(
+                    f,
+                    game_area,
+                    &enemy.examine_lines(),
+                    (enemy.position.x - cx, enemy.position.y - cy),
+                );
+            }
+
+            if let Some(tutorial) = &app.tutorial {
+                drawing::render_tutorial_prompt(f, game_area, tutorial, &app.settings);
+            }
+
+            if app.showing_help {
+                drawing::render_help_overlay(f, area, &app.settings);
+            }
+
             // Render pause indicator last (on top of everything)
             if app.is_paused {
                 drawing::render_pause_indicator(f, area);
                 // Render pause menu if paused
                 pause_menu::draw(f, app, area);
+                pause_menu::draw_quit_prompt(f, app, area);
             }
         }
         AppState::DevMenu => dev_menu::draw(f, app, area),
         AppState::SkillTree => skill_tree::draw(f, app, area),
         AppState::DeathScreen => death_screen::draw(f, app, area),
         AppState::VictoryScreen => victory_screen::draw(f, app, area),
+        AppState::Records => main_menu::draw_records(f, app, area, pulse_color),
     }
 }
 
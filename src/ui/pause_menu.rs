@@ -1,4 +1,5 @@
 use crate::app::{App, PauseSubmenu, SettingsMode};
+use crate::model::settings::SettingsRow;
 use ratatui::{prelude::*, widgets::*};
 
 const MAIN_MENU_ITEMS: &[&str] = &["Resume", "Volume", "Settings", "Quit"];
@@ -53,6 +54,16 @@ fn draw_main_pause_menu(f: &mut Frame, app: &mut App, area: Rect) {
     }
 
     lines.push(Line::from(""));
+    if app.settings.adaptive_difficulty {
+        // Keep the adaptive scaling visible so players know why enemies feel different
+        lines.push(Line::from(Span::styled(
+            format!(
+                "Adaptive difficulty: enemies at {:.0}% (R to reset)",
+                app.adaptive_difficulty.multiplier() * 100.0
+            ),
+            Style::default().fg(Color::Gray),
+        )));
+    }
     lines.push(Line::from(Span::styled(
         "↑ ↓ to navigate | ENTER to select | ESC to resume",
         Style::default()
@@ -204,42 +215,10 @@ fn draw_volume_menu(f: &mut Frame, app: &mut App, area: Rect) {
 
 fn draw_settings_menu(f: &mut Frame, app: &mut App, area: Rect) {
     let s = &app.pause_temp_settings;
-    let items = vec![
-        format!("Move Up:         [{}]", s.move_up),
-        format!("Move Left:       [{}]", s.move_left),
-        format!("Move Down:       [{}]", s.move_down),
-        format!("Move Right:      [{}]", s.move_right),
-        format!("Attack:          [{}]", s.attack),
-        format!("Dash:            [{}]", s.dash),
-        format!("Block:           [{}]", s.block),
-        format!("Inventory Focus: [{}]", s.toggle_inv),
-        format!("Use Consumable:  [{}]", s.use_consumable),
-        format!("Inv Up:          [{}]", s.inventory_up),
-        format!("Inv Down:        [{}]", s.inventory_down),
-        format!("Item Describe:   [{}]", s.item_describe),
-        format!("Pause:           [{}]", s.pause),
-        format!("Special:         [{}]", s.special_item),
-        format!("Difficulty:      [{}]", s.difficulty.name()),
-        format!("Default Difficulty: [{}]", s.default_difficulty.name()),
-        "-------------------".to_string(),
-        "SAVE CHANGES".to_string(),
-        "DISCARD & BACK".to_string(),
-        "RESET TO DEFAULT".to_string(),
-    ];
-
-    let list_items: Vec<ListItem> = items
-        .iter()
-        .enumerate()
-        .map(|(i, text)| {
-            let mut style = Style::default().fg(Color::Gray);
-            if i == 17 {
-                style = style.fg(Color::Green);
-            } else if i == 18 {
-                style = style.fg(Color::Yellow);
-            } else if i == 19 {
-                style = style.fg(Color::Red);
-            }
-            ListItem::new(text.as_str()).style(style)
+    let list_items: Vec<ListItem> = SettingsRow::pause_menu()
+        .into_iter()
+        .map(|row| {
+            ListItem::new(super::settings::row_label(row, s)).style(super::settings::row_style(row))
         })
         .collect();
 
@@ -277,6 +256,303 @@ fn centered_rect(width: u16, height: u16, r: Rect) -> Rect {
         .split(popup_layout[1])[1]
 }
 
+/// Full-inventory prompt: pick a slot to swap for the ground weapon, or leave it
+pub fn draw_weapon_swap_prompt(f: &mut Frame, app: &App, area: Rect) {
+    let Some(prompt) = &app.weapon_swap_prompt else {
+        return;
+    };
+
+    let ground_color = prompt.weapon.rarity.get_color();
+    let mut lines = vec![
+        Line::from(Span::styled(
+            "INVENTORY FULL",
+            Style::default()
+                .fg(Color::Yellow)
+                .add_modifier(Modifier::BOLD),
+        )),
+        Line::from(Span::styled(
+            format!(
+                "Found: {} ({})",
+                prompt.weapon.name,
+                prompt.weapon.rarity.name()
+            ),
+            Style::default().fg(ground_color),
+        )),
+        Line::from(""),
+    ];
+
+    let weapons = &app.character.weapon_inventory.weapons;
+    let options = weapons
+        .iter()
+        .enumerate()
+        .map(|(i, w)| format!("[{}] Swap out {} ({})", i + 1, w.name, w.rarity.name()))
+        .chain(std::iter::once("Leave it on the floor".to_string()));
+    for (idx, text) in options.enumerate() {
+        let option_idx = if idx < weapons.len() {
+            idx
+        } else {
+            crate::app::WEAPON_SWAP_LEAVE
+        };
+        let selected = option_idx == prompt.selection;
+        let style = if selected {
+            Style::default()
+                .fg(Color::Black)
+                .bg(Color::Yellow)
+                .add_modifier(Modifier::BOLD)
+        } else {
+            Style::default().fg(Color::White)
+        };
+        let prefix = if selected { "> " } else { "  " };
+        lines.push(Line::from(Span::styled(
+            format!("{}{}", prefix, text),
+            style,
+        )));
+    }
+
+    lines.push(Line::from(""));
+    lines.push(Line::from(Span::styled(
+        "↑ ↓ / 1-9 to choose | ENTER to confirm | ESC to leave it",
+        Style::default()
+            .fg(Color::DarkGray)
+            .add_modifier(Modifier::ITALIC),
+    )));
+
+    let popup_area = centered_rect(60, lines.len() as u16 + 2, area);
+    f.render_widget(Clear, popup_area);
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .title(" SWAP WEAPON ")
+        .style(Style::default().fg(Color::Yellow).bg(Color::Black));
+    let paragraph = Paragraph::new(lines)
+        .block(block)
+        .alignment(Alignment::Center);
+    f.render_widget(paragraph, popup_area);
+}
+
+/// Yes/no prompt before dropping every weapon but the equipped one
+pub fn draw_drop_all_prompt(f: &mut Frame, app: &App, area: Rect) {
+    if !app.confirm_drop_all {
+        return;
+    }
+
+    let inventory = &app.character.weapon_inventory;
+    let kept = inventory
+        .get_current_weapon()
+        .map_or("nothing".to_string(), |w| w.name.clone());
+    let lines = vec![
+        Line::from(Span::styled(
+            "Drop all other weapons?",
+            Style::default()
+                .fg(Color::Yellow)
+                .add_modifier(Modifier::BOLD),
+        )),
+        Line::from(Span::styled(
+            format!("Keeping {}", kept),
+            Style::default().fg(Color::White),
+        )),
+        Line::from(""),
+        Line::from(Span::styled(
+            "Y / ENTER to drop | N / ESC to cancel",
+            Style::default()
+                .fg(Color::DarkGray)
+                .add_modifier(Modifier::ITALIC),
+        )),
+    ];
+
+    let popup_area = centered_rect(50, lines.len() as u16 + 2, area);
+    f.render_widget(Clear, popup_area);
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .title(" DROP WEAPONS ")
+        .style(Style::default().fg(Color::Yellow).bg(Color::Black));
+    let paragraph = Paragraph::new(lines)
+        .block(block)
+        .alignment(Alignment::Center);
+    f.render_widget(paragraph, popup_area);
+}
+
+pub fn handle_drop_all_input(app: &mut App, key: crossterm::event::KeyCode) {
+    use crossterm::event::KeyCode;
+
+    match key {
+        KeyCode::Char('y' | 'Y') | KeyCode::Enter => {
+            app.confirm_drop_all = false;
+            app.drop_all_but_current_weapon();
+        }
+        KeyCode::Char('n' | 'N') | KeyCode::Esc => app.confirm_drop_all = false,
+        _ => {}
+    }
+}
+
+/// Quit - save game before returning to menu
+fn quit_run(app: &mut App) {
+    let _ = app.save_game();
+    app.state = crate::app::AppState::MainMenu;
+    app.is_paused = false;
+    app.pause_menu_selection = 0;
+    app.pause_submenu = None;
+}
+
+/// Yes/no prompt before the pause menu's Quit ends the run
+pub fn draw_quit_prompt(f: &mut Frame, app: &App, area: Rect) {
+    if !app.confirm_quit {
+        return;
+    }
+
+    let lines = vec![
+        Line::from(Span::styled(
+            "Quit this run?",
+            Style::default()
+                .fg(Color::Yellow)
+                .add_modifier(Modifier::BOLD),
+        )),
+        Line::from(Span::styled(
+            "Your progress is saved first",
+            Style::default().fg(Color::White),
+        )),
+        Line::from(""),
+        Line::from(Span::styled(
+            "Y / ENTER to quit | any other key to stay",
+            Style::default()
+                .fg(Color::DarkGray)
+                .add_modifier(Modifier::ITALIC),
+        )),
+    ];
+
+    let popup_area = centered_rect(50, lines.len() as u16 + 2, area);
+    f.render_widget(Clear, popup_area);
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .title(" QUIT ")
+        .style(Style::default().fg(Color::Yellow).bg(Color::Black));
+    let paragraph = Paragraph::new(lines)
+        .block(block)
+        .alignment(Alignment::Center);
+    f.render_widget(paragraph, popup_area);
+}
+
+/// Only an explicit yes quits; every other key closes the prompt so it never holds input
+pub fn handle_quit_input(app: &mut App, key: crossterm::event::KeyCode) {
+    use crossterm::event::KeyCode;
+
+    app.confirm_quit = false;
+    if matches!(key, KeyCode::Char('y' | 'Y') | KeyCode::Enter) {
+        quit_run(app);
+    }
+}
+
+/// Yes/no prompt before scrapping the equipped weapon for gold
+pub fn draw_scrap_prompt(f: &mut Frame, app: &App, area: Rect) {
+    if !app.confirm_scrap {
+        return;
+    }
+    let inventory = &app.character.weapon_inventory;
+    let Some(weapon) = inventory.get_current_weapon() else {
+        return;
+    };
+
+    let mut lines = vec![
+        Line::from(Span::styled(
+            format!("Scrap {}?", weapon.name),
+            Style::default()
+                .fg(Color::Yellow)
+                .add_modifier(Modifier::BOLD),
+        )),
+        Line::from(Span::styled(
+            format!("Worth {} gold", weapon.scrap_value()),
+            Style::default().fg(Color::White),
+        )),
+    ];
+    if inventory.weapons.len() == 1 {
+        lines.push(Line::from(Span::styled(
+            "This is your last weapon!",
+            Style::default().fg(Color::Red),
+        )));
+    }
+    lines.push(Line::from(""));
+    lines.push(Line::from(Span::styled(
+        "Y / ENTER to scrap | N / ESC to cancel",
+        Style::default()
+            .fg(Color::DarkGray)
+            .add_modifier(Modifier::ITALIC),
+    )));
+
+    let popup_area = centered_rect(50, lines.len() as u16 + 2, area);
+    f.render_widget(Clear, popup_area);
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .title(" SCRAP WEAPON ")
+        .style(Style::default().fg(Color::Yellow).bg(Color::Black));
+    let paragraph = Paragraph::new(lines)
+        .block(block)
+        .alignment(Alignment::Center);
+    f.render_widget(paragraph, popup_area);
+}
+
+pub fn handle_scrap_input(app: &mut App, key: crossterm::event::KeyCode) {
+    use crossterm::event::KeyCode;
+
+    match key {
+        KeyCode::Char('y' | 'Y') | KeyCode::Enter => {
+            app.confirm_scrap = false;
+            app.scrap_current_weapon();
+        }
+        KeyCode::Char('n' | 'N') | KeyCode::Esc => app.confirm_scrap = false,
+        _ => {}
+    }
+}
+
+pub fn handle_weapon_swap_input(app: &mut App, key: crossterm::event::KeyCode) {
+    use crate::app::WEAPON_SWAP_LEAVE;
+    use crossterm::event::KeyCode;
+
+    let slot_count = app.character.weapon_inventory.weapons.len();
+    let Some(prompt) = app.weapon_swap_prompt.as_mut() else {
+        return;
+    };
+
+    // Options are the weapon slots followed by "leave it"
+    let position = prompt.selection.min(slot_count);
+    match key {
+        KeyCode::Up | KeyCode::Char('w') | KeyCode::Char('W') => {
+            let next = if position == 0 {
+                slot_count
+            } else {
+                position - 1
+            };
+            prompt.selection = if next == slot_count {
+                WEAPON_SWAP_LEAVE
+            } else {
+                next
+            };
+        }
+        KeyCode::Down | KeyCode::Char('s') | KeyCode::Char('S') => {
+            let next = (position + 1) % (slot_count + 1);
+            prompt.selection = if next == slot_count {
+                WEAPON_SWAP_LEAVE
+            } else {
+                next
+            };
+        }
+        KeyCode::Char(c) if c.is_ascii_digit() && c != '0' => {
+            let slot = c.to_digit(10).unwrap() as usize - 1;
+            if slot < slot_count {
+                app.resolve_weapon_swap(Some(slot));
+            }
+        }
+        KeyCode::Enter | KeyCode::Char(' ') => {
+            let selection = prompt.selection;
+            app.resolve_weapon_swap((selection != WEAPON_SWAP_LEAVE).then_some(selection));
+        }
+        KeyCode::Esc => app.resolve_weapon_swap(None),
+        _ => {}
+    }
+}
+
 pub fn handle_input(app: &mut App, key: crossterm::event::KeyCode) {
     use crossterm::event::KeyCode;
 
@@ -311,6 +587,10 @@ fn handle_main_menu_input(app: &mut App, key: crossterm::event::KeyCode) {
         KeyCode::Down => {
             app.pause_menu_selection = (app.pause_menu_selection + 1) % MAIN_MENU_ITEMS.len();
         }
+        KeyCode::Char('r' | 'R') if app.settings.adaptive_difficulty => {
+            // Later floors go back to the chosen difficulty's own stats
+            app.adaptive_difficulty.reset();
+        }
         KeyCode::Enter => {
             match app.pause_menu_selection {
                 0 => {
@@ -335,14 +615,9 @@ fn handle_main_menu_input(app: &mut App, key: crossterm::event::KeyCode) {
                     app.pause_rebinding_mode = SettingsMode::Navigating;
                     app.pause_submenu = Some(PauseSubmenu::Settings);
                 }
-                3 => {
-                    // Quit - save game before returning to menu
-                    let _ = app.save_game();
-                    app.state = crate::app::AppState::MainMenu;
-                    app.is_paused = false;
-                    app.pause_menu_selection = 0;
-                    app.pause_submenu = None;
-                }
+                3 if app.tutorial.is_some() => app.end_tutorial(),
+                3 if app.settings.confirm_quit => app.confirm_quit = true,
+                3 => quit_run(app),
                 _ => {}
             }
         }
@@ -355,12 +630,13 @@ fn handle_volume_input(app: &mut App, key: crossterm::event::KeyCode) {
 
     match key {
         KeyCode::Up => {
-            // Move to music volume
-            app.pause_volume_selection = 0;
+            // Toggle between music and sound volume, wrapping like the other menus
+            app.pause_volume_selection =
+                crate::model::menu::previous_selectable(app.pause_volume_selection, 2, |_| true);
         }
         KeyCode::Down => {
-            // Move to sound volume
-            app.pause_volume_selection = 1;
+            app.pause_volume_selection =
+                crate::model::menu::next_selectable(app.pause_volume_selection, 2, |_| true);
         }
         KeyCode::Left | KeyCode::Char('a') | KeyCode::Char('A') => {
             // Decrease selected volume
@@ -405,27 +681,16 @@ fn handle_volume_input(app: &mut App, key: crossterm::event::KeyCode) {
 fn handle_settings_input(app: &mut App, key: crossterm::event::KeyCode) {
     use crossterm::event::KeyCode;
 
+    let rows = SettingsRow::pause_menu();
     if let SettingsMode::Rebinding = app.pause_rebinding_mode {
         // In rebinding mode, any key press rebinds
         let selected = app.pause_settings_state.selected().unwrap_or(0);
         let key_string = key_code_to_string(key);
 
-        match selected {
-            0 => app.pause_temp_settings.move_up = key_string,
-            1 => app.pause_temp_settings.move_left = key_string,
-            2 => app.pause_temp_settings.move_down = key_string,
-            3 => app.pause_temp_settings.move_right = key_string,
-            4 => app.pause_temp_settings.attack = key_string,
-            5 => app.pause_temp_settings.dash = key_string,
-            6 => app.pause_temp_settings.block = key_string,
-            7 => app.pause_temp_settings.toggle_inv = key_string,
-            8 => app.pause_temp_settings.use_consumable = key_string,
-            9 => app.pause_temp_settings.inventory_up = key_string,
-            10 => app.pause_temp_settings.inventory_down = key_string,
-            11 => app.pause_temp_settings.item_describe = key_string,
-            12 => app.pause_temp_settings.pause = key_string,
-            13 => app.pause_temp_settings.special_item = key_string,
-            _ => {}
+        if let Some(SettingsRow::Rebind(index)) = rows.get(selected) {
+            if let Some(binding) = app.pause_temp_settings.binding_mut(*index) {
+                *binding = key_string;
+            }
         }
 
         app.pause_rebinding_mode = SettingsMode::Navigating;
@@ -433,23 +698,18 @@ fn handle_settings_input(app: &mut App, key: crossterm::event::KeyCode) {
         // Normal navigation mode
         match key {
             KeyCode::Up => {
-                let current = app.pause_settings_state.selected().unwrap_or(0);
-                let new_selection = if current == 0 { 19 } else { current - 1 };
-                app.pause_settings_state.select(Some(new_selection));
+                crate::input::menu::move_row_up(&mut app.pause_settings_state, &rows);
             }
             KeyCode::Down => {
-                let current = app.pause_settings_state.selected().unwrap_or(0);
-                let new_selection = (current + 1) % 20;
-                app.pause_settings_state.select(Some(new_selection));
+                crate::input::menu::move_row_down(&mut app.pause_settings_state, &rows);
             }
             KeyCode::Enter => {
-                let selected = app.pause_settings_state.selected().unwrap_or(0);
-                match selected {
-                    0..=15 => {
+                match crate::input::menu::selected_row(&app.pause_settings_state, &rows) {
+                    Some(SettingsRow::Rebind(_)) => {
                         // Rebindable keys
                         app.pause_rebinding_mode = SettingsMode::Rebinding;
                     }
-                    17 => {
+                    Some(SettingsRow::Save) => {
                         // Save changes
                         app.settings = app.pause_temp_settings.clone();
                         // Update volume settings
@@ -458,13 +718,16 @@ fn handle_settings_input(app: &mut App, key: crossterm::event::KeyCode) {
                         let _ = app.settings.save();
                         app.pause_submenu = None;
                     }
-                    18 => {
+                    Some(SettingsRow::Discard) => {
                         // Discard & back
                         app.pause_submenu = None;
                     }
-                    19 => {
+                    Some(SettingsRow::Reset) => {
                         // Reset to default
-                        app.pause_temp_settings = crate::model::settings::Settings::default();
+                        app.pause_temp_settings = crate::model::settings::Settings {
+                            tutorial_completed: app.settings.tutorial_completed,
+                            ..Default::default()
+                        };
                         app.pause_settings_state.select(Some(0));
                     }
                     _ => {}
@@ -1,48 +1,24 @@
 use crate::app::{App, SettingsMode};
+use crate::model::settings::{Settings, SettingsRow};
 use ratatui::{prelude::*, widgets::*};
 
 pub fn draw(f: &mut Frame, app: &mut App, area: Rect, pulse: Color) {
     let s = &app.temp_settings;
-    let skip_anim_check = if s.skip_logo_animation { "☑" } else { "☐" };
-    let items = vec![
-        format!("Move Up:         [{}]", s.move_up),
-        format!("Move Left:       [{}]", s.move_left),
-        format!("Move Down:       [{}]", s.move_down),
-        format!("Move Right:      [{}]", s.move_right),
-        format!("Attack:          [{}]", s.attack),
-        format!("Dash:            [{}]", s.dash),
-        format!("Block:           [{}]", s.block),
-        format!("Inventory Focus: [{}]", s.toggle_inv),
-        format!("Use Consumable:  [{}]", s.use_consumable),
-        format!("Inv Up:          [{}]", s.inventory_up),
-        format!("Inv Down:        [{}]", s.inventory_down),
-        format!("Item Describe:   [{}]", s.item_describe),
-        format!("Pause:           [{}]", s.pause),
-        format!("Special:         [{}]", s.special_item),
-        format!("Difficulty:      [{}]", s.difficulty.name()),
-        format!("Default Difficulty: [{}]", s.default_difficulty.name()),
-        format_volume_bar("Music Volume", s.music_volume),
-        format_volume_bar("Sound Volume", s.sound_volume),
-        format!("Skip Logo Animation: {}", skip_anim_check),
-        "-------------------".to_string(),
-        "SAVE CHANGES".to_string(),
-        "DISCARD & BACK".to_string(),
-        "RESET TO DEFAULT".to_string(),
-    ];
+    let rows = SettingsRow::settings_screen();
 
-    let list_items: Vec<ListItem> = items
+    let conflicts = s.find_conflicts();
+    let bindings = s.bindings();
+    let list_items: Vec<ListItem> = rows
         .iter()
-        .enumerate()
-        .map(|(i, text)| {
-            let mut style = Style::default().fg(Color::Gray);
-            if i == 20 {
-                style = style.fg(Color::Green);
-            } else if i == 21 {
-                style = style.fg(Color::Yellow);
-            } else if i == 22 {
-                style = style.fg(Color::Red);
-            }
-            ListItem::new(text.as_str()).style(style)
+        .map(|row| {
+            let conflicting = matches!(row, SettingsRow::Rebind(i)
+                if conflicts.iter().any(|(a, b)| *a == bindings[*i].0 || *b == bindings[*i].0));
+            let style = if conflicting {
+                Style::default().fg(Color::Red)
+            } else {
+                row_style(*row)
+            };
+            ListItem::new(row_label(*row, s)).style(style)
         })
         .collect();
 
@@ -55,7 +31,146 @@ pub fn draw(f: &mut Frame, app: &mut App, area: Rect, pulse: Color) {
         .block(Block::default().borders(Borders::ALL).title(title))
         .highlight_style(Style::default().bg(pulse).fg(Color::White));
 
-    f.render_stateful_widget(list, area, &mut app.settings_state);
+    // Report the last export/import, otherwise warn when two actions share a key
+    let conflict = conflicts.first();
+
+    if let Some(status) = &app.settings_status {
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Min(0), Constraint::Length(1)])
+            .split(area);
+        f.render_stateful_widget(list, chunks[0], &mut app.settings_state);
+        let message = Paragraph::new(status.as_str()).style(Style::default().fg(Color::Cyan));
+        f.render_widget(message, chunks[1]);
+    } else if let Some((first, second)) = conflict {
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Min(0), Constraint::Length(1)])
+            .split(area);
+        f.render_stateful_widget(list, chunks[0], &mut app.settings_state);
+        let text = if app.settings_save_confirm {
+            "⚠ Keys conflict - select SAVE again to save anyway".to_string()
+        } else if conflicts.len() > 1 {
+            format!(
+                "⚠ {} and {} share a key (+{} more conflicts)",
+                first,
+                second,
+                conflicts.len() - 1
+            )
+        } else {
+            format!("⚠ {} and {} share a key", first, second)
+        };
+        let warning = Paragraph::new(text).style(Style::default().fg(Color::Red));
+        f.render_widget(warning, chunks[1]);
+    } else {
+        f.render_stateful_widget(list, area, &mut app.settings_state);
+    }
+}
+
+/// Text shown for a settings row
+pub fn row_label(row: SettingsRow, s: &Settings) -> String {
+    let check = |on: bool| if on { "☑" } else { "☐" };
+    match row {
+        SettingsRow::Rebind(i) => {
+            let (action, key) = s.bindings()[i];
+            format!("{:<17}[{}]", format!("{}:", action), key)
+        }
+        SettingsRow::Difficulty => format!("Difficulty:      [{}]", s.difficulty.name()),
+        SettingsRow::DefaultDifficulty => {
+            format!("Default Difficulty: [{}]", s.default_difficulty.name())
+        }
+        SettingsRow::MusicVolume => format_volume_bar("Music Volume", s.music_volume),
+        SettingsRow::SoundVolume => format_volume_bar("Sound Volume", s.sound_volume),
+        SettingsRow::MuteAudio => format!("Mute All Audio: {}", check(s.mute_audio)),
+        SettingsRow::SkipLogoAnimation => {
+            format!("Skip Logo Animation: {}", check(s.skip_logo_animation))
+        }
+        SettingsRow::RunMode => format!("Run Mode (new runs): [{}]", s.run_mode.name()),
+        SettingsRow::FogOfWar => format!("Fog of War: {}", check(s.fog_of_war)),
+        SettingsRow::AutoFaceNearest => {
+            format!("Auto-Face Nearest Enemy: {}", check(s.auto_face_nearest))
+        }
+        SettingsRow::AutoFaceRadius => format!("Auto-Face Radius:    [< {} >]", s.auto_face_radius),
+        SettingsRow::MergeGoldPiles => format!("Merge Gold Piles: {}", check(s.merge_gold_piles)),
+        SettingsRow::GameSpeed => format!("Game Speed:          [< {:.2}x >]", s.game_speed),
+        SettingsRow::ParticleDensity => format_volume_bar("Particle Density", s.particle_density),
+        SettingsRow::PlayerKnockback => format_volume_bar("Knockback Taken", s.player_knockback),
+        SettingsRow::MovementTrails => format!("Movement Trails: {}", check(s.movement_trails)),
+        SettingsRow::HoldMoveRate => {
+            format!("Held Move Rate:      [< {} >]", s.hold_move_rate_label())
+        }
+        SettingsRow::KeyPreset => {
+            format!("Key Preset:          [< {} >]", s.key_preset().name())
+        }
+        SettingsRow::AutoFire => format!("Hold to Auto-Fire: {}", check(s.auto_fire)),
+        SettingsRow::ConfirmQuit => format!("Confirm Before Quitting: {}", check(s.confirm_quit)),
+        SettingsRow::HitStop => format!("Hit-Stop:            [< {} >]", s.hitstop_label()),
+        SettingsRow::CameraDeadzone => {
+            format!("Camera Deadzone:     [< {}% >]", s.camera_deadzone)
+        }
+        SettingsRow::CameraSnap => format!("Snap Camera: {}", check(s.camera_snap)),
+        SettingsRow::CornerSliding => format!("Corner Sliding: {}", check(s.corner_sliding)),
+        SettingsRow::AdaptiveDifficulty => {
+            format!("Adaptive Difficulty: {}", check(s.adaptive_difficulty))
+        }
+        SettingsRow::ComboResetOnDamage => {
+            format!("Combo Ends When Hit: {}", check(s.combo_resets_on_damage))
+        }
+        SettingsRow::AutosaveInterval => {
+            format!("Auto-Save Interval:  [< {} >]", s.autosave_interval_label())
+        }
+        SettingsRow::DropGoldOnDeath => {
+            format!("Drop Gold on Death: {}", check(s.drop_gold_on_death))
+        }
+        SettingsRow::ShowFps => format!("Show FPS Counter: {}", check(s.show_fps)),
+        SettingsRow::LowDetail => format!("Low Detail Mode: {}", check(s.low_detail)),
+        SettingsRow::RoomEnemyPlacement => {
+            format!("Room Enemy Placement: {}", check(s.room_enemy_placement))
+        }
+        SettingsRow::AutoPickup => format!("Auto Pickup: {}", check(s.auto_pickup)),
+        SettingsRow::EnemyDistance => {
+            format!("Enemy Distance: [{}]", s.enemy_distance.name())
+        }
+        SettingsRow::FloorWidth => format!("Floor Width:         [< {} >]", s.floor_gen.width),
+        SettingsRow::FloorHeight => format!("Floor Height:        [< {} >]", s.floor_gen.height),
+        SettingsRow::FillProbability => {
+            format!(
+                "Open Floor Seed:     [< {}% >]",
+                s.floor_gen.fill_probability
+            )
+        }
+        SettingsRow::CaveSmoothing => {
+            format!("Cave Smoothing:      [< {} >]", s.floor_gen.iterations)
+        }
+        SettingsRow::TunnelStyle => {
+            format!("Tunnel Style:        [{}]", s.floor_gen.tunnel_style.name())
+        }
+        SettingsRow::TunnelWidth => {
+            format!("Tunnel Width:        [< {} >]", s.floor_gen.tunnel_width)
+        }
+        SettingsRow::Separator => "-------------------".to_string(),
+        SettingsRow::Save => "SAVE CHANGES".to_string(),
+        SettingsRow::Discard => "DISCARD & BACK".to_string(),
+        SettingsRow::Reset => "RESET TO DEFAULT".to_string(),
+        SettingsRow::Export => {
+            format!("EXPORT TO {}", crate::constants::SETTINGS_EXPORT_FILE)
+        }
+        SettingsRow::Import => {
+            format!("IMPORT FROM {}", crate::constants::SETTINGS_EXPORT_FILE)
+        }
+    }
+}
+
+/// Save/Discard/Reset stand out from the regular rows
+pub fn row_style(row: SettingsRow) -> Style {
+    let color = match row {
+        SettingsRow::Save => Color::Green,
+        SettingsRow::Discard => Color::Yellow,
+        SettingsRow::Reset => Color::Red,
+        SettingsRow::Export | SettingsRow::Import => Color::Cyan,
+        _ => Color::Gray,
+    };
+    Style::default().fg(color)
 }
 
 fn format_volume_bar(label: &str, volume: f32) -> String {
@@ -1,7 +1,12 @@
-use crate::app::App;
-use crate::model::ultimate_shop::UltimateShop;
+use crate::model::character::Character;
+use crate::model::menu::{next_selectable, previous_selectable, scroll_to_show};
+use crate::model::ultimate_shop::{UltimateShop, UltimateShopInventory};
+use crate::model::weapon::Weapon;
 use ratatui::prelude::*;
-use ratatui::widgets::{Block, Borders, Clear, List, ListItem, ListState, Paragraph};
+use ratatui::widgets::{
+    Block, Borders, Clear, List, ListItem, ListState, Paragraph, Scrollbar, ScrollbarOrientation,
+    ScrollbarState,
+};
 
 pub struct UltimateShopUI {
     pub selected_tab: ShopTab,
@@ -9,6 +14,9 @@ pub struct UltimateShopUI {
     pub ultimate_list_state: ListState,
     pub upgrade_list_items: Vec<ListItem<'static>>,
     pub upgrade_list_state: ListState,
+    pub enchant_list_items: Vec<ListItem<'static>>,
+    pub enchant_list_state: ListState,
+    pub visible_rows: usize, // List rows that fit in the modal, updated on draw
     pub message: Option<String>,
     pub message_timer: f32,
 }
@@ -17,6 +25,7 @@ pub struct UltimateShopUI {
 pub enum ShopTab {
     Ultimates,
     StatUpgrades,
+    Enchants,
 }
 
 impl Default for UltimateShopUI {
@@ -25,6 +34,8 @@ impl Default for UltimateShopUI {
         ult_state.select(Some(0));
         let mut upg_state = ListState::default();
         upg_state.select(Some(0));
+        let mut ench_state = ListState::default();
+        ench_state.select(Some(0));
 
         Self {
             selected_tab: ShopTab::Ultimates,
@@ -32,6 +43,9 @@ impl Default for UltimateShopUI {
             ultimate_list_state: ult_state,
             upgrade_list_items: vec![],
             upgrade_list_state: upg_state,
+            enchant_list_items: vec![],
+            enchant_list_state: ench_state,
+            visible_rows: 0,
             message: None,
             message_timer: 0.0,
         }
@@ -43,131 +57,125 @@ impl UltimateShopUI {
         Self::default()
     }
 
-    pub fn update_lists(&mut self, app: &App, shop: &UltimateShop) {
-        // Update ultimates list
-        let mut ult_items = vec![];
-        for ult in &shop.ultimates {
-            let status = if app
-                .character
-                .shop_inventory
-                .owns_ultimate(&ult.ultimate_type)
-            {
-                "✓ OWNED".to_string()
-            } else if ult.is_locked(app.floor_level) {
-                format!("LOCKED (Lvl {})", ult.unlock_level)
-            } else {
-                format!("{} gold", ult.cost)
-            };
-            ult_items.push(ListItem::new(format!(
-                "  {} - {} - {}",
-                ult.ultimate_type.name(),
-                ult.ultimate_type.description(),
-                status
-            )));
-        }
-        self.ultimate_list_items = ult_items;
-        self.ultimate_list_state.select(Some(0));
-
-        // Update upgrades list
-        let mut upg_items = vec![];
-        for upg in &shop.stat_upgrades {
-            let owned = app
-                .character
-                .shop_inventory
-                .get_upgrade_count(&upg.upgrade_type);
-            let status = if upg.is_locked(app.floor_level) {
-                format!("LOCKED (Lvl {})", upg.unlock_level)
-            } else if upg.max_upgrades > 0 && owned >= upg.max_upgrades {
-                "MAX".to_string()
-            } else {
-                format!("{} gold (Lvl: {})", upg.cost, owned + 1)
-            };
-            upg_items.push(ListItem::new(format!(
-                "  {} - {} - {}",
-                upg.upgrade_type.name(),
-                upg.upgrade_type.description(),
-                status
-            )));
+    /// Rebuild the item lists. Runs every draw so prices and their affordability
+    /// colors follow the player's gold; selections are kept.
+    pub fn update_lists(
+        &mut self,
+        shop: &UltimateShop,
+        gold: u32,
+        floor_level: u32,
+        inventory: &UltimateShopInventory,
+        weapon: Option<&Weapon>,
+    ) {
+        let price = |cost: u32, suffix: String| price_span(shop, cost, gold, suffix);
+
+        self.ultimate_list_items = shop
+            .ultimates
+            .iter()
+            .map(|ult| {
+                let status = if inventory.owns_ultimate(&ult.ultimate_type) {
+                    muted_span("✓ OWNED".to_string())
+                } else if ult.is_locked(floor_level) {
+                    muted_span(format!("LOCKED (Lvl {})", ult.unlock_level))
+                } else {
+                    price(ult.cost, String::new())
+                };
+                shop_item(
+                    ult.ultimate_type.name(),
+                    ult.ultimate_type.description(),
+                    status,
+                )
+            })
+            .collect();
+
+        self.upgrade_list_items = shop
+            .stat_upgrades
+            .iter()
+            .map(|upg| {
+                let owned = inventory.get_upgrade_count(&upg.upgrade_type);
+                let status = if upg.is_locked(floor_level) {
+                    muted_span(format!("LOCKED (Lvl {})", upg.unlock_level))
+                } else if upg.max_upgrades > 0 && owned >= upg.max_upgrades {
+                    muted_span("MAX".to_string())
+                } else {
+                    price(upg.cost, format!(" (Lvl: {})", owned + 1))
+                };
+                shop_item(
+                    upg.upgrade_type.name(),
+                    upg.upgrade_type.description(),
+                    status,
+                )
+            })
+            .collect();
+
+        self.enchant_list_items = shop
+            .enchants
+            .iter()
+            .map(|enchant| {
+                let max = crate::constants::MAX_WEAPON_ENCHANTS;
+                let status = match weapon {
+                    None => muted_span("NO WEAPON".to_string()),
+                    Some(w) if w.enchants.len() >= max => muted_span("MAX".to_string()),
+                    Some(w) => price(
+                        enchant.cost_for(w.enchants.len()),
+                        format!(" ({}/{})", w.enchants.len(), max),
+                    ),
+                };
+                shop_item(enchant.name(), &enchant.description(), status)
+            })
+            .collect();
+
+        for state in [
+            &mut self.ultimate_list_state,
+            &mut self.upgrade_list_state,
+            &mut self.enchant_list_state,
+        ] {
+            if state.selected().is_none() {
+                state.select(Some(0));
+            }
         }
-        self.upgrade_list_items = upg_items;
-        self.upgrade_list_state.select(Some(0));
     }
 
-    pub fn update_lists_from_shop(&mut self, shop: &UltimateShop) {
-        // Only repopulate if lists are empty (first time opening shop)
-        if !self.ultimate_list_items.is_empty() && !self.upgrade_list_items.is_empty() {
-            return; // Lists already populated, don't reset selections
-        }
-
-        let mut ult_items = vec![];
-        for ult in &shop.ultimates {
-            ult_items.push(ListItem::new(format!(
-                "  {} - {} - {} gold",
-                ult.ultimate_type.name(),
-                ult.ultimate_type.description(),
-                ult.cost
-            )));
-        }
-        self.ultimate_list_items = ult_items;
-        // Only set initial selection if not already set
-        if self.ultimate_list_state.selected().is_none() {
-            self.ultimate_list_state.select(Some(0));
-        }
-
-        let mut upg_items = vec![];
-        for upg in &shop.stat_upgrades {
-            upg_items.push(ListItem::new(format!(
-                "  {} - {} - {} gold",
-                upg.upgrade_type.name(),
-                upg.upgrade_type.description(),
-                upg.cost
-            )));
-        }
-        self.upgrade_list_items = upg_items;
-        // Only set initial selection if not already set
-        if self.upgrade_list_state.selected().is_none() {
-            self.upgrade_list_state.select(Some(0));
+    /// The list on the current tab and how many items it holds
+    fn current_list(&mut self) -> (&mut ListState, usize) {
+        match self.selected_tab {
+            ShopTab::Ultimates => (
+                &mut self.ultimate_list_state,
+                self.ultimate_list_items.len(),
+            ),
+            ShopTab::StatUpgrades => (&mut self.upgrade_list_state, self.upgrade_list_items.len()),
+            ShopTab::Enchants => (&mut self.enchant_list_state, self.enchant_list_items.len()),
         }
     }
 
     pub fn next(&mut self) {
-        match self.selected_tab {
-            ShopTab::Ultimates => {
-                if let Some(sel) = self.ultimate_list_state.selected() {
-                    self.ultimate_list_state
-                        .select(Some((sel + 1) % self.ultimate_list_items.len().max(1)));
-                }
-            }
-            ShopTab::StatUpgrades => {
-                if let Some(sel) = self.upgrade_list_state.selected() {
-                    self.upgrade_list_state
-                        .select(Some((sel + 1) % self.upgrade_list_items.len().max(1)));
-                }
-            }
-        }
+        self.step_selection(true);
     }
 
     pub fn previous(&mut self) {
-        match self.selected_tab {
-            ShopTab::Ultimates => {
-                if let Some(sel) = self.ultimate_list_state.selected() {
-                    let len = self.ultimate_list_items.len().max(1);
-                    self.ultimate_list_state.select(Some((sel + len - 1) % len));
-                }
-            }
-            ShopTab::StatUpgrades => {
-                if let Some(sel) = self.upgrade_list_state.selected() {
-                    let len = self.upgrade_list_items.len().max(1);
-                    self.upgrade_list_state.select(Some((sel + len - 1) % len));
-                }
-            }
+        self.step_selection(false);
+    }
+
+    /// Move the selection with wrapping and scroll so it stays on screen
+    fn step_selection(&mut self, forward: bool) {
+        let visible = self.visible_rows;
+        let (state, len) = self.current_list();
+        if let Some(sel) = state.selected() {
+            let sel = if forward {
+                next_selectable(sel, len, |_| true)
+            } else {
+                previous_selectable(sel, len, |_| true)
+            };
+            state.select(Some(sel));
+            *state.offset_mut() = scroll_to_show(sel, state.offset(), visible);
         }
     }
 
     pub fn switch_tab(&mut self) {
         self.selected_tab = match self.selected_tab {
             ShopTab::Ultimates => ShopTab::StatUpgrades,
-            ShopTab::StatUpgrades => ShopTab::Ultimates,
+            ShopTab::StatUpgrades => ShopTab::Enchants,
+            ShopTab::Enchants => ShopTab::Ultimates,
         };
     }
 
@@ -178,6 +186,8 @@ impl UltimateShopUI {
         self.ultimate_list_state.select(None);
         self.upgrade_list_items.clear();
         self.upgrade_list_state.select(None);
+        self.enchant_list_items.clear();
+        self.enchant_list_state.select(None);
         self.message = None;
         self.message_timer = 0.0;
     }
@@ -198,22 +208,54 @@ impl UltimateShopUI {
     }
 }
 
+/// One shop row: name and description, then a status or price span
+fn shop_item(name: &str, description: &str, status: Span<'static>) -> ListItem<'static> {
+    ListItem::new(Line::from(vec![
+        Span::raw(format!("  {} - {} - ", name, description)),
+        status,
+    ]))
+}
+
+/// Price in green when the player can pay it, red when they can't
+fn price_span(shop: &UltimateShop, cost: u32, gold: u32, suffix: String) -> Span<'static> {
+    let color = if shop.can_afford(cost, gold) {
+        Color::Green
+    } else {
+        Color::Red
+    };
+    Span::styled(
+        format!("{} gold{}", cost, suffix),
+        Style::default().fg(color),
+    )
+}
+
+fn muted_span(text: String) -> Span<'static> {
+    Span::styled(text, Style::default().fg(Color::DarkGray))
+}
+
 pub fn draw(
     f: &mut Frame,
-    gold: u32,
+    character: &Character,
     floor_level: u32,
     shop: &UltimateShop,
     shop_ui: &mut UltimateShopUI,
     area: Rect,
 ) {
-    // Update lists if needed
-    shop_ui.update_lists_from_shop(shop);
+    let gold = character.gold;
+    let weapon = character.weapon_inventory.get_current_weapon();
+    shop_ui.update_lists(shop, gold, floor_level, &character.shop_inventory, weapon);
 
     // Draw semi-transparent background
     f.render_widget(Clear, area);
 
+    let gold_title = Line::from(Span::styled(
+        format!(" Gold: {} ", gold),
+        Style::default().fg(Color::Yellow).bold(),
+    ))
+    .right_aligned();
     let block = Block::default()
         .title(" Ultimate Shop ")
+        .title(gold_title)
         .borders(Borders::ALL)
         .border_type(ratatui::widgets::BorderType::Rounded)
         .style(Style::default().fg(Color::Cyan));
@@ -239,7 +281,11 @@ pub fn draw(
     // Draw header with tabs
     let header_chunks = Layout::default()
         .direction(Direction::Horizontal)
-        .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+        .constraints([
+            Constraint::Ratio(1, 3),
+            Constraint::Ratio(1, 3),
+            Constraint::Ratio(1, 3),
+        ])
         .split(chunks[0]);
 
     let ultimate_tab_style = if shop_ui.selected_tab == ShopTab::Ultimates {
@@ -254,6 +300,12 @@ pub fn draw(
         Style::default().fg(Color::Gray)
     };
 
+    let enchant_tab_style = if shop_ui.selected_tab == ShopTab::Enchants {
+        Style::default().fg(Color::Yellow).bold()
+    } else {
+        Style::default().fg(Color::Gray)
+    };
+
     let ultimate_tab = Paragraph::new("Ultimate Abilities [TAB]")
         .style(ultimate_tab_style)
         .alignment(Alignment::Center)
@@ -264,8 +316,17 @@ pub fn draw(
         .alignment(Alignment::Center)
         .block(Block::default().borders(Borders::BOTTOM));
 
+    let enchant_tab = Paragraph::new("Weapon Enchants [TAB]")
+        .style(enchant_tab_style)
+        .alignment(Alignment::Center)
+        .block(Block::default().borders(Borders::BOTTOM));
+
     f.render_widget(ultimate_tab, header_chunks[0]);
     f.render_widget(upgrade_tab, header_chunks[1]);
+    f.render_widget(enchant_tab, header_chunks[2]);
+
+    // Rows inside the list's borders, so keyboard scrolling knows what fits
+    shop_ui.visible_rows = chunks[1].height.saturating_sub(2) as usize;
 
     // Draw content based on selected tab
     match shop_ui.selected_tab {
@@ -295,6 +356,34 @@ pub fn draw(
 
             f.render_stateful_widget(list, chunks[1], &mut shop_ui.upgrade_list_state);
         }
+        ShopTab::Enchants => {
+            let title = match weapon {
+                Some(w) => format!(" Enchant {} [↑/↓] [ENTER] ", w.name),
+                None => " Enchant [↑/↓] [ENTER] ".to_string(),
+            };
+            let list = List::new(shop_ui.enchant_list_items.clone())
+                .block(Block::default().title(title).borders(Borders::ALL))
+                .style(Style::default().fg(Color::White))
+                .highlight_style(Style::default().fg(Color::Black).bg(Color::Cyan))
+                .highlight_symbol(">> ");
+
+            f.render_stateful_widget(list, chunks[1], &mut shop_ui.enchant_list_state);
+        }
+    }
+
+    // Scrollbar when the list doesn't fit
+    let visible_rows = shop_ui.visible_rows;
+    let (state, len) = shop_ui.current_list();
+    if len > visible_rows {
+        let mut scrollbar_state = ScrollbarState::new(len).position(state.selected().unwrap_or(0));
+        f.render_stateful_widget(
+            Scrollbar::new(ScrollbarOrientation::VerticalRight),
+            chunks[1].inner(Margin {
+                vertical: 1,
+                horizontal: 0,
+            }),
+            &mut scrollbar_state,
+        );
     }
 
     // Draw footer
@@ -24,7 +24,62 @@ pub fn draw(f: &mut Frame, app: &mut App, area: Rect) {
         .constraints([Constraint::Min(0), Constraint::Length(8)])
         .split(area);
 
-    // Main victory screen content
+    // Victory stats
+    let time_minutes = app.victory_win_time as i32 / 60;
+    let time_seconds = (app.victory_win_time as i32) % 60;
+
+    let best_weapon = app
+        .character
+        .best_weapon()
+        .map(|w| format!("{} ({})", w.name, w.rarity.name()))
+        .unwrap_or_else(|| "None".to_string());
+    let skill_path = app
+        .character
+        .skill_tree_path
+        .chosen_path
+        .map(|p| p.name())
+        .unwrap_or("None");
+    let best_line = if app.victory_score >= app.best_score {
+        format!("🥇 Best Run: {} (new record!)", app.best_score)
+    } else {
+        format!("🥇 Best Run: {}", app.best_score)
+    };
+
+    let stats = vec![
+        (
+            format!("⏱️  Time Survived: {}m {}s", time_minutes, time_seconds),
+            Color::Cyan,
+        ),
+        (
+            format!("⚔️  Enemies Slain: {}", app.character.enemies_killed),
+            Color::Yellow,
+        ),
+        (
+            format!("📈 Levels Cleared: {}", app.max_levels),
+            Color::Green,
+        ),
+        (
+            format!("💰 Gold Collected: {}", app.character.gold),
+            Color::LightYellow,
+        ),
+        (format!("🗡️  Best Weapon: {}", best_weapon), Color::Magenta),
+        (format!("🌳 Skill Path: {}", skill_path), Color::LightGreen),
+        (String::new(), Color::Reset),
+        (
+            if app.new_game_plus > 0 {
+                format!("⭐ Score: {} (NG+{})", app.victory_score, app.new_game_plus)
+            } else {
+                format!("⭐ Score: {}", app.victory_score)
+            },
+            Color::LightCyan,
+        ),
+        (best_line, Color::LightYellow),
+    ];
+
+    // Truncate to the inner area so small terminals clip lines instead of overflowing
+    let inner_width = chunks[0].width.saturating_sub(2) as usize;
+    let inner_height = chunks[0].height.saturating_sub(2) as usize;
+
     let mut lines = vec![
         Line::from(""),
         Line::from(Span::styled(
@@ -35,32 +90,13 @@ pub fn draw(f: &mut Frame, app: &mut App, area: Rect) {
         )),
         Line::from(""),
     ];
-
-    // Victory stats
-    let time_minutes = app.victory_win_time as i32 / 60;
-    let time_seconds = (app.victory_win_time as i32) % 60;
-
-    lines.push(Line::from(Span::styled(
-        format!("⏱️  Time Survived: {}m {}s", time_minutes, time_seconds),
-        Style::default().fg(Color::Cyan),
-    )));
-
-    lines.push(Line::from(Span::styled(
-        format!("⚔️  Enemies Slain: {}", app.character.enemies_killed),
-        Style::default().fg(Color::Yellow),
-    )));
-
-    lines.push(Line::from(Span::styled(
-        format!("📈 Levels Cleared: {}", app.max_levels),
-        Style::default().fg(Color::Green),
-    )));
-
-    lines.push(Line::from(Span::styled(
-        format!("💰 Gold Collected: {}", app.character.gold),
-        Style::default().fg(Color::LightYellow),
-    )));
-
-    lines.push(Line::from(""));
+    for (text, color) in stats {
+        lines.push(Line::from(Span::styled(
+            fit_to_width(&text, inner_width),
+            Style::default().fg(color),
+        )));
+    }
+    lines.truncate(inner_height);
 
     let victory_block = Block::default()
         .borders(Borders::ALL)
@@ -78,6 +114,7 @@ pub fn draw(f: &mut Frame, app: &mut App, area: Rect) {
     if alpha_progress > 0.7 {
         let hints = vec![
             ("R", "Play Again", Some(Color::Green)),
+            ("N", "New Game+", Some(Color::LightMagenta)),
             ("ESC", "Main Menu", Some(Color::Red)),
         ];
 
@@ -85,6 +122,17 @@ pub fn draw(f: &mut Frame, app: &mut App, area: Rect) {
     }
 }
 
+/// Cut a line down to `max_width` columns, marking the cut with an ellipsis
+fn fit_to_width(text: &str, max_width: usize) -> String {
+    if crate::emoji::display_width(text) <= max_width {
+        text.to_string()
+    } else if max_width == 0 {
+        String::new()
+    } else {
+        format!("{}…", crate::emoji::truncate_to_width(text, max_width - 1))
+    }
+}
+
 pub fn handle_input(app: &mut App, key: crossterm::event::KeyCode) {
     use crossterm::event::KeyCode;
 
@@ -92,6 +140,10 @@ pub fn handle_input(app: &mut App, key: crossterm::event::KeyCode) {
         KeyCode::Char('r') | KeyCode::Char('R') => {
             app.restart_game();
         }
+        KeyCode::Char('n') | KeyCode::Char('N') => {
+            // Carry the shop purchases into a harder run
+            app.start_new_game_plus();
+        }
         KeyCode::Esc => {
             app.state = crate::app::AppState::MainMenu;
             app.main_menu_state.select(Some(0));
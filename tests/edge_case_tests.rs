@@ -268,7 +268,7 @@ mod edge_case_tests {
 
         // Try to trigger special ability many times
         for _ in 0..10 {
-            if boss.can_use_special_ability(std::time::Instant::now()) {
+            if boss.can_use_special_ability(std::time::Instant::now(), 1.0) {
                 boss.trigger_special_ability();
             }
         }
@@ -2,11 +2,13 @@
 
 #[cfg(test)]
 mod integration_tests {
+    use roguelite_dungeon::model::attack_pattern::{AttackPattern, MAX_PATTERN_REACH};
     use roguelite_dungeon::model::character::Character;
     use roguelite_dungeon::model::consumable::Consumable;
     use roguelite_dungeon::model::consumable::ConsumableType;
     use roguelite_dungeon::model::enemy::Enemy;
     use roguelite_dungeon::model::floor::Floor;
+    use roguelite_dungeon::model::item_tier::Difficulty;
     use roguelite_dungeon::model::weapon::{Enchant, EnchantType, Weapon};
 
     #[test]
@@ -78,9 +80,9 @@ mod integration_tests {
     fn test_cooldown_system() {
         let mut character = Character::default();
 
-        assert!(character.can_dash());
+        assert!(character.can_dash(1.0));
         character.start_dash_cooldown();
-        assert!(!character.can_dash());
+        assert!(!character.can_dash(1.0));
     }
 
     #[test]
@@ -106,11 +108,49 @@ mod integration_tests {
         assert_eq!(weapon.get_total_damage(), 8);
     }
 
+    #[test]
+    fn test_attack_pattern_scaling() {
+        let scaled = AttackPattern::Fireball(2).scaled(1);
+        assert_eq!(scaled, AttackPattern::Fireball(3));
+        assert_eq!(
+            scaled.get_affected_tiles(10, 10, 1, 0),
+            AttackPattern::Fireball(3).get_affected_tiles(10, 10, 1, 0)
+        );
+
+        // Fixed-shape patterns are unaffected
+        assert_eq!(AttackPattern::BasicSlash.scaled(3), AttackPattern::BasicSlash);
+
+        // Huge bonuses are clamped
+        match AttackPattern::MeteorShower(8, 4).scaled(100) {
+            AttackPattern::MeteorShower(reach, width) => {
+                assert_eq!(reach, MAX_PATTERN_REACH);
+                assert!(width < 10);
+            }
+            other => panic!("unexpected pattern {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_radius_enchant_area_bonus() {
+        let mut character = Character::default();
+        assert_eq!(character.get_attack_area_bonus(), 0);
+
+        character.weapon_inventory.weapons[0].add_enchant(Enchant {
+            enchant_type: EnchantType::RadiusIncrease,
+            value: 1,
+        });
+        assert_eq!(character.get_attack_area_bonus(), 1);
+        assert_eq!(
+            AttackPattern::Fireball(2).scaled(character.get_attack_area_bonus()),
+            AttackPattern::Fireball(3)
+        );
+    }
+
     #[test]
     fn test_ultimate_ability() {
         let mut character = Character::default();
 
-        assert!(character.ultimate.can_use());
+        assert!(character.ultimate.can_use(1.0));
         character.ultimate_charge = 100.0;
         let damage = character.calculate_ultimate_damage();
         // 15 * 1.5 = 22.5 as i32 = 22
@@ -345,7 +385,7 @@ mod integration_tests {
 
         let boss_count_before = floor.enemies.len();
 
-        let boss = floor.spawn_boss(BossType::SkeletalKnight);
+        let boss = floor.spawn_boss(BossType::SkeletalKnight, &Difficulty::Normal);
         assert!(boss.is_some());
 
         assert!(floor.enemies.len() > boss_count_before);
@@ -363,7 +403,7 @@ mod integration_tests {
 
         for boss_type in boss_types {
             let mut floor = Floor::new(100, 40, 43);
-            let boss = floor.spawn_boss(boss_type);
+            let boss = floor.spawn_boss(boss_type, &Difficulty::Normal);
             assert!(boss.is_some(), "Failed to spawn {:?}", boss_type);
         }
     }
@@ -380,7 +420,7 @@ mod integration_tests {
             .max_by_key(|r| r.tiles.len())
             .map(|r| r.tiles.len());
 
-        let boss = floor.spawn_boss(BossType::ShadowAssassin);
+        let boss = floor.spawn_boss(BossType::ShadowAssassin, &Difficulty::Normal);
 
         if let Some(boss_ref) = boss {
             assert!(floor.is_walkable(
@@ -434,11 +474,11 @@ mod integration_tests {
         let mut floor = Floor::new(80, 40, 45);
 
         // Spawn multiple enemies
-        floor.spawn_enemies(&Difficulty::Normal);
+        floor.spawn_enemies(&Difficulty::Normal, None);
         let regular_enemy_count = floor.enemies.len();
 
         // Then spawn a boss
-        let boss = floor.spawn_boss(BossType::CorruptedWarden);
+        let boss = floor.spawn_boss(BossType::CorruptedWarden, &Difficulty::Normal);
 
         if boss.is_some() {
             assert_eq!(floor.enemies.len(), regular_enemy_count + 1);
@@ -460,4 +500,1423 @@ mod integration_tests {
         assert!(!character.is_skill_ready(SkillType::Slash));
         assert!(character.is_skill_ready(SkillType::Pierce));
     }
+
+    #[test]
+    fn test_run_mode_persistence() {
+        use roguelite_dungeon::model::gamesave::GameSave;
+        use roguelite_dungeon::model::settings::RunMode;
+
+        assert_eq!(
+            RunMode::from_name(RunMode::Permadeath.name()),
+            RunMode::Permadeath
+        );
+        assert_eq!(RunMode::Checkpoint.toggled(), RunMode::Permadeath);
+
+        // Saves written before run modes existed load as Checkpoint
+        let mut value = serde_json::to_value(GameSave::default()).unwrap();
+        value.as_object_mut().unwrap().remove("run_mode");
+        let save: GameSave = serde_json::from_value(value).unwrap();
+        assert_eq!(RunMode::from_name(&save.run_mode), RunMode::Checkpoint);
+    }
+
+    #[test]
+    fn test_reveal_stairs() {
+        let mut floor = Floor::new(100, 40, 42);
+        assert!(floor.stairs.is_none());
+
+        let (px, py) = floor.find_walkable_tile().unwrap();
+        let (sx, sy) = floor.reveal_stairs(px, py).unwrap();
+
+        assert!(floor.is_walkable(sx, sy));
+        assert_ne!((sx, sy), (px, py));
+        assert!(floor.is_stairs_at(sx, sy));
+
+        // Revealing again keeps the same exit
+        assert_eq!(floor.reveal_stairs(px + 1, py), Some((sx, sy)));
+    }
+
+    #[test]
+    fn test_fog_of_war_visibility() {
+        let mut floor = Floor::new(100, 40, 42);
+        let (px, py) = floor.find_walkable_tile().unwrap();
+
+        assert!(!floor.is_explored(px, py));
+        floor.update_visibility(px, py, 5);
+        assert!(floor.is_visible(px, py));
+        assert!(floor.is_explored(px, py));
+
+        // Tiles outside the radius stay hidden
+        assert!(!floor.is_visible(px + 20, py));
+
+        // Moving away keeps explored memory but clears visibility
+        floor.update_visibility((px + 40) % floor.width, py, 0);
+        assert!(floor.is_explored(px, py));
+        assert!(!floor.is_visible(px, py));
+    }
+
+    #[test]
+    fn test_charged_arrow_outdamages_tap_shot() {
+        use roguelite_dungeon::model::arrow::{Arrow, ProjectileType};
+
+        let tap = Arrow::charged(0.0, 0.0, 1, 0, 8.0, 0.0);
+        let full = Arrow::charged(0.0, 0.0, 1, 0, 8.0, 1.0);
+
+        assert_eq!(tap.projectile_type, ProjectileType::Arrow);
+        assert_eq!(full.projectile_type, ProjectileType::Charged);
+        assert!(full.get_damage(10) > tap.get_damage(10));
+        assert!(full.speed > tap.speed);
+        assert!(full.pierce_remaining > 0);
+        assert_eq!(tap.pierce_remaining, 0);
+    }
+
+    #[test]
+    fn test_status_effects_report_new_applications_once() {
+        use roguelite_dungeon::model::status_effect::{
+            StatusEffect, StatusEffectManager, StatusEffectType,
+        };
+
+        let mut effects = StatusEffectManager::default();
+        effects.add(StatusEffect::poison(5.0));
+        effects.add(StatusEffect::poison(5.0)); // Refresh, not a new application
+        effects.add(StatusEffect::bleed_with_stacks(1));
+
+        assert_eq!(
+            effects.take_newly_applied(),
+            vec![StatusEffectType::Poison, StatusEffectType::Bleed]
+        );
+        assert!(effects.take_newly_applied().is_empty());
+    }
+
+    #[test]
+    fn test_menu_shortcuts_default_for_old_settings() {
+        use roguelite_dungeon::model::settings::Settings;
+
+        // Settings saved before skill tree / shop keys were rebindable
+        let mut value = serde_json::to_value(Settings::default()).unwrap();
+        let map = value.as_object_mut().unwrap();
+        map.remove("skill_tree");
+        map.remove("ultimate_shop");
+
+        let settings: Settings = serde_json::from_value(value).unwrap();
+        assert_eq!(settings.skill_tree, "T");
+        assert_eq!(settings.ultimate_shop, "Y");
+        assert_eq!(
+            Settings::normalize_key("Char('t')"),
+            Settings::normalize_key(&settings.skill_tree)
+        );
+    }
+
+    #[test]
+    fn test_settings_find_conflicts() {
+        use roguelite_dungeon::model::settings::Settings;
+
+        let mut settings = Settings::default();
+        assert!(settings.find_conflicts().is_empty());
+
+        settings.dash = "Char('w')".to_string();
+        assert_eq!(settings.find_conflicts(), vec![("Move Up", "Dash")]);
+
+        // Mouse buttons never count as conflicts
+        settings.dash = "Space".to_string();
+        settings.block = "LeftClick".to_string();
+        assert!(settings.find_conflicts().is_empty());
+    }
+
+    #[test]
+    fn test_key_presets_apply_full_sets() {
+        use roguelite_dungeon::model::settings::{KeyPreset, Settings, FIXED_KEYS};
+
+        let mut settings = Settings::default();
+        assert_eq!(settings.key_preset(), KeyPreset::Wasd);
+
+        settings.apply_key_preset(KeyPreset::Vim);
+        assert_eq!(settings.move_up, "K");
+        assert_eq!(settings.move_down, "J");
+        assert_eq!(settings.move_left, "H");
+        assert_eq!(settings.move_right, "L");
+        assert_eq!(settings.key_preset(), KeyPreset::Vim);
+
+        // No preset binds two actions to one key
+        for preset in KeyPreset::NAMED {
+            settings.apply_key_preset(preset);
+            assert!(settings.find_conflicts().is_empty(), "{}", preset.name());
+        }
+
+        // The fixed V / X / Z shortcuts count as taken
+        for (fixed_action, fixed_key) in FIXED_KEYS {
+            settings.apply_key_preset(KeyPreset::Wasd);
+            settings.dash = fixed_key.to_string();
+            assert_eq!(settings.find_conflicts(), vec![("Dash", fixed_action)]);
+        }
+
+        // H gives way: Vim moves left on it without a conflict, and help keeps '?'
+        settings.apply_key_preset(KeyPreset::Vim);
+        assert!(settings.binds_key("H"));
+        assert!(settings.find_conflicts().is_empty());
+
+        // A single rebind makes the bindings custom, and applying Custom keeps them
+        settings.dash = "Char('x')".to_string();
+        assert_eq!(settings.key_preset(), KeyPreset::Custom);
+        settings.apply_key_preset(KeyPreset::Custom);
+        assert_eq!(settings.dash, "Char('x')");
+    }
+
+    #[test]
+    fn test_nearest_enemy_selects_closest() {
+        let mut floor = Floor::new(60, 40, 42);
+        floor.enemies.clear();
+        let (px, py) = (20, 20);
+
+        // Open a small room around the player so line of sight is clear
+        for y in py - 4..=py + 4 {
+            for x in px - 4..=px + 4 {
+                let idx = (y * floor.width + x) as usize;
+                floor.tiles[idx] = false;
+            }
+        }
+
+        floor.enemies.push(Enemy::new(px + 3, py, 2.0));
+        floor.enemies.push(Enemy::new(px + 1, py + 1, 2.0));
+        assert_eq!(floor.nearest_enemy(px, py, 8), Some((px + 1, py + 1)));
+
+        // Dead enemies are never targeted
+        floor.enemies[1].health = 0;
+        assert_eq!(floor.nearest_enemy(px, py, 8), Some((px + 3, py)));
+
+        // Nothing outside the radius
+        assert_eq!(floor.nearest_enemy(px, py, 2), None);
+    }
+
+    #[test]
+    fn test_item_magnet_pulls_gold_within_radius() {
+        use roguelite_dungeon::model::item::ItemDrop;
+
+        let mut floor = Floor::new(60, 40, 42);
+        floor.items.clear();
+        let (px, py) = (20, 20);
+        for y in py - 3..=py + 3 {
+            for x in px - 3..=px + 3 {
+                floor.tiles[(y * floor.width + x) as usize] = false;
+            }
+        }
+
+        floor.add_item(ItemDrop::gold(25, px + 2, py));
+        floor.add_item(ItemDrop::weapon(Weapon::new_sword(), px, py + 2));
+
+        let mut arrived = false;
+        for _ in 0..20 {
+            arrived |= floor.update_items(0.05, (px, py), 2);
+        }
+
+        assert!(arrived);
+        assert_eq!(floor.items_at(px, py).len(), 1, "only the gold is pulled in");
+        assert!(floor.item_exists_at(px, py + 2), "weapons need exact-tile pickup");
+
+        // Without the upgrade nothing moves
+        floor.add_item(ItemDrop::gold(5, px - 2, py));
+        assert!(!floor.update_items(1.0, (px, py), 0));
+        assert!(floor.item_exists_at(px - 2, py));
+    }
+
+    #[test]
+    fn test_gold_drops_merge_into_one_pile() {
+        use roguelite_dungeon::model::floor::Floor;
+        use roguelite_dungeon::model::item::{ItemDrop, ItemDropType};
+
+        let mut floor = Floor::new(20, 20, 1);
+        floor.items.clear();
+        floor.add_item_merged(ItemDrop::gold(10, 5, 5));
+        floor.add_item_merged(ItemDrop::gold(15, 5, 5));
+
+        let items = floor.items_at(5, 5);
+        assert_eq!(
+            items.len(),
+            1,
+            "Gold on one tile should merge into a single pile"
+        );
+        assert!(matches!(items[0].item_type, ItemDropType::Gold(25)));
+        assert_eq!(items[0].pile_count, 2);
+
+        let pile = floor
+            .pickup_item(5, 5)
+            .expect("Merged pile should be picked up");
+        assert!(matches!(pile.item_type, ItemDropType::Gold(25)));
+        assert!(floor.items_at(5, 5).is_empty());
+    }
+
+    #[test]
+    fn test_shockwave_ultimate_damages_and_knocks_back() {
+        use roguelite_dungeon::model::ultimate::{Ultimate, UltimateType};
+
+        let ultimate = Ultimate::with_type(UltimateType::Shockwave);
+        let (px, py) = (10, 10);
+        let mut enemies = vec![
+            Enemy::new(px + 2, py, 0.1),  // inside the radius
+            Enemy::new(px + 10, py, 0.1), // far outside
+        ];
+        for enemy in &mut enemies {
+            enemy.health = 100;
+            enemy.max_health = 100;
+        }
+
+        let hits = ultimate.strike(&mut enemies, px, py);
+        assert_eq!(hits.len(), 1);
+        assert_eq!(enemies[0].health, 100 - ultimate.damage);
+        assert!(
+            enemies[0].knockback_velocity.0 > 0.0,
+            "Shockwave should push enemies away from the player"
+        );
+        assert_eq!(enemies[1].health, 100);
+        assert_eq!(enemies[1].knockback_velocity, (0.0, 0.0));
+    }
+
+    #[test]
+    fn test_dash_passes_over_enemies() {
+        let mut floor = Floor::new(60, 40, 42);
+        floor.enemies.clear();
+        let (px, py) = (20, 20);
+
+        // Open corridor to the right of the player, capped by a wall
+        for x in px..=px + 6 {
+            let idx = (py * floor.width + x) as usize;
+            floor.tiles[idx] = false;
+        }
+        let wall_idx = (py * floor.width + px + 7) as usize;
+        floor.tiles[wall_idx] = true;
+
+        // Enemy right next to the player no longer blocks the dash
+        floor.enemies.push(Enemy::new(px + 1, py, 2.0));
+        assert_eq!(floor.dash_landing((px, py), (1, 0), 5), Some((px + 5, py)));
+
+        // An enemy on the landing tile pulls the landing back to the last free tile
+        floor.enemies.push(Enemy::new(px + 5, py, 2.0));
+        assert_eq!(floor.dash_landing((px, py), (1, 0), 5), Some((px + 4, py)));
+
+        // Walls stop the dash early
+        assert_eq!(floor.dash_landing((px, py), (1, 0), 10), Some((px + 6, py)));
+    }
+
+    #[test]
+    fn test_trail_particles_are_capped() {
+        use roguelite_dungeon::constants::MAX_TRAIL_PARTICLES;
+        use roguelite_dungeon::model::arrow::{Arrow, ProjectileType};
+        use roguelite_dungeon::model::particle::ParticleSystem;
+
+        let fire_oil = Arrow::new_with_type(0.0, 0.0, 1, 0, 10.0, ProjectileType::FireOil);
+        let mut particles = ParticleSystem::new();
+        for i in 0..MAX_TRAIL_PARTICLES * 2 {
+            particles.emit_trail(i as f32, 0.0, fire_oil.trail_color());
+        }
+
+        assert_eq!(particles.particles.len(), MAX_TRAIL_PARTICLES);
+        assert!(particles
+            .get_active_particles()
+            .iter()
+            .all(|&(_, _, _, color)| color == fire_oil.trail_color()));
+    }
+
+    #[test]
+    fn test_autosave_interval_controls_save_frequency() {
+        use roguelite_dungeon::model::cooldown::IntervalTimer;
+        use roguelite_dungeon::model::settings::Settings;
+
+        // Count saves over two minutes of simulated 0.5s ticks
+        fn saves_over_two_minutes(settings: &Settings) -> u32 {
+            let mut timer = IntervalTimer::default();
+            let interval = settings.autosave_interval_secs as f32;
+            (0..240).filter(|_| timer.tick(0.5, interval)).count() as u32
+        }
+
+        let mut settings = Settings {
+            autosave_interval_secs: 30,
+            ..Default::default()
+        };
+        assert_eq!(saves_over_two_minutes(&settings), 4);
+
+        settings.cycle_autosave_interval(1);
+        assert_eq!(settings.autosave_interval_secs, 60);
+        assert_eq!(saves_over_two_minutes(&settings), 2);
+
+        settings.autosave_interval_secs = 0;
+        assert_eq!(settings.autosave_interval_label(), "Off");
+        assert_eq!(saves_over_two_minutes(&settings), 0);
+    }
+
+    #[test]
+    fn test_weapon_swap_when_inventory_full() {
+        let mut inventory = Character::default().weapon_inventory;
+        while !inventory.is_full() {
+            inventory.add_weapon(Weapon::new_sword());
+        }
+        assert!(!inventory.add_weapon(Weapon::new_mace()));
+
+        // Swapping replaces the slot and hands back the old weapon, without growing the inventory
+        let replaced = inventory
+            .swap_weapon(1, Weapon::new_mace())
+            .expect("slot 1 holds a weapon");
+        assert_eq!(replaced.name, Weapon::new_bow().name);
+        assert_eq!(inventory.weapons[1].name, Weapon::new_mace().name);
+        assert_eq!(inventory.weapons.len(), 9);
+
+        // Out-of-range slots leave the inventory untouched
+        assert!(inventory.swap_weapon(9, Weapon::new_bow()).is_none());
+        assert_eq!(inventory.weapons.len(), 9);
+    }
+
+    #[test]
+    fn test_gold_recovery_matches_difficulty_fraction() {
+        use roguelite_dungeon::model::game_difficulty::GameDifficulty;
+        use roguelite_dungeon::model::gamesave::GoldRecovery;
+
+        let pile =
+            GoldRecovery::on_death(200, 3, (12, 7), &GameDifficulty::Normal).expect("gold to drop");
+        let expected = (200.0 * GameDifficulty::Normal.death_gold_drop_fraction()) as u32;
+        assert_eq!(pile.amount, expected);
+        assert_eq!((pile.floor_level, pile.x, pile.y), (3, 12, 7));
+
+        // Harder difficulties leave more behind, but never more than the player carried
+        let easy = GoldRecovery::on_death(200, 1, (0, 0), &GameDifficulty::Easy).unwrap();
+        let death = GoldRecovery::on_death(200, 1, (0, 0), &GameDifficulty::Death).unwrap();
+        assert!(easy.amount < pile.amount);
+        assert!(death.amount <= 200);
+
+        assert!(GoldRecovery::on_death(0, 1, (0, 0), &GameDifficulty::Hard).is_none());
+    }
+
+    #[test]
+    fn test_settings_navigation_wraps_and_skips_separator() {
+        use roguelite_dungeon::model::menu::{next_selectable, previous_selectable};
+        use roguelite_dungeon::model::settings::SettingsRow;
+
+        let rows = SettingsRow::settings_screen();
+        let selectable = |i: usize| rows[i].is_selectable();
+        let last = rows.len() - 1;
+        assert_eq!(rows[last], SettingsRow::Reset);
+
+        // Both ends wrap
+        assert_eq!(next_selectable(last, rows.len(), selectable), 0);
+        assert_eq!(previous_selectable(0, rows.len(), selectable), last);
+
+        // The separator before SAVE is stepped over in both directions
+        let save = rows.iter().position(|r| *r == SettingsRow::Save).unwrap();
+        assert_eq!(rows[save - 1], SettingsRow::Separator);
+        assert_eq!(previous_selectable(save, rows.len(), selectable), save - 2);
+        assert_eq!(next_selectable(save - 2, rows.len(), selectable), save);
+    }
+
+    #[test]
+    fn test_styled_tile_cache_matches_on_demand_styles() {
+        use ratatui::style::{Color, Style};
+
+        fn assert_cache_matches(floor: &Floor) {
+            for y in 0..floor.height {
+                for x in 0..floor.width {
+                    let (ch, color_idx) = floor.get_styled_tile_cached(x, y).unwrap();
+                    let expected = floor.get_styled_tile(x, y).unwrap();
+                    assert_eq!((ch, Style::new().fg(Color::Indexed(color_idx))), expected);
+                }
+            }
+        }
+
+        let mut floor = Floor::new(60, 30, 1234);
+        assert_cache_matches(&floor);
+
+        // Carving a tile refreshes the neighbours whose shading depends on it
+        floor.set_wall(10, 10, false);
+        floor.set_wall(11, 10, true);
+        assert_cache_matches(&floor);
+        assert!(floor.get_styled_tile_cached(-1, 0).is_none());
+    }
+
+    #[test]
+    fn test_spatial_grid_finds_dense_enemy_cluster() {
+        let mut floor = Floor::new(60, 40, 7);
+        floor.enemies.clear();
+        let (cx, cy) = (20, 20);
+
+        // A packed 3x3 block, with three extra enemies stacked on the centre tile
+        for dy in -1..=1 {
+            for dx in -1..=1 {
+                floor.enemies.push(Enemy::new(cx + dx, cy + dy, 0.1));
+            }
+        }
+        for _ in 0..3 {
+            floor.enemies.push(Enemy::new(cx, cy, 0.1));
+        }
+        // A dead enemy on the centre tile must never be hit
+        floor.enemies.push(Enemy::new(cx, cy, 0.1));
+        floor.enemies[12].health = 0;
+        floor.rebuild_enemy_grid();
+
+        // Arrows take the first enemy on a tile, in the same order as a linear scan
+        assert_eq!(floor.enemies_at(cx, cy), &[4, 9, 10, 11]);
+        assert_eq!(floor.enemies_at(cx + 1, cy + 1), &[8]);
+        assert!(floor.enemies_at(cx + 2, cy).is_empty());
+
+        // A melee swing over the whole block hits every living enemy exactly once
+        let area: Vec<(i32, i32)> = (-1..=1)
+            .flat_map(|dy| (-1..=1).map(move |dx| (cx + dx, cy + dy)))
+            .collect();
+        assert_eq!(floor.enemies_in_area(&area), (0..12).collect::<Vec<_>>());
+
+        // Overlapping tiles don't produce duplicate hits
+        assert_eq!(
+            floor.enemies_in_area(&[(cx, cy), (cx, cy), (cx - 1, cy)]),
+            vec![3, 4, 9, 10, 11]
+        );
+    }
+
+    #[test]
+    fn test_enemy_count_scales_with_open_region() {
+        use roguelite_dungeon::constants::MIN_OPEN_TILES_PER_ENEMY;
+        use roguelite_dungeon::model::item_tier::Difficulty;
+
+        let mut small = Floor::new(24, 12, 99);
+        let mut large = Floor::new(180, 60, 99);
+        small.spawn_enemies(&Difficulty::Normal, None);
+        large.spawn_enemies(&Difficulty::Normal, None);
+
+        let small_open = small.largest_region_tiles().len();
+        let large_open = large.largest_region_tiles().len();
+        assert!(small_open < large_open);
+        assert!(small.enemies.len() < large.enemies.len());
+        assert!(small.enemies.len() <= (small_open / MIN_OPEN_TILES_PER_ENEMY).max(1));
+
+        // The same open area gets more enemies on a harder difficulty, never past the cap
+        let open = 5000;
+        let easy = Floor::enemy_spawn_count(open, &Difficulty::Easy, 1.0);
+        let death = Floor::enemy_spawn_count(open, &Difficulty::Death, 1.0);
+        assert!(easy < death);
+        assert_eq!(Floor::enemy_spawn_count(120, &Difficulty::Death, 1.0), 1);
+        assert_eq!(Floor::enemy_spawn_count(10, &Difficulty::Death, 1.0), 0);
+    }
+
+    #[test]
+    fn test_room_placement_keeps_enemies_in_rooms() {
+        use roguelite_dungeon::constants::ROOM_PLAYER_CLEARANCE;
+        use roguelite_dungeon::model::item_tier::Difficulty;
+
+        let mut floor = Floor::new(180, 60, 42);
+        assert!(floor.rooms.len() > 1, "cave should split into rooms");
+
+        let player = floor.find_player_spawn().unwrap();
+        floor.spawn_enemies_in_rooms(&Difficulty::Hard, Some(player));
+        assert!(!floor.enemies.is_empty());
+
+        // Look rooms up from their own tile lists rather than the room_at index
+        let mut occupied = Vec::new();
+        for enemy in &floor.enemies {
+            let (x, y) = (enemy.position.x, enemy.position.y);
+            assert!(!floor.get_tile(x, y), "enemy inside a wall");
+            let room = floor
+                .rooms
+                .iter()
+                .find(|room| room.tiles.contains(&(x, y)))
+                .expect("enemy outside rooms");
+            assert_eq!(floor.room_at(x, y), Some(room.id));
+            assert!((x - player.0).abs().max((y - player.1).abs()) > ROOM_PLAYER_CLEARANCE);
+            occupied.push(room.id.0);
+        }
+
+        // Guards are posted in groups rather than one per room
+        occupied.sort();
+        occupied.dedup();
+        assert!(occupied.len() < floor.enemies.len());
+    }
+
+    #[test]
+    fn test_treasure_room_holds_high_tier_weapon() {
+        use roguelite_dungeon::model::item::ItemDropType;
+        use roguelite_dungeon::model::item_tier::{Difficulty, ItemTier};
+
+        let mut floor = Floor::new(180, 60, 42);
+        let player = floor.find_player_spawn().unwrap();
+        let treasure = floor.choose_treasure_room(player).unwrap();
+        floor.stock_treasure_room(&Difficulty::Normal, true);
+
+        // Exactly one room is the treasure room, and it isn't the one the player starts in
+        let treasure_rooms = floor
+            .rooms
+            .iter()
+            .filter(|room| Some(room.id) == floor.treasure_room)
+            .count();
+        assert_eq!(treasure_rooms, 1);
+        assert_ne!(floor.room_at(player.0, player.1), Some(treasure));
+
+        let in_treasure_room = |x: i32, y: i32| floor.room_at(x, y) == Some(treasure);
+        assert!(floor.items.iter().any(|item| {
+            matches!(item.item_type, ItemDropType::Weapon(_))
+                && item.tier > ItemTier::Common
+                && in_treasure_room(item.x, item.y)
+        }));
+        assert!(floor
+            .items
+            .iter()
+            .any(|item| item.item_type.is_gold() && in_treasure_room(item.x, item.y)));
+        assert_eq!(floor.enemies.len(), 1);
+        assert!(in_treasure_room(
+            floor.enemies[0].position.x,
+            floor.enemies[0].position.y
+        ));
+    }
+
+    #[test]
+    fn test_fill_probability_changes_wall_ratio() {
+        use roguelite_dungeon::model::floor::{FloorGenParams, TunnelStyle};
+
+        let wall_ratio = |floor: &Floor| {
+            floor.tiles.iter().filter(|&&wall| wall).count() as f32 / floor.tiles.len() as f32
+        };
+        let params = |fill_probability| FloorGenParams {
+            width: 120,
+            height: 50,
+            fill_probability,
+            ..FloorGenParams::default()
+        };
+
+        let tight = Floor::with_params(&params(40), 5);
+        let open = Floor::with_params(&params(55), 5);
+        assert!(wall_ratio(&tight) > wall_ratio(&open) + 0.1);
+
+        // Straight tunnels still join every cave into one connected floor
+        let straight = Floor::with_params(
+            &FloorGenParams {
+                tunnel_style: TunnelStyle::Straight,
+                ..params(40)
+            },
+            5,
+        );
+        let open_tiles = straight.tiles.iter().filter(|&&wall| !wall).count();
+        assert_eq!(straight.largest_region_tiles().len(), open_tiles);
+
+        // Out-of-range values are pulled back to something playable
+        let clamped = FloorGenParams {
+            width: 5,
+            height: 5000,
+            fill_probability: 99,
+            iterations: 50,
+            tunnel_style: TunnelStyle::LShaped,
+            tunnel_width: 2,
+        }
+        .clamped((100, 30));
+        assert_eq!((clamped.width, clamped.height), (100, 160));
+        assert_eq!((clamped.fill_probability, clamped.iterations), (60, 10));
+    }
+
+    #[test]
+    fn test_widened_tunnels_remove_chokepoints() {
+        use roguelite_dungeon::model::floor::FloorGenParams;
+
+        // Open tiles squeezed between walls on both sides, horizontally or vertically
+        let chokepoints = |floor: &Floor| {
+            (1..floor.height - 1)
+                .flat_map(|y| (1..floor.width - 1).map(move |x| (x, y)))
+                .filter(|&(x, y)| !floor.get_tile(x, y))
+                .filter(|&(x, y)| {
+                    (floor.get_tile(x - 1, y) && floor.get_tile(x + 1, y))
+                        || (floor.get_tile(x, y - 1) && floor.get_tile(x, y + 1))
+                })
+                .count()
+        };
+        // A tight cave with many separate pockets, so lots of tunnels get carved
+        let params = |tunnel_width| FloorGenParams {
+            fill_probability: 38,
+            tunnel_width,
+            ..FloorGenParams::default()
+        };
+
+        let narrow = Floor::with_params(&params(1), 11);
+        let wide = Floor::with_params(&params(2), 11);
+        // Widening clears the carved corridors; what remains is the cave's own texture
+        assert!(chokepoints(&wide) * 2 < chokepoints(&narrow));
+
+        // The boundary stays solid and rooms are detected on the widened layout
+        for x in 0..wide.width {
+            assert!(wide.get_tile(x, 0) && wide.get_tile(x, wide.height - 1));
+        }
+        for y in 0..wide.height {
+            assert!(wide.get_tile(0, y) && wide.get_tile(wide.width - 1, y));
+        }
+        let room_tiles: usize = wide.rooms.iter().map(|room| room.tiles.len()).sum();
+        assert_eq!(room_tiles, wide.tiles.iter().filter(|&&wall| !wall).count());
+    }
+
+    #[test]
+    fn test_all_enemies_reachable_from_player_spawn() {
+        use roguelite_dungeon::model::item_tier::Difficulty;
+
+        for seed in 0..24u64 {
+            let mut scattered = Floor::new(100, 40, seed);
+            let mut in_rooms = scattered.clone();
+            let player = scattered.find_player_spawn().unwrap();
+            let reachable = scattered.reachable_from(player);
+
+            scattered.spawn_enemies(&Difficulty::Death, Some(player));
+            in_rooms.spawn_enemies_in_rooms(&Difficulty::Death, Some(player));
+            assert!(
+                !scattered.enemies.is_empty(),
+                "seed {seed} spawned no enemies"
+            );
+
+            for enemy in scattered.enemies.iter().chain(&in_rooms.enemies) {
+                let pos = (enemy.position.x, enemy.position.y);
+                assert!(
+                    reachable.contains(&pos),
+                    "seed {seed}: enemy at {pos:?} is cut off"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_enemy_arrows_only_hurt_the_player() {
+        use roguelite_dungeon::model::arrow::{Arrow, ArrowHit, ArrowOwner};
+
+        let arrow = Arrow::enemy_shot(4.0, 4.0, 1, 0, 7);
+        assert_eq!(arrow.owner, ArrowOwner::Enemy { damage: 7 });
+
+        // Overlapping the player it lands and deals its damage
+        let mut character = Character::default();
+        let before = character.health;
+        let Some(ArrowHit::Player(damage)) = arrow.resolve_hit((4, 4), &[]) else {
+            panic!("enemy arrow should hit the player");
+        };
+        character.take_damage(damage);
+        assert_eq!(character.health, before - 7);
+
+        // Over an enemy it just keeps flying
+        assert_eq!(arrow.resolve_hit((9, 9), &[0]), None);
+
+        // The player's own arrows still hit enemies and pass over the player
+        let shot = Arrow::new(4.0, 4.0, 1, 0, 8.0);
+        assert_eq!(shot.resolve_hit((4, 4), &[]), None);
+        assert_eq!(shot.resolve_hit((9, 9), &[2]), Some(ArrowHit::Enemy(2)));
+    }
+
+    #[test]
+    fn test_scrying_eye_starts_reveal_timer() {
+        use roguelite_dungeon::app::App;
+        use roguelite_dungeon::model::consumable::{Consumable, ConsumableType};
+
+        let mut app = App::headless(9, 120, 40);
+        app.restart_game();
+        app.settings.fog_of_war = true;
+        app.character.consumable_inventory.items.clear();
+        app.character
+            .consumable_inventory
+            .add(Consumable::new(ConsumableType::ScryingEye));
+        assert!(app.fog_hides_map());
+
+        // Using it lifts the fog for the reveal window
+        app.use_consumable(0);
+        assert!(app.character.consumable_inventory.is_empty());
+        assert!(app.map_reveal.is_active());
+        assert!(!app.fog_hides_map());
+
+        // Other consumables leave the map alone
+        assert!(Consumable::new(ConsumableType::BlessedBread)
+            .get_reveal_duration()
+            .is_none());
+    }
+
+    #[test]
+    fn test_enchant_purchase_costs_gold_and_enchants_weapon() {
+        use roguelite_dungeon::model::ultimate_shop::UltimateShop;
+
+        let shop = UltimateShop::new();
+        let (damage, radius) = (EnchantType::DamageIncrease, EnchantType::RadiusIncrease);
+        let mut weapon = Weapon::new_sword();
+        let mut gold = 1000;
+
+        assert!(shop
+            .purchase_enchant(&mut gold, Some(&mut weapon), &damage)
+            .is_ok());
+        assert_eq!(weapon.enchants.len(), 1);
+        assert_eq!(weapon.enchants[0].enchant_type, damage);
+        let first_cost = 1000 - gold;
+        assert!(first_cost > 0);
+
+        // The next enchant costs more than the first
+        let before = gold;
+        assert!(shop
+            .purchase_enchant(&mut gold, Some(&mut weapon), &radius)
+            .is_ok());
+        assert_eq!(weapon.enchants[1].enchant_type, radius);
+        assert!(before - gold > first_cost);
+
+        // Not enough gold leaves the weapon untouched
+        let mut broke = 0;
+        assert!(shop
+            .purchase_enchant(&mut broke, Some(&mut weapon), &damage)
+            .is_err());
+        assert_eq!(weapon.enchants.len(), 2);
+
+        // Weapons stop taking enchants at the cap
+        while weapon.enchants.len() < roguelite_dungeon::constants::MAX_WEAPON_ENCHANTS {
+            assert!(shop
+                .purchase_enchant(&mut gold, Some(&mut weapon), &damage)
+                .is_ok());
+        }
+        let before = gold;
+        assert!(shop
+            .purchase_enchant(&mut gold, Some(&mut weapon), &damage)
+            .is_err());
+        assert_eq!(gold, before);
+    }
+
+    #[test]
+    fn test_archer_loadout_starts_with_bow() {
+        use roguelite_dungeon::model::loadout::StartingClass;
+        use roguelite_dungeon::model::weapon::WeaponType;
+
+        let mut character = Character::default();
+        StartingClass::Archer.apply(&mut character);
+
+        let equipped = character.weapon_inventory.get_current_weapon().unwrap();
+        assert_eq!(equipped.weapon_type, WeaponType::Bow);
+        assert!(character.arrow_speed > Character::default().arrow_speed);
+
+        // The class round-trips through its saved name
+        let saved = StartingClass::Archer.name();
+        assert_eq!(StartingClass::from_name(saved), StartingClass::Archer);
+        assert_eq!(StartingClass::from_name("Unknown"), StartingClass::Warrior);
+    }
+
+    #[test]
+    fn test_hard_fighters_have_more_health() {
+        use roguelite_dungeon::model::enemy_type::{self, EnemyRarity};
+
+        let scaled = |difficulty: &Difficulty| {
+            let template = enemy_type::create_rotting_footsoldier();
+            assert_eq!(template.rarity, EnemyRarity::Fighter);
+            let mut fighter = Enemy::new(0, 0, template.speed);
+            fighter.max_health = template.health;
+            fighter.attacks = template.attacks;
+            fighter.scale_stats(difficulty.enemy_stat_multiplier());
+            fighter
+        };
+        let normal = scaled(&Difficulty::Normal);
+        let hard = scaled(&Difficulty::Hard);
+        let easy = scaled(&Difficulty::Easy);
+        let expected = (normal.max_health as f32 * 1.4).round() as i32;
+        assert_eq!(hard.max_health, expected);
+        assert!(easy.max_health < normal.max_health);
+        assert!(hard.attacks[0].damage_max > normal.attacks[0].damage_max);
+
+        // Spawned enemies carry the scaled health of one of the floor's templates
+        let mut floor = Floor::new(120, 50, 9);
+        floor.spawn_enemies(&Difficulty::Hard, None);
+        let templates = enemy_type::get_enemies_for_difficulty(&Difficulty::Hard);
+        assert!(!floor.enemies.is_empty());
+        for enemy in &floor.enemies {
+            assert!(templates
+                .iter()
+                .any(|t| enemy.max_health == (t.health as f32 * 1.4).round() as i32));
+        }
+    }
+
+    #[test]
+    fn test_manual_pickup_only_takes_gold_automatically() {
+        use roguelite_dungeon::model::item::ItemDrop;
+        use roguelite_dungeon::model::settings::Settings;
+
+        // Interact defaults to G, even for settings saved before it existed
+        let settings = Settings::default();
+        assert_eq!(settings.interact, "G");
+        assert!(!settings.auto_pickup);
+        assert!(settings.find_conflicts().is_empty());
+        let mut value = serde_json::to_value(&settings).unwrap();
+        value.as_object_mut().unwrap().remove("interact");
+        let old: Settings = serde_json::from_value(value).unwrap();
+        assert_eq!(old.interact, "G");
+
+        // Walking onto a tile in manual mode collects the gold and leaves the rest
+        let mut floor = Floor::new(40, 30, 3);
+        floor.items.clear();
+        let potion = Consumable::new(ConsumableType::WeakHealingDraught);
+        floor.items.push(ItemDrop::consumable(potion, 5, 5));
+        let sword = Weapon::new_sword();
+        floor.items.push(ItemDrop::weapon(sword, 5, 5));
+        floor.items.push(ItemDrop::gold(25, 5, 5));
+
+        let gold_only = |item: &ItemDrop| item.item_type.is_gold();
+        let picked = floor.pickup_item_where(5, 5, gold_only).unwrap();
+        assert!(picked.item_type.is_gold());
+        assert!(floor.pickup_item_where(5, 5, gold_only).is_none());
+        assert_eq!(floor.items_at(5, 5).len(), 2);
+
+        // Interact then takes everything that is left
+        assert!(floor.pickup_item(5, 5).is_some());
+        assert!(floor.pickup_item(5, 5).is_some());
+        assert!(floor.items_at(5, 5).is_empty());
+    }
+
+    #[test]
+    fn test_settings_export_import_round_trip() {
+        use roguelite_dungeon::model::settings::Settings;
+
+        let dir = std::env::temp_dir();
+        let path = dir.join(format!("settings_export_{}.json", std::process::id()));
+        let settings = Settings {
+            dash: "Q".to_string(),
+            difficulty: Difficulty::Hard,
+            music_volume: 0.25,
+            ..Settings::default()
+        };
+
+        settings.export_to(&path).unwrap();
+        let (imported, rejected) = Settings::import_from(&path).unwrap();
+        assert_eq!(imported, settings);
+        assert!(rejected.is_empty());
+
+        // Bad fields fall back to defaults while the rest still import
+        let mut value = serde_json::to_value(&settings).unwrap();
+        value["difficulty"] = serde_json::json!("Nightmare");
+        value["sound_volume"] = serde_json::json!(3.5);
+        std::fs::write(&path, value.to_string()).unwrap();
+        let (imported, rejected) = Settings::import_from(&path).unwrap();
+        let _ = std::fs::remove_file(&path);
+
+        assert_eq!(imported.difficulty, Settings::default().difficulty);
+        assert_eq!(imported.sound_volume, Settings::default().sound_volume);
+        assert_eq!(imported.dash, "Q");
+        assert_eq!(imported.music_volume, 0.25);
+        assert_eq!(rejected, vec!["difficulty", "sound_volume"]);
+    }
+
+    #[test]
+    fn test_old_settings_block_binding_migrates_to_f() {
+        use roguelite_dungeon::model::settings::{KeyPreset, Settings, SETTINGS_VERSION};
+
+        let dir = std::env::temp_dir();
+        let path = dir.join(format!("settings_block_{}.json", std::process::id()));
+
+        // Files from before versioning still carry the old, never-used block default
+        let mut value = serde_json::to_value(Settings::default()).unwrap();
+        value.as_object_mut().unwrap().remove("version");
+        value["block"] = serde_json::json!("RightClick");
+        std::fs::write(&path, value.to_string()).unwrap();
+        let (imported, _) = Settings::import_from(&path).unwrap();
+        assert_eq!(imported.block, "F");
+        assert_eq!(imported.version, SETTINGS_VERSION);
+        assert_eq!(imported.key_preset(), KeyPreset::Wasd);
+
+        // Right click picked in a current build is kept
+        let settings = Settings {
+            block: "RightClick".to_string(),
+            ..Settings::default()
+        };
+        settings.export_to(&path).unwrap();
+        let (imported, _) = Settings::import_from(&path).unwrap();
+        let _ = std::fs::remove_file(&path);
+        assert_eq!(imported.block, "RightClick");
+    }
+
+    #[test]
+    fn test_no_enemy_spawns_next_to_player() {
+        use roguelite_dungeon::constants::ENEMY_SPAWN_SAFE_RADIUS;
+        use roguelite_dungeon::model::item_tier::Difficulty;
+
+        for seed in 0..24u64 {
+            let mut floor = Floor::new(100, 40, seed);
+            let (px, py) = floor.find_player_spawn().unwrap();
+            floor.spawn_enemies(&Difficulty::Death, Some((px, py)));
+
+            for enemy in &floor.enemies {
+                let (x, y) = (enemy.position.x, enemy.position.y);
+                let distance = (x - px).abs().max((y - py).abs());
+                assert!(
+                    distance > ENEMY_SPAWN_SAFE_RADIUS,
+                    "seed {seed}: enemy at ({x}, {y}) spawned {distance} tiles from the player"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_hold_move_rate_steps_through_options() {
+        use roguelite_dungeon::model::settings::Settings;
+
+        let mut settings = Settings::default();
+        assert_eq!(settings.hold_move_rate_label(), "10/s");
+
+        // Rate 0 falls back to the terminal's key repeat
+        for _ in 0..10 {
+            settings.cycle_hold_move_rate(-1);
+        }
+        assert_eq!(settings.hold_move_rate, 0);
+        assert_eq!(settings.hold_move_rate_label(), "Key Repeat");
+
+        for _ in 0..10 {
+            settings.cycle_hold_move_rate(1);
+        }
+        assert_eq!(settings.hold_move_rate, 20);
+
+        // Settings saved before the option existed get the default rate
+        let mut value = serde_json::to_value(Settings::default()).unwrap();
+        value.as_object_mut().unwrap().remove("hold_move_rate");
+        let old: Settings = serde_json::from_value(value).unwrap();
+        assert_eq!(old.hold_move_rate, 10);
+    }
+
+    #[test]
+    fn test_same_seed_generates_identical_floor() {
+        use roguelite_dungeon::model::item::ItemDropType;
+
+        for seed in [0u64, 7, 42, 1234, u64::MAX] {
+            let mut first = Floor::new(120, 50, seed);
+            let mut second = Floor::new(120, 50, seed);
+            assert_eq!(first.tiles, second.tiles, "seed {seed}: terrain differs");
+            assert_eq!(first.rooms.len(), second.rooms.len());
+
+            let spawn = first.find_player_spawn();
+            assert_eq!(spawn, second.find_player_spawn());
+
+            for floor in [&mut first, &mut second] {
+                floor.spawn_random_items(10, &Difficulty::Hard, spawn);
+                if floor.choose_treasure_room(spawn.unwrap()).is_some() {
+                    floor.stock_treasure_room(&Difficulty::Hard, true);
+                }
+                floor.spawn_enemies_in_rooms(&Difficulty::Hard, spawn);
+            }
+            let items = |floor: &Floor| -> Vec<(i32, i32, String)> {
+                floor
+                    .items
+                    .iter()
+                    .map(|item| (item.x, item.y, item.get_glyph().to_string()))
+                    .collect()
+            };
+            assert_eq!(items(&first), items(&second), "seed {seed}: items differ");
+            let weapons = |floor: &Floor| -> Vec<String> {
+                floor
+                    .items
+                    .iter()
+                    .filter_map(|item| match &item.item_type {
+                        ItemDropType::Weapon(weapon) => Some(weapon.name.clone()),
+                        _ => None,
+                    })
+                    .collect()
+            };
+            assert_eq!(weapons(&first), weapons(&second));
+            let enemies = |floor: &Floor| -> Vec<(i32, i32)> {
+                floor
+                    .enemies
+                    .iter()
+                    .map(|enemy| (enemy.position.x, enemy.position.y))
+                    .collect()
+            };
+            assert_eq!(enemies(&first), enemies(&second));
+        }
+    }
+
+    #[test]
+    fn test_connected_caves_form_one_region() {
+        use roguelite_dungeon::model::floor::{FloorGenParams, TunnelStyle};
+
+        for tunnel_style in [TunnelStyle::LShaped, TunnelStyle::Straight] {
+            for seed in 0..40u64 {
+                let params = FloorGenParams {
+                    width: 120,
+                    height: 50,
+                    tunnel_style,
+                    ..FloorGenParams::default()
+                };
+                let floor = Floor::with_params(&params, seed);
+                let walkable = floor.tiles.iter().filter(|&&wall| !wall).count();
+                assert_eq!(
+                    floor.largest_region_tiles().len(),
+                    walkable,
+                    "seed {seed} ({}): cave left disconnected",
+                    tunnel_style.name()
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_player_spawn_is_walkable() {
+        for seed in 0..40u64 {
+            let floor = Floor::new(100, 40, seed);
+            let (x, y) = floor.find_player_spawn().expect("floor has open space");
+            assert!(floor.is_walkable(x, y), "seed {seed}: spawned in a wall");
+        }
+    }
+
+    #[test]
+    fn test_pattern_shapes_do_not_depend_on_origin() {
+        // The dev preview draws patterns from (0, 0); they must match what lands in game
+        for pattern in AttackPattern::ALL.iter() {
+            for (dx, dy) in [(0, -1), (1, 0), (0, 1), (-1, 0), (1, 1), (-1, -1)] {
+                let at_origin = pattern.get_affected_tiles(0, 0, dx, dy);
+                let moved: Vec<(i32, i32)> = pattern
+                    .get_affected_tiles(30, 12, dx, dy)
+                    .into_iter()
+                    .map(|(x, y)| (x - 30, y - 12))
+                    .collect();
+                assert!(!at_origin.is_empty(), "{} hits nothing", pattern.name());
+                assert_eq!(at_origin, moved, "{} facing ({dx}, {dy})", pattern.name());
+            }
+        }
+    }
+
+    #[test]
+    fn test_unversioned_save_migrates_to_current_version() {
+        use roguelite_dungeon::model::gamesave::{GameSave, SAVE_VERSION};
+
+        // A save from before versioning, run modes, scores, gold recovery and classes
+        let v1 = r#"{
+            "player_name": "Old Hero",
+            "player_stats": {
+                "attack_damage": 7, "attack_length": 2, "attack_width": 1,
+                "dash_distance": 5, "health": 80, "max_health": 100, "gold": 120,
+                "enemies_killed": 9, "speed": 100.0, "ultimate_charge": 0.5
+            },
+            "inventory_data": { "weapons": [], "current_weapon_index": 0, "consumables": [] },
+            "skill_tree_data": { "path_nodes": [], "chosen_path": null },
+            "ultimate_shop_data": {
+                "owned_ultimates": [], "stat_upgrades": [], "current_ultimate_type": "Shockwave"
+            },
+            "floor_level": 3,
+            "max_levels": 10,
+            "position_x": 12,
+            "position_y": 8,
+            "difficulty": "Hard",
+            "time_elapsed": 321.0
+        }"#;
+
+        let save = GameSave::from_json(v1).expect("v1 save loads");
+        assert_eq!(save.version, SAVE_VERSION);
+        assert_eq!(save.player_name, "Old Hero");
+        assert_eq!(save.player_stats.gold, 120);
+        assert_eq!(save.floor_level, 3);
+        assert_eq!(save.run_mode, "Checkpoint");
+        assert_eq!(save.best_score, 0);
+        assert!(save.gold_recovery.is_none());
+        assert_eq!(save.starting_class, "Warrior");
+
+        // Round-trips at the current version
+        let written = serde_json::to_string(&save).unwrap();
+        let reloaded = GameSave::from_json(&written).unwrap();
+        assert_eq!(reloaded.version, SAVE_VERSION);
+        assert_eq!(reloaded.position_x, 12);
+    }
+
+    #[test]
+    fn test_archer_save_without_arrow_speed_keeps_class_bonus() {
+        use roguelite_dungeon::constants::PLAYER_ARROW_SPEED;
+        use roguelite_dungeon::model::gamesave::GameSave;
+
+        let mut value = serde_json::to_value(GameSave::default()).unwrap();
+        value["version"] = serde_json::json!(2);
+        value["starting_class"] = serde_json::json!("Archer");
+        value["player_stats"]
+            .as_object_mut()
+            .unwrap()
+            .remove("arrow_speed");
+
+        let save = GameSave::from_json(&value.to_string()).expect("v2 save loads");
+        assert_eq!(save.player_stats.arrow_speed, PLAYER_ARROW_SPEED + 2.0);
+    }
+
+    #[test]
+    fn test_newer_save_version_is_rejected() {
+        use roguelite_dungeon::model::gamesave::{GameSave, SAVE_VERSION};
+
+        let mut value = serde_json::to_value(GameSave::default()).unwrap();
+        value["version"] = serde_json::json!(SAVE_VERSION + 1);
+        let err = GameSave::from_json(&value.to_string()).unwrap_err();
+        assert_eq!(err.kind(), std::io::ErrorKind::Unsupported);
+        assert!(err.to_string().contains("newer"), "{err}");
+    }
+
+    #[test]
+    fn test_corrupt_save_errors_and_is_backed_up() {
+        use roguelite_dungeon::model::gamesave::GameSave;
+
+        let dir = std::env::temp_dir();
+        let path = dir.join(format!("corrupt_save_{}.json", std::process::id()));
+        let backup = dir.join(format!("corrupt_save_{}.json.bak", std::process::id()));
+        let corrupt = r#"{"player_name": "Broken", "player_stats": {"gold": 12"#;
+        std::fs::write(&path, corrupt).unwrap();
+
+        let result = GameSave::load_from(&path);
+        let backed_up = std::fs::read_to_string(&backup);
+        let original = std::fs::read_to_string(&path);
+        let _ = std::fs::remove_file(&path);
+        let _ = std::fs::remove_file(&backup);
+
+        // The load screen stays put on an error, so nothing half-loaded reaches the game
+        let err = result.expect_err("malformed JSON must not load");
+        assert_eq!(err.kind(), std::io::ErrorKind::InvalidData);
+        assert!(err.to_string().contains(".bak"), "{err}");
+        assert_eq!(backed_up.unwrap(), corrupt);
+        assert_eq!(original.unwrap(), corrupt);
+
+        // Valid JSON with the wrong shape is corrupt too
+        assert_eq!(
+            GameSave::from_json(r#"{"player_name": 5}"#)
+                .unwrap_err()
+                .kind(),
+            std::io::ErrorKind::InvalidData
+        );
+    }
+
+    #[test]
+    fn test_new_game_plus_toughens_enemies() {
+        let mut floor = Floor::new(100, 40, 7);
+        floor.spawn_enemies(&Difficulty::Normal, None);
+        let before: Vec<i32> = floor.enemies.iter().map(|e| e.max_health).collect();
+        assert!(!before.is_empty());
+
+        // A first run is untouched
+        floor.scale_enemies_for_new_game_plus(0);
+        let unchanged: Vec<i32> = floor.enemies.iter().map(|e| e.max_health).collect();
+        assert_eq!(unchanged, before);
+
+        floor.scale_enemies_for_new_game_plus(2);
+        for (enemy, base) in floor.enemies.iter().zip(before) {
+            assert!(enemy.max_health > base, "{} -> {}", base, enemy.max_health);
+            assert_eq!(enemy.health, enemy.max_health);
+        }
+    }
+
+    #[test]
+    fn test_status_effects_survive_save_and_load() {
+        use roguelite_dungeon::model::gamesave::{GameSave, StatusEffectData};
+        use roguelite_dungeon::model::status_effect::{StatusEffect, StatusEffectType};
+
+        let mut character = Character::default();
+        character.status_effects.add(StatusEffect::poison(4.5));
+        character
+            .status_effects
+            .add(StatusEffect::bleed_with_stacks(3));
+
+        let save = GameSave {
+            status_effects: StatusEffectData::capture(&character.status_effects),
+            ..GameSave::default()
+        };
+        let written = serde_json::to_string(&save).unwrap();
+        let reloaded = GameSave::from_json(&written).unwrap();
+        let mut restored = StatusEffectData::restore(&reloaded.status_effects);
+
+        let poison = restored
+            .effects
+            .iter()
+            .find(|e| e.effect_type == StatusEffectType::Poison)
+            .expect("poison restored");
+        assert_eq!(poison.duration, 4.5);
+        assert_eq!(poison.damage_per_sec, 1.0);
+        assert!(restored.has_effect(&StatusEffectType::Bleed));
+        assert_eq!(
+            restored.get_total_damage_per_sec(),
+            character.status_effects.get_total_damage_per_sec()
+        );
+        assert!(restored.take_newly_applied().is_empty());
+
+        // Saves from before status effects were stored load with none
+        let mut value = serde_json::to_value(GameSave::default()).unwrap();
+        value.as_object_mut().unwrap().remove("status_effects");
+        let old = GameSave::from_json(&value.to_string()).unwrap();
+        assert!(old.status_effects.is_empty());
+    }
+
+    #[test]
+    fn test_random_items_spawn_where_the_player_can_reach() {
+        for seed in [1u64, 7, 42, 99, 1234, 9001] {
+            let mut floor = Floor::new(120, 50, seed);
+            let spawn = floor.find_player_spawn().expect("floor has a spawn");
+            floor.spawn_random_items(10, &Difficulty::Normal, Some(spawn));
+            assert!(!floor.items.is_empty(), "seed {seed}: no items");
+
+            let reachable = floor.reachable_from(spawn);
+            for item in &floor.items {
+                assert!(
+                    reachable.contains(&(item.x, item.y)),
+                    "seed {seed}: item at ({}, {}) is unreachable",
+                    item.x,
+                    item.y
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_drop_all_keeps_only_equipped_and_unplaceable_weapons() {
+        use roguelite_dungeon::model::item::ItemDrop;
+        use roguelite_dungeon::model::weapon::WeaponInventory;
+
+        // Open room: the four tiles around the player take one weapon each
+        let mut floor = Floor::from_layout(&["#####", "#...#", "#...#", "#...#", "#####"], 0);
+        let (px, py) = (2, 2);
+        let mut inventory = WeaponInventory {
+            weapons: vec![
+                Weapon::new_sword(),
+                Weapon::new_bow(),
+                Weapon::new_mace(),
+                Weapon::quarterstaff(),
+                Weapon::new_sword(),
+                Weapon::new_bow(),
+            ],
+            current_weapon_index: 2,
+        };
+
+        let dropped = inventory.drop_all_but_current(|weapon| {
+            floor.try_drop_item_adjacent(ItemDrop::weapon(weapon.clone(), px, py), px, py)
+        });
+        assert_eq!(dropped, 4);
+        assert_eq!(floor.items.len(), 4);
+
+        // The mace stays equipped; the last bow found no free tile and stays too
+        let names: Vec<&str> = inventory.weapons.iter().map(|w| w.name.as_str()).collect();
+        assert_eq!(names, vec!["Stone Mace", "Wood Bow"]);
+        assert_eq!(inventory.current_weapon_index, 0);
+        assert_eq!(inventory.get_current_weapon().unwrap().name, "Stone Mace");
+    }
+
+    #[test]
+    fn test_scrapping_legendary_yields_more_gold_than_common() {
+        use roguelite_dungeon::model::item_tier::ItemTier;
+        use roguelite_dungeon::model::weapon::{WeaponInventory, WeaponType};
+
+        let scrap = |weapon: Weapon| {
+            let mut character = Character {
+                weapon_inventory: WeaponInventory {
+                    weapons: vec![Weapon::new_bow(), weapon],
+                    current_weapon_index: 1,
+                },
+                ..Character::default()
+            };
+            let gold = character.scrap_current_weapon().unwrap();
+            assert_eq!(character.gold, gold);
+            assert_eq!(character.weapon_inventory.weapons.len(), 1);
+            gold
+        };
+
+        let common = scrap(Weapon::for_type_and_rarity(
+            &WeaponType::Sword,
+            &ItemTier::Common,
+        ));
+        let legendary = scrap(Weapon::for_type_and_rarity(
+            &WeaponType::Sword,
+            &ItemTier::Legendary,
+        ));
+        assert!(legendary > common);
+    }
+
+    #[test]
+    fn test_moving_a_weapon_keeps_the_equipped_one_selected() {
+        use roguelite_dungeon::model::weapon::WeaponInventory;
+
+        let mut inventory = WeaponInventory {
+            weapons: vec![Weapon::new_sword(), Weapon::new_bow(), Weapon::new_mace()],
+            current_weapon_index: 2,
+        };
+        let names = |inventory: &WeaponInventory| -> Vec<String> {
+            inventory.weapons.iter().map(|w| w.name.clone()).collect()
+        };
+
+        // Moving the equipped mace to the top carries the selection with it
+        assert!(inventory.move_weapon(2, -1));
+        assert!(inventory.move_weapon(1, -1));
+        assert_eq!(
+            names(&inventory),
+            vec!["Stone Mace", "Iron Sword", "Wood Bow"]
+        );
+        assert_eq!(inventory.current_weapon_index, 0);
+
+        // Moving another weapon past it shifts the equipped index instead
+        inventory.current_weapon_index = 1;
+        assert!(inventory.move_weapon(2, -1));
+        assert_eq!(
+            names(&inventory),
+            vec!["Stone Mace", "Wood Bow", "Iron Sword"]
+        );
+        assert_eq!(inventory.get_current_weapon().unwrap().name, "Iron Sword");
+
+        // Nothing moves past either end
+        assert!(!inventory.move_weapon(0, -1));
+        assert!(!inventory.move_weapon(2, 1));
+    }
+
+    #[test]
+    fn test_finished_run_appends_history_record() {
+        use roguelite_dungeon::constants::RUN_HISTORY_MAX_RECORDS;
+        use roguelite_dungeon::model::run_history::{RunHistory, RunOutcome, RunRecord};
+
+        let path = std::env::temp_dir().join(format!("run_history_{}.json", std::process::id()));
+        let _ = std::fs::remove_file(&path);
+
+        let mut character = Character::new(1.0);
+        character.name = "Ash".to_string();
+        character.enemies_killed = 12;
+        let record = RunRecord::new(&character, "Hard", 5, 321.5, RunOutcome::Victory);
+        RunHistory::append_to(&path, record).unwrap();
+
+        let history = RunHistory::load_from(&path);
+        assert_eq!(history.records.len(), 1);
+        let saved = &history.records[0];
+        assert_eq!(saved.character_name, "Ash");
+        assert_eq!(saved.difficulty, "Hard");
+        assert_eq!(saved.floors_cleared, 5);
+        assert_eq!(saved.time_elapsed, 321.5);
+        assert_eq!(saved.enemies_killed, 12);
+        assert_eq!(saved.outcome, RunOutcome::Victory);
+        assert_eq!(saved.score, character.run_score(5));
+        assert!(saved.timestamp > 0);
+
+        // The history is capped, dropping the oldest runs first
+        for floors in 0..RUN_HISTORY_MAX_RECORDS as u32 {
+            let death = RunRecord::new(&character, "Hard", floors, 10.0, RunOutcome::Death);
+            RunHistory::append_to(&path, death).unwrap();
+        }
+        let history = RunHistory::load_from(&path);
+        let _ = std::fs::remove_file(&path);
+        assert_eq!(history.records.len(), RUN_HISTORY_MAX_RECORDS);
+        assert!(history
+            .records
+            .iter()
+            .all(|record| record.outcome == RunOutcome::Death));
+        assert_eq!(
+            history.by_score()[0].floors_cleared,
+            RUN_HISTORY_MAX_RECORDS as u32 - 1
+        );
+    }
+
+    #[test]
+    fn test_headless_runs_are_repeatable() {
+        use roguelite_dungeon::app::{App, AppState};
+
+        let mut first = App::headless(7, 120, 40);
+        let mut second = App::headless(7, 120, 40);
+        first.restart_game();
+        second.restart_game();
+
+        let (a, b) = (first.current_floor.as_ref(), second.current_floor.as_ref());
+        assert_eq!(a.map(|floor| floor.seed), b.map(|floor| floor.seed));
+        assert_eq!(first.character_position, second.character_position);
+
+        first.tick_n(30);
+        assert!(first.state == AppState::Game);
+    }
+
+    #[test]
+    fn test_camera_snap_setting_reaches_target_in_one_update() {
+        use roguelite_dungeon::app::App;
+
+        let mut app = App::headless(4, 120, 40);
+        app.restart_game();
+        let target = (42.0, 17.5);
+
+        app.settings.camera_snap = true;
+        app.camera_offset = (0.0, 0.0);
+        app.camera_target = target;
+        app.update_camera_smooth();
+        assert_eq!(app.camera_offset, target);
+
+        // Easing covers only part of the way
+        app.settings.camera_snap = false;
+        app.camera_offset = (0.0, 0.0);
+        app.update_camera_smooth();
+        assert!(app.camera_offset.0 > 0.0 && app.camera_offset.0 < target.0);
+    }
 }